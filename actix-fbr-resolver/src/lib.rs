@@ -4,14 +4,18 @@ use lazy_static::lazy_static;
 use regex::Regex;
 use walkdir::WalkDir;
 
-fn get_methods(file_path: &str) -> Vec<String> {
+/// Handler discovered from the routes directory.
+pub struct RouteHandler {
+	/// Path of the handler e.g. `routes::users::_id::get`.
+	pub path: String,
+	/// Predicates of the `cfg` attributes gating the handler, either the inner `#![cfg(...)]`
+	/// of the route file or `#[cfg(...)]` of the handler function itself.
+	pub cfg: Vec<String>,
+}
+
+fn get_methods(contents: &str) -> Vec<String> {
 	let mut handlers: Vec<String> = vec![];
 
-	let mut file = File::open(file_path).unwrap();
-	
-	let mut contents = String::new();
-	file.read_to_string(&mut contents).unwrap();
-	
 	if contents.contains("async fn get") {
 		handlers.push("get".into())
 	}
@@ -31,22 +35,94 @@ fn get_methods(file_path: &str) -> Vec<String> {
 	handlers
 }
 
-pub fn modules_path(routes_dir: &str) -> Vec<String> {
-	let entries = WalkDir::new(&routes_dir)
+fn get_file_cfg(contents: &str) -> Vec<String> {
+	lazy_static! {
+		static ref RE: Regex = Regex::new(r"(?m)^\s*#!\[cfg\((.*)\)\]\s*$").unwrap();
+	}
+
+	RE.captures_iter(contents).map(|it| it[1].trim().to_owned()).collect()
+}
+
+/// Find start of the attribute `#[...]` ending at the end of `source`.
+fn attribute_start(source: &str) -> Option<usize> {
+	if !source.ends_with(']') {
+		return None;
+	}
+	let mut depth = 0;
+	for (index, char) in source.char_indices().rev() {
+		match char {
+			']' => depth += 1,
+			'[' => {
+				depth -= 1;
+				if depth == 0 {
+					return source[..index].ends_with('#').then_some(index - 1);
+				}
+			}
+			_ => (),
+		}
+	}
+
+	None
+}
+
+/// Get `cfg` predicates of the attributes directly preceding the handler function of `method`.
+fn get_handler_cfg(contents: &str, method: &str) -> Vec<String> {
+	let mut cfg = vec![];
+	let index = match contents.find(&format!("async fn {method}")) {
+		Some(index) => index,
+		None => return cfg,
+	};
+	let mut before = contents[..index].trim_end().trim_end_matches("pub").trim_end();
+
+	loop {
+		let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+		if before[line_start..].trim_start().starts_with("//") {
+			before = before[..line_start].trim_end();
+		} else if let Some(start) = attribute_start(before) {
+			let attribute = &before[start..];
+			if let Some(predicate) = attribute.strip_prefix("#[cfg(").and_then(|it| it.strip_suffix(")]")) {
+				cfg.push(predicate.trim().to_owned());
+			}
+			before = before[..start].trim_end();
+		} else {
+			break;
+		}
+	}
+
+	cfg
+}
+
+pub fn route_handlers(routes_dir: &str) -> Vec<RouteHandler> {
+	WalkDir::new(routes_dir)
 		.into_iter()
 		.filter_map(|e| e.ok())
 		.filter(|it| it.file_type().is_file() && !it.file_name().to_str().unwrap().ends_with("mod.rs"))
-		.map(|it| {
+		.flat_map(|it| {
 			let path = it.path().to_string_lossy();
-			let methods = get_methods(&path);
-			let relative_path = path.replace(&routes_dir, "");
+			let mut file = File::open(path.as_ref())
+				.unwrap_or_else(|error| panic!("failed to open route file `{path}`: {error}"));
+			let mut contents = String::new();
+			file.read_to_string(&mut contents)
+				.unwrap_or_else(|error| panic!("failed to read route file `{path}`: {error}"));
+
+			let file_cfg = get_file_cfg(&contents);
+			let relative_path = path.replace(routes_dir, "");
 			let module_path = relative_path.replace("/", "::").replace(".rs", "");
 
-			methods.into_iter().map(|it| {
-				format!("routes{module_path}::{it}")
-			}).collect::<Vec<String>>()
-		}).flatten().collect::<Vec<String>>();
-	entries
+			get_methods(&contents).into_iter().map(|it| {
+				let mut cfg = file_cfg.clone();
+				cfg.extend(get_handler_cfg(&contents, &it));
+
+				RouteHandler {
+					path: format!("routes{module_path}::{it}"),
+					cfg,
+				}
+			}).collect::<Vec<RouteHandler>>()
+		}).collect::<Vec<RouteHandler>>()
+}
+
+pub fn modules_path(routes_dir: &str) -> Vec<String> {
+	route_handlers(routes_dir).into_iter().map(|it| it.path).collect()
 }
 
 pub fn actix_path(source_path_buf: PathBuf) -> String {
@@ -8,12 +8,31 @@ echo "Testing crate: $crate..."
 
 if [[ "$crate" == "utoipa" ]]; then
     cargo test -p utoipa --features openapi_extensions
+    cargo test -p utoipa --features axum_06,axum_07,actix_web_3,actix_web_4 --lib serve
 elif [[ "$crate" == "utoipa-gen" ]]; then
     cargo test -p utoipa-gen --features utoipa/actix_extras,chrono,decimal,utoipa/uuid,utoipa/time,time,utoipa/repr
 
     cargo test -p utoipa-gen --test path_derive_actix --test path_parameter_derive_actix --features actix_extras
     cargo test -p utoipa-gen --test path_derive_rocket --features rocket_extras
     cargo test -p utoipa-gen --test path_derive_axum_test --features axum_extras
+    cargo test -p utoipa-gen --test path_derive_poem_test --features poem_extras
+    cargo test -p utoipa-gen --test auto_collect_test --features utoipa/auto_collect,auto_collect
 elif [[ "$crate" == "utoipa-swagger-ui" ]]; then
-    cargo test -p utoipa-swagger-ui --features actix-web,rocket,axum
+    cargo test -p utoipa-swagger-ui --features actix-web,rocket,axum,tower,compression
+elif [[ "$crate" == "utoipa-rapidoc" ]]; then
+    cargo test -p utoipa-rapidoc --features actix-web,axum
+elif [[ "$crate" == "utoipa-redoc" ]]; then
+    cargo test -p utoipa-redoc --features actix-web,axum
+elif [[ "$crate" == "utoipa-tide" ]]; then
+    cargo test -p utoipa-tide
+elif [[ "$crate" == "utoipa-poem" ]]; then
+    cargo test -p utoipa-poem
+elif [[ "$crate" == "utoipa-warp" ]]; then
+    cargo test -p utoipa-warp
+elif [[ "$crate" == "utoipa-salvo" ]]; then
+    cargo test -p utoipa-salvo
+elif [[ "$crate" == "utoipa-axum" ]]; then
+    cargo test -p utoipa-axum
+elif [[ "$crate" == "utoipa-actix" ]]; then
+    cargo test -p utoipa-actix
 fi
@@ -0,0 +1,245 @@
+#![cfg_attr(doc_cfg, feature(doc_cfg))]
+//! This crate implements bindings between [`utoipa`][utoipa] and [`actix-web`][actix_web] for
+//! mounting services under a context path so that the served OpenAPI document matches the
+//! actual mount point.
+//!
+//! Handlers annotated with [`#[utoipa::path(...)]`][path] document their paths relative to the
+//! [`Scope`] they are registered to. With [`scope`] the routes are mounted under the scope and the
+//! given paths as well as the `servers` of the [`OpenApi`] document are prefixed with the same
+//! context path at the same time thus they cannot drift apart.
+//!
+//! [utoipa]: <https://docs.rs/utoipa/>
+//! [actix_web]: <https://docs.rs/actix-web/>
+//! [path]: <https://docs.rs/utoipa/latest/utoipa/attr.path.html>
+//!
+//! # Install
+//!
+//! ```toml
+//! [dependencies]
+//! utoipa = "2"
+//! utoipa-actix = "0.1"
+//! ```
+//!
+//! # Examples
+//!
+//! _**Mount users service under `/api/v1` scope.**_
+//! ```rust
+//! # use actix_web::{get, web, App};
+//! # use utoipa::{openapi::{Info, OpenApi, Paths}, Path};
+//! #[utoipa::path(
+//!     get,
+//!     path = "/users/{id}",
+//!     params(("id" = u64, Path)),
+//!     responses((status = 200, description = "Get user"))
+//! )]
+//! #[get("/users/{id}")]
+//! async fn get_user(id: web::Path<u64>) -> String {
+//!     format!("user {id}")
+//! }
+//!
+//! let mut openapi = OpenApi::new(Info::new("Users", "v1"), Paths::new());
+//! openapi
+//!     .paths
+//!     .add_path(__path_get_user::path(), __path_get_user::path_item(None));
+//! let app = App::new().service(utoipa_actix::scope(
+//!     "/api/v1",
+//!     &mut openapi,
+//!     [__path_get_user::path()],
+//!     |config| {
+//!         config.service(get_user);
+//!     },
+//! ));
+//!
+//! assert!(openapi.paths.get_path_item("/api/v1/users/{id}").is_some());
+//! ```
+use actix_web::{
+    web::{self, ServiceConfig},
+    Scope,
+};
+use utoipa::openapi::OpenApi;
+
+/// Create [`Scope`] for given `path` configured with `config` and prefix the `paths` and the
+/// `servers` of the given [`OpenApi`] with the `path` as context path.
+///
+/// The `paths` are the document paths of the handlers registered to the scope e.g.
+/// _`__path_get_user::path()`_. Other paths of the [`OpenApi`] are left untouched thus the same
+/// document can hold services mounted to other scopes or to the root of the app. Prefixed path
+/// already found from the document is merged the same way as with
+/// [`Paths::add_path`][utoipa::openapi::path::Paths::add_path]. Trailing slash of the `path` is
+/// ignored.
+///
+/// Applying the context path is idempotent. Paths not found from the [`OpenApi`] e.g. because
+/// they are already prefixed are skipped and `servers` already ending with the context path are
+/// left as is. Thus `scope` can be called from the `HttpServer::new` app factory which actix-web
+/// calls once per worker.
+///
+/// # Examples
+///
+/// _**Prefix only the paths of the scope.**_
+/// ```rust
+/// # use actix_web::{web, App, HttpResponse};
+/// # use utoipa::openapi::{Info, OpenApi, PathItem, PathItemType, Paths, path::OperationBuilder};
+/// let mut openapi = OpenApi::new(Info::new("Users", "v1"), Paths::new());
+/// openapi.paths.add_path("/users", PathItem::new(PathItemType::Get, OperationBuilder::new()));
+/// openapi.paths.add_path("/health", PathItem::new(PathItemType::Get, OperationBuilder::new()));
+///
+/// let scope = utoipa_actix::scope("/api/v1", &mut openapi, ["/users"], |config| {
+///     config.route("/users", web::get().to(HttpResponse::Ok));
+/// });
+///
+/// assert!(openapi.paths.get_path_item("/api/v1/users").is_some());
+/// assert!(openapi.paths.get_path_item("/health").is_some());
+/// ```
+pub fn scope<F, I, P>(path: &str, openapi: &mut OpenApi, paths: I, config: F) -> Scope
+where
+    F: FnOnce(&mut ServiceConfig),
+    I: IntoIterator<Item = P>,
+    P: AsRef<str>,
+{
+    let path = path.trim_end_matches('/');
+    apply_context_path(path, openapi, paths);
+
+    web::scope(path).configure(config)
+}
+
+fn apply_context_path<I, P>(path: &str, openapi: &mut OpenApi, paths: I)
+where
+    I: IntoIterator<Item = P>,
+    P: AsRef<str>,
+{
+    if path.is_empty() {
+        return;
+    }
+
+    let items = paths
+        .into_iter()
+        .filter_map(|item_path| {
+            let item_path = item_path.as_ref();
+            let path_item = openapi.paths.paths.remove(item_path)?;
+
+            Some((format!("{path}{item_path}"), path_item))
+        })
+        .collect::<Vec<_>>();
+
+    for (item_path, path_item) in items {
+        openapi.paths.add_path(item_path, path_item);
+    }
+
+    for server in openapi.servers.iter_mut().flatten() {
+        let url = server.url.trim_end_matches('/');
+        if !url.ends_with(path) {
+            server.url = format!("{url}{path}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::{
+        get,
+        http::StatusCode,
+        test::{call_service, init_service, read_body, TestRequest},
+        App,
+    };
+    use utoipa::{
+        openapi::{path::OperationBuilder, Info, PathItem, PathItemType, Paths, Server},
+        Path,
+    };
+
+    use super::*;
+
+    #[utoipa::path(
+        get,
+        path = "/users/{id}",
+        params(("id" = u64, Path)),
+        responses((status = 200, description = "Get user"))
+    )]
+    #[get("/users/{id}")]
+    async fn get_user(id: web::Path<u64>) -> String {
+        format!("user {id}")
+    }
+
+    fn openapi() -> OpenApi {
+        let mut openapi = OpenApi::new(Info::new("title", "1.0.0"), Paths::new());
+        openapi
+            .paths
+            .add_path(__path_get_user::path(), __path_get_user::path_item(None));
+
+        openapi
+    }
+
+    #[actix_web::test]
+    async fn mount_scope_and_prefix_openapi_paths() {
+        let mut openapi = openapi();
+        let app = init_service(App::new().service(scope(
+            "/api/v1/",
+            &mut openapi,
+            [__path_get_user::path()],
+            |config| {
+                config.service(get_user);
+            },
+        )))
+        .await;
+
+        assert_eq!(
+            openapi.paths.paths.keys().collect::<Vec<_>>(),
+            ["/api/v1/users/{id}"]
+        );
+
+        let response =
+            call_service(&app, TestRequest::get().uri("/api/v1/users/1").to_request()).await;
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(read_body(response).await, "user 1");
+
+        let response = call_service(&app, TestRequest::get().uri("/users/1").to_request()).await;
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[test]
+    fn apply_context_path_only_to_given_paths() {
+        let mut openapi = openapi();
+        openapi.servers = Some(vec![Server::new("https://example.com")]);
+        openapi.paths.add_path(
+            "/health",
+            PathItem::new(PathItemType::Get, OperationBuilder::new()),
+        );
+
+        apply_context_path("/api/v1", &mut openapi, ["/users/{id}"]);
+
+        assert_eq!(
+            openapi.paths.paths.keys().collect::<Vec<_>>(),
+            ["/api/v1/users/{id}", "/health"]
+        );
+        assert_eq!(
+            openapi.servers.unwrap()[0].url,
+            "https://example.com/api/v1"
+        );
+    }
+
+    #[test]
+    fn apply_empty_context_path() {
+        let mut openapi = openapi();
+        openapi.servers = Some(vec![Server::new("/")]);
+
+        apply_context_path("", &mut openapi, ["/users/{id}"]);
+
+        assert!(openapi.paths.get_path_item("/users/{id}").is_some());
+        assert_eq!(openapi.servers.unwrap()[0].url, "/");
+    }
+
+    #[test]
+    fn apply_context_path_is_idempotent() {
+        let mut openapi = openapi();
+        openapi.servers = Some(vec![Server::new("/")]);
+
+        for _ in 0..2 {
+            apply_context_path("/api/v1", &mut openapi, ["/users/{id}", "/pets"]);
+        }
+
+        assert_eq!(
+            openapi.paths.paths.keys().collect::<Vec<_>>(),
+            ["/api/v1/users/{id}"]
+        );
+        assert_eq!(openapi.servers.unwrap()[0].url, "/api/v1");
+    }
+}
@@ -0,0 +1,416 @@
+#![cfg_attr(doc_cfg, feature(doc_cfg))]
+//! This crate implements bindings between [`utoipa`][utoipa] and [`axum`][axum] for registering
+//! the routes and their OpenAPI documentation together.
+//!
+//! Handlers annotated with [`#[utoipa::path(...)]`][path] are registered to
+//! [`OpenApiRouter`][router] with [`routes!`] macro. The router uses the path and the HTTP
+//! methods of the [`#[utoipa::path(...)]`][path] attribute to route the requests and collects
+//! the operations to [`OpenApi`][openapi] document. This way the handlers do not need to be
+//! listed in _`#[openapi(paths(...))]`_ and the documented paths cannot drift apart from the
+//! actual routes.
+//!
+//! [utoipa]: <https://docs.rs/utoipa/>
+//! [axum]: <https://docs.rs/axum/>
+//! [path]: <https://docs.rs/utoipa/latest/utoipa/attr.path.html>
+//! [router]: router/struct.OpenApiRouter.html
+//! [openapi]: <https://docs.rs/utoipa/latest/utoipa/openapi/struct.OpenApi.html>
+//!
+//! # Install
+//!
+//! ```toml
+//! [dependencies]
+//! utoipa = "2"
+//! utoipa-axum = "0.1"
+//! ```
+//!
+//! # Examples
+//!
+//! _**Collect OpenAPI of users routes nested under `/api/v1` path.**_
+//! ```rust
+//! # use utoipa::OpenApi;
+//! # use utoipa_axum::{router::OpenApiRouter, routes};
+//! #[derive(OpenApi)]
+//! #[openapi(info(title = "Users API"))]
+//! struct ApiDoc;
+//!
+//! #[utoipa::path(get, path = "/users", responses((status = 200, description = "List users")))]
+//! async fn list_users() {}
+//!
+//! #[utoipa::path(get, path = "/users/{id}", params(("id" = u64, Path)))]
+//! async fn get_user() {}
+//!
+//! #[utoipa::path(delete, path = "/users/{id}", params(("id" = u64, Path)))]
+//! async fn delete_user() {}
+//!
+//! let users = OpenApiRouter::new()
+//!     .routes(routes!(list_users))
+//!     .routes(routes!(get_user, delete_user));
+//!
+//! let (router, openapi): (axum::Router, _) = OpenApiRouter::with_openapi(ApiDoc::openapi())
+//!     .nest("/api/v1", users)
+//!     .split_for_parts();
+//!
+//! assert!(openapi.paths.get_path_item("/api/v1/users/{id}").is_some());
+//! ```
+use axum::routing::MethodFilter;
+use utoipa::openapi::path::PathItemType;
+
+pub mod router;
+
+/// Collect handlers annotated with [`#[utoipa::path(...)]`][path] to
+/// [`UtoipaMethodRouter`][method_router] for [`OpenApiRouter::routes`][routes].
+///
+/// All the handlers must share the same path but they can be defined for different HTTP
+/// methods e.g. _`routes!(get_user, update_user, delete_user)`_. Handlers can be referred with
+/// module path e.g. _`routes!(users::get_user)`_. Handlers defined as methods of a type are
+/// not supported and handlers of _`CONNECT`_ requests are rejected at compile time since axum
+/// cannot route them.
+///
+/// [path]: <https://docs.rs/utoipa/latest/utoipa/attr.path.html>
+/// [method_router]: router/type.UtoipaMethodRouter.html
+/// [routes]: router/struct.OpenApiRouter.html#method.routes
+#[macro_export]
+macro_rules! routes {
+    ( $( $( $handler:ident )::+ ),+ $(,)? ) => {
+        {
+            let mut paths = $crate::__dev::utoipa::openapi::path::Paths::new();
+            let mut method_router = $crate::__dev::axum::routing::MethodRouter::new();
+            $(
+                let (path, path_item) = $crate::routes!( @path_item [] $( $handler )+ );
+                let method_filter = path_item
+                    .operations
+                    .keys()
+                    .filter_map($crate::method_filter)
+                    .fold(
+                        $crate::__dev::axum::routing::MethodFilter::empty(),
+                        |filter, method| filter | method,
+                    );
+                method_router = method_router.on(method_filter, $( $handler )::+);
+                paths.add_path(path, path_item);
+            )+
+            (paths, method_router)
+        }
+    };
+    ( @path_item [ $( $module:ident )* ] $name:ident $( $rest:ident )+ ) => {
+        $crate::routes!( @path_item [ $( $module )* $name ] $( $rest )+ )
+    };
+    ( @path_item [ $( $module:ident )* ] $name:ident ) => {
+        $crate::__dev::paste! {
+            {
+                const _: () = if $crate::__dev::has_connect(
+                    <$( $module:: )* [<__path_ $name>] as $crate::__dev::utoipa::Path>::PATH_ITEM_TYPES,
+                ) {
+                    panic!(concat!(
+                        "`",
+                        stringify!($name),
+                        "` cannot be routed since axum does not support routing CONNECT requests",
+                    ));
+                };
+
+                (
+                    <$( $module:: )* [<__path_ $name>] as $crate::__dev::utoipa::Path>::path(),
+                    <$( $module:: )* [<__path_ $name>] as $crate::__dev::utoipa::Path>::path_item(None),
+                )
+            }
+        }
+    };
+}
+
+/// Get [`MethodFilter`] of given [`PathItemType`]. Returns `None` with
+/// [`PathItemType::Connect`] since [`axum`] does not support routing of _`CONNECT`_ requests
+/// with [`MethodFilter`]. Handlers for _`CONNECT`_ requests are rejected at compile time by
+/// [`routes!`].
+pub fn method_filter(path_item_type: &PathItemType) -> Option<MethodFilter> {
+    match path_item_type {
+        PathItemType::Get => Some(MethodFilter::GET),
+        PathItemType::Post => Some(MethodFilter::POST),
+        PathItemType::Put => Some(MethodFilter::PUT),
+        PathItemType::Delete => Some(MethodFilter::DELETE),
+        PathItemType::Options => Some(MethodFilter::OPTIONS),
+        PathItemType::Head => Some(MethodFilter::HEAD),
+        PathItemType::Patch => Some(MethodFilter::PATCH),
+        PathItemType::Trace => Some(MethodFilter::TRACE),
+        PathItemType::Connect => None,
+    }
+}
+
+/// Format OpenAPI path template to [`axum`] route path e.g. _`/users/{id}`_ to _`/users/:id`_.
+fn to_axum_path(path: &str) -> String {
+    path.split('/')
+        .map(|segment| {
+            match segment
+                .strip_prefix('{')
+                .and_then(|segment| segment.strip_suffix('}'))
+            {
+                Some(name) => format!(":{name}"),
+                None => segment.to_string(),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Format [`axum`] route path to OpenAPI path template e.g. _`/users/:id`_ to _`/users/{id}`_.
+fn to_openapi_path(path: &str) -> String {
+    path.split('/')
+        .map(|segment| {
+            match segment
+                .strip_prefix(':')
+                .or_else(|| segment.strip_prefix('*'))
+            {
+                Some(name) => format!("{{{name}}}"),
+                None => segment.to_string(),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+#[doc(hidden)]
+pub mod __dev {
+    pub use axum;
+    pub use paste::paste;
+    pub use utoipa;
+
+    use utoipa::openapi::PathItemType;
+
+    /// Check whether given path item types have _`CONNECT`_ which cannot be routed with axum.
+    /// This is evaluated at compile time to reject such handlers in [`routes!`][crate::routes].
+    pub const fn has_connect(path_item_types: &[PathItemType]) -> bool {
+        let mut index = 0;
+        while index < path_item_types.len() {
+            if matches!(path_item_types[index], PathItemType::Connect) {
+                return true;
+            }
+            index += 1;
+        }
+
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{
+        body::Body,
+        extract::Path,
+        http::{Method, Request, StatusCode},
+    };
+    use tower::ServiceExt;
+
+    use super::*;
+    use crate::router::OpenApiRouter;
+
+    mod users {
+        use axum::extract::Path;
+
+        #[utoipa::path(get, path = "/users/{id}", params(("id" = u64, Path)))]
+        pub async fn get_user(Path(id): Path<u64>) -> String {
+            format!("user {id}")
+        }
+
+        #[utoipa::path(delete, path = "/users/{id}", params(("id" = u64, Path)))]
+        pub async fn delete_user(Path(id): Path<u64>) -> String {
+            format!("deleted {id}")
+        }
+    }
+
+    #[utoipa::path(get, path = "/pets/{id}", params(("id" = u64, Path)))]
+    async fn get_pet(Path(id): Path<u64>) -> String {
+        format!("pet {id}")
+    }
+
+    #[utoipa::path(post, path = "/pets")]
+    async fn create_pet() -> StatusCode {
+        StatusCode::CREATED
+    }
+
+    mod orgs {
+        use axum::extract::Path;
+
+        #[utoipa::path(get, path = "/")]
+        pub async fn list_orgs() -> &'static str {
+            "orgs"
+        }
+
+        #[utoipa::path(
+            get,
+            path = "/members/{id}",
+            params(("org_id" = u64, Path), ("id" = u64, Path))
+        )]
+        pub async fn get_member(Path((org_id, id)): Path<(u64, u64)>) -> String {
+            format!("org {org_id} member {id}")
+        }
+    }
+
+    fn openapi_with_schema(name: &str) -> utoipa::openapi::OpenApi {
+        utoipa::openapi::OpenApiBuilder::new()
+            .components(Some(
+                utoipa::openapi::ComponentsBuilder::new()
+                    .schema(name, utoipa::openapi::ObjectBuilder::new())
+                    .build(),
+            ))
+            .build()
+    }
+
+    async fn call(router: axum::Router, method: Method, uri: &str) -> (StatusCode, String) {
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(method)
+                    .uri(uri)
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        let status = response.status();
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+
+        (status, String::from_utf8(body.to_vec()).unwrap())
+    }
+
+    #[test]
+    fn format_paths_between_openapi_and_axum() {
+        assert_eq!(
+            to_axum_path("/users/{id}/pets/{pet_id}"),
+            "/users/:id/pets/:pet_id"
+        );
+        assert_eq!(to_axum_path("/users"), "/users");
+        assert_eq!(to_openapi_path("/orgs/:org_id"), "/orgs/{org_id}");
+        assert_eq!(to_openapi_path("/api/v1"), "/api/v1");
+    }
+
+    #[tokio::test]
+    async fn route_handlers_by_their_path_attributes() {
+        let (router, openapi) = OpenApiRouter::<()>::new()
+            .routes(routes!(users::get_user, users::delete_user))
+            .routes(routes!(get_pet))
+            .split_for_parts();
+
+        assert_eq!(
+            call(router.clone(), Method::GET, "/users/1").await,
+            (StatusCode::OK, "user 1".to_string())
+        );
+        assert_eq!(
+            call(router.clone(), Method::DELETE, "/users/2").await,
+            (StatusCode::OK, "deleted 2".to_string())
+        );
+        assert_eq!(
+            call(router.clone(), Method::POST, "/users/2").await.0,
+            StatusCode::METHOD_NOT_ALLOWED
+        );
+        assert_eq!(
+            call(router, Method::GET, "/pets/3").await,
+            (StatusCode::OK, "pet 3".to_string())
+        );
+
+        assert_eq!(
+            openapi.paths.paths.keys().collect::<Vec<_>>(),
+            ["/pets/{id}", "/users/{id}"]
+        );
+        assert_eq!(
+            openapi
+                .paths
+                .get_path_operation("/users/{id}", PathItemType::Delete)
+                .and_then(|operation| operation.operation_id.as_deref()),
+            Some("delete_user")
+        );
+    }
+
+    #[tokio::test]
+    async fn nest_and_merge_routers_with_openapi() {
+        let users = OpenApiRouter::new().routes(routes!(users::get_user));
+        let pets = OpenApiRouter::new().routes(routes!(get_pet));
+        let more_pets = OpenApiRouter::new().routes(routes!(create_pet));
+
+        let (router, openapi) = OpenApiRouter::<()>::new()
+            .nest("/api/v1", users.merge(pets))
+            .merge(OpenApiRouter::new().nest("/api/v1", more_pets))
+            .route(
+                "/health",
+                axum::routing::get(|| async { StatusCode::NO_CONTENT }),
+            )
+            .split_for_parts();
+
+        assert_eq!(
+            call(router.clone(), Method::GET, "/api/v1/pets/3").await,
+            (StatusCode::OK, "pet 3".to_string())
+        );
+        assert_eq!(
+            call(router.clone(), Method::POST, "/api/v1/pets").await.0,
+            StatusCode::CREATED
+        );
+        assert_eq!(
+            call(router, Method::GET, "/health").await.0,
+            StatusCode::NO_CONTENT
+        );
+
+        assert_eq!(
+            openapi.paths.paths.keys().collect::<Vec<_>>(),
+            ["/api/v1/pets", "/api/v1/pets/{id}", "/api/v1/users/{id}"]
+        );
+    }
+
+    #[tokio::test]
+    async fn nest_routers_deeply_with_openapi_components() {
+        let members = OpenApiRouter::with_openapi(openapi_with_schema("Member"))
+            .routes(routes!(orgs::get_member));
+        let orgs = OpenApiRouter::with_openapi(openapi_with_schema("Org"))
+            .routes(routes!(orgs::list_orgs))
+            .nest("/:org_id/", members);
+
+        let (router, openapi) = OpenApiRouter::<()>::new()
+            .nest("/api", OpenApiRouter::new().nest("/v1/orgs", orgs))
+            .split_for_parts();
+
+        assert_eq!(
+            call(router.clone(), Method::GET, "/api/v1/orgs").await,
+            (StatusCode::OK, "orgs".to_string())
+        );
+        assert_eq!(
+            call(router, Method::GET, "/api/v1/orgs/1/members/2").await,
+            (StatusCode::OK, "org 1 member 2".to_string())
+        );
+
+        assert_eq!(
+            openapi.paths.paths.keys().collect::<Vec<_>>(),
+            ["/api/v1/orgs", "/api/v1/orgs/{org_id}/members/{id}"]
+        );
+        assert_eq!(
+            openapi
+                .components
+                .map(|components| components.schemas.into_keys().collect::<Vec<_>>()),
+            Some(vec!["Member".to_string(), "Org".to_string()])
+        );
+    }
+
+    #[test]
+    fn nest_router_merges_operations_of_existing_paths() {
+        let mut api_doc = utoipa::openapi::OpenApi::default();
+        api_doc.paths.add_path(
+            "/api/v1/users/{id}",
+            <users::__path_get_user as utoipa::Path>::path_item(None),
+        );
+        let openapi = OpenApiRouter::<()>::with_openapi(api_doc)
+            .nest(
+                "/api/v1",
+                OpenApiRouter::new().routes(routes!(users::delete_user)),
+            )
+            .into_openapi();
+
+        assert_eq!(
+            openapi
+                .paths
+                .get_path_item("/api/v1/users/{id}")
+                .map(|path_item| path_item.operations.len()),
+            Some(2)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "handlers of routes!(...) must share the same path")]
+    fn routes_with_different_paths_panics() {
+        let _ = OpenApiRouter::<()>::new().routes(routes!(get_pet, create_pet));
+    }
+}
@@ -0,0 +1,206 @@
+//! Implements [`OpenApiRouter`] for registering [`axum`] routes together with their OpenAPI
+//! documentation.
+
+use std::convert::Infallible;
+
+use axum::{
+    body::Body,
+    http::Request,
+    response::IntoResponse,
+    routing::{MethodRouter, Route},
+    Router,
+};
+use tower_layer::Layer;
+use tower_service::Service;
+use utoipa::openapi::{path::Paths, OpenApi};
+
+/// Handlers of a single path created with [`routes!`][routes] macro. It holds the OpenAPI
+/// [`Paths`] of the handlers and the [`MethodRouter`] routing requests to them.
+///
+/// [routes]: ../macro.routes.html
+pub type UtoipaMethodRouter<S = ()> = (Paths, MethodRouter<S, Body>);
+
+/// Wrapper around [`axum::Router`] collecting OpenAPI documentation of the routes registered
+/// with [`OpenApiRouter::routes`].
+///
+/// Routes are registered by the path defined in [`#[utoipa::path(...)]`][path] of the handlers
+/// thus the served route and the documented path cannot drift apart. When the router is ready
+/// use [`OpenApiRouter::split_for_parts`] to get the [`axum::Router`] and the collected
+/// [`OpenApi`] for serving.
+///
+/// # Examples
+///
+/// _**Register handlers and serve the collected OpenAPI document.**_
+/// ```rust
+/// # use axum::{routing, Json};
+/// # use utoipa::openapi::OpenApi;
+/// # use utoipa_axum::{router::OpenApiRouter, routes};
+/// #[utoipa::path(get, path = "/users/{id}", params(("id" = u64, Path)))]
+/// async fn get_user() {}
+///
+/// #[utoipa::path(delete, path = "/users/{id}", params(("id" = u64, Path)))]
+/// async fn delete_user() {}
+///
+/// let (router, openapi) = OpenApiRouter::<()>::new()
+///     .routes(routes!(get_user, delete_user))
+///     .split_for_parts();
+///
+/// let router = router.route(
+///     "/api-docs/openapi.json",
+///     routing::get(move || async { Json(openapi) }),
+/// );
+/// ```
+///
+/// [path]: https://docs.rs/utoipa/latest/utoipa/attr.path.html
+#[derive(Clone)]
+pub struct OpenApiRouter<S = ()>(Router<S, Body>, OpenApi);
+
+impl<S> OpenApiRouter<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    /// Construct a new [`OpenApiRouter`] with empty [`OpenApi`] document.
+    pub fn new() -> Self {
+        Self::with_openapi(OpenApi::default())
+    }
+
+    /// Construct a new [`OpenApiRouter`] with given [`OpenApi`] e.g. one derived with
+    /// _`#[derive(OpenApi)]`_ holding info, components and tags of the API. Paths of the
+    /// registered routes are added to this document.
+    pub fn with_openapi(openapi: OpenApi) -> Self {
+        Self(Router::new(), openapi)
+    }
+
+    /// Register handlers created with [`routes!`][routes] macro. Handlers are routed by the
+    /// path and methods defined in their [`#[utoipa::path(...)]`][path] attribute and the
+    /// operations are added to the [`OpenApi`] of the router.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the handlers do not share the same path or if the route is not accepted by
+    /// [`axum::Router::route`].
+    ///
+    /// [routes]: ../macro.routes.html
+    /// [path]: https://docs.rs/utoipa/latest/utoipa/attr.path.html
+    pub fn routes(self, (paths, method_router): UtoipaMethodRouter<S>) -> Self {
+        let mut path_keys = paths.paths.keys();
+        let path = path_keys
+            .next()
+            .expect("routes!(...) must have at least one handler");
+        if let Some(other_path) = path_keys.next() {
+            panic!(
+                "handlers of routes!(...) must share the same path, found: {path} and {other_path}"
+            );
+        }
+
+        let router = self.0.route(&crate::to_axum_path(path), method_router);
+
+        let mut openapi = self.1;
+        openapi.paths.merge(paths);
+
+        Self(router, openapi)
+    }
+
+    /// Register undocumented route with [`axum::Router::route`]. The route is not added to the
+    /// [`OpenApi`] of the router.
+    pub fn route(self, path: &str, method_router: MethodRouter<S, Body>) -> Self {
+        Self(self.0.route(path, method_router), self.1)
+    }
+
+    /// Nest `router` under given `path` with [`axum::Router::nest`].
+    ///
+    /// Paths of the nested router's [`OpenApi`] are prefixed with the `path` thus routers can be
+    /// nested arbitrarily deep and the documented paths still match the served routes. Path
+    /// parameters of the `path` e.g. _`"/orgs/:org_id"`_ are formatted to OpenAPI path
+    /// templates and the root route _`"/"`_ of the nested router is documented at the `path`
+    /// itself the same way [`axum`] routes it. Operations of paths found from both documents are
+    /// merged and the rest of the nested router's [`OpenApi`] e.g. components and tags is merged
+    /// with [`OpenApi::merge`].
+    pub fn nest(self, path: &str, router: OpenApiRouter<S>) -> Self {
+        let OpenApiRouter(other_router, mut other_openapi) = router;
+
+        let prefix = crate::to_openapi_path(path.trim_end_matches('/'));
+        let mut openapi = self.1;
+        for (item_path, path_item) in std::mem::take(&mut other_openapi.paths.paths) {
+            let item_path = match &*item_path {
+                "/" if !prefix.is_empty() => prefix.clone(),
+                _ => format!("{prefix}{item_path}"),
+            };
+            openapi.paths.add_path(item_path, path_item);
+        }
+        openapi.merge(other_openapi);
+
+        Self(self.0.nest(path, other_router), openapi)
+    }
+
+    /// Merge routes of `router` to this router with [`axum::Router::merge`]. Operations of
+    /// paths found from both documents are merged and the rest of the `router`'s [`OpenApi`] is
+    /// merged with [`OpenApi::merge`].
+    pub fn merge(self, router: OpenApiRouter<S>) -> Self {
+        let OpenApiRouter(other_router, mut other_openapi) = router;
+
+        let mut openapi = self.1;
+        openapi
+            .paths
+            .merge(std::mem::take(&mut other_openapi.paths));
+        openapi.merge(other_openapi);
+
+        Self(self.0.merge(other_router), openapi)
+    }
+
+    /// Apply [`tower_layer::Layer`] to all routes of the router with [`axum::Router::layer`].
+    pub fn layer<L>(self, layer: L) -> Self
+    where
+        L: Layer<Route<Body>> + Clone + Send + 'static,
+        L::Service: Service<Request<Body>> + Clone + Send + 'static,
+        <L::Service as Service<Request<Body>>>::Response: IntoResponse + 'static,
+        <L::Service as Service<Request<Body>>>::Error: Into<Infallible> + 'static,
+        <L::Service as Service<Request<Body>>>::Future: Send + 'static,
+    {
+        Self(self.0.layer(layer), self.1)
+    }
+
+    /// Provide the state for the router with [`axum::Router::with_state`].
+    pub fn with_state<S2>(self, state: S) -> OpenApiRouter<S2> {
+        OpenApiRouter(self.0.with_state(state), self.1)
+    }
+
+    /// Get reference to the [`OpenApi`] collected so far.
+    pub fn get_openapi(&self) -> &OpenApi {
+        &self.1
+    }
+
+    /// Consume the router and return the collected [`OpenApi`].
+    pub fn into_openapi(self) -> OpenApi {
+        self.1
+    }
+
+    /// Split the router to the [`axum::Router`] and the collected [`OpenApi`].
+    pub fn split_for_parts(self) -> (Router<S, Body>, OpenApi) {
+        (self.0, self.1)
+    }
+}
+
+impl<S> Default for OpenApiRouter<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S> From<Router<S, Body>> for OpenApiRouter<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    fn from(router: Router<S, Body>) -> Self {
+        Self(router, OpenApi::default())
+    }
+}
+
+impl<S> From<OpenApiRouter<S>> for Router<S, Body> {
+    fn from(router: OpenApiRouter<S>) -> Self {
+        router.0
+    }
+}
@@ -0,0 +1,98 @@
+use proc_macro2::{Ident, TokenStream};
+use proc_macro_error::ResultExt;
+use quote::{quote, ToTokens};
+use syn::{parenthesized, parse::Parse, Attribute, Generics, LitStr, Token};
+
+use crate::{doc_comment::CommentAttributes, parse_utils, ExternalDocs};
+
+pub struct ApiTag {
+    pub attributes: Vec<Attribute>,
+    pub ident: Ident,
+    pub generics: Generics,
+}
+
+#[derive(Default)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+struct ApiTagAttr {
+    name: Option<LitStr>,
+    description: Option<LitStr>,
+    external_docs: Option<ExternalDocs>,
+}
+
+impl Parse for ApiTagAttr {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        const EXPECTED_ATTRIBUTE: &str =
+            "unexpected attribute, expected any of: name, description, external_docs";
+
+        let mut attr = ApiTagAttr::default();
+
+        while !input.is_empty() {
+            let ident = input.parse::<Ident>().map_err(|error| {
+                syn::Error::new(error.span(), format!("{EXPECTED_ATTRIBUTE}, {error}"))
+            })?;
+
+            match &*ident.to_string() {
+                "name" => attr.name = Some(parse_utils::parse_next(input, || input.parse())?),
+                "description" => {
+                    attr.description = Some(parse_utils::parse_next(input, || input.parse())?)
+                }
+                "external_docs" => {
+                    let external_docs;
+                    parenthesized!(external_docs in input);
+                    attr.external_docs = Some(external_docs.parse()?);
+                }
+                _ => return Err(syn::Error::new(ident.span(), EXPECTED_ATTRIBUTE)),
+            }
+
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+        }
+
+        Ok(attr)
+    }
+}
+
+impl ToTokens for ApiTag {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let attr = self
+            .attributes
+            .iter()
+            .filter(|attribute| attribute.path.is_ident("tag"))
+            .map(|attribute| attribute.parse_args::<ApiTagAttr>().unwrap_or_abort())
+            .next_back()
+            .unwrap_or_default();
+
+        let ident = &self.ident;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+        let name = attr
+            .name
+            .unwrap_or_else(|| LitStr::new(&ident.to_string(), ident.span()));
+
+        let description = attr
+            .description
+            .map(|description| description.value())
+            .or_else(|| {
+                Some(CommentAttributes::from_attributes(&self.attributes).as_formatted_string())
+                    .filter(|description| !description.is_empty())
+            })
+            .map(|description| quote! { .description(Some(#description)) });
+        let external_docs = attr
+            .external_docs
+            .map(|external_docs| quote! { .external_docs(Some(#external_docs)) });
+
+        tokens.extend(quote! {
+            impl #impl_generics utoipa::ApiTag for #ident #ty_generics #where_clause {
+                const NAME: &'static str = #name;
+
+                fn tag() -> utoipa::openapi::tag::Tag {
+                    utoipa::openapi::tag::TagBuilder::new()
+                        .name(Self::NAME)
+                        #description
+                        #external_docs
+                        .build()
+                }
+            }
+        })
+    }
+}
@@ -286,6 +286,24 @@ impl<'t> TypeTree<'t> {
     pub fn is_object(&self) -> bool {
         self.is("Object")
     }
+
+    /// Check whether [`TypeTree`] is a byte buffer such as `Vec<u8>`, `[u8]` or `bytes::Bytes`
+    /// optionally wrapped in `Option`.
+    pub fn is_byte_buffer(&self) -> bool {
+        let first_child = || self.children.as_ref().and_then(|children| children.first());
+
+        match self.generic_type {
+            Some(GenericType::Option) => first_child().is_some_and(TypeTree::is_byte_buffer),
+            Some(GenericType::Vec) => first_child()
+                .and_then(|child| child.path.as_deref())
+                .is_some_and(|path| SchemaType(path).is_byte()),
+            None => self
+                .path
+                .as_deref()
+                .is_some_and(|path| SchemaType(path).is_bytes()),
+            Some(_) => false,
+        }
+    }
 }
 
 #[cfg(not(feature = "debug"))]
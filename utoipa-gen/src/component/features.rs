@@ -6,18 +6,24 @@ use quote::{quote, ToTokens};
 use syn::{
     parenthesized,
     parse::{Parse, ParseStream},
-    LitFloat, LitInt, LitStr, TypePath,
+    LitFloat, LitInt, LitStr, Token, TypePath,
 };
 
 use crate::{
     parse_utils,
-    path::parameter::{self, ParameterStyle},
+    path::{
+        self,
+        parameter::{self, ParameterStyle},
+    },
     schema_type::{SchemaFormat, SchemaType},
-    AnyValue,
+    AnyValue, Array,
 };
 
 use super::{schema, serde::RenameRule, GenericType, TypeTree};
 
+#[cfg(feature = "validator")]
+pub mod validator;
+
 /// Parse `LitInt` from parse stream
 fn parse_integer<T: FromStr + Display>(input: ParseStream) -> syn::Result<T>
 where
@@ -87,6 +93,7 @@ pub trait Validate: Validatable {
 #[derive(Clone)]
 pub enum Feature {
     Example(Example),
+    Examples(Examples),
     Default(Default),
     Inline(Inline),
     XmlAttr(XmlAttr),
@@ -100,6 +107,7 @@ pub enum Feature {
     RenameAll(RenameAll),
     Style(Style),
     AllowReserved(AllowReserved),
+    AllowEmptyValue(AllowEmptyValue),
     Explode(Explode),
     ParameterIn(ParameterIn),
     IntoParamsNames(Names),
@@ -116,15 +124,18 @@ pub enum Feature {
     MaxProperties(MaxProperties),
     MinProperties(MinProperties),
     SchemaWith(SchemaWith),
+    Flatten(Flatten),
+    Header(Header),
 }
 
 impl Feature {
     pub fn parse_named<T: Name>(input: syn::parse::ParseStream, ident: Ident) -> syn::Result<Self> {
         let name = T::get_name();
 
-        const ALLOWED_NAMES: [&str; 30] = [
+        const ALLOWED_NAMES: [&str; 34] = [
             "default",
             "example",
+            "examples",
             "inline",
             "xml",
             "format",
@@ -137,6 +148,7 @@ impl Feature {
             "rename_all",
             "style",
             "allow_reserved",
+            "allow_empty_value",
             "explode",
             "parameter_in",
             "names",
@@ -153,11 +165,14 @@ impl Feature {
             "max_properties",
             "min_properties",
             "schema_with",
+            "flatten",
+            "header",
         ];
 
         match name {
             "default" => Default::parse(input).map(Self::Default),
             "example" => Example::parse(input).map(Self::Example),
+            "examples" => Examples::parse(input).map(Self::Examples),
             "inline" => Inline::parse(input).map(Self::Inline),
             "xml" => XmlAttr::parse(input).map(Self::XmlAttr),
             "format" => Format::parse(input).map(Self::Format),
@@ -170,6 +185,7 @@ impl Feature {
             "rename_all" => RenameAll::parse(input).map(Self::RenameAll),
             "style" => Style::parse(input).map(Self::Style),
             "allow_reserved" => AllowReserved::parse(input).map(Self::AllowReserved),
+            "allow_empty_value" => AllowEmptyValue::parse(input).map(Self::AllowEmptyValue),
             "explode" => Explode::parse(input).map(Self::Explode),
             "parameter_in" => ParameterIn::parse(input).map(Self::ParameterIn),
             "names" => Names::parse(input).map(Self::IntoParamsNames),
@@ -194,6 +210,8 @@ impl Feature {
                 MinProperties::parse_with_ident(input, ident).map(Self::MinProperties)
             }
             "schema_with" => SchemaWith::parse(input).map(Self::SchemaWith),
+            "flatten" => Flatten::parse(input).map(Self::Flatten),
+            "header" => Header::parse(input).map(Self::Header),
             _unexpected => Err(syn::Error::new(
                 ident.span(),
                 format!(
@@ -259,6 +277,7 @@ impl ToTokens for Feature {
         let feature = match &self {
             Feature::Default(default) => quote! { .default(Some(#default)) },
             Feature::Example(example) => quote! { .example(Some(#example)) },
+            Feature::Examples(examples) => quote! { .examples_from_iter(#examples) },
             Feature::XmlAttr(xml) => quote! { .xml(Some(#xml)) },
             Feature::Format(format) => quote! { .format(Some(#format)) },
             Feature::WriteOnly(write_only) => quote! { .write_only(Some(#write_only)) },
@@ -272,6 +291,9 @@ impl ToTokens for Feature {
             Feature::AllowReserved(allow_reserved) => {
                 quote! { .allow_reserved(Some(#allow_reserved)) }
             }
+            Feature::AllowEmptyValue(allow_empty_value) => {
+                quote! { .allow_empty_value(Some(#allow_empty_value)) }
+            }
             Feature::Explode(explode) => quote! { .explode(Some(#explode)) },
             Feature::Maximum(maximum) => quote! { .maximum(Some(#maximum)) },
             Feature::Minimum(minimum) => quote! { .minimum(Some(#minimum)) },
@@ -319,6 +341,20 @@ impl ToTokens for Feature {
                     help = "Names is only used with IntoParams to artificially give names for unnamed struct type `IntoParams`."
                 }
             }
+            Feature::Flatten(_) => {
+                abort! {
+                    Span::call_site(),
+                    "Flatten feature does not support `ToTokens`";
+                    help = "Flatten is only used with IntoParams to expand parameters of a nested `IntoParams` type."
+                }
+            }
+            Feature::Header(_) => {
+                abort! {
+                    Span::call_site(),
+                    "Header feature does not support `ToTokens`";
+                    help = "Header is only used with IntoParams to define header parameter and its name."
+                }
+            }
         };
 
         tokens.extend(feature)
@@ -330,6 +366,7 @@ impl Display for Feature {
         match self {
             Feature::Default(default) => default.fmt(f),
             Feature::Example(example) => example.fmt(f),
+            Feature::Examples(examples) => examples.fmt(f),
             Feature::XmlAttr(xml) => xml.fmt(f),
             Feature::Format(format) => format.fmt(f),
             Feature::WriteOnly(write_only) => write_only.fmt(f),
@@ -340,6 +377,7 @@ impl Display for Feature {
             Feature::Style(style) => style.fmt(f),
             Feature::ParameterIn(parameter_in) => parameter_in.fmt(f),
             Feature::AllowReserved(allow_reserved) => allow_reserved.fmt(f),
+            Feature::AllowEmptyValue(allow_empty_value) => allow_empty_value.fmt(f),
             Feature::Explode(explode) => explode.fmt(f),
             Feature::RenameAll(rename_all) => rename_all.fmt(f),
             Feature::ValueType(value_type) => value_type.fmt(f),
@@ -358,6 +396,8 @@ impl Display for Feature {
             Feature::MaxProperties(max_properties) => max_properties.fmt(f),
             Feature::MinProperties(min_properties) => min_properties.fmt(f),
             Feature::SchemaWith(with_schema) => with_schema.fmt(f),
+            Feature::Flatten(flatten) => flatten.fmt(f),
+            Feature::Header(header) => header.fmt(f),
         }
     }
 }
@@ -367,6 +407,7 @@ impl Validatable for Feature {
         match &self {
             Feature::Default(default) => default.is_validatable(),
             Feature::Example(example) => example.is_validatable(),
+            Feature::Examples(examples) => examples.is_validatable(),
             Feature::XmlAttr(xml) => xml.is_validatable(),
             Feature::Format(format) => format.is_validatable(),
             Feature::WriteOnly(write_only) => write_only.is_validatable(),
@@ -377,6 +418,7 @@ impl Validatable for Feature {
             Feature::Style(style) => style.is_validatable(),
             Feature::ParameterIn(parameter_in) => parameter_in.is_validatable(),
             Feature::AllowReserved(allow_reserved) => allow_reserved.is_validatable(),
+            Feature::AllowEmptyValue(allow_empty_value) => allow_empty_value.is_validatable(),
             Feature::Explode(explode) => explode.is_validatable(),
             Feature::RenameAll(rename_all) => rename_all.is_validatable(),
             Feature::ValueType(value_type) => value_type.is_validatable(),
@@ -395,6 +437,8 @@ impl Validatable for Feature {
             Feature::MaxProperties(max_properties) => max_properties.is_validatable(),
             Feature::MinProperties(min_properties) => min_properties.is_validatable(),
             Feature::SchemaWith(with_schema) => with_schema.is_validatable(),
+            Feature::Flatten(flatten) => flatten.is_validatable(),
+            Feature::Header(header) => header.is_validatable(),
         }
     }
 }
@@ -414,6 +458,7 @@ macro_rules! is_validatable {
 is_validatable! {
     Default => false,
     Example => false,
+    Examples => false,
     XmlAttr => false,
     Format => false,
     WriteOnly => false,
@@ -424,11 +469,14 @@ is_validatable! {
     Style => false,
     ParameterIn => false,
     AllowReserved => false,
+    AllowEmptyValue => false,
     Explode => false,
     RenameAll => false,
     ValueType => false,
     Inline => false,
     Names => false,
+    Flatten => false,
+    Header => false,
     MultipleOf => true,
     Maximum => true,
     Minimum => true,
@@ -462,19 +510,76 @@ impl ToTokens for Example {
 
 name!(Example = "example");
 
+/// Named examples defined with `examples(("name" = (summary = "...", value = json!(...))), ...)`.
 #[derive(Clone)]
 #[cfg_attr(feature = "debug", derive(Debug))]
-pub struct Default(AnyValue);
+pub struct Examples(Vec<path::example::Example>);
+
+impl Parse for Examples {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        parse_utils::parse_punctuated_within_parenthesis::<path::example::Example>(input)
+            .map(|examples| Self(examples.into_iter().collect()))
+    }
+}
+
+impl ToTokens for Examples {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let examples = self
+            .0
+            .iter()
+            .map(|example| {
+                let name = &example.name;
+                quote!((#name, #example))
+            })
+            .collect::<Array<TokenStream>>();
+
+        examples.to_tokens(tokens)
+    }
+}
+
+name!(Examples = "examples");
+
+#[derive(Clone)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct Default(Option<AnyValue>);
+
+impl Default {
+    /// Check whether default value should be resolved from the `Default` implementation of the
+    /// container, e.g. _`#[param(default)]`_ without a value.
+    pub fn is_container_default(&self) -> bool {
+        self.0.is_none()
+    }
+
+    /// Create [`Default`] which evaluates value of the given field `member` from the `Default`
+    /// implementation of the container at runtime.
+    pub fn from_container_default(member: &syn::Member) -> Self {
+        Self(Some(AnyValue::Json(quote! {
+            <Self as ::core::default::Default>::default().#member
+        })))
+    }
+}
 
 impl Parse for Default {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
-        parse_utils::parse_next(input, || AnyValue::parse_any(input)).map(Self)
+        if input.peek(Token![=]) {
+            parse_utils::parse_next(input, || AnyValue::parse_any(input))
+                .map(|value| Self(Some(value)))
+        } else {
+            Ok(Self(None))
+        }
     }
 }
 
 impl ToTokens for Default {
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
-        tokens.extend(self.0.to_token_stream())
+        match &self.0 {
+            Some(value) => tokens.extend(value.to_token_stream()),
+            None => abort! {
+                Span::call_site(),
+                "`default` without a value is only supported on `IntoParams` fields";
+                help = "Try defining the default value e.g. `default = 10` or `default = json!(...)`"
+            },
+        }
     }
 }
 
@@ -702,6 +807,12 @@ name!(RenameAll = "rename_all");
 #[derive(Clone)]
 pub struct Style(ParameterStyle);
 
+impl Style {
+    pub fn is_deep_object(&self) -> bool {
+        matches!(self.0, ParameterStyle::DeepObject)
+    }
+}
+
 impl From<ParameterStyle> for Style {
     fn from(style: ParameterStyle) -> Self {
         Self(style)
@@ -740,6 +851,24 @@ impl ToTokens for AllowReserved {
 
 name!(AllowReserved = "allow_reserved");
 
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Clone)]
+pub struct AllowEmptyValue(bool);
+
+impl Parse for AllowEmptyValue {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        parse_utils::parse_bool_or_true(input).map(Self)
+    }
+}
+
+impl ToTokens for AllowEmptyValue {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.0.to_tokens(tokens)
+    }
+}
+
+name!(AllowEmptyValue = "allow_empty_value");
+
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[derive(Clone)]
 pub struct Explode(bool);
@@ -758,6 +887,50 @@ impl ToTokens for Explode {
 
 name!(Explode = "explode");
 
+/// Expand parameters of a nested `IntoParams` type in place of the field with `flatten` attribute.
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Clone)]
+pub struct Flatten(bool);
+
+impl Flatten {
+    pub fn is_flatten(&self) -> bool {
+        self.0
+    }
+}
+
+impl Parse for Flatten {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        parse_utils::parse_bool_or_true(input).map(Self)
+    }
+}
+
+name!(Flatten = "flatten");
+
+/// Define field as header parameter with `header` or `header = "x-header-name"` attribute for
+/// `IntoParams` derive.
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Clone)]
+pub struct Header(Option<String>);
+
+impl Header {
+    pub fn into_name(self) -> Option<String> {
+        self.0
+    }
+}
+
+impl Parse for Header {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        if input.peek(Token![=]) {
+            parse_utils::parse_next(input, || input.parse::<LitStr>())
+                .map(|name| Self(Some(name.value())))
+        } else {
+            Ok(Self(None))
+        }
+    }
+}
+
+name!(Header = "header");
+
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[derive(Clone)]
 pub struct ParameterIn(parameter::ParameterIn);
@@ -1016,7 +1189,7 @@ name!(MinLength = "min_length");
 
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[derive(Clone)]
-pub struct Pattern(String, Ident);
+pub struct Pattern(TokenStream, Ident);
 
 impl Validate for Pattern {
     fn validate(&self, validator: impl Validator) {
@@ -1034,7 +1207,7 @@ impl ParseWithIdent for Pattern {
         Self: Sized,
     {
         parse_utils::parse_next(input, || input.parse::<LitStr>())
-            .map(|pattern| Self(pattern.value(), ident))
+            .map(|pattern| Self(pattern.to_token_stream(), ident))
     }
 }
 
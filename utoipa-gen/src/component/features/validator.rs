@@ -0,0 +1,216 @@
+//! Translates [validator](https://crates.io/crates/validator) crate's `#[validate(...)]`
+//! attributes to schema [`Feature`]s.
+
+use proc_macro2::{Ident, TokenStream};
+use proc_macro_error::ResultExt;
+use quote::quote;
+use syn::{
+    parenthesized,
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    token::{Comma, Paren},
+    Attribute, Expr, ExprLit, ExprUnary, Lit, Token, UnOp,
+};
+
+use crate::component::{GenericType, TypeTree};
+
+use super::{
+    ExclusiveMaximum, ExclusiveMinimum, Feature, MaxItems, MaxLength, Maximum, MinItems, MinLength,
+    Minimum, Pattern,
+};
+
+/// Single `name = value` argument of a `#[validate(...)]` rule e.g. _`min = 1`_.
+struct RuleArg {
+    name: Ident,
+    value: Expr,
+}
+
+impl Parse for RuleArg {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name = input.parse::<Ident>()?;
+        input.parse::<Token![=]>()?;
+
+        Ok(Self {
+            name,
+            value: input.parse()?,
+        })
+    }
+}
+
+/// Single rule of `#[validate(...)]` attribute. Only rules affecting the schema are parsed,
+/// the rest of the rules are skipped.
+enum Rule {
+    Range(Ident, Vec<RuleArg>),
+    Length(Ident, Vec<RuleArg>),
+    Regex(Ident, Box<Expr>),
+    Other,
+}
+
+impl Parse for Rule {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name = input.parse::<Ident>()?;
+
+        let args = if input.peek(Paren) {
+            let content;
+            parenthesized!(content in input);
+
+            if name == "range" || name == "length" || name == "regex" {
+                Some(Punctuated::<RuleArg, Comma>::parse_terminated(&content)?)
+            } else {
+                // skip arguments of rules not affecting the schema e.g. `custom(...)`
+                content.parse::<TokenStream>()?;
+                None
+            }
+        } else {
+            None
+        };
+
+        if input.peek(Token![=]) {
+            input.parse::<Token![=]>()?;
+            let value = input.parse::<Expr>()?;
+
+            return Ok(if name == "regex" {
+                Self::Regex(name, Box::new(value))
+            } else {
+                Self::Other
+            });
+        }
+
+        let args = args.map(|args| args.into_iter().collect::<Vec<_>>());
+        Ok(match args {
+            Some(args) if name == "range" => Self::Range(name, args),
+            Some(args) if name == "length" => Self::Length(name, args),
+            Some(args) if name == "regex" => args
+                .into_iter()
+                .find(|arg| arg.name == "path")
+                .map(|arg| Self::Regex(name, Box::new(arg.value)))
+                .unwrap_or(Self::Other),
+            _ => Self::Other,
+        })
+    }
+}
+
+/// Parse schema [`Feature`]s from `#[validate(...)]` attributes of a field.
+///
+/// Supported rules are:
+/// * `range(min = ..., max = ..., exclusive_min = ..., exclusive_max = ...)` as _`minimum`_,
+///   _`maximum`_, _`exclusive_minimum`_ and _`exclusive_maximum`_.
+/// * `length(min = ..., max = ..., equal = ...)` as _`min_length`_ and _`max_length`_ for strings
+///   or as _`min_items`_ and _`max_items`_ for arrays.
+/// * `regex = "PATH"`, `regex(path = "PATH")` or `regex(path = *PATH)` as _`pattern`_ where
+///   _`PATH`_ refers to a regex with `as_str()` method.
+///
+/// Only literal bounds can be translated, bounds referring to constants are ignored.
+pub fn parse_validate_features(attributes: &[Attribute], type_tree: &TypeTree) -> Vec<Feature> {
+    attributes
+        .iter()
+        .filter(|attribute| attribute.path.is_ident("validate"))
+        .flat_map(|attribute| {
+            attribute
+                .parse_args_with(Punctuated::<Rule, Comma>::parse_terminated)
+                .unwrap_or_abort()
+        })
+        .flat_map(|rule| match rule {
+            Rule::Range(ident, args) => args
+                .into_iter()
+                .filter_map(|arg| {
+                    let value = as_number(&arg.value)?;
+                    let ident = ident.clone();
+                    match &*arg.name.to_string() {
+                        "min" => Some(Feature::Minimum(Minimum(value, ident))),
+                        "max" => Some(Feature::Maximum(Maximum(value, ident))),
+                        "exclusive_min" => {
+                            Some(Feature::ExclusiveMinimum(ExclusiveMinimum(value, ident)))
+                        }
+                        "exclusive_max" => {
+                            Some(Feature::ExclusiveMaximum(ExclusiveMaximum(value, ident)))
+                        }
+                        _ => None,
+                    }
+                })
+                .collect::<Vec<_>>(),
+            Rule::Length(ident, args) => {
+                let is_vec = is_vec(type_tree);
+                args.into_iter()
+                    .filter_map(|arg| {
+                        let value = as_number(&arg.value).map(|value| value as usize)?;
+                        Some((arg.name.to_string(), value))
+                    })
+                    .flat_map(|(name, value)| match &*name {
+                        "min" => vec![min_length(is_vec, value, &ident)],
+                        "max" => vec![max_length(is_vec, value, &ident)],
+                        "equal" => vec![
+                            min_length(is_vec, value, &ident),
+                            max_length(is_vec, value, &ident),
+                        ],
+                        _ => Vec::new(),
+                    })
+                    .collect::<Vec<_>>()
+            }
+            Rule::Regex(ident, path) => {
+                let pattern = match *path {
+                    Expr::Lit(ExprLit {
+                        lit: Lit::Str(path),
+                        ..
+                    }) => path.parse::<Expr>().unwrap_or_abort(),
+                    path => path,
+                };
+
+                vec![Feature::Pattern(Pattern(
+                    quote! { (#pattern).as_str() },
+                    ident,
+                ))]
+            }
+            Rule::Other => Vec::new(),
+        })
+        .collect()
+}
+
+fn min_length(is_vec: bool, value: usize, ident: &Ident) -> Feature {
+    if is_vec {
+        Feature::MinItems(MinItems(value, ident.clone()))
+    } else {
+        Feature::MinLength(MinLength(value, ident.clone()))
+    }
+}
+
+fn max_length(is_vec: bool, value: usize, ident: &Ident) -> Feature {
+    if is_vec {
+        Feature::MaxItems(MaxItems(value, ident.clone()))
+    } else {
+        Feature::MaxLength(MaxLength(value, ident.clone()))
+    }
+}
+
+/// Check whether the type is `Vec` possibly wrapped within `Option`.
+fn is_vec(type_tree: &TypeTree) -> bool {
+    match type_tree.generic_type {
+        Some(GenericType::Vec) => true,
+        Some(GenericType::Option) => type_tree
+            .children
+            .as_ref()
+            .and_then(|children| children.first())
+            .map(is_vec)
+            .unwrap_or(false),
+        _ => false,
+    }
+}
+
+/// Resolve numeric literal value of an expression e.g. _`1`_, _`-1`_ or _`1.5`_.
+fn as_number(expr: &Expr) -> Option<f64> {
+    match expr {
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(int), ..
+        }) => int.base10_parse().ok(),
+        Expr::Lit(ExprLit {
+            lit: Lit::Float(float),
+            ..
+        }) => float.base10_parse().ok(),
+        Expr::Unary(ExprUnary {
+            op: UnOp::Neg(_),
+            expr,
+            ..
+        }) => as_number(expr).map(|value| -value),
+        _ => None,
+    }
+}
@@ -1,11 +1,13 @@
 use std::borrow::Cow;
+#[cfg(feature = "validator")]
+use std::mem;
 
 use proc_macro2::TokenStream;
 use proc_macro_error::{abort, ResultExt};
 use quote::{quote, quote_spanned, ToTokens};
 use syn::{
     parse::Parse, punctuated::Punctuated, spanned::Spanned, token::Comma, Attribute, Data, Field,
-    Generics, Ident,
+    Generics, Ident, Index, Member,
 };
 
 use crate::{
@@ -105,9 +107,13 @@ impl ToTokens for IntoParams {
         let params = self
             .get_struct_fields(&names.as_ref())
             .enumerate()
+            .filter(|(_, field)| {
+                !serde::parse_value(&field.attrs).is_some_and(|serde_value| serde_value.skip)
+            })
             .map(|(index, field)| {
                 Param {
                     field,
+                    index,
                     container_attributes: FieldParamContainerAttributes {
                         rename_all: rename_all.as_ref().and_then(|feature| {
                             match feature {
@@ -129,10 +135,30 @@ impl ToTokens for IntoParams {
             })
             .collect::<Array<Param>>();
 
+        let parameters = self.parameters_tokens(&params);
+
+        let params = if params.iter().any(Param::is_flatten) {
+            // flattened fields produce multiple parameters thus every field is collected as vec
+            let params = params.iter().map(|param| {
+                if param.is_flatten() {
+                    param.to_token_stream()
+                } else {
+                    quote! { vec![#param] }
+                }
+            });
+            quote! {
+                vec![#(#params),*].into_iter().flatten().collect()
+            }
+        } else {
+            quote! { #params.to_vec() }
+        };
+
         tokens.extend(quote! {
             impl #impl_generics utoipa::IntoParams for #ident #ty_generics #where_clause {
+                #parameters
+
                 fn into_params(parameter_in_provider: impl Fn() -> Option<utoipa::openapi::path::ParameterIn>) -> Vec<utoipa::openapi::path::Parameter> {
-                    #params.to_vec()
+                    #params
                 }
             }
         });
@@ -140,6 +166,42 @@ impl ToTokens for IntoParams {
 }
 
 impl IntoParams {
+    /// Create _`IntoParams::PARAMETERS`_ of the type with compile time check for duplicate
+    /// parameters. Generic types with flattened fields are left out since the parameters of the
+    /// flattened types cannot be resolved in constant context.
+    fn parameters_tokens(&self, params: &[Param]) -> Option<TokenStream> {
+        let (flattened, parameters): (Vec<&Param>, Vec<&Param>) =
+            params.iter().partition(|param| param.is_flatten());
+        if !flattened.is_empty() && !self.generics.params.is_empty() {
+            return None;
+        }
+
+        let parameters = parameters
+            .iter()
+            .map(|param| param.parameter_tokens())
+            .collect::<Array<_>>();
+        let parameters_len = parameters.len();
+        let lengths = flattened.iter().map(|param| {
+            let ty = &param.field.ty;
+            quote!(<#ty as utoipa::IntoParams>::PARAMETERS.len())
+        });
+        let flattened = flattened.iter().map(|param| param.parameter_tokens());
+        let message = format!("`{}` defines duplicate parameters", self.ident);
+
+        Some(quote! {
+            const PARAMETERS: &'static [utoipa::__dev::Parameter] = {
+                const PARAMETERS: [utoipa::__dev::Parameter; #parameters_len #( + #lengths )*] =
+                    utoipa::__dev::concat_parameters(&[(&#parameters, None) #(, #flattened )*]);
+                const _: () = {
+                    if utoipa::__dev::has_duplicate_parameters(&PARAMETERS) {
+                        panic!(#message)
+                    }
+                };
+                &PARAMETERS
+            };
+        })
+    }
+
     fn get_struct_fields(
         &self,
         field_names: &Option<&Vec<String>>,
@@ -225,10 +287,15 @@ impl Parse for FieldFeatures {
             input as component::features::ValueType,
             Rename,
             Style,
+            features::ParameterIn,
             AllowReserved,
+            features::AllowEmptyValue,
             Example,
+            features::Examples,
             Explode,
             SchemaWith,
+            features::Flatten,
+            features::Header,
             // param schema features
             Inline,
             Format,
@@ -255,6 +322,8 @@ impl Parse for FieldFeatures {
 struct Param<'a> {
     /// Field in the container used to create a single parameter.
     field: &'a Field,
+    /// Index of the field in the container.
+    index: usize,
     /// Attributes on the container which are relevant for this macro.
     container_attributes: FieldParamContainerAttributes<'a>,
     /// Either serde rename all rule or into_params rename all rule if provided.
@@ -262,6 +331,103 @@ struct Param<'a> {
 }
 
 impl Param<'_> {
+    /// Resolve name of the parameter and whether it is a header parameter. Features used to
+    /// resolve the name are removed from the `param_features`.
+    fn resolve_name(&self, param_features: &mut Vec<Feature>) -> (String, bool) {
+        let field = self.field;
+        let ident = &field.ident;
+        let mut name = &*ident
+            .as_ref()
+            .map(|ident| ident.to_string())
+            .or_else(|| self.container_attributes.name.cloned())
+            .unwrap_or_else(|| abort!(
+                field, "No name specified for unnamed field.";
+                help = "Try adding #[into_params(names(...))] container attribute to specify the name for this field"
+            ));
+
+        if name.starts_with("r#") {
+            name = &name[2..];
+        }
+
+        let field_param_serde = serde::parse_value(&field.attrs);
+
+        let header =
+            pop_feature!(param_features => Feature::Header(_)).and_then(|feature| match feature {
+                Feature::Header(header) => Some(header),
+                _ => None,
+            });
+        let is_header = header.is_some();
+        let header_name = header.and_then(|header| header.into_name());
+
+        let rename = param_features
+            .pop_rename_feature()
+            .map(|rename| rename.into_value());
+        let rename_to = field_param_serde
+            .as_ref()
+            .and_then(|field_param_serde| field_param_serde.rename.as_deref().map(Cow::Borrowed))
+            .or_else(|| rename.map(Cow::Owned));
+        let rename_all = self
+            .serde_container
+            .as_ref()
+            .and_then(|serde_container| serde_container.rename_all.as_ref())
+            .or_else(|| {
+                self.container_attributes
+                    .rename_all
+                    .map(|rename_all| rename_all.as_rename_rule())
+            });
+        let name = match header_name {
+            Some(header_name) => Cow::Owned(header_name),
+            None => super::rename::<FieldRename>(name, rename_to, rename_all)
+                .unwrap_or(Cow::Borrowed(name)),
+        };
+
+        (name.into_owned(), is_header)
+    }
+
+    /// Create _`(name, parameter_in)`_ tokens of the parameter to _`IntoParams::PARAMETERS`_.
+    /// Flattened field is returned as _`(parameters, parameter_in)`_ of the nested type.
+    fn parameter_tokens(&self) -> TokenStream {
+        let (_, mut param_features) = self.resolve_field_features();
+        let parameter_in = match pop_feature!(param_features => Feature::ParameterIn(_))
+            .or_else(|| self.container_attributes.parameter_in.clone())
+        {
+            Some(Feature::ParameterIn(parameter_in)) => quote! { Some(#parameter_in) },
+            _ => quote! { None },
+        };
+
+        if self.is_flatten() {
+            let ty = &self.field.ty;
+            return quote! { (<#ty as utoipa::IntoParams>::PARAMETERS, #parameter_in) };
+        }
+
+        let (name, is_header) = self.resolve_name(&mut param_features);
+        if is_header {
+            quote! { (#name, Some(utoipa::openapi::path::ParameterIn::Header)) }
+        } else {
+            quote! { (#name, #parameter_in) }
+        }
+    }
+
+    /// Check whether field is flattened with `#[param(flatten)]` or `#[serde(flatten)]` to
+    /// parameters of the nested `IntoParams` type.
+    fn is_flatten(&self) -> bool {
+        if serde::parse_value(&self.field.attrs).is_some_and(|serde_value| serde_value.flatten) {
+            return true;
+        }
+
+        self.field
+            .attrs
+            .iter()
+            .filter(|attribute| attribute.path.is_ident("param"))
+            .flat_map(|attribute| {
+                attribute
+                    .parse_args::<FieldFeatures>()
+                    .unwrap_or_abort()
+                    .into_inner()
+            })
+            .any(|feature| matches!(feature, Feature::Flatten(flatten) if flatten.is_flatten()))
+    }
+
     /// Resolve [`Param`] features and split features into two [`Vec`]s. Features are split by
     /// whether they should be renderd in [`Param`] itself or in [`Param`]s schema.
     ///
@@ -281,6 +447,36 @@ impl Param<'_> {
             .reduce(|acc, item| acc.merge(item))
             .unwrap_or_default();
 
+        #[cfg(feature = "validator")]
+        {
+            // constraints of `#[param(...)]` take precedence over `#[validate(...)]` constraints
+            let type_tree = TypeTree::from_type(&self.field.ty);
+            for feature in
+                features::validator::parse_validate_features(&self.field.attrs, &type_tree)
+            {
+                if !field_features.iter().any(|field_feature| {
+                    mem::discriminant(field_feature) == mem::discriminant(&feature)
+                }) {
+                    field_features.push(feature);
+                }
+            }
+        }
+
+        if let Some(Feature::Default(default)) = field_features
+            .iter_mut()
+            .find(|feature| matches!(feature, Feature::Default(_)))
+        {
+            if default.is_container_default() {
+                let member = self
+                    .field
+                    .ident
+                    .clone()
+                    .map(Member::Named)
+                    .unwrap_or_else(|| Member::Unnamed(Index::from(self.index)));
+                *default = features::Default::from_container_default(&member);
+            }
+        }
+
         if let Some(ref style) = self.container_attributes.style {
             if !field_features
                 .iter()
@@ -327,56 +523,45 @@ impl Param<'_> {
 impl ToTokens for Param<'_> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let field = self.field;
-        let ident = &field.ident;
-        let mut name = &*ident
-            .as_ref()
-            .map(|ident| ident.to_string())
-            .or_else(|| self.container_attributes.name.cloned())
-            .unwrap_or_else(|| abort!(
-                field, "No name specified for unnamed field.";
-                help = "Try adding #[into_params(names(...))] container attribute to specify the name for this field"
-            ));
 
-        if name.starts_with("r#") {
-            name = &name[2..];
+        if self.is_flatten() {
+            let (_, mut param_features) = self.resolve_field_features();
+            let parameter_in = pop_feature!(param_features => Feature::ParameterIn(_))
+                .or_else(|| self.container_attributes.parameter_in.clone());
+            let parameter_in_provider = match parameter_in {
+                Some(Feature::ParameterIn(parameter_in)) => quote! { || Some(#parameter_in) },
+                _ => quote! { &parameter_in_provider },
+            };
+            let ty = &field.ty;
+
+            tokens.extend(quote_spanned! {ty.span()=>
+                <#ty as utoipa::IntoParams>::into_params(#parameter_in_provider)
+            });
+            return;
         }
 
         let field_param_serde = serde::parse_value(&field.attrs);
 
         let (schema_features, mut param_features) = self.resolve_field_features();
-
-        let rename = param_features
-            .pop_rename_feature()
-            .map(|rename| rename.into_value());
-        let rename_to = field_param_serde
-            .as_ref()
-            .and_then(|field_param_serde| field_param_serde.rename.as_deref().map(Cow::Borrowed))
-            .or_else(|| rename.map(Cow::Owned));
-        let rename_all = self
-            .serde_container
-            .as_ref()
-            .and_then(|serde_container| serde_container.rename_all.as_ref())
-            .or_else(|| {
-                self.container_attributes
-                    .rename_all
-                    .map(|rename_all| rename_all.as_rename_rule())
-            });
-        let name = super::rename::<FieldRename>(name, rename_to, rename_all)
-            .unwrap_or(Cow::Borrowed(name));
+        let (name, is_header) = self.resolve_name(&mut param_features);
         let type_tree = TypeTree::from_type(&field.ty);
 
         tokens.extend(quote! { utoipa::openapi::path::ParameterBuilder::new()
             .name(#name)
         });
-        tokens.extend(
-            if let Some(ref parameter_in) = self.container_attributes.parameter_in {
-                parameter_in.into_token_stream()
-            } else {
-                quote! {
-                    .parameter_in(parameter_in_provider().unwrap_or_default())
-                }
-            },
-        );
+        let parameter_in = pop_feature!(param_features => Feature::ParameterIn(_));
+        tokens.extend(if is_header {
+            quote! { .parameter_in(utoipa::openapi::path::ParameterIn::Header) }
+        } else if let Some(parameter_in) = parameter_in
+            .as_ref()
+            .or(self.container_attributes.parameter_in.as_ref())
+        {
+            parameter_in.into_token_stream()
+        } else {
+            quote! {
+                .parameter_in(parameter_in_provider().unwrap_or_default())
+            }
+        });
 
         if let Some(deprecated) = super::get_deprecated(&field.attrs) {
             tokens.extend(quote! { .deprecated(Some(#deprecated)) });
@@ -406,6 +591,24 @@ impl ToTokens for Param<'_> {
             tokens.extend(quote! {
                 .required(#required)
             });
+
+            let is_deep_object = param_features
+                .iter()
+                .any(|feature| matches!(feature, Feature::Style(style) if style.is_deep_object()));
+            let has_explode = param_features
+                .iter()
+                .any(|feature| matches!(feature, Feature::Explode(_)));
+            if is_deep_object && !has_explode {
+                // deepObject style is only defined for exploded object parameters
+                tokens.extend(quote! { .explode(Some(true)) })
+            }
+            if param_features
+                .iter()
+                .any(|feature| matches!(feature, Feature::Examples(_)))
+            {
+                // example and examples are mutually exclusive, examples take precedence
+                pop_feature!(param_features => Feature::Example(_));
+            }
             tokens.extend(param_features.to_token_stream());
 
             let schema = ParamSchema {
@@ -120,8 +120,22 @@ impl ToTokens for Schema<'_> {
                 .collect::<TokenStream>()
         });
 
+        let binary_properties = match &variant {
+            SchemaVariant::Named(schema) => Some(schema.binary_properties()),
+            _ => None,
+        }
+        .filter(|properties| !properties.is_empty())
+        .map(|properties| {
+            quote! {
+                const BINARY_PROPERTIES: &'static [&'static str] = &[#( #properties ),*];
+            }
+        });
+
         tokens.extend(quote! {
             impl #impl_generics utoipa::ToSchema for #ident #ty_generics #where_clause {
+                const TYPE_PATH: &'static str = concat!(module_path!(), "::", stringify!(#ident));
+                #binary_properties
+
                 fn schema() -> utoipa::openapi::RefOr<utoipa::openapi::schema::Schema> {
                     #variant.into()
                 }
@@ -230,6 +244,80 @@ pub struct NamedStructSchema<'a> {
 }
 
 impl NamedStructSchema<'_> {
+    /// Resolve name of the property of a field with the serde and schema rename rules.
+    fn property_name<'n>(
+        &'n self,
+        field_name: &'n str,
+        field_rule: Option<&'n SerdeValue>,
+        container_rules: Option<&'n SerdeContainer>,
+        rename: Option<Cow<'n, str>>,
+    ) -> Cow<'n, str> {
+        let rename_to = field_rule
+            .and_then(|field_rule| field_rule.rename.as_deref().map(Cow::Borrowed))
+            .or(rename);
+        let rename_all = container_rules
+            .and_then(|container_rule| container_rule.rename_all.as_ref())
+            .or_else(|| {
+                self.rename_all
+                    .as_ref()
+                    .map(|rename_all| rename_all.as_rename_rule())
+            });
+
+        super::rename::<FieldRename>(field_name, rename_to, rename_all)
+            .unwrap_or(Cow::Borrowed(field_name))
+    }
+
+    /// Names of the properties of byte buffer fields such as `Vec<u8>` which are binary parts of
+    /// `multipart/form-data` request body. Fields with `value_type` or `schema_with` are left out
+    /// since their schema is already defined explicitly.
+    fn binary_properties(&self) -> Vec<String> {
+        let container_rules = serde::parse_container(self.attributes);
+
+        self.fields
+            .iter()
+            .filter_map(|field| {
+                let field_rule = serde::parse_value(&field.attrs);
+                if !is_not_skipped(&field_rule)
+                    || is_flatten(&field_rule)
+                    || !TypeTree::from_type(&field.ty).is_byte_buffer()
+                {
+                    return None;
+                }
+
+                let mut field_features = field
+                    .attrs
+                    .parse_features::<NamedFieldFeatures>()
+                    .into_inner();
+                if field_features
+                    .as_mut()
+                    .and_then(|features| features.pop_value_type_feature())
+                    .is_some()
+                    || pop_feature!(field_features => Feature::SchemaWith(_)).is_some()
+                {
+                    return None;
+                }
+                let rename =
+                    pop_feature!(field_features => Feature::Rename(_)).and_then(|feature| {
+                        match feature {
+                            Feature::Rename(rename) => Some(Cow::Owned(rename.into_value())),
+                            _ => None,
+                        }
+                    });
+
+                let field_name = field.ident.as_ref().unwrap().to_string();
+                let field_name = field_name.trim_start_matches("r#");
+                let name = self.property_name(
+                    field_name,
+                    field_rule.as_ref(),
+                    container_rules.as_ref(),
+                    rename,
+                );
+
+                Some(name.into_owned())
+            })
+            .collect()
+    }
+
     fn field_as_schema_property<R>(
         &self,
         field: &Field,
@@ -320,21 +408,12 @@ impl ToTokens for NamedStructSchema<'_> {
                     }
 
                     self.field_as_schema_property(field, |property, rename| {
-                        let rename_to = field_rule
-                            .as_ref()
-                            .and_then(|field_rule| field_rule.rename.as_deref().map(Cow::Borrowed))
-                            .or(rename);
-                        let rename_all = container_rules
-                            .as_ref()
-                            .and_then(|container_rule| container_rule.rename_all.as_ref())
-                            .or_else(|| {
-                                self.rename_all
-                                    .as_ref()
-                                    .map(|rename_all| rename_all.as_rename_rule())
-                            });
-
-                        let name = super::rename::<FieldRename>(field_name, rename_to, rename_all)
-                            .unwrap_or(Cow::Borrowed(field_name));
+                        let name = self.property_name(
+                            field_name,
+                            field_rule.as_ref(),
+                            container_rules.as_ref(),
+                            rename,
+                        );
 
                         object_tokens.extend(quote! {
                             .property(#name, #property)
@@ -12,18 +12,24 @@ use crate::path::PathOperation;
 #[cfg(feature = "actix_extras")]
 pub mod actix;
 
-#[cfg(feature = "axum_extras")]
+// poem names its extractors the same way as axum thus the arguments are resolved the same way.
+#[cfg(any(feature = "axum_extras", feature = "poem_extras"))]
 pub mod axum;
 
 #[cfg(feature = "rocket_extras")]
 pub mod rocket;
 
+#[cfg(feature = "salvo_extras")]
+pub mod salvo;
+
 /// Represents single argument of handler operation.
 #[cfg_attr(
     not(any(
         feature = "actix_extras",
         feature = "rocket_extras",
-        feature = "axum_extras"
+        feature = "axum_extras",
+        feature = "poem_extras",
+        feature = "salvo_extras"
     )),
     allow(dead_code)
 )]
@@ -33,7 +39,9 @@ pub struct ValueArgument<'a> {
     #[cfg(any(
         feature = "actix_extras",
         feature = "rocket_extras",
-        feature = "axum_extras"
+        feature = "axum_extras",
+        feature = "poem_extras",
+        feature = "salvo_extras"
     ))]
     pub argument_in: ArgumentIn,
     pub type_tree: Option<TypeTree<'a>>,
@@ -43,7 +51,9 @@ pub struct ValueArgument<'a> {
     not(any(
         feature = "actix_extras",
         feature = "rocket_extras",
-        feature = "axum_extras"
+        feature = "axum_extras",
+        feature = "poem_extras",
+        feature = "salvo_extras"
     )),
     allow(dead_code)
 )]
@@ -58,16 +68,36 @@ pub struct IntoParamsType<'a> {
 #[cfg(any(
     feature = "actix_extras",
     feature = "rocket_extras",
-    feature = "axum_extras"
+    feature = "axum_extras",
+    feature = "poem_extras",
+    feature = "salvo_extras"
 ))]
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[derive(PartialEq, Eq)]
 pub enum ArgumentIn {
     Path,
-    #[cfg(feature = "rocket_extras")]
     Query,
 }
 
+/// Represents request body resolved from the extractor argument of handler operation
+/// e.g. `web::Json<T>`.
+#[cfg_attr(
+    not(any(
+        feature = "actix_extras",
+        feature = "axum_extras",
+        feature = "poem_extras",
+        feature = "salvo_extras"
+    )),
+    allow(dead_code)
+)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct RequestBody<'r> {
+    pub ty: &'r syn::Type,
+    pub content_type: &'static str,
+    /// `false` when the extractor is wrapped in `Option` e.g. `Option<Json<T>>`.
+    pub required: bool,
+}
+
 #[cfg_attr(feature = "debug", derive(Debug))]
 pub struct MacroPath {
     pub path: String,
@@ -110,7 +140,7 @@ pub struct ArgValue {
 
 #[cfg_attr(feature = "debug", derive(Debug))]
 pub struct ResolvedOperation {
-    pub path_operation: PathOperation,
+    pub path_operations: Vec<PathOperation>,
     pub path: String,
 }
 
@@ -126,6 +156,22 @@ pub trait ArgumentResolver {
     }
 }
 
+#[cfg_attr(
+    not(any(
+        feature = "actix_extras",
+        feature = "rocket_extras",
+        feature = "axum_extras",
+        feature = "poem_extras",
+        feature = "salvo_extras"
+    )),
+    allow(dead_code)
+)]
+pub trait RequestBodyResolver {
+    fn resolve_request_body(_: &'_ Punctuated<syn::FnArg, Comma>) -> Option<RequestBody<'_>> {
+        None
+    }
+}
+
 pub trait PathResolver {
     fn resolve_path(_: &Option<String>) -> Option<MacroPath> {
         None
@@ -143,10 +189,94 @@ pub struct PathOperations;
 #[cfg(not(any(
     feature = "actix_extras",
     feature = "rocket_extras",
-    feature = "axum_extras"
+    feature = "axum_extras",
+    feature = "poem_extras",
+    feature = "salvo_extras"
 )))]
 impl ArgumentResolver for PathOperations {}
 
+#[cfg(not(any(
+    feature = "actix_extras",
+    feature = "axum_extras",
+    feature = "poem_extras",
+    feature = "salvo_extras"
+)))]
+impl RequestBodyResolver for PathOperations {}
+
+/// Get ident of the framework path attribute macro defined either as _`#[get(...)]`_ or
+/// qualified with the framework crate name e.g. _`#[actix_web::get(...)]`_.
+#[cfg(any(feature = "actix_extras", feature = "rocket_extras"))]
+fn get_attribute_ident<'a>(
+    attribute: &'a syn::Attribute,
+    crate_name: &str,
+) -> Option<&'a proc_macro2::Ident> {
+    let segments = &attribute.path.segments;
+    match segments.len() {
+        1 => segments.first().map(|segment| &segment.ident),
+        2 if segments.first()?.ident == crate_name => segments.last().map(|segment| &segment.ident),
+        _ => None,
+    }
+}
+
+/// Resolve request body from `Json<T>` or `Form<T>` extractor argument of the handler.
+/// **actix-web**, **axum** and **poem** name their extractors the same way and **salvo** names
+/// them `JsonBody<T>` and `FormBody<T>`. Extractor wrapped in `Option` resolves to optional
+/// request body.
+#[cfg(any(
+    feature = "actix_extras",
+    feature = "axum_extras",
+    feature = "poem_extras",
+    feature = "salvo_extras"
+))]
+fn resolve_extractor_request_body(
+    fn_args: &Punctuated<syn::FnArg, Comma>,
+) -> Option<RequestBody<'_>> {
+    use syn::{GenericArgument, PathArguments, PathSegment, Type};
+
+    fn get_generic_type(segment: &PathSegment) -> Option<&Type> {
+        match &segment.arguments {
+            PathArguments::AngleBracketed(arguments) => match arguments.args.first()? {
+                GenericArgument::Type(ty) => Some(ty),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    fn get_last_segment(ty: &Type) -> Option<&PathSegment> {
+        match ty {
+            Type::Path(type_path) => type_path.path.segments.last(),
+            _ => None,
+        }
+    }
+
+    fn_args
+        .iter()
+        .filter_map(|arg| match arg {
+            syn::FnArg::Typed(pat_type) => Some(pat_type.ty.as_ref()),
+            syn::FnArg::Receiver(_) => None,
+        })
+        .find_map(|ty| {
+            let mut segment = get_last_segment(ty)?;
+            let required = segment.ident != "Option";
+            if !required {
+                segment = get_last_segment(get_generic_type(segment)?)?;
+            }
+
+            let content_type = match &*segment.ident.to_string() {
+                "Json" | "JsonBody" => "application/json",
+                "Form" | "FormBody" => "application/x-www-form-urlencoded",
+                _ => return None,
+            };
+
+            Some(RequestBody {
+                ty: get_generic_type(segment)?,
+                content_type,
+                required,
+            })
+        })
+}
+
 #[cfg(not(any(feature = "actix_extras", feature = "rocket_extras")))]
 impl PathResolver for PathOperations {}
 
@@ -156,7 +286,8 @@ impl PathOperationResolver for PathOperations {}
 #[cfg(any(
     feature = "actix_extras",
     feature = "axum_extras",
-    feature = "rocket_extras"
+    feature = "rocket_extras",
+    feature = "poem_extras"
 ))]
 pub mod fn_arg {
 
@@ -164,15 +295,29 @@ pub mod fn_arg {
 
     use proc_macro2::{Ident, TokenStream};
     use proc_macro_error::abort;
-    #[cfg(any(feature = "actix_extras", feature = "axum_extras"))]
+    #[cfg(any(
+        feature = "actix_extras",
+        feature = "axum_extras",
+        feature = "poem_extras"
+    ))]
     use quote::quote;
     use syn::{punctuated::Punctuated, token::Comma, Pat, PatType};
 
     use crate::component::TypeTree;
-    #[cfg(any(feature = "actix_extras", feature = "axum_extras"))]
-    use crate::component::ValueType;
+    #[cfg(any(
+        feature = "actix_extras",
+        feature = "axum_extras",
+        feature = "poem_extras"
+    ))]
+    use crate::component::{GenericType, ValueType};
 
     use super::IntoParamsType;
+    #[cfg(any(
+        feature = "actix_extras",
+        feature = "axum_extras",
+        feature = "poem_extras"
+    ))]
+    use super::{ArgumentIn, ValueArgument};
 
     /// Http operation handler functions fn argument.
     #[cfg_attr(feature = "debug", derive(Debug))]
@@ -232,9 +377,8 @@ pub mod fn_arg {
     pub fn get_fn_args(fn_args: &Punctuated<syn::FnArg, Comma>) -> impl Iterator<Item = FnArg<'_>> {
         fn_args
             .iter()
-            .map(|arg| {
-                let pat_type = get_fn_arg_pat_type(arg);
-
+            .filter_map(get_fn_arg_pat_type)
+            .map(|pat_type| {
                 let arg_name = get_pat_fn_arg_type(pat_type.pat.as_ref());
                 (TypeTree::from_type(&pat_type.ty), arg_name)
             })
@@ -265,15 +409,20 @@ pub mod fn_arg {
         arg_name
     }
 
+    /// Get typed argument of the function, `self` receiver of a method is skipped.
     #[inline]
-    fn get_fn_arg_pat_type(fn_arg: &syn::FnArg) -> &PatType {
+    fn get_fn_arg_pat_type(fn_arg: &syn::FnArg) -> Option<&PatType> {
         match fn_arg {
-            syn::FnArg::Typed(value) => value,
-            _ => abort!(fn_arg, "unexpected fn argument type, expected FnArg::Typed"),
+            syn::FnArg::Typed(value) => Some(value),
+            syn::FnArg::Receiver(_) => None,
         }
     }
 
-    #[cfg(any(feature = "actix_extras", feature = "axum_extras"))]
+    #[cfg(any(
+        feature = "actix_extras",
+        feature = "axum_extras",
+        feature = "poem_extras"
+    ))]
     pub(super) fn with_parameter_in(
         arg: FnArg<'_>,
     ) -> Option<(Option<std::borrow::Cow<'_, syn::Path>>, TokenStream)> {
@@ -307,7 +456,11 @@ pub mod fn_arg {
     }
 
     // if type is either Path or Query with direct children as Object types without generics
-    #[cfg(any(feature = "actix_extras", feature = "axum_extras"))]
+    #[cfg(any(
+        feature = "actix_extras",
+        feature = "axum_extras",
+        feature = "poem_extras"
+    ))]
     pub(super) fn is_into_params(fn_arg: &FnArg) -> bool {
         (fn_arg.ty.is("Path") || fn_arg.ty.is("Query"))
             && fn_arg
@@ -322,4 +475,46 @@ pub mod fn_arg {
                 })
                 .unwrap_or(false)
     }
+
+    // if type is Query with free form map e.g. `Query<HashMap<String, String>>`
+    #[cfg(any(
+        feature = "actix_extras",
+        feature = "axum_extras",
+        feature = "poem_extras"
+    ))]
+    pub(super) fn is_query_map(fn_arg: &FnArg) -> bool {
+        fn_arg.ty.is("Query")
+            && fn_arg
+                .ty
+                .children
+                .as_ref()
+                .map(|children| {
+                    children
+                        .iter()
+                        .all(|child| matches!(child.generic_type, Some(GenericType::Map)))
+                })
+                .unwrap_or(false)
+    }
+
+    /// Convert free form query map argument to query [`ValueArgument`] named after the argument.
+    #[cfg(any(
+        feature = "actix_extras",
+        feature = "axum_extras",
+        feature = "poem_extras"
+    ))]
+    pub(super) fn into_query_map_argument(fn_arg: FnArg<'_>) -> Option<ValueArgument<'_>> {
+        let name = match fn_arg.arg_type {
+            FnArgType::Single(name) => name,
+            FnArgType::Tuple(_) => return None,
+        };
+
+        Some(ValueArgument {
+            name: Some(Cow::Owned(name.to_string())),
+            argument_in: ArgumentIn::Query,
+            type_tree: fn_arg
+                .ty
+                .children
+                .and_then(|children| children.into_iter().next()),
+        })
+    }
 }
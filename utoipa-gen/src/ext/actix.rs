@@ -4,7 +4,7 @@ use lazy_static::lazy_static;
 use proc_macro2::Ident;
 use proc_macro_error::abort;
 use regex::{Captures, Regex};
-use syn::{parse::Parse, punctuated::Punctuated, token::Comma, ItemFn, LitStr};
+use syn::{parse::Parse, punctuated::Punctuated, token::Comma, ItemFn, LitStr, Token};
 
 use crate::{
     component::{TypeTree, ValueType},
@@ -15,7 +15,7 @@ use crate::{
 use super::{
     fn_arg::{self, FnArg},
     ArgumentIn, ArgumentResolver, MacroArg, MacroPath, PathOperationResolver, PathOperations,
-    PathResolver, ResolvedOperation, ValueArgument,
+    PathResolver, RequestBody, RequestBodyResolver, ResolvedOperation, ValueArgument,
 };
 
 impl ArgumentResolver for PathOperations {
@@ -29,6 +29,11 @@ impl ArgumentResolver for PathOperations {
         let (into_params_args, value_args): (Vec<FnArg>, Vec<FnArg>) = fn_arg::get_fn_args(fn_args)
             .into_iter()
             .partition(fn_arg::is_into_params);
+        let (query_map_args, value_args): (Vec<FnArg>, Vec<FnArg>) =
+            value_args.into_iter().partition(fn_arg::is_query_map);
+        let query_map_arguments = query_map_args
+            .into_iter()
+            .flat_map(fn_arg::into_query_map_argument);
 
         if let Some(macro_args) = macro_args {
             let primitive_args = get_primitive_args(value_args);
@@ -39,6 +44,7 @@ impl ArgumentResolver for PathOperations {
                         .into_iter()
                         .zip(primitive_args)
                         .map(into_value_argument)
+                        .chain(query_map_arguments)
                         .collect(),
                 ),
                 Some(
@@ -51,7 +57,7 @@ impl ArgumentResolver for PathOperations {
             )
         } else {
             (
-                None,
+                Some(query_map_arguments.collect()),
                 Some(
                     into_params_args
                         .into_iter()
@@ -95,27 +101,72 @@ fn into_value_argument((macro_arg, primitive_arg): (MacroArg, TypeTree)) -> Valu
     }
 }
 
+impl RequestBodyResolver for PathOperations {
+    fn resolve_request_body(fn_args: &Punctuated<syn::FnArg, Comma>) -> Option<RequestBody<'_>> {
+        super::resolve_extractor_request_body(fn_args)
+    }
+}
+
 impl PathOperationResolver for PathOperations {
+    /// Resolve path and operations from **actix-web** path attribute macros such as
+    /// _`#[get("/foo")]`_, _`#[actix_web::get("/foo")]`_ or _`#[route("/foo", method = "GET")]`_.
+    /// Operations of multiple macros sharing the same path e.g. with _`#[routes]`_ are collected
+    /// together. Macros with different paths abort since a single _`#[utoipa::path(...)]`_ can
+    /// document only one path.
     fn resolve_operation(item_fn: &ItemFn) -> Option<ResolvedOperation> {
-        item_fn.attrs.iter().find_map(|attribute| {
-            if is_valid_request_type(attribute.path.get_ident()) {
+        let mut resolved_operations = item_fn.attrs.iter().filter_map(|attribute| {
+            let ident = super::get_attribute_ident(attribute, "actix_web")?;
+
+            if is_valid_request_type(Some(ident)) {
                 match attribute.parse_args::<Path>() {
-                    Ok(path) => Some(ResolvedOperation {
-                        path: path.0,
-                        path_operation: PathOperation::from_ident(
-                            attribute.path.get_ident().unwrap(),
-                        ),
-                    }),
+                    Ok(path) => Some((
+                        attribute,
+                        ResolvedOperation {
+                            path: path.0,
+                            path_operations: vec![PathOperation::from_ident(ident)],
+                        },
+                    )),
                     Err(error) => abort!(
                         error.span(),
                         "parse path of path operation attribute: {}",
                         error
                     ),
                 }
+            } else if ident == "route" {
+                match attribute.parse_args::<Route>() {
+                    Ok(route) => Some((
+                        attribute,
+                        ResolvedOperation {
+                            path: route.path,
+                            path_operations: route.path_operations,
+                        },
+                    )),
+                    Err(error) => abort!(error.span(), "parse route attribute: {}", error),
+                }
             } else {
                 None
             }
-        })
+        });
+
+        let (_, mut resolved_operation) = resolved_operations.next()?;
+        for (attribute, other) in resolved_operations {
+            if other.path != resolved_operation.path {
+                abort!(
+                    attribute,
+                    "path `{}` differs from path `{}` of the previous path operation attribute",
+                    other.path,
+                    resolved_operation.path;
+                    help = "Stacked path operation attributes under `#[routes]` must share the same path to be documented with `#[utoipa::path(...)]`";
+                    help = "Split the handler to separate handlers for each path"
+                );
+            }
+
+            resolved_operation
+                .path_operations
+                .extend(other.path_operations);
+        }
+
+        Some(resolved_operation)
     }
 }
 
@@ -138,6 +189,49 @@ impl Parse for Path {
     }
 }
 
+/// Path and methods of _`#[route("/foo", method = "GET", method = "POST")]`_ attribute macro.
+/// Other arguments such as _`guard = "..."`_ are ignored.
+struct Route {
+    path: String,
+    path_operations: Vec<PathOperation>,
+}
+
+impl Parse for Route {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let path = input.parse::<LitStr>()?.value();
+        let mut path_operations = Vec::new();
+
+        while !input.is_empty() {
+            input.parse::<Token![,]>()?;
+            if input.is_empty() {
+                break;
+            }
+
+            let name = input.parse::<Ident>()?;
+            input.parse::<Token![=]>()?;
+            let value = input.parse::<LitStr>()?;
+
+            if name == "method" {
+                let path_operation = value
+                    .value()
+                    .to_lowercase()
+                    .parse::<PathOperation>()
+                    .map_err(|error| syn::Error::new(value.span(), error))?;
+                path_operations.push(path_operation);
+            }
+        }
+
+        if path_operations.is_empty() {
+            return Err(input.error("expected at least one `method = \"...\"` argument"));
+        }
+
+        Ok(Self {
+            path,
+            path_operations,
+        })
+    }
+}
+
 impl PathResolver for PathOperations {
     fn resolve_path(path: &Option<String>) -> Option<MacroPath> {
         path.as_ref().map(|path| {
@@ -6,10 +6,10 @@ use crate::component::TypeTree;
 
 use super::{
     fn_arg::{self, FnArg, FnArgType},
-    ArgumentResolver, PathOperations, ValueArgument,
+    ArgumentResolver, PathOperations, RequestBody, RequestBodyResolver, ValueArgument,
 };
 
-// axum framework is only able to resolve handler function arguments.
+// axum framework is only able to resolve handler function arguments and request body.
 // `PathResolver` and `PathOperationResolver` is not supported in axum.
 impl ArgumentResolver for PathOperations {
     fn resolve_arguments(
@@ -22,9 +22,19 @@ impl ArgumentResolver for PathOperations {
         let (into_params_args, value_args): (Vec<FnArg>, Vec<FnArg>) = fn_arg::get_fn_args(args)
             .into_iter()
             .partition(fn_arg::is_into_params);
+        let (query_map_args, value_args): (Vec<FnArg>, Vec<FnArg>) =
+            value_args.into_iter().partition(fn_arg::is_query_map);
 
         (
-            Some(get_value_arguments(value_args).collect()),
+            Some(
+                get_value_arguments(value_args)
+                    .chain(
+                        query_map_args
+                            .into_iter()
+                            .flat_map(fn_arg::into_query_map_argument),
+                    )
+                    .collect(),
+            ),
             Some(
                 into_params_args
                     .into_iter()
@@ -74,3 +84,9 @@ fn to_value_argument<'a>(name: Option<Cow<'a, str>>, ty: TypeTree<'a>) -> ValueA
         argument_in: super::ArgumentIn::Path,
     }
 }
+
+impl RequestBodyResolver for PathOperations {
+    fn resolve_request_body(args: &Punctuated<syn::FnArg, Comma>) -> Option<RequestBody<'_>> {
+        super::resolve_extractor_request_body(args)
+    }
+}
@@ -144,7 +144,8 @@ fn is_anonymous_arg(arg: &MacroArg) -> bool {
 impl PathOperationResolver for PathOperations {
     fn resolve_operation(ast_fn: &syn::ItemFn) -> Option<super::ResolvedOperation> {
         ast_fn.attrs.iter().find_map(|attribute| {
-            if is_valid_route_type(attribute.path.get_ident()) {
+            let ident = super::get_attribute_ident(attribute, "rocket")?;
+            if is_valid_route_type(Some(ident)) {
                 let Path(path, operation) = match attribute.parse_args::<Path>() {
                     Ok(path) => path,
                     Err(error) => abort!(
@@ -156,14 +157,12 @@ impl PathOperationResolver for PathOperations {
 
                 if let Some(operation) = operation {
                     Some(ResolvedOperation {
-                        path_operation: PathOperation::from_str(&operation).unwrap(),
+                        path_operations: vec![PathOperation::from_str(&operation).unwrap()],
                         path,
                     })
                 } else {
                     Some(ResolvedOperation {
-                        path_operation: PathOperation::from_ident(
-                            attribute.path.get_ident().unwrap(),
-                        ),
+                        path_operations: vec![PathOperation::from_ident(ident)],
                         path,
                     })
                 }
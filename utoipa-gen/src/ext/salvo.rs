@@ -0,0 +1,82 @@
+use std::borrow::Cow;
+
+use syn::{punctuated::Punctuated, token::Comma, Expr, GenericArgument, Lit, PathArguments, Type};
+
+use crate::component::{GenericType, TypeTree, ValueType};
+
+use super::{
+    ArgumentIn, ArgumentResolver, PathOperations, RequestBody, RequestBodyResolver, ValueArgument,
+};
+
+// salvo framework is only able to resolve handler function arguments and request body.
+// `PathResolver` and `PathOperationResolver` is not supported in salvo.
+impl ArgumentResolver for PathOperations {
+    fn resolve_arguments(
+        args: &'_ Punctuated<syn::FnArg, Comma>,
+        _: Option<Vec<super::MacroArg>>, // ignored, cannot be provided
+    ) -> (
+        Option<Vec<super::ValueArgument<'_>>>,
+        Option<Vec<super::IntoParamsType<'_>>>,
+    ) {
+        (
+            Some(args.iter().filter_map(get_value_argument).collect()),
+            None,
+        )
+    }
+}
+
+/// Resolve [`ValueArgument`] named after the handler argument from `PathParam<T>` or
+/// `QueryParam<T, REQUIRED>` extractor. `QueryParam<T, false>` resolves to optional parameter.
+fn get_value_argument(fn_arg: &syn::FnArg) -> Option<ValueArgument<'_>> {
+    let pat_type = match fn_arg {
+        syn::FnArg::Typed(pat_type) => pat_type,
+        syn::FnArg::Receiver(_) => return None,
+    };
+    let name = match pat_type.pat.as_ref() {
+        syn::Pat::Ident(ident) => &ident.ident,
+        _ => return None,
+    };
+    let segment = match pat_type.ty.as_ref() {
+        Type::Path(type_path) => type_path.path.segments.last()?,
+        _ => return None,
+    };
+    let argument_in = match &*segment.ident.to_string() {
+        "PathParam" => ArgumentIn::Path,
+        "QueryParam" => ArgumentIn::Query,
+        _ => return None,
+    };
+    let generic_args = match &segment.arguments {
+        PathArguments::AngleBracketed(arguments) => &arguments.args,
+        _ => return None,
+    };
+
+    let ty = match generic_args.first()? {
+        GenericArgument::Type(ty) => TypeTree::from_type(ty),
+        _ => return None,
+    };
+    let required = !matches!(
+        generic_args.iter().nth(1),
+        Some(GenericArgument::Const(Expr::Lit(expr))) if matches!(&expr.lit, Lit::Bool(required) if !required.value)
+    );
+
+    Some(ValueArgument {
+        name: Some(Cow::Owned(name.to_string())),
+        argument_in,
+        type_tree: Some(if required {
+            ty
+        } else {
+            TypeTree {
+                path: None,
+                value_type: ValueType::Object,
+                generic_type: Some(GenericType::Option),
+                children: Some(vec![ty]),
+            }
+        }),
+    })
+}
+
+impl RequestBodyResolver for PathOperations {
+    fn resolve_request_body(args: &Punctuated<syn::FnArg, Comma>) -> Option<RequestBody<'_>> {
+        super::resolve_extractor_request_body(args)
+    }
+}
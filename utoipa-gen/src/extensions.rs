@@ -0,0 +1,84 @@
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::token::Comma;
+use syn::{parenthesized, Error, LitStr, Token};
+
+use crate::{parse_utils, AnyValue};
+
+/// Specification extensions parsed from `extensions(("x-name" = value), ...)`. The value can be
+/// a literal, `json!(...)` or a method reference.
+#[derive(Default, Clone)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub(crate) struct Extensions(Punctuated<Extension, Comma>);
+
+impl Parse for Extensions {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let extensions: Punctuated<Extension, Comma> =
+            parse_utils::parse_punctuated_within_parenthesis(input)?;
+
+        for (index, extension) in extensions.iter().enumerate() {
+            if extensions
+                .iter()
+                .take(index)
+                .any(|other| other.name.value() == extension.name.value())
+            {
+                return Err(Error::new(
+                    extension.name.span(),
+                    format!("duplicate extension: {}", extension.name.value()),
+                ));
+            }
+        }
+
+        Ok(Self(extensions))
+    }
+}
+
+impl ToTokens for Extensions {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let extensions = self.0.iter();
+
+        tokens.extend(quote! {
+            utoipa::openapi::extensions::ExtensionsBuilder::new()
+                #( #extensions )*
+                .build()
+        })
+    }
+}
+
+#[derive(Clone)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+struct Extension {
+    name: LitStr,
+    value: AnyValue,
+}
+
+impl Parse for Extension {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let content;
+        parenthesized!(content in input);
+        let name = content.parse::<LitStr>()?;
+        if !name.value().starts_with("x-") {
+            return Err(Error::new(
+                name.span(),
+                format!("extension name must start with x-, found: {}", name.value()),
+            ));
+        }
+        content.parse::<Token![=]>()?;
+
+        Ok(Self {
+            name,
+            value: AnyValue::parse_any(&content)?,
+        })
+    }
+}
+
+impl ToTokens for Extension {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let name = &self.name;
+        let value = &self.value;
+
+        tokens.extend(quote! { .add(#name, #value) })
+    }
+}
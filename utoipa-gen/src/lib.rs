@@ -4,7 +4,6 @@
 //! of the library documentation is available through **utoipa** library itself.
 //! Consider browsing via the **utoipa** crate so all links will work correctly.
 
-#![feature(proc_macro_span)]
 #![warn(missing_docs)]
 #![warn(rustdoc::broken_intra_doc_links)]
 
@@ -18,19 +17,21 @@ use ext::{PathOperationResolver, PathOperations, PathResolver};
 use openapi::{OpenApi, OpenApiAttr};
 use path::{FbrPathAttr, PathOperation};
 use proc_macro::TokenStream;
-use proc_macro_error::{proc_macro_error, OptionExt, ResultExt};
+use proc_macro_error::{abort, proc_macro_error, OptionExt, ResultExt};
 use quote::{quote, ToTokens, TokenStreamExt};
 
 use proc_macro2::{Group, Ident, Punct, TokenStream as TokenStream2};
 use syn::{
     parse::{Parse, ParseStream},
     punctuated::Punctuated,
-    DeriveInput, ExprPath, ItemFn, Lit, LitStr, Token,
+    Attribute, DeriveInput, ExprPath, Item, ItemFn, Lit, LitStr, Token,
 };
 
+mod api_tag;
 mod component;
 mod doc_comment;
 mod ext;
+mod extensions;
 mod openapi;
 mod path;
 mod schema_type;
@@ -577,7 +578,9 @@ pub fn derive_to_schema(input: TokenStream) -> TokenStream {
 ///
 /// `#[deprecated]` attribute supports adding additional details such as a reason and or since version but this is is not supported in
 /// OpenAPI. OpenAPI has only a boolean flag to determine deprecation. While it is totally okay to declare deprecated with reason
-/// `#[deprecated  = "There is better way to do this"]` the reason would not render in OpenAPI spec.
+/// `#[deprecated  = "There is better way to do this"]` the reason would not render in OpenAPI spec. To let clients know
+/// the migration path use _`deprecated(note = "...")`_ attribute of the macro instead which will append the note to the
+/// _`description`_ of the operation.
 ///
 /// Doc comment at decorated function will be used for _`description`_ and _`summary`_ of the path.
 /// First line of the doc comment will be used as the _`summary`_ and the whole doc comment will be
@@ -593,9 +596,21 @@ pub fn derive_to_schema(input: TokenStream) -> TokenStream {
 /// # Path Attributes
 ///
 /// * `operation` _**Must be first parameter!**_ Accepted values are known http operations such as
-///   _`get, post, put, delete, head, options, connect, patch, trace`_.
+///   _`get, post, put, delete, head, options, connect, patch, trace`_. Multiple operations can be
+///   given e.g. _`get, head`_ in which case the path item will have the same operation for each of them.
+///   The operation id of every operation after the first one is suffixed with the operation e.g. _`health_head`_.
 ///
 /// * `path = "..."` Must be OpenAPI format compatible str with arguments withing curly braces. E.g _`{id}`_
+///   Regular expressions of arguments such as _`{tail:.*}`_ of **actix-web** are removed and wildcard
+///   segment such as _`/*rest`_ of **axum** is changed to argument _`/{rest}`_. Wildcard segment without
+///   name _`/*`_ is named _`rest`_ as well. The type and description of the wildcard argument can be
+///   defined in _`params(...)`_ by its name e.g. _`("rest" = String, Path)`_.
+///
+/// * `webhook = "..."` Mark the operation as a webhook with given unique name e.g. _`"newPet"`_ instead of
+///   a path. Webhook describes a request the API sends to the consumer and it is added to the _`webhooks`_
+///   of the [`OpenApi`][openapi] by listing the handler in _`webhooks(...)`_ of the derive. This cannot be
+///   used together with `path` and webhooks are only supported by OpenAPI 3.1 thus the derive must define
+///   _`openapi = "3.1"`_.
 ///
 /// * `operation_id = "..."` Unique operation id for the endpoint. By default this is mapped to function name.
 ///
@@ -606,7 +621,11 @@ pub fn derive_to_schema(input: TokenStream) -> TokenStream {
 ///
 /// * `tag = "..."` Can be used to group operations. Operations with same tag are grouped together. By default
 ///   this is derived from the handler that is given to [`OpenApi`][openapi]. If derive results empty str
-///   then default value _`crate`_ is used instead.
+///   then default value _`crate`_ is used instead. Tag can also be given as path to constant
+///   e.g. _`tag = <PetsTag as utoipa::ApiTag>::NAME`_ of type implementing [`ApiTag`][api_tag].
+///
+/// * `tag_from_module` Use name of the module containing the handler as the default tag of the operation,
+///   e.g. handler in module _`api::users`_ is tagged with _`users`_. Explicitly defined `tag` still takes precedence.
 ///
 /// * `request_body = ... | request_body(...)` Defining request body indicates that the request is expecting request body within
 ///   the performed request.
@@ -615,8 +634,30 @@ pub fn derive_to_schema(input: TokenStream) -> TokenStream {
 ///
 /// * `params(...)` Slice of params that the endpoint accepts.
 ///
+/// * `path_params(...)` Slice of params shared by all operations of the path, e.g. _`("org_id" = u64, Path)`_.
+///   These are added to the parameters of the [`PathItem`][path_item] instead of the operation and
+///   use the same syntax as _`params(...)`_. Operations of the same path sharing a parameter with same
+///   name and location only define it once.
+///
 /// * `security(...)` List of [`SecurityRequirement`][security]s local to the path operation.
 ///
+/// * `servers(...)` List of [`Server`][server]s serving this particular operation. These will override the
+///   servers defined to the [`OpenApi`][openapi]. Syntax is same as in _`servers(...)`_ of the
+///   [`OpenApi`][openapi] derive, e.g. _`servers((url = "https://files.example.com", description = "CDN"))`_.
+///
+/// * `callbacks(...)` List of [`Callback`][callback]s the operation may initiate. Each callback is defined as
+///   _`("name" = path::to::handler)`_ where the _`handler`_ is a function annotated with `#[utoipa::path(...)]`
+///   describing the callback request. The _`path`_ of the callback handler is used as the runtime expression
+///   of the callback, e.g. _`path = "{$request.body#/callbackUrl}"`_. Handlers with same callback name are
+///   combined to single callback.
+///
+/// * `external_docs(...)` Reference to external resource with extended documentation of the operation.
+///   Defined as _`external_docs(url = "...", description = "...")`_ where _`description`_ is optional.
+///
+/// * `deprecated` or `deprecated(note = "...")` Mark the operation deprecated regardless of Rust's
+///   `#[deprecated]` attribute. Optional _`note`_ is appended to the _`description`_ of the operation
+///   e.g. _`deprecated(note = "use /v2/users")`_.
+///
 /// # Request Body Attributes
 ///
 /// **Simple format definition by `request_body = ...`**
@@ -626,6 +667,9 @@ pub fn derive_to_schema(input: TokenStream) -> TokenStream {
 ///   [`ToSchema`][to_schema] types. _`ref("./external.json")`_ can be used to reference external
 ///   json file for body schema. **Note!** Utoipa does **not** guarantee that free form _`ref`_ is accessbile via
 ///   OpenAPI doc or Swagger UI, users are eligible to make these guarantees.
+/// * _`request_body = upload`_ or _`request_body = upload("...")`_ Shorthand for simple file upload
+///   endpoints. Request body will be required _`multipart/form-data`_ with single binary property
+///   named _`file`_ or by the given name.
 ///
 /// **Advanced format definition by `request_body(...)`**
 /// * `content = ...` Can be _`content = Type`_, _`content = inline(Type)`_ or _`content = ref("...")`_. The
@@ -636,13 +680,23 @@ pub fn derive_to_schema(input: TokenStream) -> TokenStream {
 ///   that free form _`ref`_ is accessbile via OpenAPI doc or Swagger UI, users are eligible
 ///   to make these guarantees.
 ///
+/// * `upload` or `upload = "..."` Define the request body as _`multipart/form-data`_ file upload
+///   instead of _`content`_. Name of the binary property is _`file`_ unless given. Can be combined
+///   with _`description`_ and _`encoding(...)`_ e.g. to restrict content type of the uploaded file.
+///
 /// * `description = "..."` Define the description for the request body object as str.
 ///
+/// * `required` Define whether the request body is required. Value can be omitted or defined as boolean
+///   e.g. _`required = false`_. By default request body is required unless _`content`_ is an _`Option`_.
+///   Request body resolved from _`Option<Json<...>>`_ handler argument is not required.
+///
 /// * `content_type = "..."` Can be used to override the default behavior of auto resolving the content type
 ///   from the `content` attribute. If defined the value should be valid content type such as
 ///   _`application/json`_. By default the content type is _`text/plain`_ for
-///   [primitive Rust types][primitive], `application/octet-stream` for _`[u8]`_ and
-///   _`application/json`_ for struct and complex enum types.
+///   [primitive Rust types][primitive], `application/octet-stream` for _`[u8]`_ and _`bytes::Bytes`_ and
+///   _`application/json`_ for struct and complex enum types. With _`multipart/form-data`_ the fields of
+///   the _`content`_ struct deriving [`ToSchema`][to_schema] are expanded to the inlined request body
+///   schema where byte buffer fields such as _`Vec<u8>`_ are binary parts.
 ///
 /// * `example = ...` Can be _`json!(...)`_. _`json!(...)`_ should be something that
 ///   _`serde_json::json!`_ can parse as a _`serde_json::Value`_.
@@ -652,11 +706,31 @@ pub fn derive_to_schema(input: TokenStream) -> TokenStream {
 ///   This has same syntax as _`examples(...)`_ in [Response Attributes](#response-attributes)
 ///   _examples(...)_
 ///
+/// * `encoding(...)` Define encoding for the properties of the request body. This is mostly useful
+///   with _`multipart/form-data`_ request bodies where each part can have its own content type and headers.
+///   Each encoding is defined within parentheses as
+///   _`("property_name", content_type = "...", headers(...), style = ..., explode, allow_reserved)`_
+///   where all attributes but the property name are optional.
+///     * `content_type = "..."` Content type of the property, e.g. _`"image/png"`_ for a file part.
+///     * `headers(...)` Headers of the part. This has same syntax as _`headers(...)`_ in
+///       [Response Attributes](#response-attributes).
+///     * `style = ...`, `explode` and `allow_reserved` have same meaning as in
+///       [Params Attributes](#params-attributes) and apply only to _`application/x-www-form-urlencoded`_
+///       request bodies.
+///
 /// _**Example request body defintions.**_
 /// ```text
 ///  request_body(content = String, description = "Xml as string request", content_type = "text/xml"),
 ///  request_body = Pet,
 ///  request_body = Option<[Pet]>,
+///  request_body(content = Upload, content_type = "multipart/form-data",
+///     encoding(("file", content_type = "image/png"), ("metadata", content_type = "application/json")))
+///  request_body = upload("avatar"),
+///  request_body(content = User, examples(
+///     ("minimal" = (value = json!({"name": "bob"}))),
+///     ("full" = (external_value = "https://example.com/examples/user.json"))
+///  )),
+///  request_body(upload, description = "Image to store", encoding(("file", content_type = "image/*"))),
 /// ```
 ///
 /// # Response Attributes
@@ -664,8 +738,12 @@ pub fn derive_to_schema(input: TokenStream) -> TokenStream {
 /// * `status = ...` Is either a valid http status code integer. E.g. _`200`_ or a string value representing
 ///   a range such as _`"4XX"`_ or `"default"` or a valid _`http::status::StatusCode`_.
 ///   _`StatusCode`_ can either be use path to the status code or _status code_ constant directly.
+///   Integers between _`100`_ and _`599`_ are accepted also for codes not registered by IANA such as
+///   _`499`_. Path to any other _`u16`_ constant can be used as well e.g. _`status = CLIENT_CLOSED_REQUEST`_.
 ///
-/// * `description = "..."` Define description for the response as str.
+/// * `description = "..."` Define description for the response as str. If omitted the reason phrase of
+///   the status code such as _`"Not Found"`_ is used. Status codes without a known reason phrase and
+///   status ranges are described by their class e.g. _`"Client Error"`_.
 ///
 /// * `body = ...` Optional response body object type. When left empty response does not expect to send any
 ///   response body. Can be _`body = Type`_, _`body = inline(Type)`_, or _`body = ref("...")`_.
@@ -679,12 +757,15 @@ pub fn derive_to_schema(input: TokenStream) -> TokenStream {
 /// * `content_type = "..." | content_type = [...]` Can be used to override the default behavior of auto resolving the content type
 ///   from the `body` attribute. If defined the value should be valid content type such as
 ///   _`application/json`_. By default the content type is _`text/plain`_ for
-///   [primitive Rust types][primitive], `application/octet-stream` for _`[u8]`_ and
+///   [primitive Rust types][primitive], `application/octet-stream` for _`[u8]`_ and _`bytes::Bytes`_ and
 ///   _`application/json`_ for struct and complex enum types.
 ///   Content type can also be slice of **content_type** values if the endpoint support returning multiple
 ///  response content types. E.g _`["application/json", "text/xml"]`_ would indicate that endpoint can return both
 ///  _`json`_ and _`xml`_ formats. **The order** of the content types define the default example show first in
 ///  the Swagger UI. Swagger UI wil use the first _`content_type`_ value as a default example.
+///  If response does not have a body the _`content_type`_ will create a media type without schema.
+///  This is useful for documenting empty responses like _`204`_ or plain text responses without
+///  known schema.
 ///
 /// * `headers(...)` Slice of response headers that are returned back to a caller.
 ///
@@ -697,7 +778,9 @@ pub fn derive_to_schema(input: TokenStream) -> TokenStream {
 /// * `content((...), (...))` Can be used to define multiple return types for single response status. Supported format for single
 ///   _content_ is `(content_type = response_body, example = "...", examples(...))`. _`example`_
 ///   and _`examples`_ are optional arguments. Examples attribute behaves exactly same way as in
-///   the response and is mutually exclusive with the example attribute.
+///   the response and is mutually exclusive with the example attribute. The _`response_body`_ accepts
+///   same forms as _`body`_, e.g. _`("application/json" = inline(MyPrivateType))`_ expands the schema
+///   inline for that content type only.
 ///
 /// * `examples(...)` Define mulitple examples for single response. This attribute is mutually
 ///   exclusive to the _`example`_ attribute and if both are defined this will override the _`example`_.
@@ -782,6 +865,49 @@ pub fn derive_to_schema(input: TokenStream) -> TokenStream {
 /// responses(MyResponse)
 /// ```
 ///
+/// ## Responses of error types
+///
+/// Error types which cannot implement [`IntoResponses`][into_responses_trait], such as
+/// _`anyhow::Error`_ or _`Box<dyn Error>`_, can be documented by grouping response tuples under
+/// the error type with _`from_error(...)`_. The error type is only used for documentation purposes
+/// of the handler and the tuples behave exactly as if they were defined directly within
+/// _`responses(...)`_.
+///
+/// ```text
+/// responses(
+///     (status = 200, description = "Success"),
+///     from_error(anyhow::Error => [
+///         (status = 404, description = "Not found"),
+///         (status = 500, description = "Server error")
+///     ])
+/// )
+/// ```
+///
+/// ## Responses from return type
+///
+/// If _`responses(...)`_ is not defined the responses are resolved from the return type of the
+/// handler function. A _`Json<T>`_ success type creates a _`200`_ response with _`T`_ as
+/// _`application/json`_ body. The error type of _`Result<_, E>`_ contributes its responses if it
+/// implements [`IntoResponses`][into_responses_trait] and is ignored otherwise.
+///
+/// Common response wrapper types such as the ones of **axum** are recognized as success types too:
+/// * _`Html<T>`_ creates a _`200`_ response with _`T`_ as _`text/html`_ body.
+/// * _`Redirect`_ creates a _`3XX`_ response with _`Location`_ header.
+/// * _`StatusCode`_ creates a _`default`_ response without body since the status code is only
+///   known at runtime.
+///
+/// ```text
+/// #[utoipa::path(get, path = "/user/{id}")]
+/// async fn get_user(id: Path<i32>) -> Result<Json<User>, ApiError> {
+///     // ...
+/// }
+/// ```
+///
+/// Status codes must be unique within the responses. Defining the same status code twice with
+/// tuples is a compile error, as is a type implementing [`IntoResponses`][into_responses_trait]
+/// that declares a status code already defined elsewhere in _`responses(...)`_. Generic types
+/// are not checked.
+///
 /// # Response Header Attributes
 ///
 /// * `name` Name of the header. E.g. _`x-csrf-token`_
@@ -794,11 +920,19 @@ pub fn derive_to_schema(input: TokenStream) -> TokenStream {
 ///
 /// * `description = "..."` Can be used to define optional description for the response header as str.
 ///
+/// * `required = ...` Define whether the header is always present in the response.
+///   Value can be omitted or defined as boolean e.g. _`required = true`_ or _`required`_.
+///
+/// * `deprecated = ...` Define whether the header is deprecated. Value can be omitted or defined
+///   as boolean e.g. _`deprecated = true`_ or _`deprecated`_.
+///
 /// **Header supported formats:**
 ///
 /// ```text
 /// ("x-csrf-token"),
 /// ("x-csrf-token" = String, description = "New csrf token"),
+/// ("x-csrf-token" = String, required, description = "New csrf token"),
+/// ("x-legacy-token", deprecated),
 /// ```
 ///
 /// # Params Attributes
@@ -827,15 +961,32 @@ pub fn derive_to_schema(input: TokenStream) -> TokenStream {
 ///
 /// * `description = "..."` Define possible description for the parameter as str.
 ///
+/// * `content("content/type" = Type)` Describe the parameter with a media type instead of a schema.
+///   This is useful e.g. for query parameters carrying JSON encoded values. _`Type`_ can be
+///   `Type` or `inline(Type)` the same way as with `parameter_type` but the two cannot be used together.
+///   E.g. _`("filter", Query, content("application/json" = Filter))`_
+///
 /// * `style = ...` Defines how parameters are serialized by [`ParameterStyle`][style]. Default values are based on _`in`_ attribute.
 ///
 /// * `explode` Defines whether new _`parameter=value`_ is created for each parameter withing _`object`_ or _`array`_.
+///   Value can be omitted or defined as boolean e.g. _`explode = false`_.
 ///
 /// * `allow_reserved` Defines whether reserved characters _`:/?#[]@!$&'()*+,;=`_ is allowed within value.
+///   Value can be omitted or defined as boolean e.g. _`allow_reserved = false`_.
+///
+/// * `allow_empty_value` Defines whether empty value e.g. _`?flag=`_ is allowed for a _`Query`_
+///   parameter. Value can be omitted or defined as boolean e.g. _`allow_empty_value = false`_.
 ///
 /// * `example = ...` Can method reference or _`json!(...)`_. Given example
 ///   will override any example in underlying parameter type.
 ///
+/// * `examples(...)` Define multiple named examples for the parameter given as
+///   _`("name" = json!(...), summary = "...", description = "...")`_ where _`summary`_ and
+///   _`description`_ are optional e.g. _`examples(("uuid" = json!("6a2f..."), description = "Id as uuid"))`_.
+///   The same syntax as _`examples(...)`_ in [Response Attributes](#response-attributes) is
+///   also supported. This attribute is mutually exclusive to _`example`_ and if both are defined
+///   _`examples`_ takes precedence.
+///
 /// **For example:**
 ///
 /// ```text
@@ -879,6 +1030,14 @@ pub fn derive_to_schema(input: TokenStream) -> TokenStream {
 /// )
 /// ```
 ///
+/// Parameters are identified by name and location. Defining the same parameter twice in
+/// _`path_params(...)`_ and _`params(...)`_ e.g. _`("id" = u32, Path), ("id" = String, Path)`_ is
+/// a compile error. Parameters of [`IntoParams`][into_params] types are checked at compile time
+/// as well with [`IntoParams::PARAMETERS`][into_params_parameters] which is left empty for
+/// manual implementations and generic types with flattened fields.
+///
+/// [into_params_parameters]: trait.IntoParams.html#associatedconstant.PARAMETERS
+///
 /// # Security Requirement Attributes
 ///
 /// * `name` Define the name for security requirement. This must match to name of existing
@@ -892,19 +1051,58 @@ pub fn derive_to_schema(input: TokenStream) -> TokenStream {
 /// (),
 /// ("name" = []),
 /// ("name" = ["scope1", "scope2"]),
+/// ("name" = ["scope1", "scope2"], "name2" = []),
 /// ```
 ///
 /// Leaving empty _`()`_ creates an empty [`SecurityRequirement`][security] this is useful when
 /// security requirement is optional for operation.
 ///
+/// Each _`(...)`_ group is an alternative security requirement and only one of them must be satisfied.
+/// Multiple names within a single group are all required at the same time. E.g.
+/// _`security(("api_key" = []), ("oauth" = ["read"], "basic" = []))`_ accepts either _`api_key`_ alone or
+/// both _`oauth`_ and _`basic`_ together. Empty _`security()`_ removes all security from the operation.
+///
+/// # Methods of types and traits
+///
+/// Handlers can also be methods of a type or default methods of a trait. In that case the
+/// `#[utoipa::path]` without attributes must be also added to the _`impl`_ block or the _`trait`_
+/// itself since the path struct cannot be generated inside of them. Methods are then referenced with
+/// the type name e.g. _`paths(UserService::get_user)`_. The type name must start with an upper
+/// case letter to distinguish it from a module.
+/// ```rust
+/// struct UserService;
+///
+/// #[utoipa::path]
+/// impl UserService {
+///     /// Get user by id
+///     #[utoipa::path(get, path = "/users/{id}", responses((status = 200, description = "User found")))]
+///     fn get_user(&self, id: u64) {}
+/// }
+///
+/// #[utoipa::path]
+/// trait Health {
+///     #[utoipa::path(get, path = "/health", responses((status = 200, description = "Healthy")))]
+///     fn health() {}
+/// }
+/// ```
+///
 /// # actix_extras feature support for actix-web
 ///
 /// **actix_extras** feature gives **utoipa** ability to parse path operation information from **actix-web** types and macros.
 ///
-/// 1. Ability to parse `path` from **actix-web** path attribute macros e.g. _`#[get(...)]`_.
+/// 1. Ability to parse `path` and operation from **actix-web** path attribute macros e.g. _`#[get(...)]`_
+///    or _`#[actix_web::get(...)]`_. Operations of _`#[route("/pet", method = "GET", method = "HEAD")]`_
+///    and stacked path attribute macros under _`#[routes]`_ sharing the same path are all documented.
+///    Stacked path attribute macros with different paths are rejected at compile time since one
+///    handler can document only one path.
 /// 2. Ability to parse [`std::primitive`]  or [`String`] or [`tuple`] typed `path` parameters from **actix-web** _`web::Path<...>`_.
 /// 3. Ability to parse `path` and `query` parameters form **actix-web** _`web::Path<...>`_, _`web::Query<...>`_ types
 ///    with [`IntoParams`][into_params] trait.
+/// 4. Ability to resolve `request_body` from **actix-web** _`web::Json<...>`_ and _`web::Form<...>`_
+///    arguments when _`request_body`_ is not defined. _`web::Json<...>`_ uses _`application/json`_
+///    and _`web::Form<...>`_ uses _`application/x-www-form-urlencoded`_ as content type.
+/// 5. Ability to resolve free form `query` parameter maps e.g. _`web::Query<HashMap<String, String>>`_
+///    as an _`object`_ query parameter named after the handler argument.
 ///
 /// See the **actix_extras** in action in examples [todo-actix](https://github.com/juhaku/utoipa/tree/master/examples/todo-actix).
 ///
@@ -947,10 +1145,34 @@ pub fn derive_to_schema(input: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// Request body is resolved from the _`web::Json<...>`_ argument of the handler.
+/// ```rust
+/// use actix_web::{post, web, HttpResponse, Responder};
+/// use serde::Deserialize;
+/// use utoipa::ToSchema;
+///
+/// #[derive(Deserialize, ToSchema)]
+/// struct Pet {
+///     name: String,
+/// }
+///
+/// /// Create new Pet
+/// #[utoipa::path(
+///     responses(
+///         (status = 201, description = "Pet created")
+///     )
+/// )]
+/// #[post("/pet")]
+/// async fn create_pet(pet: web::Json<Pet>) -> impl Responder {
+///     HttpResponse::Created().json(pet.into_inner().name)
+/// }
+/// ```
+///
 /// # rocket_extras feature support for rocket
 ///
 /// **rocket_extras** feature enahances path operation parameter support. It gives **utoipa** ability to parse `path`, `path parameters`
-/// and `query parameters` based on arguments given to **rocket**  proc macros such as _**`#[get(...)]`**_.
+/// and `query parameters` based on arguments given to **rocket**  proc macros such as _**`#[get(...)]`**_
+/// or _**`#[rocket::get(...)]`**_ e.g. _`#[get("/item/<id>?<filter..>")]`_.
 ///
 /// 1. It is able to parse parameter types for [primitive types][primitive], [`String`], [`Vec`], [`Option`] or [`std::path::PathBuf`]
 ///    type.
@@ -967,6 +1189,15 @@ pub fn derive_to_schema(input: TokenStream) -> TokenStream {
 ///    parameter names and types from it.
 /// 2. It enhances [`IntoParams` derive][into_params_derive] functionality by automatically resolving _`parameter_in`_ from
 ///   _`Path<...>`_ or _`Query<...>`_ handler function arguments.
+/// 3. Parameters resolved from handler function arguments do not need to be listed in _`params(...)`_.
+///   _`IntoParams`_ types of _`Path<...>`_ or _`Query<...>`_ arguments are added to the parameters
+///   only if _`params(...)`_ is not defined. Once _`params(...)`_ is defined all _`IntoParams`_
+///   types must be listed in it.
+/// 4. Free form query parameter maps e.g. _`Query<HashMap<String, String>>`_ are resolved as an
+///   _`object`_ query parameter named after the handler argument.
+/// 5. Request body is resolved from _`Json<...>`_ and _`Form<...>`_ handler function arguments when
+///   _`request_body`_ is not defined. _`Form<...>`_ uses _`application/x-www-form-urlencoded`_ and the
+///   schema of the wrapped type as the request body.
 ///
 /// _**Resole path argument types from tuple style handler arguments.**_
 /// ```rust
@@ -1021,6 +1252,87 @@ pub fn derive_to_schema(input: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// _**Resolve all parameters from the handler arguments without `params(...)`.**_
+/// ```rust
+/// # use serde::Deserialize;
+/// # use utoipa::IntoParams;
+/// # use axum::{extract::{Path, Query}, Json};
+/// #[derive(Deserialize, IntoParams)]
+/// struct TodoSearchQuery {
+///     /// Search by value. Search is incase sensitive.
+///     value: String,
+/// }
+///
+/// /// Search Todos of user by query params.
+/// #[utoipa::path(
+///     get,
+///     path = "/user/{id}/todo/search",
+///     responses(
+///         (status = 200, description = "List matching todos by query", body = [String])
+///     )
+/// )]
+/// async fn search_user_todos(
+///     Path(id): Path<i32>,
+///     query: Query<TodoSearchQuery>,
+/// ) -> Json<Vec<String>> {
+///     Json(vec![])
+/// }
+/// ```
+///
+/// # poem_extras feature support for poem
+///
+/// **poem_extras** feature resolves parameters and request body from the handler function
+/// arguments of [poem](https://github.com/poem-web/poem) handlers. Poem names its extractors the
+/// same way as axum thus all the enhancements of **axum_extras** listed above apply to
+/// _`poem::web::Path<...>`_, _`poem::web::Query<...>`_, _`poem::web::Json<...>`_ and
+/// _`poem::web::Form<...>`_ arguments as well. Place the _`#[utoipa::path(...)]`_ attribute above
+/// the _`#[handler]`_ attribute.
+///
+/// _**Resolve path parameter and request body from poem handler arguments.**_
+/// ```text
+/// #[utoipa::path(
+///     put,
+///     path = "/todo/{id}",
+///     responses(
+///         (status = 200, description = "Todo updated")
+///     )
+/// )]
+/// #[handler]
+/// async fn update_todo(Path(id): Path<i32>, Json(todo): Json<Todo>) {}
+/// ```
+///
+/// # salvo_extras feature support for salvo
+///
+/// **salvo_extras** feature enhances parameter support for path operation of
+/// [salvo](https://github.com/salvo-rs/salvo) handlers in following ways.
+///
+/// 1. Parameters are resolved from _`PathParam<...>`_ and _`QueryParam<...>`_ handler function
+///   arguments and named after the argument. They do not need to be listed in _`params(...)`_.
+///   Parameters listed in _`params(...)`_ by the same name get their type from the argument.
+/// 2. _`QueryParam<T, false>`_ resolves to a query parameter which is not required.
+/// 3. Request body is resolved from _`JsonBody<...>`_ and _`FormBody<...>`_ handler function
+///   arguments when _`request_body`_ is not defined.
+///
+/// _**Resolve parameters and request body from salvo handler arguments.**_
+/// ```text
+/// #[utoipa::path(
+///     put,
+///     path = "/todo/{id}",
+///     responses(
+///         (status = 200, description = "Todo updated")
+///     )
+/// )]
+/// #[handler]
+/// async fn update_todo(id: PathParam<i32>, force: QueryParam<bool, false>, todo: JsonBody<Todo>) {}
+/// ```
+///
+/// # auto_collect feature support
+///
+/// With **auto_collect** feature the generated path is registered globally when the handler is
+/// compiled in. All registered paths can be gathered with `utoipa::collect_paths()` or added to the
+/// document with _`collect_paths`_ attribute of the [`OpenApi`][openapi] derive which removes the
+/// need to list every handler manually.
+///
 /// # Examples
 ///
 /// _**More complete example.**_
@@ -1177,7 +1489,11 @@ pub fn derive_to_schema(input: TokenStream) -> TokenStream {
 /// [path]: trait.Path.html
 /// [to_schema]: trait.ToSchema.html
 /// [openapi]: derive.OpenApi.html
+/// [api_tag]: trait.ApiTag.html
 /// [security]: openapi/security/struct.SecurityRequirement.html
+/// [server]: openapi/server/struct.Server.html
+/// [callback]: openapi/path/struct.Callback.html
+/// [path_item]: openapi/path/struct.PathItem.html
 /// [security_schema]: openapi/security/struct.SecuritySchema.html
 /// [primitive]: https://doc.rust-lang.org/std/primitive/index.html
 /// [into_params]: trait.IntoParams.html
@@ -1186,19 +1502,123 @@ pub fn derive_to_schema(input: TokenStream) -> TokenStream {
 /// [into_params_derive]: derive.IntoParams.html
 /// [to_response_trait]: trait.ToResponse.html
 pub fn path(attr: TokenStream, item: TokenStream) -> TokenStream {
-    let path_attribute = syn::parse_macro_input!(attr as PathAttr);
+    let item = syn::parse::<Item>(item).unwrap_or_abort();
+    if matches!(item, Item::Impl(_) | Item::Trait(_)) && !attr.is_empty() {
+        abort!(
+            TokenStream2::from(attr),
+            "unexpected attribute, #[utoipa::path] on impl block or trait does not accept attributes";
+            help = "Define the attributes to the #[utoipa::path(...)] of the methods instead"
+        )
+    }
 
-    #[cfg(any(
-        feature = "actix_extras",
-        feature = "rocket_extras",
-        feature = "axum_extras"
-    ))]
-    let mut path_attribute = path_attribute;
+    match item {
+        Item::Fn(ast_fn) => {
+            let path_attribute = syn::parse_macro_input!(attr as PathAttr);
+            let path = path_tokens(path_attribute, &ast_fn, None);
 
-    let ast_fn = syn::parse::<ItemFn>(item).unwrap_or_abort();
+            quote! {
+                #path
+                #ast_fn
+            }
+            .into()
+        }
+        Item::Impl(mut item_impl) => {
+            let type_name = match item_impl.self_ty.as_ref() {
+                syn::Type::Path(type_path) => type_path
+                    .path
+                    .segments
+                    .last()
+                    .map(|segment| segment.ident.clone()),
+                _ => None,
+            }
+            .expect_or_abort("expected impl block of a named type");
+
+            let paths = item_impl
+                .items
+                .iter_mut()
+                .filter_map(|item| match item {
+                    syn::ImplItem::Method(method) => {
+                        take_path_attribute(&mut method.attrs).map(|attribute| {
+                            let ast_fn = ItemFn {
+                                attrs: method.attrs.clone(),
+                                vis: method.vis.clone(),
+                                sig: method.sig.clone(),
+                                block: Box::new(method.block.clone()),
+                            };
+                            method_path_tokens(attribute, ast_fn, &type_name)
+                        })
+                    }
+                    _ => None,
+                })
+                .collect::<TokenStream2>();
+
+            quote! {
+                #paths
+                #item_impl
+            }
+            .into()
+        }
+        Item::Trait(mut item_trait) => {
+            let type_name = item_trait.ident.clone();
+
+            let paths = item_trait
+                .items
+                .iter_mut()
+                .filter_map(|item| match item {
+                    syn::TraitItem::Method(method) => {
+                        take_path_attribute(&mut method.attrs).map(|attribute| {
+                            let block = method.default.clone();
+                            let ast_fn = ItemFn {
+                                attrs: method.attrs.clone(),
+                                vis: syn::Visibility::Inherited,
+                                sig: method.sig.clone(),
+                                block: Box::new(block.unwrap_or_else(|| syn::parse_quote!({}))),
+                            };
+                            method_path_tokens(attribute, ast_fn, &type_name)
+                        })
+                    }
+                    _ => None,
+                })
+                .collect::<TokenStream2>();
+
+            quote! {
+                #paths
+                #item_trait
+            }
+            .into()
+        }
+        item => abort!(
+            item,
+            "unexpected item, expected #[utoipa::path] on function, impl block or trait"
+        ),
+    }
+}
+
+/// Take the `#[utoipa::path(...)]` attribute from attributes of a method.
+fn take_path_attribute(attributes: &mut Vec<Attribute>) -> Option<Attribute> {
+    let index = attributes.iter().position(|attribute| {
+        matches!(attribute.path.segments.last(), Some(segment) if segment.ident == "path")
+    })?;
+
+    Some(attributes.remove(index))
+}
+
+fn method_path_tokens(attribute: Attribute, ast_fn: ItemFn, type_name: &Ident) -> TokenStream2 {
+    let path_attribute = attribute.parse_args::<PathAttr>().unwrap_or_abort();
+
+    path_tokens(path_attribute, &ast_fn, Some(type_name))
+}
+
+/// Create the path struct and [`utoipa::Path`] implementation for the handler function.
+fn path_tokens<'a>(
+    mut path_attribute: PathAttr<'a>,
+    ast_fn: &'a ItemFn,
+    type_name: Option<&Ident>,
+) -> TokenStream2 {
     let fn_name = &*ast_fn.sig.ident.to_string();
+    path_attribute.update_responses(&ast_fn.sig.output);
 
-    let mut resolved_operation = PathOperations::resolve_operation(&ast_fn);
+    let mut resolved_operation = PathOperations::resolve_operation(ast_fn);
 
     let resolved_path = PathOperations::resolve_path(
         &resolved_operation
@@ -1210,27 +1630,40 @@ pub fn path(attr: TokenStream, item: TokenStream) -> TokenStream {
     #[cfg(any(
         feature = "actix_extras",
         feature = "rocket_extras",
-        feature = "axum_extras"
+        feature = "axum_extras",
+        feature = "poem_extras",
+        feature = "salvo_extras"
     ))]
     let mut resolved_path = resolved_path;
 
     #[cfg(any(
         feature = "actix_extras",
         feature = "rocket_extras",
-        feature = "axum_extras"
+        feature = "axum_extras",
+        feature = "poem_extras",
+        feature = "salvo_extras"
     ))]
     {
-        use ext::ArgumentResolver;
+        use ext::{ArgumentResolver, RequestBodyResolver};
         let args = resolved_path.as_mut().map(|path| mem::take(&mut path.args));
         let (arguments, into_params_types) =
             PathOperations::resolve_arguments(&ast_fn.sig.inputs, args);
 
+        let explicit_params = path_attribute.has_params();
         path_attribute.update_parameters(arguments);
-        path_attribute.update_parameters_parameter_in(into_params_types);
+        path_attribute.update_parameters_parameter_in(into_params_types, explicit_params);
+        path_attribute.update_request_body(PathOperations::resolve_request_body(
+            &ast_fn.sig.inputs,
+        ));
     }
 
-    let path = Path::new(path_attribute, fn_name)
-        .path_operation(resolved_operation.map(|operation| operation.path_operation))
+    Path::new(path_attribute, fn_name)
+        .type_name(type_name)
+        .path_operations(
+            resolved_operation
+                .map(|operation| operation.path_operations)
+                .unwrap_or_default(),
+        )
         .path(|| resolved_path.map(|path| path.path))
         .doc_comments(CommentAttributes::from_attributes(&ast_fn.attrs).0)
         .deprecated(ast_fn.attrs.iter().find_map(|attr| {
@@ -1240,13 +1673,8 @@ pub fn path(attr: TokenStream, item: TokenStream) -> TokenStream {
             } else {
                 Some(true)
             }
-        }));
-
-    quote! {
-        #path
-        #ast_fn
-    }
-    .into()
+        }))
+        .to_token_stream()
 }
 
 #[proc_macro_error]
@@ -1263,17 +1691,10 @@ pub fn fbr_path(attr: TokenStream, item: TokenStream) -> TokenStream {
     };
 
     let span = proc_macro::Span::call_site();
-    let source = span.source_file();
-	let source_path_buf = source.path();
+    let source_path_buf = span.local_file().unwrap_or_default();
     let actix_path = actix_fbr_resolver::actix_path(source_path_buf);
-    let path_attribute = path::fbr_to_path_attr(fbr_path_attribute, &actix_path, path_operation);
-
-    #[cfg(any(
-        feature = "actix_extras",
-        feature = "rocket_extras",
-        feature = "axum_extras"
-    ))]
-    let mut path_attribute = path_attribute;
+    let mut path_attribute = path::fbr_to_path_attr(fbr_path_attribute, &actix_path, path_operation);
+    path_attribute.update_responses(&ast_fn.sig.output);
 
     let mut resolved_operation = PathOperations::resolve_operation(&ast_fn);
 
@@ -1287,27 +1708,39 @@ pub fn fbr_path(attr: TokenStream, item: TokenStream) -> TokenStream {
     #[cfg(any(
         feature = "actix_extras",
         feature = "rocket_extras",
-        feature = "axum_extras"
+        feature = "axum_extras",
+        feature = "poem_extras",
+        feature = "salvo_extras"
     ))]
     let mut resolved_path = resolved_path;
 
     #[cfg(any(
         feature = "actix_extras",
         feature = "rocket_extras",
-        feature = "axum_extras"
+        feature = "axum_extras",
+        feature = "poem_extras",
+        feature = "salvo_extras"
     ))]
     {
-        use ext::ArgumentResolver;
+        use ext::{ArgumentResolver, RequestBodyResolver};
         let args = resolved_path.as_mut().map(|path| mem::take(&mut path.args));
         let (arguments, into_params_types) =
             PathOperations::resolve_arguments(&ast_fn.sig.inputs, args);
 
+        let explicit_params = path_attribute.has_params();
         path_attribute.update_parameters(arguments);
-        path_attribute.update_parameters_parameter_in(into_params_types);
+        path_attribute.update_parameters_parameter_in(into_params_types, explicit_params);
+        path_attribute.update_request_body(PathOperations::resolve_request_body(
+            &ast_fn.sig.inputs,
+        ));
     }
 
     let path = Path::new(path_attribute, fn_name)
-        .path_operation(resolved_operation.map(|operation| operation.path_operation))
+        .path_operations(
+            resolved_operation
+                .map(|operation| operation.path_operations)
+                .unwrap_or_default(),
+        )
         .path(|| resolved_path.map(|path| path.path))
         .doc_comments(CommentAttributes::from_attributes(&ast_fn.attrs).0)
         .deprecated(ast_fn.attrs.iter().find_map(|attr| {
@@ -1328,198 +1761,6 @@ pub fn fbr_path(attr: TokenStream, item: TokenStream) -> TokenStream {
 
 // #[proc_macro_error]
 // #[proc_macro_derive(OpenApi, attributes(openapi))]
-// /// Generate OpenApi base object with defaults from
-// /// project settings.
-// ///
-// /// This is `#[derive]` implementation for [`OpenApi`][openapi] trait. The macro accepts one `openapi` argument.
-// ///
-// /// # OpenApi `#[openapi(...)]` attributes
-// ///
-// /// * `paths(...)`  List of method references having attribute [`#[utoipa::path]`][path] macro.
-// /// * `components(schemas(...), responses(...))` Takes available _`component`_ configurations. Currently only
-// ///    _`schema`_ and _`response`_ components are supported.
-// ///    * `schemas(...)` List of [`ToSchema`][to_schema]s in OpenAPI schema.
-// ///    * `responses(...)` List of types that implement
-// /// [`ToResponse`][to_response_trait].
-// /// * `modifiers(...)` List of items implementing [`Modify`][modify] trait for runtime OpenApi modification.
-// ///   See the [trait documentation][modify] for more details.
-// /// * `security(...)` List of [`SecurityRequirement`][security]s global to all operations.
-// ///   See more details in [`#[utoipa::path(...)]`][path] [attribute macro security options][path_security].
-// /// * `tags(...)` List of [`Tag`][tags] which must match the tag _**path operation**_. By default
-// ///   the tag is derived from path given to **handlers** list or if undefined then `crate` is used by default.
-// ///   Alternatively the tag name can be given to path operation via [`#[utoipa::path(...)]`][path] macro.
-// ///   Tag can be used to define extra information for the api to produce richer documentation.
-// /// * `external_docs(...)` Can be used to reference external resource to the OpenAPI doc for extended documentation.
-// ///   External docs can be in [`OpenApi`][openapi_struct] or in [`Tag`][tags] level.
-// /// * `servers(...)` Define [`servers`][servers] as derive argumenst to the _`OpenApi`_. Servers
-// ///   are completely optional and thus can be omitted from the declaration.
-// /// * `info(...)` Declare [`Info`][info] attribute values used to override the default values
-// ///   generated from Cargo environment variables. **Note!** Defined attributes will override the
-// ///   whole attribute from generated values of Cargo environment variables. E.g. defining
-// ///   `contact(name = ...)` will ultimately override whole contact of info and not just partially
-// ///   the name.
-// ///
-// /// OpenApi derive macro will also derive [`Info`][info] for OpenApi specification using Cargo
-// /// environment variables.
-// ///
-// /// * env `CARGO_PKG_NAME` map to info `title`
-// /// * env `CARGO_PKG_VERSION` map to info `version`
-// /// * env `CARGO_PKG_DESCRIPTION` map info `description`
-// /// * env `CARGO_PKG_AUTHORS` map to contact `name` and `email` **only first author will be used**
-// /// * env `CARGO_PKG_LICENSE` map to info `license`
-// ///
-// /// # `info(...)` attribute syntax
-// /// * `title = ...` Define title of the API. It can be literal string.
-// /// * `description = ...` Define description of the API. Markdown can be used for rich text
-// ///   representation. It can be literal string or [`include_str!`] statement.
-// /// * `contanct(...)` Used to override the whole contanct generated from environment variables.
-// ///     * `name = ...` Define identifying name of contact person / organization. It Can be a literal string.
-// ///     * `email = ...` Define email address of the contact person / organization. It can be a literal string.
-// ///     * `url = ...` Define URL pointing to the contact information. It must be in URL formatted string.
-// /// * `license(...)` Used to override the whole license generated from environment variables.
-// ///     * `name = ...` License name of the API. It can be a literal string.
-// ///     * `url = ...` Define optional URL of the license. It must be URL formatted string.
-// ///
-// /// # `servers(...)` attribute syntax
-// /// * `url = ...` Define the url for server. It can be literal string.
-// /// * `description = ...` Define description for the server. It can be literal string.
-// /// * `variables(...)` Can be used to define variables for the url.
-// ///     * `name = ...` Is the first argument withing parentheses. It must be literal string.
-// ///     * `default = ...` Defines a default value for the variable if nothing else will be
-// ///       provided. If _`enum_values`_ is defined the _`default`_ must be found within the enum
-// ///       options. It can be a literal string.
-// ///     * `description = ...` Define the description for the variable. It can be a literal string.
-// ///     * `enum_values(...)` Define list of possible values for the variable. Values must be
-// ///       literal strings.
-// ///
-// ///  _**Example server variable definition.**_
-// ///  ```text
-// /// ("username" = (default = "demo", description = "Default username for API")),
-// /// ("port" = (enum_values("8080", "5000", "4545")))
-// /// ```
-// ///
-// /// # Examples
-// ///
-// /// _**Define OpenApi schema with some paths and components.**_
-// /// ```rust
-// /// # use utoipa::{OpenApi, ToSchema};
-// /// #
-// /// #[derive(ToSchema)]
-// /// struct Pet {
-// ///     name: String,
-// ///     age: i32,
-// /// }
-// ///
-// /// #[derive(ToSchema)]
-// /// enum Status {
-// ///     Active, InActive, Locked,
-// /// }
-// ///
-// /// #[utoipa::path(get, path = "/pet")]
-// /// fn get_pet() -> Pet {
-// ///     Pet {
-// ///         name: "bob".to_string(),
-// ///         age: 8,
-// ///     }
-// /// }
-// ///
-// /// #[utoipa::path(get, path = "/status")]
-// /// fn get_status() -> Status {
-// ///     Status::Active
-// /// }
-// ///
-// /// #[derive(OpenApi)]
-// /// #[openapi(
-// ///     paths(get_pet, get_status),
-// ///     components(schemas(Pet, Status)),
-// ///     security(
-// ///         (),
-// ///         ("my_auth" = ["read:items", "edit:items"]),
-// ///         ("token_jwt" = [])
-// ///     ),
-// ///     tags(
-// ///         (name = "pets::api", description = "All about pets",
-// ///             external_docs(url = "http://more.about.pets.api", description = "Find out more"))
-// ///     ),
-// ///     external_docs(url = "http://more.about.our.apis", description = "More about our APIs")
-// /// )]
-// /// struct ApiDoc;
-// /// ```
-// ///
-// /// _**Define servers to OpenApi.**_
-// ///```rust
-// /// # use utoipa::OpenApi;
-// /// #[derive(OpenApi)]
-// /// #[openapi(
-// ///     servers(
-// ///         (url = "http://localhost:8989", description = "Local server"),
-// ///         (url = "http://api.{username}:{port}", description = "Remote API",
-// ///             variables(
-// ///                 ("username" = (default = "demo", description = "Default username for API")),
-// ///                 ("port" = (default = "8080", enum_values("8080", "5000", "3030"), description = "Supported ports for API"))
-// ///             )
-// ///         )
-// ///     )
-// /// )]
-// /// struct ApiDoc;
-// ///```
-// ///
-// /// _**Define info attribute values used to override auto generated ones from Cargo environment
-// /// variables.**_
-// /// ```compile_fail
-// /// # use utoipa::OpenApi;
-// /// #[derive(OpenApi)]
-// /// #[openapi(info(
-// ///     title = "title override",
-// ///     description = include_str!("./path/to/content"), // fail compile cause no such file
-// ///     contact(name = "Test")
-// /// ))]
-// /// struct ApiDoc;
-// /// ```
-// ///
-// /// _**Create OpenAPI with resuable response.**_
-// /// ```rust
-// /// #[derive(utoipa::ToSchema)]
-// /// struct Person {
-// ///     name: String,
-// /// }
-// ///
-// /// /// Person list response
-// /// #[derive(utoipa::ToResponse)]
-// /// struct PersonList(Vec<Person>);
-// ///
-// /// #[utoipa::path(
-// ///     get,
-// ///     path = "/person-list",
-// ///     responses(
-// ///         (status = 200, response = PersonList)
-// ///     )
-// /// )]
-// /// fn get_persons() -> Vec<Person> {
-// ///     vec![]
-// /// }
-// ///
-// /// #[derive(utoipa::OpenApi)]
-// /// #[openapi(
-// ///     components(
-// ///         schemas(Person),
-// ///         responses(PersonList)
-// ///     )
-// /// )]
-// /// struct ApiDoc;
-// /// ```
-// ///
-// /// [openapi]: trait.OpenApi.html
-// /// [openapi_struct]: openapi/struct.OpenApi.html
-// /// [to_schema]: derive.ToSchema.html
-// /// [path]: attr.path.html
-// /// [modify]: trait.Modify.html
-// /// [info]: openapi/info/struct.Info.html
-// /// [security]: openapi/security/struct.SecurityRequirement.html
-// /// [path_security]: attr.path.html#security-requirement-attributes
-// /// [tags]: openapi/tag/struct.Tag.html
-// /// [to_response_trait]: trait.ToResponse.html
-// /// [servers]: openapi/server/index.html
 // pub fn openapi(input: TokenStream) -> TokenStream {
 //     let DeriveInput { attrs, ident, .. } = syn::parse_macro_input!(input);
 
@@ -1534,6 +1775,292 @@ pub fn fbr_path(attr: TokenStream, item: TokenStream) -> TokenStream {
 
 #[proc_macro_error]
 #[proc_macro_derive(OpenApi, attributes(openapi))]
+/// Generate OpenApi base object with defaults from
+/// project settings.
+///
+/// This is `#[derive]` implementation for [`OpenApi`][openapi] trait. The macro accepts one `openapi` argument.
+///
+/// # OpenApi `#[openapi(...)]` attributes
+///
+/// * `paths(...)`  List of method references having attribute [`#[utoipa::path]`][path] macro.
+///   Handlers discovered from _`src/routes`_ honor the inner _`#![cfg(...)]`_ of the route file
+///   and _`#[cfg(...)]`_ of the handler function.
+/// * `components(schemas(...), responses(...), parameters(...), examples(...), request_bodies(...), headers(...), path_items(...), security_schemes(...))`
+///    Takes available _`component`_ configurations. Components can be referenced from elsewhere
+///    of the document with _`Ref::from_parameter_name(...)`_ and its siblings.
+///    * `schemas(...)` List of [`ToSchema`][to_schema]s in OpenAPI schema. Different types
+///      resolving to same schema name are reported as compile error.
+///    * `responses(...)` List of types that implement
+/// [`ToResponse`][to_response_trait].
+///    * `parameters(...)` List of [`IntoParams`][into_params] types. Each parameter of the type
+///      is registered by its name.
+///    * `examples(...)` List of named examples given as _`("name" = expression)`_ where the
+///      expression evaluates to _`Example`_, _`ExampleBuilder`_ or _`Ref`_ e.g.
+///      _`("Pet" = example_pet())`_. Duplicate names are reported as compile error.
+///    * `request_bodies(...)` List of named request bodies with the same syntax as
+///      _`examples(...)`_ where the expression evaluates to _`RequestBody`_ or _`Ref`_.
+///    * `headers(...)` List of named headers with the same syntax as _`examples(...)`_ where
+///      the expression evaluates to _`Header`_ or _`Ref`_.
+///    * `path_items(...)` List of named path items with the same syntax as _`examples(...)`_ where
+///      the expression evaluates to _`PathItem`_. Paths and webhooks can reference them with
+///      _`Ref::from_path_item_name(...)`_ e.g. in a modifier. Reusable path items require
+///      _`openapi = "3.1"`_.
+///    * `security_schemes(...)` List of named [`SecurityScheme`][security_scheme]s given as
+///      _`("name" = scheme(...))`_. See [`security_schemes(...)` syntax](#security_schemes-attribute-syntax).
+/// * `modifiers(...)` List of items implementing [`Modify`][modify] trait for runtime OpenApi modification.
+///   Functions _`fn(&mut utoipa::openapi::OpenApi)`_ can be listed by their path without the _`&`_
+///   e.g. _`modifiers(&SecurityAddon, add_servers)`_. Modifiers are applied in the listed order.
+///   See the [trait documentation][modify] for more details.
+/// * `security(...)` List of [`SecurityRequirement`][security]s global to all operations.
+///   See more details in [`#[utoipa::path(...)]`][path] [attribute macro security options][path_security].
+///   Public operations of globally secured API opt out with empty _`security()`_ of the
+///   [`#[utoipa::path(...)]`][path].
+/// * `tags(...)` List of [`Tag`][tags] which must match the tag _**path operation**_. By default
+///   the tag is derived from path given to **handlers** list or if undefined then `crate` is used by default.
+///   Alternatively the tag name can be given to path operation via [`#[utoipa::path(...)]`][path] macro.
+///   Tag can be used to define extra information for the api to produce richer documentation.
+///   Each tag is defined as _`(name = "...", description = "...", external_docs(...))`_ where only
+///   _`name`_ is required and must be unique, or as path to type implementing
+///   [`ApiTag`][api_tag] e.g. one using [`#[derive(ApiTag)]`][api_tag_derive]. Duplicate names
+///   of _`ApiTag`_ types are reported at compile time. Tags are
+///   serialized in the declared order which UIs use to order the operation groups.
+/// * `external_docs(...)` Can be used to reference external resource to the OpenAPI doc for extended documentation.
+///   External docs can be in [`OpenApi`][openapi_struct] or in [`Tag`][tags] level.
+/// * `servers(...)` Define [`servers`][servers] as derive argumenst to the _`OpenApi`_. Servers
+///   are completely optional and thus can be omitted from the declaration.
+/// * `operation_id_format = "..."` Generate operation ids for paths which do not define
+///   `operation_id` themselves. Use _`"snake"`_ or _`"camel"`_ to format the handler function name,
+///   or a template such as _`"{module}_{fn}"`_ where `{module}` is the handler module path joined
+///   with underscores. Duplicate generated operation ids are reported at compile time.
+/// * `context_path = "..."` Prefix prepended to every path of the _`OpenApi`_ e.g. _`"/api/v1"`_. Use this
+///   when handlers are mounted under a scope or nested router so the documented paths match the
+///   served ones. It is applied in addition to `context_path` of [`#[utoipa::path(...)]`][path].
+/// * `nest(...)` List of other _`OpenApi`_ types nested under a path prefix given as
+///   _`("/prefix" = path::to::ApiDoc)`_ e.g. _`nest(("/api/v1" = V1Api), ("/admin" = AdminApi))`_.
+///   Paths of the nested docs are prefixed and their components, tags and security are merged
+///   to this _`OpenApi`_ with [`OpenApi::nest`][openapi_nest]. Existing items are not overridden
+///   thus a nested _`OpenApi`_ defining a schema with same name by a different type than this or
+///   other nested _`OpenApi`_ is reported as compile error naming the schema and both types.
+///   Nesting the _`OpenApi`_ to itself or the same _`OpenApi`_ twice under the same path is
+///   reported as compile error as well.
+/// * `webhooks(...)` List of method references having [`#[utoipa::path(webhook = "...")]`][path]
+///   macro. These are added to the _`webhooks`_ of the OpenAPI document by their webhook name.
+///   Webhooks are only supported by OpenAPI 3.1 thus _`openapi = "3.1"`_ must be defined as well and
+///   handlers of _`src/routes`_ cannot be listed since they are already served as paths.
+///
+/// Items of _`paths(...)`_, _`webhooks(...)`_ and _`schemas(...)`_, _`responses(...)`_ and
+/// _`parameters(...)`_ of _`components(...)`_ can be prefixed with _`#[cfg(...)]`_ attributes
+/// to include them only in matching build configurations e.g.
+/// _`schemas(Pet, #[cfg(feature = "admin")] AdminUser)`_.
+///
+/// * `extensions(...)` List of specification extensions written to the root of the OpenAPI
+///   document given as _`("x-name" = value)`_ where value can be a literal, _`json!(...)`_ or a
+///   method reference returning _`serde_json::Value`_. Names must start with _`x-`_ e.g.
+///   _`extensions(("x-google-backend" = json!({"address": "https://backend"})))`_.
+/// * `collect_paths` Add paths of all handlers registered with **auto_collect** feature to the
+///   document in addition to the handlers of _`src/routes`_. Paths are prefixed with
+///   _`context_path`_ but operation ids are not formatted with _`operation_id_format`_. Operations
+///   already defined by the handlers of _`src/routes`_ are kept. Requires **auto_collect** feature.
+/// * `openapi = ...` Version of the generated OpenAPI document either _`"3.0"`_ (default) or
+///   _`"3.1"`_. OpenAPI 3.1 document defines _`jsonSchemaDialect`_ and its schemas are serialized
+///   as JSON Schema e.g. nullable types as _`"type": ["string", "null"]`_ and schema _`example`_ as
+///   _`examples`_ array.
+/// * `info(...)` Declare [`Info`][info] attribute values used to override the default values
+///   generated from Cargo environment variables. **Note!** Defined attributes will override the
+///   whole attribute from generated values of Cargo environment variables. E.g. defining
+///   `contact(name = ...)` will ultimately override whole contact of info and not just partially
+///   the name.
+///
+/// OpenApi derive macro will also derive [`Info`][info] for OpenApi specification using Cargo
+/// environment variables.
+///
+/// * env `CARGO_PKG_NAME` map to info `title`
+/// * env `CARGO_PKG_VERSION` map to info `version`
+/// * env `CARGO_PKG_DESCRIPTION` map info `description`
+/// * env `CARGO_PKG_AUTHORS` map to contact `name` and `email` **only first author will be used**
+/// * env `CARGO_PKG_LICENSE` map to info `license`
+///
+/// Environment variables which are empty are ignored. Values generated for _`description`_,
+/// _`contact`_ and _`license`_ can be left out from the [`Info`][info] by setting them to
+/// _`None`_ e.g. _`info(license = None)`_.
+///
+/// # `info(...)` attribute syntax
+/// * `title = ...` Define title of the API. It can be literal string.
+/// * `summary = ...` Define short summary of the API. It can be literal string. Only supported
+///   with _`openapi = "3.1"`_.
+/// * `version = ...` Define version of the API. It can be literal string.
+/// * `description = ...` Define description of the API. Markdown can be used for rich text
+///   representation. It can be literal string or [`include_str!`] statement.
+/// * `terms_of_service = ...` Define URL to the terms of service of the API. It can be literal
+///   string.
+/// * `contact(...)` Used to override the whole contact generated from environment variables.
+///     * `name = ...` Define identifying name of contact person / organization. It Can be a literal string.
+///     * `email = ...` Define email address of the contact person / organization. It can be a literal string.
+///     * `url = ...` Define URL pointing to the contact information. It must be in URL formatted string.
+/// * `license(...)` Used to override the whole license generated from environment variables.
+///     * `name = ...` License name of the API. It can be a literal string.
+///     * `url = ...` Define optional URL of the license. It must be URL formatted string.
+///     * `identifier = ...` Define [SPDX](https://spdx.org/licenses/) license expression of
+///       the API e.g. _`"MIT OR Apache-2.0"`_ instead of the URL. It can be a literal string.
+///       Only supported with _`openapi = "3.1"`_.
+/// * `extensions(...)` Specification extensions of the info object with same syntax as
+///   _`extensions(...)`_ of the _`OpenApi`_ e.g. _`extensions(("x-logo" = json!({"url": "..."})))`_.
+///
+/// # `servers(...)` attribute syntax
+/// * `url = ...` Define the url for server. It can be literal string.
+/// * `description = ...` Define description for the server. It can be literal string.
+/// * `variables(...)` Can be used to define variables for the url. Every _`{variable}`_ of the url
+///   must be defined and every defined variable must be used in the url, otherwise compile error
+///   is reported.
+///     * `name = ...` Is the first argument withing parentheses. It must be literal string.
+///     * `default = ...` Defines a default value for the variable if nothing else will be
+///       provided. If _`enum_values`_ is defined the _`default`_ must be found within the enum
+///       options and when omitted the first enum value is used. It can be a literal string.
+///     * `description = ...` Define the description for the variable. It can be a literal string.
+///     * `enum_values(...)` Define list of possible values for the variable. Values must be
+///       literal strings.
+///
+///  _**Example server variable definition.**_
+///  ```text
+/// ("username" = (default = "demo", description = "Default username for API")),
+/// ("port" = (enum_values("8080", "5000", "4545")))
+/// ```
+///
+/// # `security_schemes(...)` attribute syntax
+/// * `api_key(header = ..., description = ...)` Api key sent in header. Use _`query = ...`_ or
+///   _`cookie = ...`_ instead of _`header`_ for api key sent in query parameter or cookie.
+/// * `http(scheme = ..., bearer_format = ..., description = ...)` Http authentication with given
+///   scheme e.g. _`"basic"`_ or _`"bearer"`_. _`bearer_format`_ is only applicable to bearer scheme.
+/// * `oauth2(flows..., description = ...)` OAuth2 with at least one of the flows
+///   _`implicit(...)`_, _`password(...)`_, _`client_credentials(...)`_ and _`authorization_code(...)`_.
+///   Flow takes _`auth_url = ...`_, _`token_url = ...`_, optional _`refresh_url = ...`_ and
+///   _`scopes(("scope" = "description"), ...)`_ where the urls are required as defined by the flow.
+/// * `open_id_connect(url = ..., description = ...)` OpenID Connect discovery url.
+/// * `mutual_tls(description = ...)` Authentication with client certificate.
+///
+///  _**Example security schemes definition.**_
+///  ```text
+/// ("api_key" = api_key(header = "x-api-key")),
+/// ("oauth" = oauth2(authorization_code(
+///     auth_url = "https://localhost/authorize",
+///     token_url = "https://localhost/token",
+///     scopes(("read:items" = "Read items"))
+/// )))
+/// ```
+///
+/// # Examples
+///
+/// _**Define OpenApi schema with components, security requirements and tags.**_
+/// ```rust
+/// # use utoipa::{OpenApi, ToSchema};
+/// #
+/// #[derive(ToSchema)]
+/// struct Pet {
+///     name: String,
+///     age: i32,
+/// }
+///
+/// #[derive(ToSchema)]
+/// enum Status {
+///     Active, InActive, Locked,
+/// }
+///
+/// #[derive(OpenApi)]
+/// #[openapi(
+///     components(schemas(Pet, Status)),
+///     security(
+///         (),
+///         ("my_auth" = ["read:items", "edit:items"]),
+///         ("token_jwt" = [])
+///     ),
+///     tags(
+///         (name = "pets::api", description = "All about pets",
+///             external_docs(url = "http://more.about.pets.api", description = "Find out more"))
+///     ),
+///     external_docs(url = "http://more.about.our.apis", description = "More about our APIs")
+/// )]
+/// struct ApiDoc;
+/// ```
+///
+/// _**Define servers to OpenApi.**_
+///```rust
+/// # use utoipa::OpenApi;
+/// #[derive(OpenApi)]
+/// #[openapi(
+///     servers(
+///         (url = "http://localhost:8989", description = "Local server"),
+///         (url = "http://api.{username}:{port}", description = "Remote API",
+///             variables(
+///                 ("username" = (default = "demo", description = "Default username for API")),
+///                 ("port" = (default = "8080", enum_values("8080", "5000", "3030"), description = "Supported ports for API"))
+///             )
+///         )
+///     )
+/// )]
+/// struct ApiDoc;
+///```
+///
+/// _**Define info attribute values used to override auto generated ones from Cargo environment
+/// variables.**_
+/// ```compile_fail
+/// # use utoipa::OpenApi;
+/// #[derive(OpenApi)]
+/// #[openapi(info(
+///     title = "title override",
+///     description = include_str!("./path/to/content"), // fail compile cause no such file
+///     contact(name = "Test")
+/// ))]
+/// struct ApiDoc;
+/// ```
+///
+/// _**Create OpenAPI with resuable response.**_
+/// ```rust
+/// #[derive(utoipa::ToSchema)]
+/// struct Person {
+///     name: String,
+/// }
+///
+/// /// Person list response
+/// #[derive(utoipa::ToResponse)]
+/// struct PersonList(Vec<Person>);
+///
+/// #[utoipa::path(
+///     get,
+///     path = "/person-list",
+///     responses(
+///         (status = 200, response = PersonList)
+///     )
+/// )]
+/// fn get_persons() -> Vec<Person> {
+///     vec![]
+/// }
+///
+/// #[derive(utoipa::OpenApi)]
+/// #[openapi(
+///     components(
+///         schemas(Person),
+///         responses(PersonList)
+///     )
+/// )]
+/// struct ApiDoc;
+/// ```
+///
+/// [openapi]: trait.OpenApi.html
+/// [openapi_struct]: openapi/struct.OpenApi.html
+/// [to_schema]: derive.ToSchema.html
+/// [path]: attr.path.html
+/// [modify]: trait.Modify.html
+/// [info]: openapi/info/struct.Info.html
+/// [security]: openapi/security/struct.SecurityRequirement.html
+/// [path_security]: attr.path.html#security-requirement-attributes
+/// [tags]: openapi/tag/struct.Tag.html
+/// [api_tag]: trait.ApiTag.html
+/// [api_tag_derive]: derive.ApiTag.html
+/// [to_response_trait]: trait.ToResponse.html
+/// [into_params]: trait.IntoParams.html
+/// [servers]: openapi/server/index.html
+/// [openapi_nest]: openapi/struct.OpenApi.html#method.nest
+/// [security_scheme]: openapi/security/enum.SecurityScheme.html
 pub fn openapi(input: TokenStream) -> TokenStream {
     let DeriveInput { attrs, ident, .. } = syn::parse_macro_input!(input);
 
@@ -1547,7 +2074,14 @@ pub fn openapi(input: TokenStream) -> TokenStream {
 }
 
 #[proc_macro_error]
-#[proc_macro_derive(IntoParams, attributes(param, into_params))]
+#[cfg_attr(
+    not(feature = "validator"),
+    proc_macro_derive(IntoParams, attributes(param, into_params))
+)]
+#[cfg_attr(
+    feature = "validator",
+    proc_macro_derive(IntoParams, attributes(param, into_params, validate))
+)]
 /// Generate [path parameters][path_params] from struct's
 /// fields.
 ///
@@ -1587,7 +2121,8 @@ pub fn openapi(input: TokenStream) -> TokenStream {
 /// * `parameter_in = ...` =  Defines where the parameters of this field are used with a value from
 ///    [`openapi::path::ParameterIn`][in_enum]. There is no default value, if this attribute is not
 ///    supplied, then the value is determined by the `parameter_in_provider` in
-///    [`IntoParams::into_params()`](trait.IntoParams.html#tymethod.into_params).
+///    [`IntoParams::into_params()`](trait.IntoParams.html#tymethod.into_params). Use
+///    _`parameter_in = Header`_ for typed header extraction structs.
 /// * `rename_all = ...` Can be provided to alternatively to the serde's `rename_all` attribute. Effectively provides same functionality.
 ///
 /// # IntoParams Field Attributes for `#[param(...)]`
@@ -1595,10 +2130,29 @@ pub fn openapi(input: TokenStream) -> TokenStream {
 /// The following attributes are available for use in the `#[param(...)]` on struct fields:
 ///
 /// * `style = ...` Defines how the parameter is serialized by [`ParameterStyle`][style]. Default values are based on _`parameter_in`_ attribute.
+///   With _`style = DeepObject`_ the _`explode`_ defaults to `true` so a nested struct field is
+///   rendered as single _`filter[name]=value`_ style parameter.
+/// * `parameter_in = ...` Defines where this parameter is used with a value from
+///    [`openapi::path::ParameterIn`][in_enum] e.g. _`parameter_in = Cookie`_ for session cookie
+///    among query parameters. Overrides the _`parameter_in`_ of the container attribute and the
+///    `parameter_in_provider`.
+/// * `header` or `header = "..."` Defines the field as a _`Header`_ parameter. Optional value
+///    defines the name of the header e.g. _`header = "x-correlation-id"`_ which overrides any
+///    rename of the field.
 /// * `explode` Defines whether new _`parameter=value`_ pair is created for each parameter withing _`object`_ or _`array`_.
+///   Value can be omitted or defined as boolean e.g. _`explode = false`_. Together with _`style`_
+///   overrides the _`style`_ of the container attribute for the field.
 /// * `allow_reserved` Defines whether reserved characters _`:/?#[]@!$&'()*+,;=`_ is allowed within value.
+/// * `allow_empty_value` Defines whether empty value e.g. _`?flag=`_ is allowed for a _`Query`_
+///   parameter. Useful for flag style query parameters.
 /// * `example = ...` Can be method reference or _`json!(...)`_. Given example
 ///   will override any example in underlying parameter type.
+/// * `examples(...)` Define multiple named examples for the parameter given as
+///   _`("name" = json!(...), summary = "...", description = "...")`_ where _`summary`_ and
+///   _`description`_ are optional. The same syntax as _`examples(...)`_ in
+///   [`#[utoipa::path(...)]` response attributes][path_responses] is also supported. This
+///   attribute is mutually exclusive to _`example`_ and if both are defined _`examples`_ takes
+///   precedence.
 /// * `value_type = ...` Can be used to override default type derived from type of the field used in OpenAPI spec.
 ///   This is useful in cases where the default type does not correspond to the actual type e.g. when
 ///   any third-party types are used which are not [`ToSchema`][to_schema]s nor [`primitive` types][primitive].
@@ -1606,10 +2160,14 @@ pub fn openapi(input: TokenStream) -> TokenStream {
 ///    _`Object`_ will be rendered as generic OpenAPI object.
 /// * `inline` If set, the schema for this field's type needs to be a [`ToSchema`][to_schema], and
 ///   the schema definition will be inlined.
-/// * `default = ...` Can be method reference or _`json!(...)`_.
+/// * `default = ...` Can be method reference or _`json!(...)`_. If defined without a value as
+///   _`default`_, the value of the field is evaluated at runtime from the [`Default`]
+///   implementation of the container. In this case the container must implement [`Default`] and
+///   the field must implement [`Serialize`][serialize].
 /// * `format = ...` May either be variant of the [`KnownFormat`][known_format] enum, or otherwise
-///   an open value as a string. By default the format is derived from the type of the property
-///   according OpenApi spec.
+///   an open value as a string. By default the format is derived from the type of the field
+///   the same way as for [`ToSchema`][to_schema] fields e.g. _`Uuid`_ is rendered with _`uuid`_
+///   format when `uuid` feature is enabled. For _`Vec`_ fields the format applies to the items.
 /// * `write_only` Defines property is only used in **write** operations *POST,PUT,PATCH* but not in *GET*
 /// * `read_only` Defines property is only used in **read** operations *GET* but not in *POST,PUT,PATCH*
 /// * `xml(...)` Can be used to define [`Xml`][xml] object properties applicable to named fields.
@@ -1631,6 +2189,9 @@ pub fn openapi(input: TokenStream) -> TokenStream {
 /// * `with_schema = ...` Use _`schema`_ created by provided function reference instead of the
 ///   default derived _`schema`_. The function must match to `fn() -> Into<RefOr<Schema>>`. It does
 ///   not accept arguments and must return anything that can be convered into `RefOr<Schema>`.
+/// * `flatten` Expand parameters of the field's type in place of the field. The type of the field
+///   must implement [`IntoParams`][into_params]. Parameters of the nested type inherit the
+///   _`parameter_in`_ of the field or the container unless they define their own.
 ///
 /// **Note!** `#[into_params(...)]` is only supported on unnamed struct types to declare names for the arguments.
 ///
@@ -1660,9 +2221,37 @@ pub fn openapi(input: TokenStream) -> TokenStream {
 /// * `rename_all = "..."` Supported at the container level.
 /// * `rename = "..."` Supported **only** at the field level.
 /// * `default` Supported at the container level and field level according to [serde attributes].
+/// * `skip` Supported **only** at the field level. Skipped fields are not rendered as parameters.
+/// * `flatten` Supported **only** at the field level. Works the same way as _`#[param(flatten)]`_
+///   and merges parameters of the flattened [`IntoParams`][into_params] type to the parent's parameters.
 ///
 /// Other _`serde`_ attributes will impact the serialization but will not be reflected on the generated OpenAPI doc.
 ///
+/// # `#[validate(...)]` attributes support
+///
+/// With **validator** feature enabled [validator] crate's field attributes are translated to
+/// parameter schema constraints so that the limits need not be repeated in _`#[param(...)]`_.
+/// Constraints defined with _`#[param(...)]`_ take precedence over the translated ones.
+///
+/// * `range(min = ..., max = ..., exclusive_min = ..., exclusive_max = ...)` Translated to
+///   _`minimum`_, _`maximum`_, _`exclusive_minimum`_ and _`exclusive_maximum`_.
+/// * `length(min = ..., max = ..., equal = ...)` Translated to _`min_length`_ and _`max_length`_
+///   for strings and to _`min_items`_ and _`max_items`_ for arrays.
+/// * `regex = "RE"` or `regex(path = ...)` Translated to _`pattern`_ using `as_str()` of the
+///   referenced regex at runtime.
+///
+/// Only literal bounds are translated, other _`validate`_ rules are ignored.
+///
+/// ```text
+/// #[derive(Deserialize, Validate, IntoParams)]
+/// struct Filter {
+///     #[validate(range(min = 1, max = 100))]
+///     limit: i32,
+///     #[validate(length(min = 2), regex = "RE_NAME")]
+///     name: String,
+/// }
+/// ```
+///
 /// # Examples
 ///
 /// _**Demonstrate [`IntoParams`][into_params] usage with resolving `Path` and `Query` parameters
@@ -1839,16 +2428,46 @@ pub fn openapi(input: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// _**Nest `IntoParams` and `ToSchema` types either as flattened parameters or as a single
+/// _`deepObject`_ parameter.**_
+/// ```rust
+/// #[derive(utoipa::IntoParams)]
+/// struct Pagination {
+///     page: u32,
+///     per_page: Option<u32>,
+/// }
+///
+/// #[derive(utoipa::ToSchema)]
+/// struct Filter {
+///     name: Option<String>,
+///     status: Option<String>,
+/// }
+///
+/// #[derive(utoipa::IntoParams)]
+/// #[into_params(parameter_in = Query)]
+/// struct ListQuery {
+///     /// Renders `page` and `per_page` query parameters.
+///     #[param(flatten)]
+///     pagination: Pagination,
+///     /// Renders `filter[name]` and `filter[status]` query parameters.
+///     #[param(style = DeepObject)]
+///     filter: Option<Filter>,
+/// }
+/// ```
+///
 /// [to_schema]: trait.ToSchema.html
 /// [known_format]: openapi/schema/enum.KnownFormat.html
 /// [xml]: openapi/xml/struct.Xml.html
 /// [into_params]: trait.IntoParams.html
 /// [path_params]: attr.path.html#params-attributes
+/// [path_responses]: attr.path.html#response-attributes
 /// [struct]: https://doc.rust-lang.org/std/keyword.struct.html
 /// [style]: openapi/path/enum.ParameterStyle.html
 /// [in_enum]: utoipa/openapi/path/enum.ParameterIn.html
 /// [primitive]: https://doc.rust-lang.org/std/primitive/index.html
 /// [serde attributes]: https://serde.rs/attributes.html
+/// [validator]: https://crates.io/crates/validator
+/// [serialize]: https://docs.rs/serde/latest/serde/trait.Serialize.html
 pub fn into_params(input: TokenStream) -> TokenStream {
     let DeriveInput {
         attrs,
@@ -1967,12 +2586,15 @@ pub fn into_params(input: TokenStream) -> TokenStream {
 /// * `content_type = "..." | content_type = [...]` Can be used to override the default behavior of auto resolving the content type
 ///   from the `body` attribute. If defined the value should be valid content type such as
 ///   _`application/json`_. By default the content type is _`text/plain`_ for
-///   [primitive Rust types][primitive], `application/octet-stream` for _`[u8]`_ and
+///   [primitive Rust types][primitive], `application/octet-stream` for _`[u8]`_ and _`bytes::Bytes`_ and
 ///   _`application/json`_ for struct and complex enum types.
 ///   Content type can also be slice of **content_type** values if the endpoint support returning multiple
 ///  response content types. E.g _`["application/json", "text/xml"]`_ would indicate that endpoint can return both
 ///  _`json`_ and _`xml`_ formats. **The order** of the content types define the default example show first in
 ///  the Swagger UI. Swagger UI wil use the first _`content_type`_ value as a default example.
+///  If response does not have a body the _`content_type`_ will create a media type without schema.
+///  This is useful for documenting empty responses like _`204`_ or plain text responses without
+///  known schema.
 ///
 /// * `headers(...)` Slice of response headers that are returned back to a caller.
 ///
@@ -2102,26 +2724,44 @@ pub fn to_response(input: TokenStream) -> TokenStream {
 /// variants to alter [response attributes](#intoresponses-response-attributes) of responses.
 ///
 /// Doc comment on a _`struct`_ or _`enum`_ variant will be used as a description for the response.
-/// It can also be overridden with _`description = "..."`_ attribute.
+/// It can also be overridden with _`description = "..."`_ attribute. For fields and variants
+/// with _`#[to_response]`_ the doc comment or _`description`_ will override the description of
+/// the inlined [response][to_response]. Descriptions cannot be applied for _`#[ref_response]`_
+/// since the response is only a reference.
+///
+/// Unnamed field enum variant holding another type implementing [`IntoResponses`] can be marked
+/// with _`#[response(flatten)]`_. Instead of creating a single response for the variant the
+/// responses of the inner type are merged to the responses of the _`enum`_. This allows
+/// composing common responses e.g. error responses to multiple response enums.
+///
+/// Each status code may only be declared once, including the status codes of the flattened
+/// types. Duplicates are reported at compile time.
 ///
 /// # IntoResponses `#[response(...)]` attributes
 ///
 /// * `status = ...` Must be provided. Is either a valid http status code integer. E.g. _`200`_ or a
 ///   string value representing a range such as _`"4XX"`_ or `"default"` or a valid _`http::status::StatusCode`_.
 ///   _`StatusCode`_ can either be use path to the status code or _status code_ constant directly.
+///   Custom codes between _`100`_ and _`599`_ and paths to _`u16`_ constants are accepted as well.
+///   Constant status codes are not included in _`IntoResponses::STATUS_CODES`_. Status codes of
+///   flattened responses are included unless the type has generic parameters.
 ///
 /// * `description = "..."` Define description for the response as str. This can be used to
-///   override the default description resolved from doc comments if present.
+///   override the default description resolved from doc comments if present. Without either
+///   the reason phrase of the status code is used.
 ///
 /// * `content_type = "..." | content_type = [...]` Can be used to override the default behavior of auto resolving the content type
 ///   from the `body` attribute. If defined the value should be valid content type such as
 ///   _`application/json`_. By default the content type is _`text/plain`_ for
-///   [primitive Rust types][primitive], `application/octet-stream` for _`[u8]`_ and
+///   [primitive Rust types][primitive], `application/octet-stream` for _`[u8]`_ and _`bytes::Bytes`_ and
 ///   _`application/json`_ for struct and complex enum types.
 ///   Content type can also be slice of **content_type** values if the endpoint support returning multiple
 ///  response content types. E.g _`["application/json", "text/xml"]`_ would indicate that endpoint can return both
 ///  _`json`_ and _`xml`_ formats. **The order** of the content types define the default example show first in
 ///  the Swagger UI. Swagger UI wil use the first _`content_type`_ value as a default example.
+///  If response does not have a body the _`content_type`_ will create a media type without schema.
+///  This is useful for documenting empty responses like _`204`_ or plain text responses without
+///  known schema.
 ///
 /// * `headers(...)` Slice of response headers that are returned back to a caller.
 ///
@@ -2200,6 +2840,30 @@ pub fn to_response(input: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// _**Enum composing responses of other `IntoResponses` enum.**_
+/// ```rust
+/// #[derive(utoipa::IntoResponses)]
+/// enum CommonErrors {
+///     /// Unauthorized.
+///     #[response(status = 401)]
+///     Unauthorized,
+///
+///     /// Internal server error.
+///     #[response(status = 500)]
+///     ServerError,
+/// }
+///
+/// #[derive(utoipa::IntoResponses)]
+/// enum UserResponses {
+///     /// Success response description.
+///     #[response(status = 200)]
+///     Success { value: String },
+///
+///     #[response(flatten)]
+///     Errors(CommonErrors),
+/// }
+/// ```
+///
 /// [into_responses]: trait.IntoResponses.html
 /// [to_schema]: trait.ToSchema.html
 /// [to_response]: trait.ToResponse.html
@@ -2224,6 +2888,60 @@ pub fn into_responses(input: TokenStream) -> TokenStream {
     into_responses.to_token_stream().into()
 }
 
+#[proc_macro_error]
+#[proc_macro_derive(ApiTag, attributes(tag))]
+/// Generate [`ApiTag`][api_tag] implementation defining a tag of the API next to the code
+/// using it.
+///
+/// Doc comment of the type is used as _`description`_ of the tag. Derive it e.g. for a marker
+/// struct in the module of the handlers sharing the tag. Name of the tag is available as
+/// [`ApiTag::NAME`][api_tag_name] constant which can be used as _`tag = ...`_ of
+/// [`#[utoipa::path(...)]`][path].
+/// The type is added to the document by listing it in _`tags(...)`_ of
+/// [`#[derive(OpenApi)]`][openapi].
+///
+/// # ApiTag `tag(...)` attributes
+///
+/// * `name = ...` Define name of the tag. It can be literal string. By default the name of the
+///   type is used.
+/// * `description = ...` Define description of the tag overriding the doc comment. It can be
+///   literal string.
+/// * `external_docs(...)` Define external documentation of the tag with _`url = ...`_ and
+///   optional _`description = ...`_.
+///
+/// # Examples
+///
+/// _**Define tag of pet operations with doc comment.**_
+/// ```rust
+/// /// Operations of the pet store.
+/// #[derive(utoipa::ApiTag)]
+/// #[tag(name = "pets", external_docs(url = "https://example.com/pets"))]
+/// struct PetsTag;
+///
+/// assert_eq!(<PetsTag as utoipa::ApiTag>::NAME, "pets");
+/// ```
+///
+/// [api_tag]: trait.ApiTag.html
+/// [api_tag_name]: trait.ApiTag.html#associatedconstant.NAME
+/// [path]: attr.path.html
+/// [openapi]: derive.OpenApi.html
+pub fn api_tag(input: TokenStream) -> TokenStream {
+    let DeriveInput {
+        attrs,
+        ident,
+        generics,
+        ..
+    } = syn::parse_macro_input!(input);
+
+    let api_tag = api_tag::ApiTag {
+        attributes: attrs,
+        ident,
+        generics,
+    };
+
+    api_tag.to_token_stream().into()
+}
+
 /// Tokenizes slice or Vec of tokenizable items as array either with reference (`&[...]`)
 /// or without correctly to OpenAPI JSON.
 #[cfg_attr(feature = "debug", derive(Debug))]
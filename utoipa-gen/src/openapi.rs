@@ -1,25 +1,30 @@
-use proc_macro2::Ident;
-use proc_macro_error::ResultExt;
+use proc_macro2::{Group, Ident};
+use proc_macro_error::{abort, emit_error, ResultExt};
 use syn::{
     parenthesized,
     parse::{Parse, ParseStream},
     punctuated::Punctuated,
     spanned::Spanned,
     token::{And, Comma},
-    Attribute, Error, ExprPath, GenericParam, Generics, LitStr, Token, TypePath,
+    Attribute, Error, Expr, ExprPath, GenericParam, Generics, LitStr, Token, TypePath,
 };
 
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote, quote_spanned, ToTokens};
 
 use crate::{
-    component::schema, parse_utils, path::PATH_STRUCT_PREFIX,
-    security_requirement::SecurityRequirementAttr, Array, ExternalDocs,
+    component::{schema, serde::RenameRule},
+    extensions::Extensions,
+    parse_utils, path,
+    security_requirement::SecurityRequirementAttr,
+    Array, ExternalDocs,
 };
 
 use self::info::Info;
+use self::security_scheme::NamedSecurityScheme;
 
 mod info;
+mod security_scheme;
 
 #[derive(Default)]
 #[cfg_attr(feature = "debug", derive(Debug))]
@@ -29,9 +34,16 @@ pub struct OpenApiAttr<'o> {
     components: Components,
     modifiers: Punctuated<Modifier, Comma>,
     security: Option<Array<'static, SecurityRequirementAttr>>,
-    tags: Option<Array<'static, Tag>>,
+    tags: Option<Array<'static, TagDefinition>>,
     external_docs: Option<ExternalDocs>,
     servers: Punctuated<Server, Comma>,
+    operation_id_format: Option<OperationIdFormat>,
+    context_path: Option<String>,
+    nested: Punctuated<NestOpenApi, Comma>,
+    webhooks: Punctuated<ExprPath, Comma>,
+    extensions: Option<Extensions>,
+    openapi_version: Option<OpenApiVersion>,
+    collect_paths: bool,
 }
 
 #[derive(Default)]
@@ -41,28 +53,85 @@ pub struct FbrOpenApiAttr<'o> {
     components: Components,
     modifiers: Punctuated<Modifier, Comma>,
     security: Option<Array<'static, SecurityRequirementAttr>>,
-    tags: Option<Array<'static, Tag>>,
+    tags: Option<Array<'static, TagDefinition>>,
     external_docs: Option<ExternalDocs>,
     servers: Punctuated<Server, Comma>,
+    operation_id_format: Option<OperationIdFormat>,
+    context_path: Option<String>,
+    nested: Punctuated<NestOpenApi, Comma>,
+    webhooks: Punctuated<ExprPath, Comma>,
+    extensions: Option<Extensions>,
+    openapi_version: Option<OpenApiVersion>,
+    collect_paths: bool,
 }
 
 pub fn fbr_open_api_to_open_api(fbr_open_api_attr: FbrOpenApiAttr) -> OpenApiAttr {
-    let modules = actix_fbr_resolver::modules_path("src/routes");
+    let handlers = actix_fbr_resolver::route_handlers("src/routes");
+    check_webhooks_are_not_handlers(&fbr_open_api_attr.webhooks, &handlers);
+
+    let modules = handlers
+        .into_iter()
+        .map(|handler| {
+            let cfg = handler
+                .cfg
+                .iter()
+                .map(|predicate| format!("#[cfg({predicate})]"))
+                .collect::<String>();
+            format!("{cfg} {}", handler.path)
+        })
+        .collect::<Vec<_>>();
     let modules_joined = modules.join(",\n");
-    let parser = Punctuated::<ExprPath, Token![,]>::parse_terminated;
-    let modules_buffer: Punctuated<ExprPath, Comma> = syn::parse::Parser::parse_str(parser, &modules_joined).unwrap();
+    let parser = |input: ParseStream| Punctuated::parse_terminated_with(input, parse_cfg_path);
+    let modules_buffer: Punctuated<ExprPath, Comma> =
+        syn::parse::Parser::parse_str(parser, &modules_joined).unwrap();
     // let mdb: Punctuated<ExprPath, Comma> = modules_buffer.into();
     // let pp = Punctuated::<ExprPath, Comma>::parse_terminated(&modules_buffer);
 
-    OpenApiAttr { 
-        info: fbr_open_api_attr.info, 
+    OpenApiAttr {
+        info: fbr_open_api_attr.info,
         paths: modules_buffer,
-        components: fbr_open_api_attr.components, 
-        modifiers: fbr_open_api_attr.modifiers, 
-        security: fbr_open_api_attr.security, 
-        tags: fbr_open_api_attr.tags, 
-        external_docs: fbr_open_api_attr.external_docs, 
+        components: fbr_open_api_attr.components,
+        modifiers: fbr_open_api_attr.modifiers,
+        security: fbr_open_api_attr.security,
+        tags: fbr_open_api_attr.tags,
+        external_docs: fbr_open_api_attr.external_docs,
         servers: fbr_open_api_attr.servers,
+        operation_id_format: fbr_open_api_attr.operation_id_format,
+        context_path: fbr_open_api_attr.context_path,
+        nested: fbr_open_api_attr.nested,
+        webhooks: fbr_open_api_attr.webhooks,
+        extensions: fbr_open_api_attr.extensions,
+        openapi_version: fbr_open_api_attr.openapi_version,
+        collect_paths: fbr_open_api_attr.collect_paths,
+    }
+}
+
+/// Webhooks are identified only by their name thus a handler of _`src/routes`_ is served as a path
+/// and cannot be a webhook at the same time.
+fn check_webhooks_are_not_handlers(
+    webhooks: &Punctuated<ExprPath, Comma>,
+    handlers: &[actix_fbr_resolver::RouteHandler],
+) {
+    for webhook in webhooks {
+        let segments = webhook
+            .path
+            .segments
+            .iter()
+            .map(|segment| segment.ident.to_string())
+            .skip_while(|segment| segment == "crate")
+            .collect::<Vec<_>>();
+
+        if handlers.iter().any(|handler| {
+            handler
+                .path
+                .split("::")
+                .eq(segments.iter().map(String::as_str))
+        }) {
+            emit_error! {
+                webhook, "webhook cannot be a handler of src/routes, handlers are already served as paths";
+                help = "Try to define the webhook outside of the src/routes directory"
+            }
+        }
     }
 }
 
@@ -74,12 +143,7 @@ impl<'o> FbrOpenApiAttr<'o> {
         // if !other.paths.is_empty() {
         //     self.paths = other.paths;
         // }
-        if !other.components.schemas.is_empty() {
-            self.components.schemas = other.components.schemas;
-        }
-        if !other.components.responses.is_empty() {
-            self.components.responses = other.components.responses;
-        }
+        self.components.merge(other.components);
         if other.security.is_some() {
             self.security = other.security;
         }
@@ -92,6 +156,27 @@ impl<'o> FbrOpenApiAttr<'o> {
         if !other.servers.is_empty() {
             self.servers = other.servers;
         }
+        if other.operation_id_format.is_some() {
+            self.operation_id_format = other.operation_id_format;
+        }
+        if other.context_path.is_some() {
+            self.context_path = other.context_path;
+        }
+        if !other.nested.is_empty() {
+            self.nested = other.nested;
+        }
+        if !other.webhooks.is_empty() {
+            self.webhooks = other.webhooks;
+        }
+        if other.extensions.is_some() {
+            self.extensions = other.extensions;
+        }
+        if other.openapi_version.is_some() {
+            self.openapi_version = other.openapi_version;
+        }
+        if other.collect_paths {
+            self.collect_paths = other.collect_paths;
+        }
 
         self
     }
@@ -102,7 +187,8 @@ pub fn parse_fbr_openapi_attrs(attrs: &[Attribute]) -> Option<OpenApiAttr> {
         .iter()
         .filter(|attribute| attribute.path.is_ident("openapi"))
         .map(|attribute| attribute.parse_args::<FbrOpenApiAttr>().unwrap_or_abort())
-        .reduce(|acc, item| acc.merge(item)).unwrap();
+        .reduce(|acc, item| acc.merge(item))
+        .unwrap();
     let openapi_attributes = fbr_open_api_to_open_api(fbr_openapi_attrs);
     Some(openapi_attributes)
 }
@@ -110,7 +196,7 @@ pub fn parse_fbr_openapi_attrs(attrs: &[Attribute]) -> Option<OpenApiAttr> {
 impl Parse for FbrOpenApiAttr<'_> {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
         const EXPECTED_ATTRIBUTE: &str =
-            "unexpected attribute, expected any of: handlers, components, modifiers, security, tags, external_docs, servers";
+            "unexpected attribute, expected any of: handlers, components, modifiers, security, tags, external_docs, servers, operation_id_format, context_path, nest, webhooks, extensions, openapi, collect_paths";
         let mut openapi = FbrOpenApiAttr::default();
 
         while !input.is_empty() {
@@ -137,9 +223,7 @@ impl Parse for FbrOpenApiAttr<'_> {
                     openapi.security = Some(parse_utils::parse_groups(&security)?)
                 }
                 "tags" => {
-                    let tags;
-                    parenthesized!(tags in input);
-                    openapi.tags = Some(parse_utils::parse_groups(&tags)?);
+                    openapi.tags = Some(parse_tags(input)?);
                 }
                 "external_docs" => {
                     let external_docs;
@@ -149,6 +233,37 @@ impl Parse for FbrOpenApiAttr<'_> {
                 "servers" => {
                     openapi.servers = parse_utils::parse_punctuated_within_parenthesis(input)?;
                 }
+                "operation_id_format" => {
+                    openapi.operation_id_format = Some(parse_utils::parse_next(input, || {
+                        input.parse::<OperationIdFormat>()
+                    })?);
+                }
+                "context_path" => {
+                    openapi.context_path = Some(parse_utils::parse_next_literal_str(input)?);
+                }
+                "nest" => {
+                    openapi.nested = parse_utils::parse_punctuated_within_parenthesis(input)?;
+                }
+                "webhooks" => {
+                    openapi.webhooks = parse_cfg_paths(input)?;
+                }
+                "extensions" => {
+                    openapi.extensions = Some(input.parse()?);
+                }
+                "openapi" => {
+                    openapi.openapi_version = Some(parse_utils::parse_next(input, || {
+                        input.parse::<OpenApiVersion>()
+                    })?);
+                }
+                "collect_paths" => {
+                    if !cfg!(feature = "auto_collect") {
+                        return Err(Error::new(
+                            ident.span(),
+                            "collect_paths requires the auto_collect feature of utoipa",
+                        ));
+                    }
+                    openapi.collect_paths = parse_utils::parse_bool_or_true(input)?;
+                }
                 _ => {
                     return Err(Error::new(ident.span(), EXPECTED_ATTRIBUTE));
                 }
@@ -171,12 +286,7 @@ impl<'o> OpenApiAttr<'o> {
         if !other.paths.is_empty() {
             self.paths = other.paths;
         }
-        if !other.components.schemas.is_empty() {
-            self.components.schemas = other.components.schemas;
-        }
-        if !other.components.responses.is_empty() {
-            self.components.responses = other.components.responses;
-        }
+        self.components.merge(other.components);
         if other.security.is_some() {
             self.security = other.security;
         }
@@ -189,6 +299,27 @@ impl<'o> OpenApiAttr<'o> {
         if !other.servers.is_empty() {
             self.servers = other.servers;
         }
+        if other.operation_id_format.is_some() {
+            self.operation_id_format = other.operation_id_format;
+        }
+        if other.context_path.is_some() {
+            self.context_path = other.context_path;
+        }
+        if !other.nested.is_empty() {
+            self.nested = other.nested;
+        }
+        if !other.webhooks.is_empty() {
+            self.webhooks = other.webhooks;
+        }
+        if other.extensions.is_some() {
+            self.extensions = other.extensions;
+        }
+        if other.openapi_version.is_some() {
+            self.openapi_version = other.openapi_version;
+        }
+        if other.collect_paths {
+            self.collect_paths = other.collect_paths;
+        }
 
         self
     }
@@ -205,7 +336,7 @@ pub fn parse_openapi_attrs(attrs: &[Attribute]) -> Option<OpenApiAttr> {
 impl Parse for OpenApiAttr<'_> {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
         const EXPECTED_ATTRIBUTE: &str =
-            "unexpected attribute, expected any of: handlers, components, modifiers, security, tags, external_docs, servers";
+            "unexpected attribute, expected any of: handlers, components, modifiers, security, tags, external_docs, servers, operation_id_format, context_path, nest, webhooks, extensions, openapi, collect_paths";
         let mut openapi = OpenApiAttr::default();
 
         while !input.is_empty() {
@@ -221,7 +352,7 @@ impl Parse for OpenApiAttr<'_> {
                     openapi.info = Some(info_stream.parse()?)
                 }
                 "paths" => {
-                    openapi.paths = parse_utils::parse_punctuated_within_parenthesis(input)?;
+                    openapi.paths = parse_cfg_paths(input)?;
                 }
                 "components" => {
                     openapi.components = input.parse()?;
@@ -235,9 +366,7 @@ impl Parse for OpenApiAttr<'_> {
                     openapi.security = Some(parse_utils::parse_groups(&security)?)
                 }
                 "tags" => {
-                    let tags;
-                    parenthesized!(tags in input);
-                    openapi.tags = Some(parse_utils::parse_groups(&tags)?);
+                    openapi.tags = Some(parse_tags(input)?);
                 }
                 "external_docs" => {
                     let external_docs;
@@ -247,6 +376,37 @@ impl Parse for OpenApiAttr<'_> {
                 "servers" => {
                     openapi.servers = parse_utils::parse_punctuated_within_parenthesis(input)?;
                 }
+                "operation_id_format" => {
+                    openapi.operation_id_format = Some(parse_utils::parse_next(input, || {
+                        input.parse::<OperationIdFormat>()
+                    })?);
+                }
+                "context_path" => {
+                    openapi.context_path = Some(parse_utils::parse_next_literal_str(input)?);
+                }
+                "nest" => {
+                    openapi.nested = parse_utils::parse_punctuated_within_parenthesis(input)?;
+                }
+                "webhooks" => {
+                    openapi.webhooks = parse_cfg_paths(input)?;
+                }
+                "extensions" => {
+                    openapi.extensions = Some(input.parse()?);
+                }
+                "openapi" => {
+                    openapi.openapi_version = Some(parse_utils::parse_next(input, || {
+                        input.parse::<OpenApiVersion>()
+                    })?);
+                }
+                "collect_paths" => {
+                    if !cfg!(feature = "auto_collect") {
+                        return Err(Error::new(
+                            ident.span(),
+                            "collect_paths requires the auto_collect feature of utoipa",
+                        ));
+                    }
+                    openapi.collect_paths = parse_utils::parse_bool_or_true(input)?;
+                }
                 _ => {
                     return Err(Error::new(ident.span(), EXPECTED_ATTRIBUTE));
                 }
@@ -261,8 +421,42 @@ impl Parse for OpenApiAttr<'_> {
     }
 }
 
+/// Parse `#[cfg(...)]` attributes gating the following item of a list. Other attributes are
+/// not allowed.
+fn parse_cfg_attributes(input: ParseStream) -> syn::Result<Vec<Attribute>> {
+    let attributes = Attribute::parse_outer(input)?;
+    if let Some(attribute) = attributes
+        .iter()
+        .find(|attribute| !attribute.path.is_ident("cfg"))
+    {
+        return Err(Error::new(
+            attribute.path.span(),
+            "unexpected attribute, expected: cfg",
+        ));
+    }
+
+    Ok(attributes)
+}
+
+/// Parse handler path with optional `#[cfg(...)]` attributes stored to [`ExprPath::attrs`] e.g.
+/// `#[cfg(feature = "admin")] admin::delete_all`.
+fn parse_cfg_path(input: ParseStream) -> syn::Result<ExprPath> {
+    let attrs = parse_cfg_attributes(input)?;
+    let mut path = input.parse::<ExprPath>()?;
+    path.attrs = attrs;
+
+    Ok(path)
+}
+
+fn parse_cfg_paths(input: ParseStream) -> syn::Result<Punctuated<ExprPath, Comma>> {
+    let content;
+    parenthesized!(content in input);
+    Punctuated::parse_terminated_with(&content, parse_cfg_path)
+}
+
 #[cfg_attr(feature = "debug", derive(Debug))]
 struct Schema {
+    cfg: Vec<Attribute>,
     path: ExprPath,
     generics: Generics,
     alias: Option<syn::TypePath>,
@@ -279,10 +473,33 @@ impl Schema {
     fn get_ident(&self) -> Option<&Ident> {
         self.path.path.segments.last().map(|segment| &segment.ident)
     }
+
+    /// Name of the schema in components, either the alias or the type name.
+    fn get_component_name(&self) -> String {
+        self.alias
+            .as_ref()
+            .map(|path| &path.path)
+            .map(schema::format_path_ref)
+            .unwrap_or_else(|| self.get_ident().unwrap().to_token_stream().to_string())
+    }
+
+    /// Type of the schema with its generics. Lifetime generics are left to be inferred.
+    fn to_type_tokens(&self) -> TokenStream {
+        let path = &self.path;
+        let (_, ty_generics, _) = self.generics.split_for_impl();
+        let ty_generics = if self.has_lifetime_generics() {
+            None
+        } else {
+            Some(ty_generics)
+        };
+
+        quote! { #path #ty_generics }
+    }
 }
 
 impl Parse for Schema {
     fn parse(input: ParseStream) -> syn::Result<Self> {
+        let cfg = parse_cfg_attributes(input)?;
         let path: ExprPath = input.parse()?;
         let generics: Generics = input.parse()?;
 
@@ -294,6 +511,7 @@ impl Parse for Schema {
         };
 
         Ok(Schema {
+            cfg,
             path,
             generics,
             alias,
@@ -301,41 +519,237 @@ impl Parse for Schema {
     }
 }
 
+/// Type registered to components e.g. [`utoipa::ToResponse`] type of `responses(...)`.
 #[cfg_attr(feature = "debug", derive(Debug))]
-struct Responses {
+struct ComponentType {
+    cfg: Vec<Attribute>,
     path: TypePath,
 }
 
-impl Parse for Responses {
+impl Parse for ComponentType {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         Ok(Self {
+            cfg: parse_cfg_attributes(input)?,
             path: input.parse()?,
         })
     }
 }
 
+/// Modifier of the `OpenApi`, either reference to [`utoipa::Modify`] implementation e.g.
+/// `&SecurityAddon` or path to `fn(&mut utoipa::openapi::OpenApi)` e.g. `add_servers`.
 #[cfg_attr(feature = "debug", derive(Debug))]
-struct Modifier {
-    and: And,
-    ident: Ident,
+enum Modifier {
+    Modify { and: And, ident: Ident },
+    Fn(ExprPath),
 }
 
 impl ToTokens for Modifier {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        let and = &self.and;
-        let ident = &self.ident;
-        tokens.extend(quote! {
-            #and #ident
-        })
+        match self {
+            Self::Modify { and, ident } => tokens.extend(quote! {
+                #and #ident
+            }),
+            Self::Fn(path) => tokens.extend(quote_spanned! {path.span()=>
+                &(#path as fn(&mut utoipa::openapi::OpenApi))
+            }),
+        }
     }
 }
 
 impl Parse for Modifier {
     fn parse(input: ParseStream) -> syn::Result<Self> {
-        Ok(Self {
-            and: input.parse()?,
-            ident: input.parse()?,
-        })
+        if input.peek(Token![&]) {
+            Ok(Self::Modify {
+                and: input.parse()?,
+                ident: input.parse()?,
+            })
+        } else {
+            Ok(Self::Fn(input.parse()?))
+        }
+    }
+}
+
+/// Version of the OpenAPI document given with `openapi = "3.1"`.
+#[derive(PartialEq, Eq)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+enum OpenApiVersion {
+    Version3,
+    Version31,
+}
+
+impl Parse for OpenApiVersion {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let version = input.parse::<LitStr>()?;
+
+        match &*version.value() {
+            "3.0" | "3.0.3" => Ok(Self::Version3),
+            "3.1" | "3.1.0" => Ok(Self::Version31),
+            _ => Err(Error::new(
+                version.span(),
+                "unexpected OpenAPI version, expected one of: 3.0, 3.1",
+            )),
+        }
+    }
+}
+
+/// Format of the operation ids generated for paths which do not define `operation_id` explicitly.
+///
+/// `"snake"` and `"camel"` use the handler function name in the given case. Any other value is
+/// used as a template where `{module}` is replaced with the module path of the handler joined
+/// with underscores, or `crate` for handlers without module, and `{fn}` with the handler
+/// function name.
+#[cfg_attr(feature = "debug", derive(Debug))]
+enum OperationIdFormat {
+    Snake,
+    Camel,
+    Template(String),
+}
+
+impl OperationIdFormat {
+    fn format(&self, module: &str, fn_name: &str) -> String {
+        match self {
+            Self::Snake => RenameRule::Snake.rename(fn_name),
+            Self::Camel => RenameRule::Camel.rename(fn_name),
+            Self::Template(template) => {
+                let module = module
+                    .split("::")
+                    .filter(|segment| !matches!(*segment, "" | "crate" | "self" | "super"))
+                    .collect::<Vec<_>>()
+                    .join("_");
+                let module = if module.is_empty() { "crate" } else { &module };
+
+                template
+                    .replace("{module}", module)
+                    .replace("{fn}", fn_name)
+            }
+        }
+    }
+}
+
+impl Parse for OperationIdFormat {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let format = input.parse::<LitStr>()?;
+
+        match &*format.value() {
+            "snake" => Ok(Self::Snake),
+            "camel" => Ok(Self::Camel),
+            template if template.contains("{fn}") => Ok(Self::Template(template.to_string())),
+            _ => Err(Error::new(
+                format.span(),
+                "unexpected operation_id_format, expected one of: \"snake\", \"camel\" or template containing {fn} e.g. \"{module}_{fn}\"",
+            )),
+        }
+    }
+}
+
+/// Parse `tags(...)` keeping the declared order. Tag names must be unique.
+fn parse_tags(input: ParseStream) -> syn::Result<Array<'static, TagDefinition>> {
+    let content;
+    parenthesized!(content in input);
+    let definitions = Punctuated::<TagDefinition, Comma>::parse_terminated(&content)?;
+
+    let mut tags: Vec<TagDefinition> = Vec::with_capacity(definitions.len());
+    for definition in definitions {
+        if let TagDefinition::Tag(tag, span) = &definition {
+            if tag.name.is_empty() {
+                return Err(Error::new(*span, "missing tag name, expected: name = ..."));
+            }
+            if tags.iter().any(
+                |other| matches!(other, TagDefinition::Tag(other, _) if other.name == tag.name),
+            ) {
+                return Err(Error::new(
+                    *span,
+                    format!("duplicate tag name: {}", tag.name),
+                ));
+            }
+        }
+        tags.push(definition);
+    }
+
+    Ok(Array::Owned(tags))
+}
+
+/// Tag of `tags(...)` defined either inline as _`(name = "...", ...)`_ or as path to type
+/// implementing _`utoipa::ApiTag`_.
+#[cfg_attr(feature = "debug", derive(Debug))]
+enum TagDefinition {
+    Tag(Tag, proc_macro2::Span),
+    ApiTag(ExprPath),
+}
+
+impl Parse for TagDefinition {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(syn::token::Paren) {
+            let group = input.parse::<Group>()?;
+            Ok(Self::Tag(syn::parse2(group.stream())?, group.span()))
+        } else {
+            Ok(Self::ApiTag(input.parse()?))
+        }
+    }
+}
+
+impl TagDefinition {
+    fn name_tokens(&self) -> TokenStream {
+        match self {
+            Self::Tag(tag, _) => tag.name.to_token_stream(),
+            Self::ApiTag(path) => quote! { <#path as utoipa::ApiTag>::NAME },
+        }
+    }
+
+    fn describe(&self) -> String {
+        match self {
+            Self::Tag(tag, _) => format!("tag \"{}\"", tag.name),
+            Self::ApiTag(path) => format!(
+                "ApiTag `{}`",
+                path.to_token_stream().to_string().replace(' ', "")
+            ),
+        }
+    }
+}
+
+/// Create compile time assertions for duplicate tag names of `tags(...)` which cannot be checked
+/// while parsing because names of [`TagDefinition::ApiTag`]s are only known by the compiler.
+fn tag_name_assertions(tags: &[TagDefinition]) -> TokenStream {
+    let assertions = tags.iter().enumerate().flat_map(|(index, tag)| {
+        tags[..index]
+            .iter()
+            .filter(move |previous| {
+                matches!(tag, TagDefinition::ApiTag(_))
+                    || matches!(previous, TagDefinition::ApiTag(_))
+            })
+            .map(move |previous| {
+                let (name, previous_name) = (tag.name_tokens(), previous.name_tokens());
+                let message = format!(
+                    "duplicate tag name: {} has the same name as {}",
+                    tag.describe(),
+                    previous.describe()
+                );
+                let span = match tag {
+                    TagDefinition::Tag(_, span) => *span,
+                    TagDefinition::ApiTag(path) => path.span(),
+                };
+
+                quote_spanned! {span=>
+                    const _: () = {
+                        if utoipa::__dev::has_common_tag_names(&[#previous_name], &[#name]) {
+                            panic!(#message)
+                        }
+                    };
+                }
+            })
+    });
+
+    quote! { #( #assertions )* }
+}
+
+impl ToTokens for TagDefinition {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            Self::Tag(tag, _) => tag.to_tokens(tokens),
+            Self::ApiTag(path) => tokens.extend(quote_spanned! {path.span()=>
+                <#path as utoipa::ApiTag>::tag()
+            }),
+        }
     }
 }
 
@@ -408,7 +822,7 @@ impl ToTokens for Tag {
 // (url = "http:://url", description = "description", variables(...))
 #[derive(Default)]
 #[cfg_attr(feature = "debug", derive(Debug))]
-struct Server {
+pub(crate) struct Server {
     url: String,
     description: Option<String>,
     variables: Punctuated<ServerVariable, Comma>,
@@ -419,13 +833,14 @@ impl Parse for Server {
         let server_stream;
         parenthesized!(server_stream in input);
         let mut server = Server::default();
+        let mut url: Option<LitStr> = None;
         while !server_stream.is_empty() {
             let ident = server_stream.parse::<Ident>()?;
             let attribute_name = &*ident.to_string();
 
             match attribute_name {
                 "url" => {
-                    server.url = parse_utils::parse_next(&server_stream, || server_stream.parse::<LitStr>())?.value()
+                    url = Some(parse_utils::parse_next(&server_stream, || server_stream.parse::<LitStr>())?);
                 }
                 "description" => {
                     server.description =
@@ -444,6 +859,39 @@ impl Parse for Server {
             }
         }
 
+        let url_span = url
+            .as_ref()
+            .map(LitStr::span)
+            .unwrap_or_else(|| server_stream.span());
+        server.url = url.map(|url| url.value()).unwrap_or_default();
+
+        let url_variables = server_url_variables(&server.url);
+        if let Some(undefined) = url_variables.iter().find(|name| {
+            !server
+                .variables
+                .iter()
+                .any(|variable| variable.name.value() == **name)
+        }) {
+            return Err(Error::new(
+                url_span,
+                format!("server url variable `{undefined}` is not defined in variables(...)"),
+            ));
+        }
+        if let Some(unused) = server
+            .variables
+            .iter()
+            .find(|variable| !url_variables.contains(&variable.name.value().as_str()))
+        {
+            return Err(Error::new(
+                unused.name.span(),
+                format!(
+                    "server variable `{}` is not used in url: {}",
+                    unused.name.value(),
+                    server.url
+                ),
+            ));
+        }
+
         Ok(server)
     }
 }
@@ -492,13 +940,21 @@ impl ToTokens for Server {
     }
 }
 
+/// Names of the `{variable}` substitutions of server url.
+fn server_url_variables(url: &str) -> Vec<&str> {
+    url.split('{')
+        .skip(1)
+        .filter_map(|substitution| substitution.split_once('}'))
+        .map(|(name, _)| name)
+        .collect()
+}
+
 // ("username" = (default = "demo", description = "This is default username for the API")),
 // ("port" = (enum_values = (8080, 5000, 4545)))
-#[derive(Default)]
 #[cfg_attr(feature = "debug", derive(Debug))]
 struct ServerVariable {
-    name: String,
-    default: String,
+    name: LitStr,
+    default: Option<LitStr>,
     description: Option<String>,
     enum_values: Option<Punctuated<LitStr, Comma>>,
 }
@@ -508,8 +964,10 @@ impl Parse for ServerVariable {
         let variable_stream;
         parenthesized!(variable_stream in input);
         let mut server_variable = ServerVariable {
-            name: variable_stream.parse::<LitStr>()?.value(),
-            ..ServerVariable::default()
+            name: variable_stream.parse::<LitStr>()?,
+            default: None,
+            description: None,
+            enum_values: None,
         };
 
         variable_stream.parse::<Token![=]>()?;
@@ -523,7 +981,7 @@ impl Parse for ServerVariable {
             match attribute_name {
                 "default" => {
                     server_variable.default =
-                        parse_utils::parse_next(&content, || content.parse::<LitStr>())?.value()
+                        Some(parse_utils::parse_next(&content, || content.parse::<LitStr>())?)
                 }
                 "description" => {
                     server_variable.description =
@@ -543,6 +1001,29 @@ impl Parse for ServerVariable {
             }
         }
 
+        if let Some(enum_values) = &server_variable.enum_values {
+            match &server_variable.default {
+                Some(default)
+                    if !enum_values
+                        .iter()
+                        .any(|value| value.value() == default.value()) =>
+                {
+                    return Err(Error::new(
+                        default.span(),
+                        "server variable default value must be one of the enum_values",
+                    ));
+                }
+                Some(_) => (),
+                None => server_variable.default = enum_values.first().cloned(),
+            }
+        }
+        if server_variable.default.is_none() {
+            return Err(Error::new(
+                server_variable.name.span(),
+                "missing default value of server variable, expected: default = ... or enum_values(...)",
+            ));
+        }
+
         Ok(server_variable)
     }
 }
@@ -554,6 +1035,39 @@ impl ToTokens for OpenApi<'_> {
         let OpenApi(attributes, ident) = self;
 
         let info = info::impl_info(attributes.info.clone());
+        let openapi_version = match attributes.openapi_version {
+            Some(OpenApiVersion::Version31) => Some(quote! {
+                .openapi(utoipa::openapi::OpenApiVersion::Version31)
+                .json_schema_dialect(Some(utoipa::openapi::OpenApiVersion::JSON_SCHEMA_DIALECT_3_1))
+            }),
+            _ => {
+                if let Some(summary) = info.summary() {
+                    emit_error! {
+                        summary, "info summary is only supported by OpenAPI 3.1";
+                        help = "Try to define the version 3.1 with the openapi attribute of #[openapi(...)]"
+                    }
+                }
+                if let Some(identifier) = info.license().and_then(|license| license.identifier()) {
+                    emit_error! {
+                        identifier, "license identifier is only supported by OpenAPI 3.1";
+                        help = "Try to define the version 3.1 with the openapi attribute of #[openapi(...)]"
+                    }
+                }
+                if let Some(webhook) = attributes.webhooks.first() {
+                    emit_error! {
+                        webhook, "webhooks are only supported by OpenAPI 3.1";
+                        help = "Try to define the version 3.1 with the openapi attribute of #[openapi(...)]"
+                    }
+                }
+                if let Some(path_item) = attributes.components.path_items.first() {
+                    emit_error! {
+                        path_item.name, "path items of components are only supported by OpenAPI 3.1";
+                        help = "Try to define the version 3.1 with the openapi attribute of #[openapi(...)]"
+                    }
+                }
+                None
+            }
+        };
 
         let components_builder_stream = attributes.components.to_token_stream();
 
@@ -566,7 +1080,15 @@ impl ToTokens for OpenApi<'_> {
         let modifiers = &attributes.modifiers;
         let modifiers_len = modifiers.len();
 
-        let path_items = impl_paths(&attributes.paths);
+        let path_items = impl_paths(attributes);
+        let webhooks = attributes.webhooks.iter().map(|handler| {
+            let (tag, usage) = path::resolve_path_struct(handler);
+            let cfg = &handler.attrs;
+            quote! {
+                #( #cfg )*
+                let builder = builder.webhook(#usage::path(), #usage::path_item(Some(#tag)));
+            }
+        });
 
         let securities = attributes.security.as_ref().map(|securities| {
             quote! {
@@ -578,11 +1100,23 @@ impl ToTokens for OpenApi<'_> {
                 .tags(Some(#tags))
             }
         });
+        let tag_name_assertions = attributes
+            .tags
+            .as_ref()
+            .map(|tags| tag_name_assertions(tags));
         let external_docs = attributes.external_docs.as_ref().map(|external_docs| {
             quote! {
                 .external_docs(Some(#external_docs))
             }
         });
+        let extensions = attributes.extensions.as_ref().map(|extensions| {
+            quote! {
+                .extensions(Some(#extensions))
+            }
+        });
+        check_nested(&attributes.nested, ident);
+        let schemas = schemas_tokens(&attributes.components.schemas, &attributes.nested);
+        let nested = attributes.nested.iter();
         let servers = if !attributes.servers.is_empty() {
             let servers = attributes.servers.iter().collect::<Array<&Server>>();
             Some(quote! { .servers(Some(#servers)) })
@@ -592,17 +1126,25 @@ impl ToTokens for OpenApi<'_> {
 
         tokens.extend(quote! {
             impl utoipa::OpenApi for #ident {
+                #schemas
+
                 fn openapi() -> utoipa::openapi::OpenApi {
                     use utoipa::{ToSchema, Path};
-                    let mut openapi = utoipa::openapi::OpenApiBuilder::new()
+                    #tag_name_assertions
+                    let builder = utoipa::openapi::OpenApiBuilder::new()
+                        #openapi_version
                         .info(#info)
-                        .paths(#path_items)
+                        .paths(#path_items);
+                    #( #webhooks )*
+                    let mut openapi = builder
                         #components
                         #securities
                         #tags
                         #servers
                         #external_docs
+                        #extensions
                         .build();
+                    #( #nested )*
 
                     let _mods: [&dyn utoipa::Modify; #modifiers_len] = [#modifiers];
                     _mods.iter().for_each(|modifier| modifier.modify(&mut openapi));
@@ -614,11 +1156,215 @@ impl ToTokens for OpenApi<'_> {
     }
 }
 
+/// Other `OpenApi` nested under path prefix with `nest(("/prefix" = path::to::ApiDoc))`.
+#[cfg_attr(feature = "debug", derive(Debug))]
+struct NestOpenApi {
+    path: LitStr,
+    open_api: TypePath,
+}
+
+impl Parse for NestOpenApi {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let nest;
+        parenthesized!(nest in input);
+        let path = nest.parse::<LitStr>()?;
+        nest.parse::<Token![=]>()?;
+
+        Ok(Self {
+            path,
+            open_api: nest.parse()?,
+        })
+    }
+}
+
+/// Nesting the `OpenApi` itself would recurse infinitely and nesting the same `OpenApi` under
+/// the same path twice defines every schema, path and tag twice.
+fn check_nested(nested: &Punctuated<NestOpenApi, Comma>, ident: &Ident) {
+    let mut defined = Vec::<(String, String)>::with_capacity(nested.len());
+    for NestOpenApi { path, open_api } in nested {
+        if open_api.qself.is_none()
+            && (open_api.path.is_ident(ident) || open_api.path.is_ident("Self"))
+        {
+            abort! {
+                open_api, "`{}` cannot be nested to itself", ident;
+                help = "Try to nest another OpenApi type"
+            }
+        }
+
+        let entry = (
+            path.value().trim_end_matches('/').to_string(),
+            open_api.to_token_stream().to_string(),
+        );
+        if defined.contains(&entry) {
+            abort! {
+                open_api, "`{}` is already nested under `{}`", entry.1, path.value();
+                help = "Try to remove the duplicate nest"
+            }
+        }
+        defined.push(entry);
+    }
+}
+
+/// Schema definitions of the `OpenApi` including the schemas of the nested `OpenApi`s. Nested
+/// `OpenApi`s are asserted not to define schemas with same name by different types than the
+/// `OpenApi` or other nested `OpenApi`s since only the first of them would be kept when nesting.
+fn schemas_tokens(
+    schemas: &[Schema],
+    nested: &Punctuated<NestOpenApi, Comma>,
+) -> Option<TokenStream> {
+    // cfg gated schemas are skipped the same way as with assertion of same schema types
+    let schemas = schemas
+        .iter()
+        .filter(|schema| schema.cfg.is_empty())
+        .map(|schema| {
+            let name = schema.get_component_name();
+            let schema_type = schema.to_type_tokens();
+            quote! { (#name, <#schema_type as utoipa::ToSchema>::TYPE_PATH) }
+        })
+        .collect::<Vec<_>>();
+    if schemas.is_empty() && nested.is_empty() {
+        return None;
+    }
+
+    let schemas_len = schemas.len();
+    let nested_schemas = nested
+        .iter()
+        .map(|NestOpenApi { open_api, .. }| {
+            quote_spanned! {open_api.span()=> <#open_api as utoipa::OpenApi>::SCHEMAS }
+        })
+        .collect::<Vec<_>>();
+    let assertions = nested
+        .iter()
+        .enumerate()
+        .map(|(index, NestOpenApi { open_api, .. })| {
+            let previous = &nested_schemas[..index];
+            let nested = &nested_schemas[index];
+            quote_spanned! {open_api.span()=>
+                const _: () = {
+                    utoipa::__dev::assert_no_conflicting_schemas(&SCHEMAS, #nested);
+                    #( utoipa::__dev::assert_no_conflicting_schemas(#previous, #nested); )*
+                };
+            }
+        });
+
+    Some(quote! {
+        const SCHEMAS: &'static [utoipa::__dev::SchemaDefinition] = {
+            const SCHEMAS: [utoipa::__dev::SchemaDefinition; #schemas_len] = [#( #schemas ),*];
+            #( #assertions )*
+            &utoipa::__dev::concat_schemas::<{ #schemas_len #( + #nested_schemas.len() )* }>(
+                &[&SCHEMAS #( , #nested_schemas )*],
+            )
+        };
+    })
+}
+
+impl ToTokens for NestOpenApi {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let NestOpenApi { path, open_api } = self;
+        tokens.extend(quote_spanned! {open_api.span()=>
+            openapi.nest(#path, <#open_api as utoipa::OpenApi>::openapi());
+        })
+    }
+}
+
+/// Reusable component defined with name and expression e.g. _`("Example" = example())`_.
+#[cfg_attr(feature = "debug", derive(Debug))]
+struct NamedComponent {
+    name: LitStr,
+    value: Expr,
+}
+
+impl Parse for NamedComponent {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let content;
+        parenthesized!(content in input);
+        let name = content.parse::<LitStr>()?;
+        content.parse::<Token![=]>()?;
+
+        Ok(Self {
+            name,
+            value: content.parse()?,
+        })
+    }
+}
+
+/// Parse list of [`NamedComponent`]s within parenthesis and append them to `components`
+/// reporting an error on duplicate names.
+fn parse_named_components(
+    input: ParseStream,
+    kind: &str,
+    components: &mut Vec<NamedComponent>,
+) -> syn::Result<()> {
+    let punctuated: Punctuated<NamedComponent, Comma> =
+        parse_utils::parse_punctuated_within_parenthesis(input)?;
+    for component in punctuated {
+        if components
+            .iter()
+            .any(|existing| existing.name.value() == component.name.value())
+        {
+            return Err(Error::new(
+                component.name.span(),
+                format!("duplicate {kind} name: {}", component.name.value()),
+            ));
+        }
+        components.push(component);
+    }
+
+    Ok(())
+}
+
 #[derive(Default)]
 #[cfg_attr(feature = "debug", derive(Debug))]
 struct Components {
     schemas: Vec<Schema>,
-    responses: Vec<Responses>,
+    responses: Vec<ComponentType>,
+    parameters: Vec<ComponentType>,
+    examples: Vec<NamedComponent>,
+    request_bodies: Vec<NamedComponent>,
+    headers: Vec<NamedComponent>,
+    path_items: Vec<NamedComponent>,
+    security_schemes: Vec<NamedSecurityScheme>,
+}
+
+impl Components {
+    /// Override component kinds of `self` with the ones defined in `other`.
+    fn merge(&mut self, other: Components) {
+        if !other.schemas.is_empty() {
+            self.schemas = other.schemas;
+        }
+        if !other.responses.is_empty() {
+            self.responses = other.responses;
+        }
+        if !other.parameters.is_empty() {
+            self.parameters = other.parameters;
+        }
+        if !other.examples.is_empty() {
+            self.examples = other.examples;
+        }
+        if !other.request_bodies.is_empty() {
+            self.request_bodies = other.request_bodies;
+        }
+        if !other.headers.is_empty() {
+            self.headers = other.headers;
+        }
+        if !other.path_items.is_empty() {
+            self.path_items = other.path_items;
+        }
+        if !other.security_schemes.is_empty() {
+            self.security_schemes = other.security_schemes;
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.schemas.is_empty()
+            && self.responses.is_empty()
+            && self.parameters.is_empty()
+            && self.examples.is_empty()
+            && self.request_bodies.is_empty()
+            && self.headers.is_empty()
+            && self.path_items.is_empty()
+            && self.security_schemes.is_empty()
+    }
 }
 
 impl Parse for Components {
@@ -626,10 +1372,9 @@ impl Parse for Components {
         let content;
         parenthesized!(content in input);
         const EXPECTED_ATTRIBUTE: &str =
-            "unexpected attribute. expected one of: schemas, responses";
+            "unexpected attribute. expected one of: schemas, responses, parameters, examples, request_bodies, headers, path_items, security_schemes";
 
-        let mut schemas: Vec<Schema> = Vec::new();
-        let mut responses: Vec<Responses> = Vec::new();
+        let mut components = Components::default();
 
         while !content.is_empty() {
             let ident = content.parse::<Ident>().map_err(|error| {
@@ -641,14 +1386,46 @@ impl Parse for Components {
                 "schemas" => {
                     let punctuated: Punctuated<Schema, Comma> =
                         parse_utils::parse_punctuated_within_parenthesis(&content)?;
-                    let mut v: Vec<Schema> = punctuated.into_iter().collect();
-                    schemas.append(&mut v)
+                    components.schemas.extend(punctuated)
                 }
                 "responses" => {
-                    let punctuated: Punctuated<Responses, Comma> =
+                    let punctuated: Punctuated<ComponentType, Comma> =
+                        parse_utils::parse_punctuated_within_parenthesis(&content)?;
+                    components.responses.extend(punctuated)
+                }
+                "parameters" => {
+                    let punctuated: Punctuated<ComponentType, Comma> =
                         parse_utils::parse_punctuated_within_parenthesis(&content)?;
-                    let mut v: Vec<Responses> = punctuated.into_iter().collect();
-                    responses.append(&mut v)
+                    components.parameters.extend(punctuated)
+                }
+                "examples" => {
+                    parse_named_components(&content, "example", &mut components.examples)?
+                }
+                "request_bodies" => parse_named_components(
+                    &content,
+                    "request body",
+                    &mut components.request_bodies,
+                )?,
+                "headers" => parse_named_components(&content, "header", &mut components.headers)?,
+                "path_items" => {
+                    parse_named_components(&content, "path item", &mut components.path_items)?
+                }
+                "security_schemes" => {
+                    let punctuated: Punctuated<NamedSecurityScheme, Comma> =
+                        parse_utils::parse_punctuated_within_parenthesis(&content)?;
+                    for scheme in punctuated {
+                        if components
+                            .security_schemes
+                            .iter()
+                            .any(|existing| existing.name.value() == scheme.name.value())
+                        {
+                            return Err(Error::new(
+                                scheme.name.span(),
+                                format!("duplicate security scheme name: {}", scheme.name.value()),
+                            ));
+                        }
+                        components.security_schemes.push(scheme);
+                    }
                 }
                 _ => return Err(syn::Error::new(ident.span(), EXPECTED_ATTRIBUTE)),
             }
@@ -658,96 +1435,311 @@ impl Parse for Components {
             }
         }
 
-        Ok(Self { schemas, responses })
+        Ok(components)
     }
 }
 
 impl ToTokens for Components {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        if self.schemas.is_empty() && self.responses.is_empty() {
+        if self.is_empty() {
             return;
         }
 
-        let builder_tokens = self.schemas.iter().fold(
-            quote! { utoipa::openapi::ComponentsBuilder::new() },
-            |mut builder_tokens, component| {
-                let path = &component.path;
-                let ident = component.get_ident().unwrap();
+        // schemas of different types with same name would silently override each other thus they
+        // are asserted to be the same type, cfg gated schemas are skipped since they may define
+        // same name for different configurations
+        let mut defined = Vec::<(String, &Schema)>::new();
+        let mut same_schema_types = Vec::new();
+        for component in self.schemas.iter().filter(|schema| schema.cfg.is_empty()) {
+            let name = component.get_component_name();
+            match defined
+                .iter()
+                .find(|(defined_name, _)| *defined_name == name)
+            {
+                Some((_, first)) => {
+                    let first_type = first.to_type_tokens();
+                    let component_type = component.to_type_tokens();
+                    same_schema_types.push(quote_spanned! {component.path.span()=>
+                        utoipa::__dev::assert_same_schema_type(
+                            ::core::marker::PhantomData::<#first_type>,
+                            ::core::marker::PhantomData::<#component_type>,
+                        );
+                    });
+                }
+                None => defined.push((name, component)),
+            }
+        }
 
-                let component_name: String = component
-                    .alias
-                    .as_ref()
-                    .map(|path| &path.path)
-                    .map(schema::format_path_ref)
-                    .unwrap_or_else(|| ident.to_token_stream().to_string());
+        let schemas = self.schemas.iter().map(|component| {
+            let cfg = &component.cfg;
+            let ident = component.get_ident().unwrap();
+            let component_name = component.get_component_name();
+            let component_type = component.to_type_tokens();
 
-                let (_, ty_generics, _) = component.generics.split_for_impl();
+            let schema = quote_spanned! { ident.span() =>
+                .schema(#component_name, <#component_type as utoipa::ToSchema>::schema())
+                .schemas_from_iter(<#component_type as utoipa::ToSchema>::aliases())
+            };
 
-                let ty_generics = if component.has_lifetime_generics() {
-                    None
-                } else {
-                    Some(ty_generics)
-                };
+            quote! {
+                #( #cfg )*
+                let components = components #schema;
+            }
+        });
+        let responses = self.responses.iter().map(|ComponentType { cfg, path }| {
+            let response = quote_spanned! {path.span()=>
+                .response_from_into::<#path>()
+            };
 
-                builder_tokens.extend(quote_spanned! { ident.span() =>
-                    .schema(#component_name, <#path #ty_generics as utoipa::ToSchema>::schema())
-                    .schemas_from_iter(<#path #ty_generics as utoipa::ToSchema>::aliases())
-                });
+            quote! {
+                #( #cfg )*
+                let components = components #response;
+            }
+        });
+        let parameters = self.parameters.iter().map(|ComponentType { cfg, path }| {
+            let parameters = quote_spanned! {path.span()=>
+                .parameters_from_into_params::<#path>()
+            };
 
-                builder_tokens
-            },
-        );
+            quote! {
+                #( #cfg )*
+                let components = components #parameters;
+            }
+        });
+        let examples = self.examples.iter().map(|NamedComponent { name, value }| {
+            quote! { .example(#name, #value) }
+        });
+        let request_bodies = self
+            .request_bodies
+            .iter()
+            .map(|NamedComponent { name, value }| quote! { .request_body(#name, #value) });
+        let headers = self.headers.iter().map(|NamedComponent { name, value }| {
+            quote! { .header(#name, #value) }
+        });
+        let path_items = self
+            .path_items
+            .iter()
+            .map(|NamedComponent { name, value }| quote! { .path_item(#name, #value) });
+        let security_schemes = &self.security_schemes;
 
-        let builder_tokens =
-            self.responses
-                .iter()
-                .fold(builder_tokens, |mut builder_tokens, responses| {
-                    let path = &responses.path;
-                    let span = path.span();
-                    builder_tokens.extend(quote_spanned! {span =>
-                        .response_from_into::<#path>()
-                    });
-                    builder_tokens
-                });
+        tokens.extend(quote! {
+            {
+                #( #same_schema_types )*
+                let components = utoipa::openapi::ComponentsBuilder::new();
+                #( #schemas )*
+                #( #responses )*
+                #( #parameters )*
+                components
+                    #( #examples )*
+                    #( #request_bodies )*
+                    #( #headers )*
+                    #( #path_items )*
+                    #( #security_schemes )*
+                    .build()
+            }
+        });
+    }
+}
+
+fn impl_paths(attributes: &OpenApiAttr) -> TokenStream {
+    let operation_id_format = attributes.operation_id_format.as_ref();
+    let context_path = attributes
+        .context_path
+        .as_deref()
+        .map(|context_path| context_path.trim_end_matches('/'));
+
+    let handlers = attributes
+        .paths
+        .iter()
+        .map(|handler| {
+            let (tag, usage) = path::resolve_path_struct(handler);
+            let operation_ids = operation_id_format.map(|operation_id_format| {
+                formatted_operation_ids(operation_id_format, handler, &tag, &usage)
+            });
 
-        tokens.extend(quote! { #builder_tokens.build() });
+            (handler, tag, usage, operation_ids)
+        })
+        .collect::<Vec<_>>();
+
+    let paths = handlers
+        .iter()
+        .map(|(handler, tag, usage, operation_ids)| {
+            let path_item = if let Some(operation_ids) = operation_ids {
+                quote! {
+                    {
+                        const OPERATION_IDS: [&str; <#usage as utoipa::Path>::OPERATION_IDS.len()] = #operation_ids;
+                        let mut path_item = #usage::path_item(Some(#tag));
+                        path_item.operations.values_mut().for_each(|operation| {
+                            let index = <#usage as utoipa::Path>::OPERATION_IDS
+                                .iter()
+                                .position(|operation_id| operation.operation_id.as_deref() == Some(*operation_id));
+                            if let Some(index) = index {
+                                operation.operation_id = Some(String::from(OPERATION_IDS[index]));
+                            }
+                        });
+                        path_item
+                    }
+                }
+            } else {
+                quote! { #usage::path_item(Some(#tag)) }
+            };
+
+            let path = if let Some(context_path) = context_path {
+                quote! { format!("{}{}", #context_path, #usage::path()) }
+            } else {
+                quote! { #usage::path() }
+            };
+
+            let cfg = &handler.attrs;
+            quote! {
+                #( #cfg )*
+                let paths = paths.path(#path, #path_item);
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let operation_id_assertions = if operation_id_format.is_some() {
+        operation_id_assertions(&handlers)
+    } else {
+        TokenStream::new()
+    };
+
+    let collected_paths = if attributes.collect_paths {
+        let context_path = context_path.unwrap_or_default();
+        quote! {
+            let paths = utoipa::__dev::add_collected_paths(paths, #context_path);
+        }
+    } else {
+        TokenStream::new()
+    };
+
+    quote! {
+        {
+            #operation_id_assertions
+            let paths = utoipa::openapi::path::PathsBuilder::new();
+            #( #paths )*
+            #collected_paths
+            paths
+        }
     }
 }
 
-fn impl_paths(handler_paths: &Punctuated<ExprPath, Comma>) -> TokenStream {
-    handler_paths.iter().fold(
-        quote! { utoipa::openapi::path::PathsBuilder::new() },
-        |mut paths, handler| {
-            let segments = handler.path.segments.iter().collect::<Vec<_>>();
-            let handler_fn_name = &*segments.last().unwrap().ident.to_string();
+/// Create tokens of the operation ids of the handler formatted with `operation_id_format`.
+/// Operation ids generated by `#[utoipa::path(...)]` are the function name and the function name
+/// suffixed with http method which are formatted in const context only when the path does not
+/// define operation id explicitly.
+fn formatted_operation_ids(
+    operation_id_format: &OperationIdFormat,
+    handler: &ExprPath,
+    tag: &str,
+    usage: &ExprPath,
+) -> TokenStream {
+    let fn_name = handler
+        .path
+        .segments
+        .last()
+        .expect("handler path must have at least one segment")
+        .ident
+        .to_string();
+    let formats = std::iter::once(fn_name.clone())
+        .chain(
+            path::PathOperation::ALL
+                .iter()
+                .map(|path_operation| format!("{fn_name}_{}", path_operation.as_str())),
+        )
+        .map(|operation_id| {
+            let formatted = operation_id_format.format(tag, &operation_id);
+            quote! { (#operation_id, #formatted) }
+        });
 
-            let tag = &*segments
+    quote! {
+        utoipa::__dev::format_operation_ids(
+            <#usage as utoipa::Path>::OPERATION_IDS,
+            <#usage as utoipa::Path>::GENERATED_OPERATION_IDS,
+            &[#( #formats ),*]
+        )
+    }
+}
+
+/// Create compile time assertions of unique operation ids across the handlers. Each pair of
+/// handlers is asserted separately to keep the `#[cfg(...)]` attributes of the handlers effective.
+fn operation_id_assertions(
+    handlers: &[(&ExprPath, String, ExprPath, Option<TokenStream>)],
+) -> TokenStream {
+    let operation_ids = handlers
+        .iter()
+        .enumerate()
+        .map(|(index, (handler, _, usage, operation_ids))| {
+            let ident = format_ident!("OPERATION_IDS_{index}");
+            let cfg = &handler.attrs;
+            quote! {
+                #( #cfg )*
+                const #ident: [&str; <#usage as utoipa::Path>::OPERATION_IDS.len()] = #operation_ids;
+            }
+        });
+    let assertions = handlers
+        .iter()
+        .enumerate()
+        .flat_map(|(index, (handler, ..))| {
+            handlers[..index]
                 .iter()
-                .take(segments.len() - 1)
-                .map(|part| part.ident.to_string())
-                .collect::<Vec<_>>()
-                .join("::");
-
-            let handler_ident = format_ident!("{}{}", PATH_STRUCT_PREFIX, handler_fn_name);
-            let handler_ident_name = &*handler_ident.to_string();
-
-            let usage = syn::parse_str::<ExprPath>(
-                &vec![
-                    if tag.is_empty() { None } else { Some(tag) },
-                    Some(handler_ident_name),
-                ]
-                .into_iter()
-                .flatten()
-                .collect::<Vec<_>>()
-                .join("::"),
-            )
-            .unwrap();
+                .enumerate()
+                .map(move |(previous_index, (previous, ..))| {
+                    let ident = format_ident!("OPERATION_IDS_{index}");
+                    let previous_ident = format_ident!("OPERATION_IDS_{previous_index}");
+                    let (cfg, previous_cfg) = (&handler.attrs, &previous.attrs);
+                    let message = format!(
+                        "`{}` has an operation id already defined by `{}`, define operation_id in #[utoipa::path(...)] of the handler or change the operation_id_format",
+                        handler.path.to_token_stream().to_string().replace(' ', ""),
+                        previous.path.to_token_stream().to_string().replace(' ', "")
+                    );
+
+                    quote_spanned! {handler.span()=>
+                        #( #previous_cfg )*
+                        #( #cfg )*
+                        const _: () = {
+                            if utoipa::__dev::has_common_operation_ids(&#previous_ident, &#ident) {
+                                panic!(#message)
+                            }
+                        };
+                    }
+                })
+        });
 
-            paths.extend(quote! {
-                .path(#usage::path(), #usage::path_item(Some(#tag)))
-            });
+    quote! {
+        #( #operation_ids )*
+        #( #assertions )*
+    }
+}
 
-            paths
-        },
-    )
+#[cfg(test)]
+mod tests {
+    use super::OperationIdFormat;
+
+    #[test]
+    fn format_operation_id_with_case() {
+        assert_eq!(
+            OperationIdFormat::Snake.format("pet_api", "get_pet_by_id"),
+            "get_pet_by_id"
+        );
+        assert_eq!(
+            OperationIdFormat::Camel.format("pet_api", "get_pet_by_id"),
+            "getPetById"
+        );
+    }
+
+    #[test]
+    fn format_operation_id_with_template() {
+        let format = OperationIdFormat::Template("{module}_{fn}".to_string());
+
+        assert_eq!(
+            format.format("routes::users::_id", "get"),
+            "routes_users__id_get"
+        );
+        assert_eq!(
+            format.format("crate::pet_api", "get_pet"),
+            "pet_api_get_pet"
+        );
+        assert_eq!(format.format("", "get_pet"), "crate_get_pet");
+    }
 }
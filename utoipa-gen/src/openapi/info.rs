@@ -7,6 +7,7 @@ use syn::parse::Parse;
 use syn::token::Comma;
 use syn::{parenthesized, Error, LitStr, Token};
 
+use crate::extensions::Extensions;
 use crate::parse_utils;
 
 #[derive(Clone)]
@@ -47,27 +48,43 @@ impl ToTokens for Str {
 #[cfg_attr(feature = "debug", derive(Debug))]
 pub(super) struct Info<'i> {
     title: Option<String>,
+    summary: Option<LitStr>,
     version: Option<String>,
     description: Option<Str>,
+    terms_of_service: Option<String>,
     license: Option<License<'i>>,
     contact: Option<Contact<'i>>,
+    extensions: Option<Extensions>,
+    /// Fields explicitly set to `None` e.g. `license = None` which are not populated from
+    /// _`cargo`_ env variables.
+    unset: Vec<&'static str>,
 }
 
 impl Info<'_> {
+    pub(super) fn summary(&self) -> Option<&LitStr> {
+        self.summary.as_ref()
+    }
+
+    pub(super) fn license(&self) -> Option<&License<'_>> {
+        self.license.as_ref()
+    }
+
     /// Construct new [`Info`] from _`cargo`_ env variables such as
     /// * `CARGO_PGK_NAME`
     /// * `CARGO_PGK_VERSION`
     /// * `CARGO_PGK_DESCRIPTION`
     /// * `CARGO_PGK_AUTHORS`
     /// * `CARGO_PGK_LICENSE`
+    ///
+    /// Variables which are not defined or are empty are ignored.
     fn from_env() -> Self {
-        let name = std::env::var("CARGO_PKG_NAME").ok();
-        let version = std::env::var("CARGO_PKG_VERSION").ok();
-        let description = std::env::var("CARGO_PKG_DESCRIPTION").ok().map(Str::String);
-        let contact = std::env::var("CARGO_PKG_AUTHORS")
-            .ok()
-            .and_then(|authors| Contact::try_from(authors).ok());
-        let license = std::env::var("CARGO_PKG_LICENSE").ok().map(License::from);
+        let env_var = |name: &str| std::env::var(name).ok().filter(|value| !value.is_empty());
+        let name = env_var("CARGO_PKG_NAME");
+        let version = env_var("CARGO_PKG_VERSION");
+        let description = env_var("CARGO_PKG_DESCRIPTION").map(Str::String);
+        let contact =
+            env_var("CARGO_PKG_AUTHORS").and_then(|authors| Contact::try_from(authors).ok());
+        let license = env_var("CARGO_PKG_LICENSE").map(License::from);
 
         Info {
             title: name,
@@ -75,6 +92,7 @@ impl Info<'_> {
             description,
             contact,
             license,
+            ..Default::default()
         }
     }
 }
@@ -92,26 +110,37 @@ impl Parse for Info<'_> {
                     info.title =
                         Some(parse_utils::parse_next(input, || input.parse::<LitStr>())?.value())
                 }
+                "summary" => {
+                    info.summary = Some(parse_utils::parse_next(input, || input.parse::<LitStr>())?)
+                }
                 "version" => {
                     info.version =
                         Some(parse_utils::parse_next(input, || input.parse::<LitStr>())?.value())
                 }
+                "description" if parse_none(input)? => info.unset.push("description"),
                 "description" => {
                     info.description =
                         Some(parse_utils::parse_next(input, || input.parse::<Str>())?)
                 }
-                "licence" => {
+                "terms_of_service" => {
+                    info.terms_of_service =
+                        Some(parse_utils::parse_next(input, || input.parse::<LitStr>())?.value())
+                }
+                "license" | "licence" if parse_none(input)? => info.unset.push("license"),
+                "license" | "licence" => {
                     let licence_stream;
                     parenthesized!(licence_stream in input);
                     info.license = Some(licence_stream.parse()?)
                 }
+                "contact" if parse_none(input)? => info.unset.push("contact"),
                 "contact" => {
                     let contact_stream;
                     parenthesized!(contact_stream in input);
                     info.contact = Some(contact_stream.parse()?)
                 }
+                "extensions" => info.extensions = Some(input.parse()?),
                 _ => {
-                    return Err(Error::new(ident.span(), format!("unexpected attribute: {attribute_name}, expected one of: title, version, description, licence, contact")));
+                    return Err(Error::new(ident.span(), format!("unexpected attribute: {attribute_name}, expected one of: title, summary, version, description, terms_of_service, license, contact, extensions")));
                 }
             }
             if !input.is_empty() {
@@ -123,9 +152,27 @@ impl Parse for Info<'_> {
     }
 }
 
+/// Parse `= None` used to opt out from value populated from _`cargo`_ env variables. Returns
+/// `false` without consuming the input if the value is something else.
+fn parse_none(input: syn::parse::ParseStream) -> syn::Result<bool> {
+    let fork = input.fork();
+    if fork.parse::<Token![=]>().is_ok() && fork.parse::<Ident>().is_ok_and(|ident| ident == "None")
+    {
+        input.parse::<Token![=]>()?;
+        input.parse::<Ident>()?;
+        Ok(true)
+    } else {
+        Ok(false)
+    }
+}
+
 impl ToTokens for Info<'_> {
     fn to_tokens(&self, tokens: &mut TokenStream2) {
         let title = self.title.as_ref().map(|title| quote! { .title(#title) });
+        let summary = self
+            .summary
+            .as_ref()
+            .map(|summary| quote! { .summary(Some(#summary)) });
         let version = self
             .version
             .as_ref()
@@ -134,6 +181,10 @@ impl ToTokens for Info<'_> {
             .description
             .as_ref()
             .map(|description| quote! { .description(Some(#description)) });
+        let terms_of_service = self
+            .terms_of_service
+            .as_ref()
+            .map(|terms_of_service| quote! { .terms_of_service(Some(#terms_of_service)) });
         let license = self
             .license
             .as_ref()
@@ -142,14 +193,21 @@ impl ToTokens for Info<'_> {
             .contact
             .as_ref()
             .map(|contact| quote! { .contact(Some(#contact)) });
+        let extensions = self
+            .extensions
+            .as_ref()
+            .map(|extensions| quote! { .extensions(Some(#extensions)) });
 
         tokens.extend(quote! {
             utoipa::openapi::InfoBuilder::new()
                 #title
+                #summary
                 #version
                 #description
+                #terms_of_service
                 #license
                 #contact
+                #extensions
         })
     }
 }
@@ -159,6 +217,13 @@ impl ToTokens for Info<'_> {
 pub(super) struct License<'l> {
     name: Cow<'l, str>,
     url: Option<Cow<'l, str>>,
+    identifier: Option<LitStr>,
+}
+
+impl License<'_> {
+    pub(super) fn identifier(&self) -> Option<&LitStr> {
+        self.identifier.as_ref()
+    }
 }
 
 impl Parse for License<'_> {
@@ -180,11 +245,15 @@ impl Parse for License<'_> {
                         parse_utils::parse_next(input, || input.parse::<LitStr>())?.value(),
                     ))
                 }
+                "identifier" => {
+                    license.identifier =
+                        Some(parse_utils::parse_next(input, || input.parse::<LitStr>())?)
+                }
                 _ => {
                     return Err(Error::new(
                         ident.span(),
                         format!(
-                            "unexpected attribute: {attribute_name}, expected one of: name, url"
+                            "unexpected attribute: {attribute_name}, expected one of: name, url, identifier"
                         ),
                     ));
                 }
@@ -194,6 +263,13 @@ impl Parse for License<'_> {
             }
         }
 
+        if let (Some(identifier), Some(_)) = (&license.identifier, &license.url) {
+            return Err(Error::new(
+                identifier.span(),
+                "license identifier and url are mutually exclusive, expected only one of them",
+            ));
+        }
+
         Ok(license)
     }
 }
@@ -202,11 +278,16 @@ impl ToTokens for License<'_> {
     fn to_tokens(&self, tokens: &mut TokenStream2) {
         let name = &self.name;
         let url = self.url.as_ref().map(|url| quote! { .url(Some(#url))});
+        let identifier = self
+            .identifier
+            .as_ref()
+            .map(|identifier| quote! { .identifier(Some(#identifier))});
 
         tokens.extend(quote! {
             utoipa::openapi::info::LicenseBuilder::new()
                 .name(#name)
                 #url
+                #identifier
                 .build()
         })
     }
@@ -293,9 +374,11 @@ impl TryFrom<String> for Contact<'_> {
 
     fn try_from(value: String) -> Result<Self, Self::Error> {
         if let Some((name, email)) = get_parsed_author(value.split(':').into_iter().next()) {
+            let non_empty =
+                |value: &str| (!value.is_empty()).then(|| Cow::Owned(value.to_string()));
             Ok(Contact {
-                name: Some(Cow::Owned(name.to_string())),
-                email: Some(Cow::Owned(email.to_string())),
+                name: non_empty(name),
+                email: non_empty(email),
                 ..Default::default()
             })
         } else {
@@ -315,6 +398,14 @@ pub(super) fn impl_info(parsed: Option<Info>) -> Info {
             info.title = parsed.title;
         }
 
+        if parsed.summary.is_some() {
+            info.summary = parsed.summary;
+        }
+
+        if parsed.version.is_some() {
+            info.version = parsed.version;
+        }
+
         if parsed.description.is_some() {
             info.description = parsed.description;
         }
@@ -326,6 +417,23 @@ pub(super) fn impl_info(parsed: Option<Info>) -> Info {
         if parsed.contact.is_some() {
             info.contact = parsed.contact;
         }
+
+        if parsed.terms_of_service.is_some() {
+            info.terms_of_service = parsed.terms_of_service;
+        }
+
+        if parsed.extensions.is_some() {
+            info.extensions = parsed.extensions;
+        }
+
+        for field in parsed.unset {
+            match field {
+                "description" => info.description = None,
+                "license" => info.license = None,
+                "contact" => info.contact = None,
+                _ => unreachable!("unexpected unset info field: {field}"),
+            }
+        }
     }
 
     info
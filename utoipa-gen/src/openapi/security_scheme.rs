@@ -0,0 +1,533 @@
+use proc_macro2::{Ident, Span, TokenStream as TokenStream2};
+use quote::{quote, ToTokens};
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::token::Comma;
+use syn::{parenthesized, Error, LitStr, Token};
+
+use crate::parse_utils;
+
+/// Named security scheme of `components(security_schemes(...))` e.g.
+/// `("api_key" = api_key(header = "x-api-key"))`.
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub(super) struct NamedSecurityScheme {
+    pub(super) name: LitStr,
+    scheme: SecurityScheme,
+}
+
+impl Parse for NamedSecurityScheme {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let content;
+        parenthesized!(content in input);
+        let name = content.parse::<LitStr>()?;
+        content.parse::<Token![=]>()?;
+
+        Ok(Self {
+            name,
+            scheme: content.parse()?,
+        })
+    }
+}
+
+impl ToTokens for NamedSecurityScheme {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let name = &self.name;
+        let scheme = &self.scheme;
+
+        tokens.extend(quote! { .security_scheme(#name, #scheme) })
+    }
+}
+
+#[cfg_attr(feature = "debug", derive(Debug))]
+enum SecurityScheme {
+    ApiKey {
+        location: Ident,
+        name: LitStr,
+        description: Option<LitStr>,
+    },
+    Http {
+        scheme: Ident,
+        bearer_format: Option<LitStr>,
+        description: Option<LitStr>,
+    },
+    OAuth2 {
+        flows: Vec<Flow>,
+        description: Option<LitStr>,
+    },
+    OpenIdConnect {
+        url: LitStr,
+        description: Option<LitStr>,
+    },
+    MutualTls {
+        description: Option<LitStr>,
+    },
+}
+
+impl Parse for SecurityScheme {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        const EXPECTED_SCHEME: &str =
+            "unexpected security scheme, expected one of: api_key, http, oauth2, open_id_connect, mutual_tls";
+        let scheme_type = input
+            .parse::<Ident>()
+            .map_err(|error| Error::new(error.span(), format!("{EXPECTED_SCHEME}, {error}")))?;
+        let content;
+        parenthesized!(content in input);
+
+        match &*scheme_type.to_string() {
+            "api_key" => parse_api_key(&content, scheme_type.span()),
+            "http" => parse_http(&content, scheme_type.span()),
+            "oauth2" => parse_oauth2(&content, scheme_type.span()),
+            "open_id_connect" => {
+                let mut url = None;
+                let mut description = None;
+                parse_attributes(&content, "url, description", |attribute, input| {
+                    match attribute {
+                        "url" => url = Some(parse_utils::parse_next(input, || input.parse())?),
+                        "description" => {
+                            description = Some(parse_utils::parse_next(input, || input.parse())?)
+                        }
+                        _ => return Ok(false),
+                    }
+                    Ok(true)
+                })?;
+
+                Ok(Self::OpenIdConnect {
+                    url: url.ok_or_else(|| {
+                        Error::new(scheme_type.span(), "missing open_id_connect url")
+                    })?,
+                    description,
+                })
+            }
+            "mutual_tls" => {
+                let mut description = None;
+                parse_attributes(&content, "description", |attribute, input| {
+                    if attribute == "description" {
+                        description = Some(parse_utils::parse_next(input, || input.parse())?);
+                        Ok(true)
+                    } else {
+                        Ok(false)
+                    }
+                })?;
+
+                Ok(Self::MutualTls { description })
+            }
+            _ => Err(Error::new(scheme_type.span(), EXPECTED_SCHEME)),
+        }
+    }
+}
+
+/// Parse `name = value` attributes separated by comma. The `parse_attribute` is called with name of
+/// the attribute and returns `false` if the attribute is not expected.
+fn parse_attributes(
+    input: ParseStream,
+    expected: &str,
+    mut parse_attribute: impl FnMut(&str, ParseStream) -> syn::Result<bool>,
+) -> syn::Result<()> {
+    while !input.is_empty() {
+        let ident = input.parse::<Ident>()?;
+        let attribute_name = &*ident.to_string();
+
+        if !parse_attribute(attribute_name, input)? {
+            return Err(Error::new(
+                ident.span(),
+                format!("unexpected attribute: {attribute_name}, expected one of: {expected}"),
+            ));
+        }
+
+        if !input.is_empty() {
+            input.parse::<Comma>()?;
+        }
+    }
+
+    Ok(())
+}
+
+fn parse_api_key(input: ParseStream, span: Span) -> syn::Result<SecurityScheme> {
+    let mut location_and_name: Option<(Ident, LitStr)> = None;
+    let mut description = None;
+
+    while !input.is_empty() {
+        let ident = input.parse::<Ident>()?;
+
+        match &*ident.to_string() {
+            "header" | "query" | "cookie" => {
+                if location_and_name.is_some() {
+                    return Err(Error::new(
+                        ident.span(),
+                        "api_key can be only in one of: header, query, cookie",
+                    ));
+                }
+                let name = parse_utils::parse_next(input, || input.parse::<LitStr>())?;
+                location_and_name = Some((ident, name));
+            }
+            "description" => {
+                description = Some(parse_utils::parse_next(input, || input.parse())?);
+            }
+            attribute_name => {
+                return Err(Error::new(
+                    ident.span(),
+                    format!("unexpected attribute: {attribute_name}, expected one of: header, query, cookie, description"),
+                ))
+            }
+        }
+
+        if !input.is_empty() {
+            input.parse::<Comma>()?;
+        }
+    }
+
+    let (location, name) = location_and_name.ok_or_else(|| {
+        Error::new(
+            span,
+            "missing api_key name, expected one of: header = ..., query = ..., cookie = ...",
+        )
+    })?;
+
+    Ok(SecurityScheme::ApiKey {
+        location,
+        name,
+        description,
+    })
+}
+
+fn parse_http(input: ParseStream, span: Span) -> syn::Result<SecurityScheme> {
+    const HTTP_SCHEMES: [(&str, &str); 10] = [
+        ("basic", "Basic"),
+        ("bearer", "Bearer"),
+        ("digest", "Digest"),
+        ("hoba", "Hoba"),
+        ("mutual", "Mutual"),
+        ("negotiate", "Negotiate"),
+        ("oauth", "OAuth"),
+        ("scram-sha-1", "ScramSha1"),
+        ("scram-sha-256", "ScramSha256"),
+        ("vapid", "Vapid"),
+    ];
+    let mut scheme: Option<LitStr> = None;
+    let mut bearer_format: Option<LitStr> = None;
+    let mut description = None;
+
+    parse_attributes(
+        input,
+        "scheme, bearer_format, description",
+        |attribute, input| {
+            match attribute {
+                "scheme" => scheme = Some(parse_utils::parse_next(input, || input.parse())?),
+                "bearer_format" => {
+                    bearer_format = Some(parse_utils::parse_next(input, || input.parse())?)
+                }
+                "description" => {
+                    description = Some(parse_utils::parse_next(input, || input.parse())?)
+                }
+                _ => return Ok(false),
+            }
+            Ok(true)
+        },
+    )?;
+
+    let scheme =
+        scheme.ok_or_else(|| Error::new(span, "missing http scheme, expected: scheme = ..."))?;
+    let scheme_ident = HTTP_SCHEMES
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(&scheme.value()))
+        .map(|(_, variant)| Ident::new(variant, scheme.span()))
+        .ok_or_else(|| {
+            Error::new(
+                scheme.span(),
+                format!(
+                    "unexpected http scheme: {}, expected one of: {}",
+                    scheme.value(),
+                    HTTP_SCHEMES.map(|(name, _)| name).join(", ")
+                ),
+            )
+        })?;
+
+    if let Some(bearer_format) = &bearer_format {
+        if scheme_ident != "Bearer" {
+            return Err(Error::new(
+                bearer_format.span(),
+                "bearer_format is only applicable to bearer scheme",
+            ));
+        }
+    }
+
+    Ok(SecurityScheme::Http {
+        scheme: scheme_ident,
+        bearer_format,
+        description,
+    })
+}
+
+fn parse_oauth2(input: ParseStream, span: Span) -> syn::Result<SecurityScheme> {
+    let mut flows = Vec::<Flow>::new();
+    let mut description = None;
+
+    while !input.is_empty() {
+        let ident = input.parse::<Ident>()?;
+
+        match &*ident.to_string() {
+            "description" => {
+                description = Some(parse_utils::parse_next(input, || input.parse())?);
+            }
+            "implicit" | "password" | "client_credentials" | "authorization_code" => {
+                if flows.iter().any(|flow| flow.flow_type == ident) {
+                    return Err(Error::new(
+                        ident.span(),
+                        format!("duplicate oauth2 flow: {ident}"),
+                    ));
+                }
+                let content;
+                parenthesized!(content in input);
+                flows.push(Flow::parse_with_type(&content, ident)?);
+            }
+            attribute_name => {
+                return Err(Error::new(
+                    ident.span(),
+                    format!("unexpected attribute: {attribute_name}, expected one of: implicit, password, client_credentials, authorization_code, description"),
+                ))
+            }
+        }
+
+        if !input.is_empty() {
+            input.parse::<Comma>()?;
+        }
+    }
+
+    if flows.is_empty() {
+        return Err(Error::new(
+            span,
+            "missing oauth2 flow, expected at least one of: implicit, password, client_credentials, authorization_code",
+        ));
+    }
+
+    Ok(SecurityScheme::OAuth2 { flows, description })
+}
+
+impl ToTokens for SecurityScheme {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        tokens.extend(match self {
+            Self::ApiKey {
+                location,
+                name,
+                description,
+            } => {
+                let location = Ident::new(
+                    match &*location.to_string() {
+                        "header" => "Header",
+                        "query" => "Query",
+                        _ => "Cookie",
+                    },
+                    location.span(),
+                );
+                let api_key = match description {
+                    Some(description) => quote! {
+                        utoipa::openapi::security::ApiKeyValue::with_description(#name, #description)
+                    },
+                    None => quote! { utoipa::openapi::security::ApiKeyValue::new(#name) },
+                };
+                quote! {
+                    utoipa::openapi::security::SecurityScheme::ApiKey(
+                        utoipa::openapi::security::ApiKey::#location(#api_key)
+                    )
+                }
+            }
+            Self::Http {
+                scheme,
+                bearer_format,
+                description,
+            } => {
+                let bearer_format = bearer_format
+                    .as_ref()
+                    .map(|bearer_format| quote! { .bearer_format(#bearer_format) });
+                let description = description
+                    .as_ref()
+                    .map(|description| quote! { .description(Some(#description)) });
+                quote! {
+                    utoipa::openapi::security::SecurityScheme::Http(
+                        utoipa::openapi::security::HttpBuilder::new()
+                            .scheme(utoipa::openapi::security::HttpAuthScheme::#scheme)
+                            #bearer_format
+                            #description
+                            .build()
+                    )
+                }
+            }
+            Self::OAuth2 { flows, description } => {
+                let oauth2 = match description {
+                    Some(description) => quote! {
+                        utoipa::openapi::security::OAuth2::with_description([#( #flows ),*], #description)
+                    },
+                    None => quote! { utoipa::openapi::security::OAuth2::new([#( #flows ),*]) },
+                };
+                quote! { utoipa::openapi::security::SecurityScheme::OAuth2(#oauth2) }
+            }
+            Self::OpenIdConnect { url, description } => {
+                let open_id_connect = match description {
+                    Some(description) => quote! {
+                        utoipa::openapi::security::OpenIdConnect::with_description(#url, #description)
+                    },
+                    None => quote! { utoipa::openapi::security::OpenIdConnect::new(#url) },
+                };
+                quote! { utoipa::openapi::security::SecurityScheme::OpenIdConnect(#open_id_connect) }
+            }
+            Self::MutualTls { description } => {
+                let description = match description {
+                    Some(description) => quote! { Some(String::from(#description)) },
+                    None => quote! { None },
+                };
+                quote! {
+                    utoipa::openapi::security::SecurityScheme::MutualTls {
+                        description: #description,
+                    }
+                }
+            }
+        })
+    }
+}
+
+/// OAuth2 flow e.g. `authorization_code(auth_url = "...", token_url = "...", scopes(...))`.
+#[cfg_attr(feature = "debug", derive(Debug))]
+struct Flow {
+    flow_type: Ident,
+    auth_url: Option<LitStr>,
+    token_url: Option<LitStr>,
+    refresh_url: Option<LitStr>,
+    scopes: Punctuated<Scope, Comma>,
+}
+
+impl Flow {
+    fn parse_with_type(input: ParseStream, flow_type: Ident) -> syn::Result<Self> {
+        let mut flow = Flow {
+            flow_type,
+            auth_url: None,
+            token_url: None,
+            refresh_url: None,
+            scopes: Punctuated::new(),
+        };
+
+        parse_attributes(
+            input,
+            "auth_url, token_url, refresh_url, scopes",
+            |attribute, input| {
+                match attribute {
+                    "auth_url" => {
+                        flow.auth_url = Some(parse_utils::parse_next(input, || input.parse())?)
+                    }
+                    "token_url" => {
+                        flow.token_url = Some(parse_utils::parse_next(input, || input.parse())?)
+                    }
+                    "refresh_url" => {
+                        flow.refresh_url = Some(parse_utils::parse_next(input, || input.parse())?)
+                    }
+                    "scopes" => {
+                        flow.scopes = parse_utils::parse_punctuated_within_parenthesis(input)?
+                    }
+                    _ => return Ok(false),
+                }
+                Ok(true)
+            },
+        )?;
+
+        let flow_type = flow.flow_type.to_string();
+        let requires_auth_url = matches!(&*flow_type, "implicit" | "authorization_code");
+        let requires_token_url = flow_type != "implicit";
+        if requires_auth_url && flow.auth_url.is_none() {
+            return Err(Error::new(
+                flow.flow_type.span(),
+                format!("missing auth_url of oauth2 {flow_type} flow"),
+            ));
+        }
+        if requires_token_url && flow.token_url.is_none() {
+            return Err(Error::new(
+                flow.flow_type.span(),
+                format!("missing token_url of oauth2 {flow_type} flow"),
+            ));
+        }
+        if !requires_auth_url {
+            if let Some(auth_url) = &flow.auth_url {
+                return Err(Error::new(
+                    auth_url.span(),
+                    format!("auth_url is not applicable to oauth2 {flow_type} flow"),
+                ));
+            }
+        }
+        if !requires_token_url {
+            if let Some(token_url) = &flow.token_url {
+                return Err(Error::new(
+                    token_url.span(),
+                    format!("token_url is not applicable to oauth2 {flow_type} flow"),
+                ));
+            }
+        }
+
+        Ok(flow)
+    }
+}
+
+impl ToTokens for Flow {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let scopes = if self.scopes.is_empty() {
+            quote! { utoipa::openapi::security::Scopes::new() }
+        } else {
+            let scopes = self.scopes.iter();
+            quote! { utoipa::openapi::security::Scopes::from_iter([#( #scopes ),*]) }
+        };
+        // flow variants are named after the flow types
+        let flow_type = Ident::new(
+            match &*self.flow_type.to_string() {
+                "implicit" => "Implicit",
+                "password" => "Password",
+                "client_credentials" => "ClientCredentials",
+                _ => "AuthorizationCode",
+            },
+            self.flow_type.span(),
+        );
+        let urls = self
+            .auth_url
+            .iter()
+            .chain(self.token_url.iter())
+            .collect::<Vec<_>>();
+
+        let flow = if let Some(refresh_url) = &self.refresh_url {
+            quote! {
+                utoipa::openapi::security::#flow_type::with_refresh_url(#( #urls, )* #scopes, #refresh_url)
+            }
+        } else {
+            quote! {
+                utoipa::openapi::security::#flow_type::new(#( #urls, )* #scopes)
+            }
+        };
+
+        tokens.extend(quote! { utoipa::openapi::security::Flow::#flow_type(#flow) })
+    }
+}
+
+/// OAuth2 scope with description e.g. `("read:items" = "Read items")`.
+#[cfg_attr(feature = "debug", derive(Debug))]
+struct Scope {
+    name: LitStr,
+    description: LitStr,
+}
+
+impl Parse for Scope {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let content;
+        parenthesized!(content in input);
+        let name = content.parse()?;
+        content.parse::<Token![=]>()?;
+
+        Ok(Self {
+            name,
+            description: content.parse()?,
+        })
+    }
+}
+
+impl ToTokens for Scope {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let name = &self.name;
+        let description = &self.description;
+
+        tokens.extend(quote! { (#name, #description) })
+    }
+}
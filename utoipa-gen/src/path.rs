@@ -6,31 +6,39 @@ use proc_macro2::{Ident, Span, TokenStream as TokenStream2};
 use proc_macro_error::abort;
 use quote::{format_ident, quote, ToTokens};
 use syn::punctuated::Punctuated;
+use syn::token::Comma;
 use syn::token::Paren;
 use syn::{parenthesized, parse::Parse, Token};
-use syn::{LitStr, Type};
+use syn::{LitStr, ReturnType, Type};
 
 use crate::component::{GenericType, TypeTree};
-use crate::{parse_utils, Deprecated};
+use crate::openapi::Server;
+use crate::{parse_utils, Deprecated, ExternalDocs};
 use crate::{schema_type::SchemaType, security_requirement::SecurityRequirementAttr, Array};
 
+use self::callback::Callback;
 use self::response::Response;
 use self::{parameter::Parameter, request_body::RequestBodyAttr, response::Responses};
 
 #[cfg(any(
     feature = "actix_extras",
     feature = "rocket_extras",
-    feature = "axum_extras"
+    feature = "axum_extras",
+    feature = "poem_extras",
+    feature = "salvo_extras"
 ))]
-use self::parameter::ValueParameter;
+use self::parameter::{StructParameter, ValueParameter};
 
 #[cfg(any(
     feature = "actix_extras",
     feature = "rocket_extras",
-    feature = "axum_extras"
+    feature = "axum_extras",
+    feature = "poem_extras",
+    feature = "salvo_extras"
 ))]
-use crate::ext::{IntoParamsType, ValueArgument};
+use crate::ext::{IntoParamsType, RequestBody, ValueArgument};
 
+mod callback;
 pub mod example;
 mod media_type;
 pub mod parameter;
@@ -40,6 +48,58 @@ mod status;
 
 pub(crate) const PATH_STRUCT_PREFIX: &str = "__path_";
 
+/// Format name of the generated path struct for handler function. Methods of types and traits
+/// are prefixed with the type name e.g. `__path_UserService_get_user`.
+pub(crate) fn format_path_struct_ident(type_name: Option<&Ident>, fn_name: &str) -> Ident {
+    match type_name {
+        Some(type_name) => format_ident!("{}{}_{}", PATH_STRUCT_PREFIX, type_name, fn_name),
+        None => format_ident!("{}{}", PATH_STRUCT_PREFIX, fn_name),
+    }
+}
+
+/// Resolve path of the generated path struct from given handler path. Handler is expected to be
+/// either a function e.g. `api::get_user` or a method of type or trait e.g. `api::UserService::get_user`.
+/// Type is recognized by its upper case first letter.
+///
+/// Returns the module path of the handler and the path of the path struct.
+pub(crate) fn resolve_path_struct(handler: &syn::ExprPath) -> (String, syn::ExprPath) {
+    let mut segments = handler
+        .path
+        .segments
+        .iter()
+        .map(|segment| &segment.ident)
+        .collect::<Vec<_>>();
+    let fn_name = segments
+        .pop()
+        .expect("handler path must have at least one segment");
+    let type_name = segments
+        .last()
+        .filter(|ident| ident.to_string().starts_with(char::is_uppercase))
+        .copied();
+    if type_name.is_some() {
+        segments.pop();
+    }
+
+    let module = segments
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join("::");
+    let mut path_struct = handler.clone();
+    path_struct.attrs.clear();
+    path_struct.path.segments = segments
+        .into_iter()
+        .cloned()
+        .chain(Some(format_path_struct_ident(
+            type_name,
+            &fn_name.to_string(),
+        )))
+        .map(syn::PathSegment::from)
+        .collect();
+
+    (module, path_struct)
+}
+
 /// PathAttr is parsed `#[utoipa::path(...)]` proc macro and its attributes.
 /// Parsed attributes can be used to override or append OpenAPI Path
 /// options.
@@ -73,15 +133,22 @@ pub(crate) const PATH_STRUCT_PREFIX: &str = "__path_";
 #[derive(Default)]
 #[cfg_attr(feature = "debug", derive(Debug))]
 pub struct PathAttr<'p> {
-    path_operation: Option<PathOperation>,
+    path_operations: Vec<PathOperation>,
     request_body: Option<RequestBodyAttr<'p>>,
     responses: Vec<Response<'p>>,
     pub(super) path: Option<String>,
     operation_id: Option<String>,
-    tag: Option<String>,
+    tag: Option<TagValue>,
+    tag_from_module: bool,
     params: Vec<Parameter<'p>>,
+    path_params: Vec<Parameter<'p>>,
     security: Option<Array<'p, SecurityRequirementAttr>>,
     context_path: Option<String>,
+    servers: Punctuated<Server, Comma>,
+    callbacks: Punctuated<Callback, Comma>,
+    external_docs: Option<ExternalDocs>,
+    deprecated: Option<DeprecatedAttr>,
+    webhook: Option<String>,
 }
 
 #[derive(Default)]
@@ -90,113 +157,75 @@ pub struct FbrPathAttr<'p> {
     request_body: Option<RequestBodyAttr<'p>>,
     responses: Vec<Response<'p>>,
     operation_id: Option<String>,
-    tag: Option<String>,
+    tag: Option<TagValue>,
+    tag_from_module: bool,
     params: Vec<Parameter<'p>>,
+    path_params: Vec<Parameter<'p>>,
     security: Option<Array<'p, SecurityRequirementAttr>>,
     context_path: Option<String>,
+    servers: Punctuated<Server, Comma>,
+    callbacks: Punctuated<Callback, Comma>,
+    external_docs: Option<ExternalDocs>,
+    deprecated: Option<DeprecatedAttr>,
+    webhook: Option<String>,
+}
+
+/// Tag of the operation given either as literal string or as path to constant e.g.
+/// _`tag = <PetsTag as utoipa::ApiTag>::NAME`_ of type implementing _`ApiTag`_.
+#[derive(Clone)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+enum TagValue {
+    LitStr(LitStr),
+    Path(syn::ExprPath),
+}
+
+impl Parse for TagValue {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        if input.peek(LitStr) {
+            Ok(Self::LitStr(input.parse()?))
+        } else {
+            Ok(Self::Path(input.parse()?))
+        }
+    }
+}
+
+impl ToTokens for TagValue {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        match self {
+            Self::LitStr(tag) => tag.to_tokens(tokens),
+            Self::Path(path) => path.to_tokens(tokens),
+        }
+    }
 }
 
-pub fn fbr_to_path_attr<'a>(fbr_path_attribute: FbrPathAttr<'a>, path: &'a str, path_operation: PathOperation) -> PathAttr<'a> {
+pub fn fbr_to_path_attr<'a>(
+    fbr_path_attribute: FbrPathAttr<'a>,
+    path: &'a str,
+    path_operation: PathOperation,
+) -> PathAttr<'a> {
     PathAttr {
-        path_operation: Some(path_operation),
+        path_operations: vec![path_operation],
         request_body: fbr_path_attribute.request_body,
         responses: fbr_path_attribute.responses,
         path: Some(path.into()),
         operation_id: fbr_path_attribute.operation_id,
         tag: fbr_path_attribute.tag,
+        tag_from_module: fbr_path_attribute.tag_from_module,
         params: fbr_path_attribute.params,
+        path_params: fbr_path_attribute.path_params,
         security: fbr_path_attribute.security,
         context_path: fbr_path_attribute.context_path,
-    }
-}
-
-//--------
-impl<'p> FbrPathAttr<'p> {
-    #[cfg(any(
-        feature = "actix_extras",
-        feature = "rocket_extras",
-        feature = "axum_extras"
-    ))]
-    pub fn update_parameters<'a>(&mut self, arguments: Option<Vec<ValueArgument<'a>>>)
-    where
-        'a: 'p,
-    {
-        if let Some(arguments) = arguments {
-            if !self.params.is_empty() {
-                let mut value_parameters: Vec<&mut ValueParameter> = self
-                    .params
-                    .iter_mut()
-                    .filter_map(|parameter| match parameter {
-                        Parameter::Value(value) => Some(value),
-                        Parameter::Struct(_) => None,
-                    })
-                    .collect::<Vec<_>>();
-                let (existing_arguments, new_arguments): (Vec<ValueArgument>, Vec<ValueArgument>) =
-                    arguments.into_iter().partition(|argument| {
-                        value_parameters.iter().any(|parameter| {
-                            Some(parameter.name.as_ref()) == argument.name.as_deref()
-                        })
-                    });
-
-                for argument in existing_arguments {
-                    if let Some(parameter) = value_parameters
-                        .iter_mut()
-                        .find(|parameter| Some(parameter.name.as_ref()) == argument.name.as_deref())
-                    {
-                        parameter.update_parameter_type(argument.type_tree);
-                    }
-                }
-                self.params
-                    .extend(new_arguments.into_iter().map(Parameter::from));
-            } else {
-                // no parameters at all, add arguments to the parameters
-                let mut parameters = Vec::with_capacity(arguments.len());
-
-                arguments
-                    .into_iter()
-                    .map(Parameter::from)
-                    .for_each(|parameter| parameters.push(parameter));
-                self.params = parameters;
-            }
-        }
-    }
-
-    #[cfg(any(
-        feature = "actix_extras",
-        feature = "rocket_extras",
-        feature = "axum_extras"
-    ))]
-    pub fn update_parameters_parameter_in(
-        &mut self,
-        into_params_types: Option<Vec<IntoParamsType>>,
-    ) {
-        if !self.params.is_empty() {
-            if let Some(mut into_params_types) = into_params_types {
-                self.params
-                    .iter_mut()
-                    .filter_map(|parameter| match parameter {
-                        Parameter::Value(_) => None,
-                        Parameter::Struct(parameter) => Some(parameter),
-                    })
-                    .for_each(|parameter| {
-                        if let Some(into_params_argument) =
-                            into_params_types
-                                .iter_mut()
-                                .find(|argument| matches!(&argument.type_path, Some(path) if path.as_ref() == &parameter.path.path))
-                        {
-                            parameter.update_parameter_in(
-                                &mut into_params_argument.parameter_in_provider,
-                            );
-                        }
-                    })
-            }
-        }
+        servers: fbr_path_attribute.servers,
+        callbacks: fbr_path_attribute.callbacks,
+        external_docs: fbr_path_attribute.external_docs,
+        deprecated: fbr_path_attribute.deprecated,
+        webhook: fbr_path_attribute.webhook,
     }
 }
 
 impl Parse for FbrPathAttr<'_> {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
-        const EXPECTED_ATTRIBUTE_MESSAGE: &str = "unexpected identifier, expected any of: operation_id, path, get, post, put, delete, options, head, patch, trace, connect, request_body, responses, params, tag, security, context_path";
+        const EXPECTED_ATTRIBUTE_MESSAGE: &str = "unexpected identifier, expected any of: operation_id, path, get, post, put, delete, options, head, patch, trace, connect, request_body, responses, params, path_params, tag, tag_from_module, security, context_path, servers, callbacks, external_docs, deprecated, webhook";
         let mut path_attr = FbrPathAttr::default();
 
         while !input.is_empty() {
@@ -225,12 +254,18 @@ impl Parse for FbrPathAttr<'_> {
                 "params" => {
                     let params;
                     parenthesized!(params in input);
-                    path_attr.params =
-                        Punctuated::<Parameter, Token![,]>::parse_terminated(&params)
-                            .map(|punctuated| punctuated.into_iter().collect::<Vec<Parameter>>())?;
+                    path_attr.params = parameter::parse_parameters(&params)?;
+                }
+                "path_params" => {
+                    let path_params;
+                    parenthesized!(path_params in input);
+                    path_attr.path_params = parameter::parse_parameters(&path_params)?;
                 }
                 "tag" => {
-                    path_attr.tag = Some(parse_utils::parse_next_literal_str(input)?);
+                    path_attr.tag = Some(parse_utils::parse_next(input, || input.parse())?);
+                }
+                "tag_from_module" => {
+                    path_attr.tag_from_module = parse_utils::parse_bool_or_true(input)?;
                 }
                 "security" => {
                     let security;
@@ -240,6 +275,23 @@ impl Parse for FbrPathAttr<'_> {
                 "context_path" => {
                     path_attr.context_path = Some(parse_utils::parse_next_literal_str(input)?)
                 }
+                "servers" => {
+                    path_attr.servers = parse_utils::parse_punctuated_within_parenthesis(input)?
+                }
+                "callbacks" => {
+                    path_attr.callbacks = parse_utils::parse_punctuated_within_parenthesis(input)?
+                }
+                "external_docs" => {
+                    let external_docs;
+                    parenthesized!(external_docs in input);
+                    path_attr.external_docs = Some(external_docs.parse()?);
+                }
+                "deprecated" => {
+                    path_attr.deprecated = Some(input.parse::<DeprecatedAttr>()?);
+                }
+                "webhook" => {
+                    path_attr.webhook = Some(parse_utils::parse_next_literal_str(input)?);
+                }
                 _ => {}
             }
 
@@ -257,17 +309,21 @@ impl<'p> PathAttr<'p> {
     #[cfg(any(
         feature = "actix_extras",
         feature = "rocket_extras",
-        feature = "axum_extras"
+        feature = "axum_extras",
+        feature = "poem_extras",
+        feature = "salvo_extras"
     ))]
     pub fn update_parameters<'a>(&mut self, arguments: Option<Vec<ValueArgument<'a>>>)
     where
         'a: 'p,
     {
         if let Some(arguments) = arguments {
-            if !self.params.is_empty() {
+            if !self.params.is_empty() || !self.path_params.is_empty() {
+                // arguments declared in `path_params(...)` only update the type of the shared parameter
                 let mut value_parameters: Vec<&mut ValueParameter> = self
                     .params
                     .iter_mut()
+                    .chain(self.path_params.iter_mut())
                     .filter_map(|parameter| match parameter {
                         Parameter::Value(value) => Some(value),
                         Parameter::Struct(_) => None,
@@ -303,34 +359,87 @@ impl<'p> PathAttr<'p> {
         }
     }
 
+    /// Whether parameters are explicitly defined with _`params(...)`_.
+    #[cfg(any(
+        feature = "actix_extras",
+        feature = "rocket_extras",
+        feature = "axum_extras",
+        feature = "poem_extras",
+        feature = "salvo_extras"
+    ))]
+    pub fn has_params(&self) -> bool {
+        !self.params.is_empty()
+    }
+
+    /// Resolve responses from the return type of the handler if none are defined.
+    pub fn update_responses(&mut self, output: &ReturnType) {
+        if self.responses.is_empty() {
+            self.responses = Response::from_return_type(output);
+        }
+    }
+
+    #[cfg(any(
+        feature = "actix_extras",
+        feature = "rocket_extras",
+        feature = "axum_extras",
+        feature = "poem_extras",
+        feature = "salvo_extras"
+    ))]
+    pub fn update_request_body<'a>(&mut self, request_body: Option<RequestBody<'a>>)
+    where
+        'a: 'p,
+    {
+        if self.request_body.is_none() {
+            self.request_body = request_body.map(RequestBodyAttr::from);
+        }
+    }
+
     #[cfg(any(
         feature = "actix_extras",
         feature = "rocket_extras",
-        feature = "axum_extras"
+        feature = "axum_extras",
+        feature = "poem_extras",
+        feature = "salvo_extras"
     ))]
     pub fn update_parameters_parameter_in(
         &mut self,
         into_params_types: Option<Vec<IntoParamsType>>,
+        explicit_params: bool,
     ) {
-        if !self.params.is_empty() {
-            if let Some(mut into_params_types) = into_params_types {
-                self.params
-                    .iter_mut()
-                    .filter_map(|parameter| match parameter {
-                        Parameter::Value(_) => None,
-                        Parameter::Struct(parameter) => Some(parameter),
-                    })
-                    .for_each(|parameter| {
-                        if let Some(into_params_argument) =
-                            into_params_types
-                                .iter_mut()
-                                .find(|argument| matches!(&argument.type_path, Some(path) if path.as_ref() == &parameter.path.path))
-                        {
-                            parameter.update_parameter_in(
-                                &mut into_params_argument.parameter_in_provider,
-                            );
-                        }
+        if let Some(mut into_params_types) = into_params_types {
+            self.params
+                .iter_mut()
+                .filter_map(|parameter| match parameter {
+                    Parameter::Value(_) => None,
+                    Parameter::Struct(parameter) => Some(parameter),
+                })
+                .for_each(|parameter| {
+                    if let Some(into_params_argument) =
+                        into_params_types
+                            .iter_mut()
+                            .find(|argument| matches!(&argument.type_path, Some(path) if path.as_ref() == &parameter.path.path))
+                    {
+                        parameter.update_parameter_in(
+                            &mut into_params_argument.parameter_in_provider,
+                        );
+                    }
+                });
+
+            // IntoParams types of handler arguments are added only if parameters are not
+            // explicitly defined with `params(...)`
+            if !explicit_params {
+                let into_params = into_params_types
+                    .into_iter()
+                    .filter_map(|into_params_type| {
+                        into_params_type.type_path.map(|path| {
+                            Parameter::Struct(StructParameter::new(
+                                path.into_owned(),
+                                into_params_type.parameter_in_provider,
+                            ))
+                        })
                     })
+                    .collect::<Vec<_>>();
+                self.params.extend(into_params);
             }
         }
     }
@@ -338,7 +447,9 @@ impl<'p> PathAttr<'p> {
 
 impl Parse for PathAttr<'_> {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
-        const EXPECTED_ATTRIBUTE_MESSAGE: &str = "unexpected identifier, expected any of: operation_id, path, get, post, put, delete, options, head, patch, trace, connect, request_body, responses, params, tag, security, context_path";
+        const EXPECTED_ATTRIBUTE_MESSAGE: &str = "unexpected identifier, expected any of: operation_id, path, get, post, put, delete, options, head, patch, trace, connect, request_body, responses, params, path_params, tag, tag_from_module, security, context_path, servers, callbacks, external_docs, deprecated, webhook";
+        const WEBHOOK_WITH_PATH_MESSAGE: &str =
+            "path and webhook cannot be used together, webhook is identified only by its name";
         let mut path_attr = PathAttr::default();
 
         while !input.is_empty() {
@@ -355,6 +466,9 @@ impl Parse for PathAttr<'_> {
                     path_attr.operation_id = Some(parse_utils::parse_next_literal_str(input)?);
                 }
                 "path" => {
+                    if path_attr.webhook.is_some() {
+                        return Err(syn::Error::new(ident.span(), WEBHOOK_WITH_PATH_MESSAGE));
+                    }
                     path_attr.path = Some(parse_utils::parse_next_literal_str(input)?);
                 }
                 "request_body" => {
@@ -370,12 +484,18 @@ impl Parse for PathAttr<'_> {
                 "params" => {
                     let params;
                     parenthesized!(params in input);
-                    path_attr.params =
-                        Punctuated::<Parameter, Token![,]>::parse_terminated(&params)
-                            .map(|punctuated| punctuated.into_iter().collect::<Vec<Parameter>>())?;
+                    path_attr.params = parameter::parse_parameters(&params)?;
+                }
+                "path_params" => {
+                    let path_params;
+                    parenthesized!(path_params in input);
+                    path_attr.path_params = parameter::parse_parameters(&path_params)?;
                 }
                 "tag" => {
-                    path_attr.tag = Some(parse_utils::parse_next_literal_str(input)?);
+                    path_attr.tag = Some(parse_utils::parse_next(input, || input.parse())?);
+                }
+                "tag_from_module" => {
+                    path_attr.tag_from_module = parse_utils::parse_bool_or_true(input)?;
                 }
                 "security" => {
                     let security;
@@ -385,12 +505,32 @@ impl Parse for PathAttr<'_> {
                 "context_path" => {
                     path_attr.context_path = Some(parse_utils::parse_next_literal_str(input)?)
                 }
+                "servers" => {
+                    path_attr.servers = parse_utils::parse_punctuated_within_parenthesis(input)?
+                }
+                "callbacks" => {
+                    path_attr.callbacks = parse_utils::parse_punctuated_within_parenthesis(input)?
+                }
+                "external_docs" => {
+                    let external_docs;
+                    parenthesized!(external_docs in input);
+                    path_attr.external_docs = Some(external_docs.parse()?);
+                }
+                "deprecated" => {
+                    path_attr.deprecated = Some(input.parse::<DeprecatedAttr>()?);
+                }
+                "webhook" => {
+                    if path_attr.path.is_some() {
+                        return Err(syn::Error::new(ident.span(), WEBHOOK_WITH_PATH_MESSAGE));
+                    }
+                    path_attr.webhook = Some(parse_utils::parse_next_literal_str(input)?);
+                }
                 _ => {
                     // any other case it is expected to be path operation
                     if let Some(path_operation) =
                         attribute_name.parse::<PathOperation>().into_iter().next()
                     {
-                        path_attr.path_operation = Some(path_operation)
+                        path_attr.path_operations.push(path_operation)
                     } else {
                         return Err(syn::Error::new(ident.span(), EXPECTED_ATTRIBUTE_MESSAGE));
                     }
@@ -406,6 +546,44 @@ impl Parse for PathAttr<'_> {
     }
 }
 
+/// Parsed `deprecated` or `deprecated(note = "...")` attribute of path operation.
+#[derive(Default)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+struct DeprecatedAttr {
+    note: Option<String>,
+}
+
+impl Parse for DeprecatedAttr {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        const EXPECTED_ATTRIBUTE_MESSAGE: &str = "unexpected attribute, expected: note";
+        let mut deprecated = DeprecatedAttr::default();
+
+        if input.peek(Paren) {
+            let content;
+            parenthesized!(content in input);
+
+            while !content.is_empty() {
+                let ident = content
+                    .parse::<Ident>()
+                    .map_err(|error| syn::Error::new(error.span(), EXPECTED_ATTRIBUTE_MESSAGE))?;
+
+                match &*ident.to_string() {
+                    "note" => {
+                        deprecated.note = Some(parse_utils::parse_next_literal_str(&content)?)
+                    }
+                    _ => return Err(syn::Error::new(ident.span(), EXPECTED_ATTRIBUTE_MESSAGE)),
+                }
+
+                if !content.is_empty() {
+                    content.parse::<Token![,]>()?;
+                }
+            }
+        }
+
+        Ok(deprecated)
+    }
+}
+
 /// Path operation type of response
 ///
 /// Instance of path operation can be formed from str parsing with following supported values:
@@ -431,6 +609,18 @@ pub enum PathOperation {
 }
 
 impl PathOperation {
+    pub(crate) const ALL: [Self; 9] = [
+        Self::Get,
+        Self::Post,
+        Self::Put,
+        Self::Delete,
+        Self::Options,
+        Self::Head,
+        Self::Patch,
+        Self::Trace,
+        Self::Connect,
+    ];
+
     /// Create path operation from ident
     ///
     /// Ident must have value of http request type as lower case string such as `get`.
@@ -443,6 +633,23 @@ impl PathOperation {
     }
 }
 
+impl PathOperation {
+    /// Lower case name of the http method of the path operation.
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            Self::Get => "get",
+            Self::Post => "post",
+            Self::Put => "put",
+            Self::Delete => "delete",
+            Self::Options => "options",
+            Self::Head => "head",
+            Self::Patch => "patch",
+            Self::Trace => "trace",
+            Self::Connect => "connect",
+        }
+    }
+}
+
 impl FromStr for PathOperation {
     type Err = Error;
 
@@ -485,7 +692,8 @@ impl ToTokens for PathOperation {
 pub struct Path<'p> {
     path_attr: PathAttr<'p>,
     fn_name: String,
-    path_operation: Option<PathOperation>,
+    type_name: Option<Ident>,
+    path_operations: Vec<PathOperation>,
     path: Option<String>,
     doc_comments: Option<Vec<String>>,
     deprecated: Option<bool>,
@@ -496,15 +704,22 @@ impl<'p> Path<'p> {
         Self {
             path_attr,
             fn_name: fn_name.to_string(),
-            path_operation: None,
+            type_name: None,
+            path_operations: Vec::new(),
             path: None,
             doc_comments: None,
             deprecated: None,
         }
     }
 
-    pub fn path_operation(mut self, path_operation: Option<PathOperation>) -> Self {
-        self.path_operation = path_operation;
+    pub fn type_name(mut self, type_name: Option<&Ident>) -> Self {
+        self.type_name = type_name.cloned();
+
+        self
+    }
+
+    pub fn path_operations(mut self, path_operations: Vec<PathOperation>) -> Self {
+        self.path_operations = path_operations;
 
         self
     }
@@ -530,7 +745,7 @@ impl<'p> Path<'p> {
 
 impl<'p> ToTokens for Path<'p> {
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
-        let path_struct = format_ident!("{}{}", PATH_STRUCT_PREFIX, self.fn_name);
+        let path_struct = format_path_struct_ident(self.type_name.as_ref(), &self.fn_name);
         let operation_id: &String = self
             .path_attr
             .operation_id
@@ -543,17 +758,18 @@ impl<'p> ToTokens for Path<'p> {
                     help = "Did you define the #[utoipa::path(...)] over function?"
                 }
             });
-        let tag = &*self
-            .path_attr
-            .tag
-            .as_ref()
-            .map(ToOwned::to_owned)
-            .unwrap_or_default();
-        let path_operation = self
-            .path_attr
-            .path_operation
-            .as_ref()
-            .or(self.path_operation.as_ref())
+        let tag = match &self.path_attr.tag {
+            Some(tag) => quote! { Some(#tag) },
+            None => quote! { None },
+        };
+        let module_tag = if self.path_attr.tag_from_module {
+            quote! { module_path!().rsplit("::").next() }
+        } else {
+            quote! { None }
+        };
+        let path_operations = [&self.path_attr.path_operations, &self.path_operations]
+            .into_iter()
+            .find(|path_operations| !path_operations.is_empty())
             .unwrap_or_else(|| {
                 #[cfg(any(feature = "actix_extras", feature = "rocket_extras"))]
                 let help =
@@ -571,8 +787,9 @@ impl<'p> ToTokens for Path<'p> {
 
         let path = self
             .path_attr
-            .path
+            .webhook
             .as_ref()
+            .or(self.path_attr.path.as_ref())
             .or(self.path.as_ref())
             .unwrap_or_else(|| {
                 #[cfg(any(feature = "actix_extras", feature = "rocket_extras"))]
@@ -589,15 +806,32 @@ impl<'p> ToTokens for Path<'p> {
                 }
             });
 
-        let path_with_context_path = self
+        // webhook name is returned as the path but it is not a template nor under a context path
+        let path_with_context_path = if self.path_attr.webhook.is_some() {
+            path.to_string()
+        } else {
+            let path = format_path_template(path);
+            self.path_attr
+                .context_path
+                .as_ref()
+                .map(|context_path| format!("{context_path}{path}"))
+                .unwrap_or(path)
+        };
+
+        let deprecated = self
             .path_attr
-            .context_path
+            .deprecated
             .as_ref()
-            .map(|context_path| format!("{context_path}{path}"))
-            .unwrap_or_else(|| path.to_string());
+            .map(|_| true)
+            .or(self.deprecated);
 
         let operation: Operation = Operation {
-            deprecated: &self.deprecated,
+            deprecated: &deprecated,
+            deprecated_note: self
+                .path_attr
+                .deprecated
+                .as_ref()
+                .and_then(|deprecated| deprecated.note.as_ref()),
             operation_id,
             summary: self
                 .doc_comments
@@ -608,6 +842,53 @@ impl<'p> ToTokens for Path<'p> {
             request_body: self.path_attr.request_body.as_ref(),
             responses: self.path_attr.responses.as_ref(),
             security: self.path_attr.security.as_ref(),
+            servers: &self.path_attr.servers,
+            callbacks: &self.path_attr.callbacks,
+            external_docs: self.path_attr.external_docs.as_ref(),
+        };
+
+        let status_code_assertions =
+            Responses(self.path_attr.responses.as_ref()).validate_status_codes();
+        let parameter_assertions =
+            parameter::validate_unique(&self.path_attr.path_params, &self.path_attr.params);
+
+        // operations other than the first one get the method as suffix to keep operation ids unique
+        let (path_operation, other_path_operations) = path_operations
+            .split_first()
+            .expect("path operations must not be empty");
+        let other_operation_ids = other_path_operations
+            .iter()
+            .map(|path_operation| format!("{}_{}", operation_id, path_operation.as_str()))
+            .collect::<Vec<_>>();
+        let other_operations = other_path_operations.iter().zip(&other_operation_ids).map(
+            |(path_operation, operation_id)| {
+                quote! {
+                    .operation(
+                        #path_operation,
+                        utoipa::openapi::path::OperationBuilder::from(operation.clone())
+                            .operation_id(Some(#operation_id))
+                    )
+                }
+            },
+        );
+
+        let generated_operation_ids = self.path_attr.operation_id.is_none();
+        let path_params = self
+            .path_attr
+            .path_params
+            .iter()
+            .map(Parameter::to_path_item_tokens);
+        let auto_collect = if cfg!(feature = "auto_collect") && self.path_attr.webhook.is_none() {
+            quote! {
+                utoipa::__dev::inventory::submit! {
+                    utoipa::__dev::CollectedPath {
+                        path: <#path_struct as utoipa::Path>::path,
+                        path_item: <#path_struct as utoipa::Path>::path_item,
+                    }
+                }
+            }
+        } else {
+            TokenStream2::new()
         };
 
         tokens.extend(quote! {
@@ -615,7 +896,17 @@ impl<'p> ToTokens for Path<'p> {
             #[doc(hidden)]
             pub struct #path_struct;
 
+            #status_code_assertions
+
+            #parameter_assertions
+
+            #auto_collect
+
             impl utoipa::Path for #path_struct {
+                const OPERATION_IDS: &'static [&'static str] = &[#operation_id #(, #other_operation_ids)*];
+                const GENERATED_OPERATION_IDS: bool = #generated_operation_ids;
+                const PATH_ITEM_TYPES: &'static [utoipa::openapi::PathItemType] = &[#( #path_operations ),*];
+
                 fn path() -> &'static str {
                     #path_with_context_path
                 }
@@ -623,29 +914,80 @@ impl<'p> ToTokens for Path<'p> {
                 fn path_item(default_tag: Option<&str>) -> utoipa::openapi::path::PathItem {
                     use utoipa::openapi::ToArray;
                     use std::iter::FromIterator;
-                    utoipa::openapi::PathItem::new(
-                        #path_operation,
-                        #operation.tag(*[Some(#tag), default_tag, Some("crate")].iter()
-                            .flatten()
-                            .find(|t| !t.is_empty()).unwrap()
-                        )
-                    )
+                    let operation: utoipa::openapi::path::Operation = #operation.tag(*[#tag, #module_tag, default_tag, Some("crate")].iter()
+                        .flatten()
+                        .find(|t| !t.is_empty()).unwrap()
+                    ).build();
+
+                    utoipa::openapi::path::PathItemBuilder::new()
+                        #(#path_params)*
+                        #(#other_operations)*
+                        .operation(#path_operation, operation)
+                        .build()
                 }
             }
         });
     }
 }
 
+/// Format path to OpenAPI path template. Regular expressions of path arguments such as
+/// `{tail:.*}` are stripped and wildcard segment such as `/*rest` is changed to path
+/// argument e.g. `/{rest}`. Wildcard segment without name e.g. `/*` is named `rest`.
+fn format_path_template(path: &str) -> String {
+    let mut template = String::with_capacity(path.len());
+    let mut chars = path.chars();
+
+    while let Some(char) = chars.next() {
+        match char {
+            '{' => {
+                template.push(char);
+                for char in chars.by_ref() {
+                    if char == '}' {
+                        break;
+                    } else if char == ':' {
+                        // skip the regular expression which itself may contain curly braces
+                        let mut depth = 0;
+                        for char in chars.by_ref() {
+                            match char {
+                                '{' => depth += 1,
+                                '}' if depth == 0 => break,
+                                '}' => depth -= 1,
+                                _ => (),
+                            }
+                        }
+                        break;
+                    }
+                    template.push(char);
+                }
+                template.push('}');
+            }
+            '*' if template.is_empty() || template.ends_with('/') => {
+                // wildcard is always the last segment of the path
+                let name = chars.by_ref().collect::<String>();
+                let name = if name.is_empty() { "rest" } else { &name };
+                template.push_str(&format!("{{{name}}}"));
+            }
+            _ => template.push(char),
+        }
+    }
+
+    template
+}
+
 #[cfg_attr(feature = "debug", derive(Debug))]
 struct Operation<'a> {
     operation_id: &'a String,
     summary: Option<&'a String>,
     description: Option<&'a Vec<String>>,
     deprecated: &'a Option<bool>,
+    deprecated_note: Option<&'a String>,
     parameters: &'a Vec<Parameter<'a>>,
     request_body: Option<&'a RequestBodyAttr<'a>>,
     responses: &'a Vec<Response<'a>>,
     security: Option<&'a Array<'a, SecurityRequirementAttr>>,
+    servers: &'a Punctuated<Server, Comma>,
+    callbacks: &'a Punctuated<Callback, Comma>,
+    external_docs: Option<&'a ExternalDocs>,
 }
 
 impl ToTokens for Operation<'_> {
@@ -667,6 +1009,20 @@ impl ToTokens for Operation<'_> {
                 .securities(Some(#security_requirements))
             })
         }
+        if !self.servers.is_empty() {
+            let servers = self.servers.iter().collect::<Array<&Server>>();
+            tokens.extend(quote! {
+                .servers(Some(#servers))
+            })
+        }
+        self.callbacks
+            .iter()
+            .for_each(|callback| callback.to_tokens(tokens));
+        if let Some(external_docs) = self.external_docs {
+            tokens.extend(quote! {
+                .external_docs(Some(#external_docs))
+            })
+        }
         let operation_id = self.operation_id;
         tokens.extend(quote! {
             .operation_id(Some(#operation_id))
@@ -686,14 +1042,20 @@ impl ToTokens for Operation<'_> {
             })
         }
 
-        if let Some(description) = self.description {
-            let description = description.join("\n");
+        let description = self
+            .description
+            .map(|description| description.join("\n"))
+            .unwrap_or_default();
+        let description = match self.deprecated_note {
+            Some(note) if description.is_empty() => format!("**Deprecated:** {note}"),
+            Some(note) => format!("{description}\n\n**Deprecated:** {note}"),
+            None => description,
+        };
 
-            if !description.is_empty() {
-                tokens.extend(quote! {
-                    .description(Some(#description))
-                })
-            }
+        if !description.is_empty() {
+            tokens.extend(quote! {
+                .description(Some(#description))
+            })
         }
 
         self.parameters
@@ -795,6 +1157,11 @@ impl PathTypeTree for TypeTree<'_> {
                         .any(|path| SchemaType(path).is_byte())
                 })
                 .unwrap_or(false)
+            || self
+                .path
+                .as_ref()
+                .map(|path| SchemaType(path.deref()).is_bytes())
+                .unwrap_or(false)
         {
             "application/octet-stream"
         } else if self
@@ -0,0 +1,44 @@
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::parse::{Parse, ParseStream};
+use syn::{parenthesized, ExprPath, LitStr, Token};
+
+use super::resolve_path_struct;
+
+// ("name" = path::to::callback_handler)
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub(super) struct Callback {
+    name: String,
+    handler: ExprPath,
+}
+
+impl Parse for Callback {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let callback_stream;
+        parenthesized!(callback_stream in input);
+        let name = callback_stream.parse::<LitStr>()?.value();
+        callback_stream.parse::<Token![=]>()?;
+
+        Ok(Callback {
+            name,
+            handler: callback_stream.parse()?,
+        })
+    }
+}
+
+impl ToTokens for Callback {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let name = &self.name;
+        let (_, path_struct) = resolve_path_struct(&self.handler);
+
+        tokens.extend(quote! {
+            .callback(
+                #name,
+                utoipa::openapi::path::Callback::new(
+                    <#path_struct as utoipa::Path>::path(),
+                    <#path_struct as utoipa::Path>::path_item(None)
+                )
+            )
+        })
+    }
+}
@@ -1,20 +1,21 @@
 use proc_macro2::{Ident, TokenStream};
 use quote::{quote, ToTokens};
 use syn::parse::{Parse, ParseStream};
-use syn::token::Comma;
+use syn::token::{Comma, Paren};
 use syn::{parenthesized, Error, LitStr, Token};
 
 use crate::{parse_utils, AnyValue};
 
 // (name = (summary = "...", description = "...", value = "..", external_value = "..."))
-#[derive(Default)]
+// or (name = json!(...), summary = "...", description = "...")
+#[derive(Default, Clone)]
 #[cfg_attr(feature = "debug", derive(Debug))]
-pub(super) struct Example {
-    pub(super) name: String,
-    pub(super) summary: Option<String>,
-    pub(super) description: Option<String>,
-    pub(super) value: Option<AnyValue>,
-    pub(super) external_value: Option<String>,
+pub(crate) struct Example {
+    pub(crate) name: String,
+    pub(crate) summary: Option<String>,
+    pub(crate) description: Option<String>,
+    pub(crate) value: Option<AnyValue>,
+    pub(crate) external_value: Option<String>,
 }
 
 impl Parse for Example {
@@ -27,6 +28,31 @@ impl Parse for Example {
         };
         example_stream.parse::<Token![=]>()?;
 
+        if !example_stream.peek(Paren) {
+            example.value = Some(AnyValue::parse_json(&example_stream)?);
+
+            while !example_stream.is_empty() {
+                example_stream.parse::<Comma>()?;
+                let ident = example_stream.parse::<Ident>()?;
+                let attribute_name = &*ident.to_string();
+                let value =
+                    parse_utils::parse_next(&example_stream, || example_stream.parse::<LitStr>())?
+                        .value();
+                match attribute_name {
+                    "summary" => example.summary = Some(value),
+                    "description" => example.description = Some(value),
+                    _ => {
+                        return Err(Error::new(
+                            ident.span(),
+                            format!("unexpected attribute: {attribute_name}, expected one of: summary, description"),
+                        ))
+                    }
+                }
+            }
+
+            return Ok(example);
+        }
+
         let content;
         parenthesized!(content in example_stream);
 
@@ -109,7 +109,13 @@ impl ToTokens for MediaTypeSchema<'_> {
                         }
                     }
                     ValueType::Object => {
-                        if type_tree.is_object() {
+                        if SchemaType(path).is_bytes() {
+                            tokens.extend(quote! {
+                                utoipa::openapi::ObjectBuilder::new()
+                                    .schema_type(utoipa::openapi::schema::SchemaType::String)
+                                    .format(Some(utoipa::openapi::SchemaFormat::KnownFormat(utoipa::openapi::KnownFormat::Binary)))
+                            })
+                        } else if type_tree.is_object() {
                             tokens.extend(quote! {
                                 utoipa::openapi::ObjectBuilder::new()
                             })
@@ -1,22 +1,28 @@
 use std::{borrow::Cow, fmt::Display};
 
-use proc_macro2::{Ident, TokenStream};
+use proc_macro2::{Ident, Span, TokenStream};
+use proc_macro_error::emit_error;
 use quote::{quote, quote_spanned, ToTokens};
 use syn::{
     parenthesized,
     parse::{Parse, ParseBuffer, ParseStream},
+    punctuated::Punctuated,
+    spanned::Spanned,
+    token::Comma,
     Error, ExprPath, LitStr, Token,
 };
 
 #[cfg(any(
     feature = "actix_extras",
     feature = "rocket_extras",
-    feature = "axum_extras"
+    feature = "axum_extras",
+    feature = "poem_extras",
+    feature = "salvo_extras"
 ))]
 use crate::ext::{ArgumentIn, ValueArgument};
-use crate::{component::TypeTree, parse_utils, AnyValue, Deprecated, Required};
+use crate::{component::TypeTree, parse_utils, AnyValue, Array, Deprecated, Required};
 
-use super::{media_type::MediaTypeSchema, InlineType, PathTypeTree};
+use super::{example::Example, media_type::MediaTypeSchema, InlineType, PathTypeTree};
 
 /// Parameter of request suchs as in path, header, query or cookie
 ///
@@ -49,25 +55,177 @@ impl Parse for Parameter<'_> {
     }
 }
 
+/// Parse comma separated [`Parameter`]s of `params(...)` attribute. Multiple [`StructParameter`]s
+/// and [`ValueParameter`]s can be mixed freely but an error is reported if a [`ValueParameter`]
+/// with the same name and location is defined more than once.
+pub fn parse_parameters<'a>(input: ParseStream) -> syn::Result<Vec<Parameter<'a>>> {
+    let mut parameters = Vec::<Parameter>::new();
+
+    while !input.is_empty() {
+        let span = input.span();
+        let parameter = input.parse::<Parameter>()?;
+
+        if let Parameter::Value(value) = &parameter {
+            let is_duplicate = parameters.iter().any(|existing| {
+                matches!(existing, Parameter::Value(existing)
+                    if existing.name == value.name && existing.parameter_in == value.parameter_in)
+            });
+            if is_duplicate {
+                return Err(Error::new(
+                    span,
+                    format!(
+                        "duplicate parameter: {} in {}",
+                        value.name, value.parameter_in
+                    ),
+                ));
+            }
+        }
+        parameters.push(parameter);
+
+        if !input.is_empty() {
+            input.parse::<Token![,]>()?;
+        }
+    }
+
+    Ok(parameters)
+}
+
+/// Check parameters of `path_params(...)` and `params(...)` for duplicates. Duplicate
+/// [`ValueParameter`]s are reported right away while duplicates with [`StructParameter`]s are
+/// checked at compile time with _`IntoParams::PARAMETERS`_ using the returned assertions.
+pub fn validate_unique(path_params: &[Parameter], params: &[Parameter]) -> TokenStream {
+    for path_param in path_params {
+        if let Parameter::Value(path_param) = path_param {
+            let is_duplicate = params.iter().any(|param| {
+                matches!(param, Parameter::Value(param)
+                    if param.name == path_param.name && param.parameter_in == path_param.parameter_in)
+            });
+            if is_duplicate {
+                emit_error!(
+                    Span::call_site(),
+                    "duplicate parameter: {} in {} is defined in both path_params(...) and params(...)",
+                    path_param.name,
+                    path_param.parameter_in
+                );
+            }
+        }
+    }
+
+    let parameters_tokens = path_params
+        .iter()
+        .chain(params)
+        .filter_map(|parameter| Some((parameter, parameter.parameters_tokens()?)))
+        .collect::<Vec<_>>();
+
+    parameters_tokens
+        .iter()
+        .enumerate()
+        .flat_map(|(index, (parameter, (parameters, parameter_in)))| {
+            parameters_tokens[..index]
+                .iter()
+                .filter(move |(previous, _)| {
+                    matches!(parameter, Parameter::Struct(_))
+                        || matches!(previous, Parameter::Struct(_))
+                })
+                .map(
+                    move |(previous, (previous_parameters, previous_parameter_in))| {
+                        let message = format!(
+                            "duplicate parameter: {} defines a parameter already defined by {}",
+                            parameter.describe(),
+                            previous.describe()
+                        );
+
+                        quote_spanned! {parameter.source_span()=>
+                            const _: () = {
+                                if utoipa::__dev::has_common_parameters(
+                                    #previous_parameters,
+                                    #previous_parameter_in,
+                                    #parameters,
+                                    #parameter_in
+                                ) {
+                                    panic!(#message)
+                                }
+                            };
+                        }
+                    },
+                )
+        })
+        .collect()
+}
+
+impl Parameter<'_> {
+    /// Create _`(parameters, parameter_in)`_ tokens where _`parameters`_ are the names and
+    /// locations of the parameters and _`parameter_in`_ is the location given by
+    /// `parameter_in_provider`. `None` is returned if the provider is not a closure.
+    fn parameters_tokens(&self) -> Option<(TokenStream, TokenStream)> {
+        match self {
+            Parameter::Value(parameter) => {
+                let (name, parameter_in) = (&*parameter.name, &parameter.parameter_in);
+                Some((quote! { &[(#name, Some(#parameter_in))] }, quote! { None }))
+            }
+            Parameter::Struct(parameter) => {
+                let path = &parameter.path;
+                let parameter_in = match &parameter.parameter_in_fn {
+                    Some(parameter_in_fn) => {
+                        syn::parse2::<syn::ExprClosure>(parameter_in_fn.clone())
+                            .ok()?
+                            .body
+                            .to_token_stream()
+                    }
+                    None => quote! { None },
+                };
+                Some((
+                    quote! { <#path as utoipa::IntoParams>::PARAMETERS },
+                    parameter_in,
+                ))
+            }
+        }
+    }
+
+    fn describe(&self) -> String {
+        match self {
+            Parameter::Value(parameter) => {
+                format!("({:?}, {})", parameter.name, parameter.parameter_in)
+            }
+            Parameter::Struct(parameter) => format!(
+                "`{}`",
+                parameter
+                    .path
+                    .to_token_stream()
+                    .to_string()
+                    .replace(' ', "")
+            ),
+        }
+    }
+
+    fn source_span(&self) -> Span {
+        match self {
+            Parameter::Value(_) => Span::call_site(),
+            Parameter::Struct(parameter) => parameter.path.span(),
+        }
+    }
+
+    /// Create tokens adding the parameter to `PathItemBuilder`. Parameters of `IntoParams` type
+    /// are appended with `extend_parameters` since `parameters` of path item replaces the
+    /// existing parameters.
+    pub fn to_path_item_tokens(&self) -> TokenStream {
+        match self {
+            Parameter::Value(_) => self.to_token_stream(),
+            Parameter::Struct(parameter) => {
+                let into_params = parameter.to_into_params_tokens();
+                quote! { .extend_parameters(#into_params) }
+            }
+        }
+    }
+}
+
 impl ToTokens for Parameter<'_> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         match self {
             Parameter::Value(parameter) => tokens.extend(quote! { .parameter(#parameter) }),
-            Parameter::Struct(StructParameter {
-                path,
-                parameter_in_fn,
-            }) => {
-                let last_ident = &path.path.segments.last().unwrap().ident;
-
-                let default_parameter_in_provider = &quote! { || None };
-                let parameter_in_provider = parameter_in_fn
-                    .as_ref()
-                    .unwrap_or(default_parameter_in_provider);
-                tokens.extend(quote_spanned! {last_ident.span()=>
-                    .parameters(
-                        Some(<#path as utoipa::IntoParams>::into_params(#parameter_in_provider))
-                    )
-                })
+            Parameter::Struct(parameter) => {
+                let into_params = parameter.to_into_params_tokens();
+                tokens.extend(quote! { .parameters(Some(#into_params)) })
             }
         }
     }
@@ -76,7 +234,9 @@ impl ToTokens for Parameter<'_> {
 #[cfg(any(
     feature = "actix_extras",
     feature = "rocket_extras",
-    feature = "axum_extras"
+    feature = "axum_extras",
+    feature = "poem_extras",
+    feature = "salvo_extras"
 ))]
 impl<'a> From<ValueArgument<'a>> for Parameter<'a> {
     fn from(argument: ValueArgument<'a>) -> Self {
@@ -105,13 +265,17 @@ pub struct ValueParameter<'a> {
 
     /// Type only when value parameter is parsed
     parsed_type: Option<InlineType<'a>>,
+    /// Content type and type of the parameter when it is described by `content(...)`
+    content: Option<(String, InlineType<'a>)>,
 }
 
 impl<'p> ValueParameter<'p> {
     #[cfg(any(
         feature = "actix_extras",
         feature = "rocket_extras",
-        feature = "axum_extras"
+        feature = "axum_extras",
+        feature = "poem_extras",
+        feature = "salvo_extras"
     ))]
     pub fn update_parameter_type(&mut self, type_path: Option<TypeTree<'p>>) {
         self.parameter_type = type_path;
@@ -154,7 +318,7 @@ impl Parse for ValueParameter<'_> {
                 .join(", ");
 
             format!(
-                "unexpected attribute, expected any of: {}, deprecated, description, style, explode, allow_reserved, example",
+                "unexpected attribute, expected any of: {}, deprecated, description, content, style, explode, allow_reserved, allow_empty_value, example, examples",
                 parameter_in_variants
             )
         }
@@ -189,6 +353,19 @@ impl Parse for ValueParameter<'_> {
                                     .value(),
                             )
                         }
+                        "content" => {
+                            if parameter.parsed_type.is_some() {
+                                return Err(Error::new(
+                                    ident.span(),
+                                    "content cannot be used together with parameter type, expected either `\"name\" = Type` or `content(...)`",
+                                ));
+                            }
+                            let content;
+                            parenthesized!(content in input);
+                            let content_type = content.parse::<LitStr>()?.value();
+                            content.parse::<Token![=]>()?;
+                            parameter.content = Some((content_type, content.parse()?));
+                        }
                         _ => return Err(Error::new(ident.span(), expected_attribute_message())),
                     }
                 }
@@ -231,12 +408,41 @@ impl ToTokens for ValueParameter<'_> {
                 tokens.extend(quote! { .allow_reserved(Some(#allow_reserved)) });
             }
 
-            if let Some(ref example) = ext.example {
+            if let Some(ref allow_empty_value) = ext.allow_empty_value {
+                tokens.extend(quote! { .allow_empty_value(Some(#allow_empty_value)) });
+            }
+
+            // example and examples are mutually exclusive, examples take precedence
+            if let Some(example) = ext.example.as_ref().filter(|_| ext.examples.is_none()) {
                 tokens.extend(quote! { .example(Some(#example)) });
             }
+
+            if let Some(ref examples) = ext.examples {
+                let examples = examples
+                    .iter()
+                    .map(|example| {
+                        let name = &example.name;
+                        quote!((#name, #example))
+                    })
+                    .collect::<Array<TokenStream>>();
+                tokens.extend(quote! { .examples_from_iter(#examples) });
+            }
         }
 
-        if let Some(parameter_type) = &self.parameter_type {
+        if let Some((content_type, content_type_tree)) = &self.content {
+            let type_tree = content_type_tree.as_type_tree();
+
+            let media_type_schema = MediaTypeSchema {
+                type_tree: &type_tree,
+                is_inline: content_type_tree.is_inline,
+            };
+            let required: Required = (!type_tree.is_option()).into();
+
+            tokens.extend(quote! {
+                .content(#content_type, utoipa::openapi::content::ContentBuilder::new().schema(#media_type_schema).build())
+                .required(#required)
+            });
+        } else if let Some(parameter_type) = &self.parameter_type {
             let media_type_schema = MediaTypeSchema {
                 type_tree: parameter_type,
                 is_inline: false,
@@ -266,10 +472,48 @@ pub struct StructParameter {
 }
 
 impl StructParameter {
+    /// Create tokens of the parameters returned by `IntoParams::into_params` of the type.
+    fn to_into_params_tokens(&self) -> TokenStream {
+        let Self {
+            path,
+            parameter_in_fn,
+        } = self;
+        let last_ident = &path.path.segments.last().unwrap().ident;
+
+        let default_parameter_in_provider = &quote! { || None };
+        let parameter_in_provider = parameter_in_fn
+            .as_ref()
+            .unwrap_or(default_parameter_in_provider);
+        quote_spanned! {last_ident.span()=>
+            <#path as utoipa::IntoParams>::into_params(#parameter_in_provider)
+        }
+    }
+
+    /// Construct a new [`StructParameter`] for `IntoParams` type resolved from handler arguments.
+    #[cfg(any(
+        feature = "actix_extras",
+        feature = "rocket_extras",
+        feature = "axum_extras",
+        feature = "poem_extras",
+        feature = "salvo_extras"
+    ))]
+    pub fn new(path: syn::Path, parameter_in_provider: TokenStream) -> Self {
+        Self {
+            path: ExprPath {
+                attrs: Vec::new(),
+                qself: None,
+                path,
+            },
+            parameter_in_fn: Some(parameter_in_provider),
+        }
+    }
+
     #[cfg(any(
         feature = "actix_extras",
         feature = "rocket_extras",
-        feature = "axum_extras"
+        feature = "axum_extras",
+        feature = "poem_extras",
+        feature = "salvo_extras"
     ))]
     pub fn update_parameter_in(&mut self, parameter_in_provider: &mut TokenStream) {
         use std::mem;
@@ -348,7 +592,9 @@ pub struct ParameterExt {
     pub style: Option<ParameterStyle>,
     pub explode: Option<bool>,
     pub allow_reserved: Option<bool>,
+    pub allow_empty_value: Option<bool>,
     pub(crate) example: Option<AnyValue>,
+    pub(crate) examples: Option<Punctuated<Example, Comma>>,
 }
 
 impl ParameterExt {
@@ -362,14 +608,20 @@ impl ParameterExt {
         if from.allow_reserved.is_some() {
             self.allow_reserved = from.allow_reserved
         }
+        if from.allow_empty_value.is_some() {
+            self.allow_empty_value = from.allow_empty_value
+        }
         if from.example.is_some() {
             self.example = from.example
         }
+        if from.examples.is_some() {
+            self.examples = from.examples
+        }
     }
 
     pub fn parse_once(input: ParseStream) -> syn::Result<Self> {
         const EXPECTED_ATTRIBUTE_MESSAGE: &str =
-            "unexpected attribute, expected any of: style, explode, allow_reserved, example";
+            "unexpected attribute, expected any of: style, explode, allow_reserved, allow_empty_value, example, examples";
 
         let ident = input.parse::<Ident>().map_err(|error| {
             Error::new(
@@ -394,12 +646,20 @@ impl ParameterExt {
                 allow_reserved: Some(parse_utils::parse_bool_or_true(input)?),
                 ..Default::default()
             },
+            "allow_empty_value" => ParameterExt {
+                allow_empty_value: Some(parse_utils::parse_bool_or_true(input)?),
+                ..Default::default()
+            },
             "example" => ParameterExt {
                 example: Some(parse_utils::parse_next(input, || {
                     AnyValue::parse_any(input)
                 })?),
                 ..Default::default()
             },
+            "examples" => ParameterExt {
+                examples: Some(parse_utils::parse_punctuated_within_parenthesis(input)?),
+                ..Default::default()
+            },
             _ => return Err(Error::new(ident.span(), EXPECTED_ATTRIBUTE_MESSAGE)),
         };
 
@@ -416,7 +676,15 @@ impl ParameterExt {
             let ident = fork.parse::<Ident>().unwrap();
             let name = &*ident.to_string();
 
-            matches!(name, "style" | "explode" | "allow_reserved" | "example")
+            matches!(
+                name,
+                "style"
+                    | "explode"
+                    | "allow_reserved"
+                    | "allow_empty_value"
+                    | "example"
+                    | "examples"
+            )
         } else {
             false
         }
@@ -1,14 +1,17 @@
 use proc_macro2::{Ident, TokenStream as TokenStream2};
-use quote::{quote, ToTokens};
+use quote::{quote, quote_spanned, ToTokens};
 use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
 use syn::token::Comma;
-use syn::{parenthesized, parse::Parse, token::Paren, Error, Token};
+use syn::{parenthesized, parse::Parse, token::Paren, Error, LitStr, Token};
 
-use crate::component::TypeTree;
+use crate::component::{GenericType, TypeTree, ValueType};
 use crate::{parse_utils, AnyValue, Array, Required};
 
 use super::example::Example;
 use super::media_type::MediaTypeSchema;
+use super::parameter::ParameterStyle;
+use super::response::Header;
 use super::{PathType, PathTypeTree};
 
 /// Parsed information related to requst body of path.
@@ -17,8 +20,17 @@ use super::{PathType, PathTypeTree};
 ///   * **content** Request body content object type. Can also be array e.g. `content = [String]`.
 ///   * **content_type** Defines the actual content mime type of a request body such as `application/json`.
 ///     If not provided really rough guess logic is used. Basically all primitive types are treated as `text/plain`
-///     and Object types are expected to be `application/json` by default.
+///     and Object types are expected to be `application/json` by default. Fields of `multipart/form-data`
+///     content type are expanded to the request body schema where byte buffer fields are binary parts.
 ///   * **description** Additional description for request body content type.
+///   * **example** Single example of the request body content.
+///   * **examples** Named examples of the request body content, overrides the **example**.
+///   * **encoding** Encoding of the properties of the request body content, e.g. content types of
+///     `multipart/form-data` parts.
+///   * **upload** Shorthand for `multipart/form-data` file upload with single binary property. Name
+///     of the property defaults to `file`.
+///   * **required** Defines whether the request body is required. By default this is resolved from
+///     the **content** type, `Option` types are not required.
 /// # Examples
 ///
 /// Request body in path with all supported info. Where content type is treated as a String and expected
@@ -48,6 +60,21 @@ use super::{PathType, PathTypeTree};
 ///    request_body = Option<[Foo]>,
 /// )]
 /// ```
+///
+/// Or define it explicitly with `required = false`.
+/// ```text
+/// #[utoipa::path(
+///    request_body(content = Foo, required = false),
+/// )]
+/// ```
+///
+/// File upload can be defined without a dedicated type with `upload`. Optionally the name of the
+/// uploaded file property can be given within parentheses.
+/// ```text
+/// #[utoipa::path(
+///    request_body = upload("avatar"),
+/// )]
+/// ```
 #[derive(Default)]
 #[cfg_attr(feature = "debug", derive(Debug))]
 pub struct RequestBodyAttr<'r> {
@@ -56,12 +83,79 @@ pub struct RequestBodyAttr<'r> {
     description: Option<String>,
     example: Option<AnyValue>,
     examples: Option<Punctuated<Example, Comma>>,
+    encoding: Vec<Encoding>,
+    upload: Option<String>,
+    required: Option<bool>,
+}
+
+impl RequestBodyAttr<'_> {
+    const DEFAULT_UPLOAD_FIELD: &'static str = "file";
+
+    /// Check whether next token is `upload` keyword instead of a type named `upload`.
+    fn peek_upload(input: syn::parse::ParseStream) -> bool {
+        let fork = input.fork();
+        matches!(fork.parse::<Ident>(), Ok(ident) if ident == "upload")
+            && !fork.peek(Token![::])
+            && !fork.peek(Token![<])
+    }
+
+    /// Get path of the form type of `multipart/form-data` request body content. Fields of the form
+    /// type are expanded to the properties of the inlined schema where byte buffer fields are
+    /// binary parts.
+    fn multipart_form_path<'t>(&self, type_tree: &'t TypeTree) -> Option<&'t syn::Path> {
+        let type_tree = match type_tree.generic_type {
+            Some(GenericType::Option) => type_tree.children.as_ref()?.first()?,
+            Some(_) => return None,
+            None => type_tree,
+        };
+
+        let is_form = self.content_type.as_deref() == Some("multipart/form-data")
+            && type_tree.generic_type.is_none()
+            && matches!(type_tree.value_type, ValueType::Object)
+            && !type_tree.is_object()
+            && !type_tree.is_byte_buffer();
+
+        type_tree.path.as_deref().filter(|_| is_form)
+    }
+
+    fn content_tokens(&self, media_type_schema: TokenStream2) -> TokenStream2 {
+        let mut content = quote! {
+            utoipa::openapi::content::ContentBuilder::new()
+                .schema(#media_type_schema)
+        };
+
+        if let Some(ref example) = self.example {
+            content.extend(quote! {
+                .example(Some(#example))
+            })
+        }
+        if let Some(ref examples) = self.examples {
+            let examples = examples
+                .iter()
+                .map(|example| {
+                    let name = &example.name;
+                    quote!((#name, #example))
+                })
+                .collect::<Array<TokenStream2>>();
+            content.extend(quote!(
+                .examples_from_iter(#examples)
+            ))
+        }
+        for encoding in &self.encoding {
+            let property_name = &encoding.property_name;
+            content.extend(quote! {
+                .encoding(#property_name, #encoding)
+            })
+        }
+
+        content
+    }
 }
 
 impl Parse for RequestBodyAttr<'_> {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
         const EXPECTED_ATTRIBUTE_MESSAGE: &str =
-            "unexpected attribute, expected any of: content, content_type, description, examples";
+            "unexpected attribute, expected any of: content, content_type, description, example, examples, encoding, upload, required";
         let lookahead = input.lookahead1();
 
         if lookahead.peek(Paren) {
@@ -106,6 +200,21 @@ impl Parse for RequestBodyAttr<'_> {
                         request_body_attr.examples =
                             Some(parse_utils::parse_punctuated_within_parenthesis(&group)?)
                     }
+                    "encoding" => {
+                        let encoding;
+                        parenthesized!(encoding in group);
+                        request_body_attr.encoding = parse_utils::parse_groups(&encoding)?;
+                    }
+                    "upload" => {
+                        request_body_attr.upload = if group.peek(Token![=]) {
+                            Some(parse_utils::parse_next_literal_str(&group)?)
+                        } else {
+                            Some(Self::DEFAULT_UPLOAD_FIELD.to_string())
+                        }
+                    }
+                    "required" => {
+                        request_body_attr.required = Some(parse_utils::parse_bool_or_true(&group)?)
+                    }
                     _ => return Err(Error::new(ident.span(), EXPECTED_ATTRIBUTE_MESSAGE)),
                 }
 
@@ -118,6 +227,22 @@ impl Parse for RequestBodyAttr<'_> {
         } else if lookahead.peek(Token![=]) {
             input.parse::<Token![=]>()?;
 
+            if Self::peek_upload(input) {
+                input.parse::<Ident>()?;
+                let upload = if input.peek(Paren) {
+                    let field;
+                    parenthesized!(field in input);
+                    field.parse::<LitStr>()?.value()
+                } else {
+                    Self::DEFAULT_UPLOAD_FIELD.to_string()
+                };
+
+                return Ok(RequestBodyAttr {
+                    upload: Some(upload),
+                    ..Default::default()
+                });
+            }
+
             Ok(RequestBodyAttr {
                 content: Some(input.parse().map_err(|error| {
                     Error::new(
@@ -133,45 +258,70 @@ impl Parse for RequestBodyAttr<'_> {
     }
 }
 
+#[cfg(any(
+    feature = "actix_extras",
+    feature = "rocket_extras",
+    feature = "axum_extras",
+    feature = "poem_extras",
+    feature = "salvo_extras"
+))]
+impl<'r> From<crate::ext::RequestBody<'r>> for RequestBodyAttr<'r> {
+    fn from(request_body: crate::ext::RequestBody<'r>) -> Self {
+        Self {
+            content: Some(PathType::MediaType(super::InlineType {
+                ty: std::borrow::Cow::Borrowed(request_body.ty),
+                is_inline: false,
+            })),
+            content_type: Some(request_body.content_type.to_string()),
+            required: (!request_body.required).then_some(false),
+            ..Default::default()
+        }
+    }
+}
+
 impl ToTokens for RequestBodyAttr<'_> {
     fn to_tokens(&self, tokens: &mut TokenStream2) {
-        if let Some(body_type) = &self.content {
+        if let Some(ref upload) = self.upload {
+            let content = self.content_tokens(quote! {
+                utoipa::openapi::ObjectBuilder::new()
+                    .property(
+                        #upload,
+                        utoipa::openapi::ObjectBuilder::new()
+                            .schema_type(utoipa::openapi::schema::SchemaType::String)
+                            .format(Some(utoipa::openapi::SchemaFormat::KnownFormat(utoipa::openapi::KnownFormat::Binary)))
+                    )
+                    .required(#upload)
+            });
+
+            tokens.extend(quote! {
+                utoipa::openapi::request_body::RequestBodyBuilder::new()
+                    .content("multipart/form-data", #content.build())
+                    .required(Some(utoipa::openapi::Required::True))
+            });
+        } else if let Some(body_type) = &self.content {
             let media_type_schema = match body_type {
                 PathType::Ref(ref_type) => quote! {
                     utoipa::openapi::schema::Ref::new(#ref_type)
                 },
                 PathType::MediaType(body_type) => {
                     let type_tree = body_type.as_type_tree();
-                    MediaTypeSchema {
-                        type_tree: &type_tree,
-                        is_inline: body_type.is_inline,
+                    match self.multipart_form_path(&type_tree) {
+                        Some(path) => quote_spanned! {path.span()=>
+                            utoipa::__dev::multipart_form_schema(
+                                <#path as utoipa::ToSchema>::schema(),
+                                <#path as utoipa::ToSchema>::BINARY_PROPERTIES,
+                            )
+                        },
+                        None => MediaTypeSchema {
+                            type_tree: &type_tree,
+                            is_inline: body_type.is_inline,
+                        }
+                        .to_token_stream(),
                     }
-                    .to_token_stream()
                 }
                 PathType::InlineSchema(schema, _) => schema.to_token_stream(),
             };
-            let mut content = quote! {
-                utoipa::openapi::content::ContentBuilder::new()
-                    .schema(#media_type_schema)
-            };
-
-            if let Some(ref example) = self.example {
-                content.extend(quote! {
-                    .example(Some(#example))
-                })
-            }
-            if let Some(ref examples) = self.examples {
-                let examples = examples
-                    .iter()
-                    .map(|example| {
-                        let name = &example.name;
-                        quote!((#name, #example))
-                    })
-                    .collect::<Array<TokenStream2>>();
-                content.extend(quote!(
-                    .examples_from_iter(#examples)
-                ))
-            }
+            let content = self.content_tokens(media_type_schema);
 
             match body_type {
                 PathType::Ref(_) => {
@@ -206,6 +356,13 @@ impl ToTokens for RequestBodyAttr<'_> {
             }
         }
 
+        if let Some(required) = self.required {
+            let required: Required = required.into();
+            tokens.extend(quote! {
+                .required(Some(#required))
+            })
+        }
+
         if let Some(ref description) = self.description {
             tokens.extend(quote! {
                 .description(Some(#description))
@@ -215,3 +372,88 @@ impl ToTokens for RequestBodyAttr<'_> {
         tokens.extend(quote! { .build() })
     }
 }
+
+/// Parsed representation of encoding of a single property of request body content.
+///
+/// Supported configuration format is
+/// `("property_name", content_type = "...", headers(...), style = ..., explode, allow_reserved)`
+/// where all but the property name are optional.
+#[derive(Default)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+struct Encoding {
+    property_name: String,
+    content_type: Option<String>,
+    headers: Vec<Header>,
+    style: Option<ParameterStyle>,
+    explode: Option<bool>,
+    allow_reserved: Option<bool>,
+}
+
+impl Parse for Encoding {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        const EXPECTED_ATTRIBUTE_MESSAGE: &str =
+            "unexpected attribute, expected any of: content_type, headers, style, explode, allow_reserved";
+        let mut encoding = Encoding {
+            property_name: input.parse::<LitStr>()?.value(),
+            ..Default::default()
+        };
+
+        if !input.is_empty() {
+            input.parse::<Token![,]>()?;
+        }
+
+        while !input.is_empty() {
+            let ident = input
+                .parse::<Ident>()
+                .map_err(|error| Error::new(error.span(), EXPECTED_ATTRIBUTE_MESSAGE))?;
+
+            match &*ident.to_string() {
+                "content_type" => {
+                    encoding.content_type = Some(parse_utils::parse_next_literal_str(input)?)
+                }
+                "headers" => {
+                    let headers;
+                    parenthesized!(headers in input);
+                    encoding.headers = parse_utils::parse_groups(&headers)?;
+                }
+                "style" => encoding.style = Some(parse_utils::parse_next(input, || input.parse())?),
+                "explode" => encoding.explode = Some(parse_utils::parse_bool_or_true(input)?),
+                "allow_reserved" => {
+                    encoding.allow_reserved = Some(parse_utils::parse_bool_or_true(input)?)
+                }
+                _ => return Err(Error::new(ident.span(), EXPECTED_ATTRIBUTE_MESSAGE)),
+            }
+
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+        }
+
+        Ok(encoding)
+    }
+}
+
+impl ToTokens for Encoding {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        tokens.extend(quote! { utoipa::openapi::encoding::EncodingBuilder::new() });
+
+        if let Some(ref content_type) = self.content_type {
+            tokens.extend(quote! { .content_type(Some(#content_type)) })
+        }
+        for header in &self.headers {
+            let name = &header.name;
+            tokens.extend(quote! { .header(#name, #header) })
+        }
+        if let Some(ref style) = self.style {
+            tokens.extend(quote! { .style(Some(#style)) })
+        }
+        if let Some(explode) = self.explode {
+            tokens.extend(quote! { .explode(Some(#explode)) })
+        }
+        if let Some(allow_reserved) = self.allow_reserved {
+            tokens.extend(quote! { .allow_reserved(Some(#allow_reserved)) })
+        }
+
+        tokens.extend(quote! { .build() })
+    }
+}
@@ -1,16 +1,16 @@
 use std::{borrow::Cow, mem};
 
 use proc_macro2::{Ident, Span, TokenStream as TokenStream2};
-use proc_macro_error::{abort, ResultExt};
+use proc_macro_error::{abort, emit_error, ResultExt};
 use quote::{quote, quote_spanned, ToTokens};
 use syn::{
-    parenthesized,
+    bracketed, parenthesized,
     parse::{Parse, ParseStream},
     punctuated::Punctuated,
     spanned::Spanned,
-    token::Comma,
-    Attribute, Data, Error, ExprPath, Field, Fields, Generics, LitInt, LitStr, Path, Token, Type,
-    TypePath, Variant,
+    token::{Comma, Paren},
+    Attribute, Data, Error, ExprPath, Field, Fields, GenericArgument, Generics, LitInt, LitStr,
+    Path, PathArguments, ReturnType, Token, Type, TypePath, Variant,
 };
 
 use crate::{
@@ -19,12 +19,14 @@ use crate::{
         TypeTree,
     },
     doc_comment::CommentAttributes,
-    parse_utils, AnyValue, Array,
+    parse_utils, AnyValue, Array, Deprecated, Required,
 };
 
 use super::{
-    example::Example, media_type::MediaTypeSchema, status::STATUS_CODES, InlineType, PathType,
-    PathTypeTree,
+    example::Example,
+    media_type::MediaTypeSchema,
+    status::{self, STATUS_CODES},
+    InlineType, PathType, PathTypeTree,
 };
 
 pub mod derive;
@@ -289,11 +291,150 @@ pub enum Response<'r> {
     IntoResponses(ExprPath),
     /// The tuple definition of a response.
     Tuple(ResponseTuple<'r>),
+    /// Responses of an error type defined with `from_error(Type => [...])`.
+    FromError(Punctuated<ResponseTuple<'r>, Comma>),
+    /// Error type of the handler return type. Responses are only added if the type implements
+    /// `utoipa::IntoResponses`.
+    ReturnError(Type),
+}
+
+impl<'r> Response<'r> {
+    /// Get the tuple definitions of the response. [`Response::IntoResponses`] has none.
+    fn tuples(&self) -> Vec<&ResponseTuple<'r>> {
+        match self {
+            Self::IntoResponses(_) | Self::ReturnError(_) => Vec::new(),
+            Self::Tuple(tuple) => vec![tuple],
+            Self::FromError(tuples) => tuples.iter().collect(),
+        }
+    }
+}
+
+impl Response<'_> {
+    /// Resolve responses from the return type of handler function.
+    ///
+    /// `Json<T>` success type creates a _`200`_ response with `T` as body, and error type of
+    /// `Result<_, E>` adds the responses of `E` if it implements `utoipa::IntoResponses`.
+    /// Common response wrappers are recognized as well: `Html<T>` creates a _`200`_ `text/html`
+    /// response, `Redirect` a _`3XX`_ response with `Location` header and `StatusCode` a
+    /// _`default`_ response without body.
+    pub fn from_return_type(output: &ReturnType) -> Vec<Self> {
+        fn get_generic_arguments<'t>(ty: &'t Type, name: &str) -> Option<Vec<&'t Type>> {
+            let segment = match ty {
+                Type::Path(type_path) => type_path.path.segments.last()?,
+                _ => return None,
+            };
+
+            match &segment.arguments {
+                PathArguments::AngleBracketed(arguments) if segment.ident == name => Some(
+                    arguments
+                        .args
+                        .iter()
+                        .filter_map(|argument| match argument {
+                            GenericArgument::Type(ty) => Some(ty),
+                            _ => None,
+                        })
+                        .collect(),
+                ),
+                _ => None,
+            }
+        }
+
+        fn is_type(ty: &Type, name: &str) -> bool {
+            matches!(ty, Type::Path(type_path) if type_path.path.segments.last().is_some_and(|segment| {
+                segment.ident == name && segment.arguments.is_empty()
+            }))
+        }
+
+        fn response(status: &str, value: ResponseValue<'static>) -> Response<'static> {
+            Response::Tuple(ResponseTuple {
+                status_code: ResponseStatus::Literal(LitStr::new(status, Span::call_site())),
+                inner: Some(ResponseTupleInner::Value(value)),
+            })
+        }
+
+        fn body_response(body: &Type, content_type: &str) -> Response<'static> {
+            response(
+                "200",
+                ResponseValue {
+                    description: "OK".to_string(),
+                    response_type: Some(PathType::MediaType(InlineType {
+                        ty: Cow::Owned(body.clone()),
+                        is_inline: false,
+                    })),
+                    content_type: Some(vec![content_type.to_string()]),
+                    ..Default::default()
+                },
+            )
+        }
+
+        let ty = match output {
+            ReturnType::Type(_, ty) => ty.as_ref(),
+            ReturnType::Default => return Vec::new(),
+        };
+        let (ok_type, error_type) = match get_generic_arguments(ty, "Result").as_deref() {
+            Some([ok_type]) => (*ok_type, None),
+            Some([ok_type, error_type]) => (*ok_type, Some(*error_type)),
+            _ => (ty, None),
+        };
+
+        let mut responses = Vec::new();
+        if let Some([body]) = get_generic_arguments(ok_type, "Json").as_deref() {
+            responses.push(body_response(body, "application/json"));
+        } else if let Some([body]) = get_generic_arguments(ok_type, "Html").as_deref() {
+            responses.push(body_response(body, "text/html"));
+        } else if is_type(ok_type, "Redirect") {
+            responses.push(response(
+                "3XX",
+                ResponseValue {
+                    headers: vec![Header {
+                        name: "Location".to_string(),
+                        value_type: Some(InlineType {
+                            ty: Cow::Owned(syn::parse_quote!(String)),
+                            is_inline: false,
+                        }),
+                        description: Some("Target of the redirect".to_string()),
+                        ..Default::default()
+                    }],
+                    ..Default::default()
+                },
+            ));
+        } else if is_type(ok_type, "StatusCode") {
+            responses.push(response("default", ResponseValue::default()));
+        }
+        if let Some(error_type) = error_type {
+            responses.push(Response::ReturnError(error_type.clone()));
+        }
+
+        responses
+    }
 }
 
 impl Parse for Response<'_> {
     fn parse(input: ParseStream) -> syn::Result<Self> {
-        if input.fork().parse::<ExprPath>().is_ok() {
+        if input.peek(syn::Ident)
+            && input.peek2(Paren)
+            && input.fork().parse::<Ident>()? == "from_error"
+        {
+            input.parse::<Ident>()?;
+            let from_error;
+            parenthesized!(from_error in input);
+
+            // The error type only documents which error the responses originate from, e.g.
+            // opaque errors such as `anyhow::Error` which cannot implement `IntoResponses`.
+            from_error.parse::<Type>()?;
+            from_error.parse::<Token![=>]>()?;
+
+            let tuples;
+            bracketed!(tuples in from_error);
+            Ok(Self::FromError(Punctuated::parse_terminated_with(
+                &tuples,
+                |input| {
+                    let response;
+                    parenthesized!(response in input);
+                    response.parse()
+                },
+            )?))
+        } else if input.fork().parse::<ExprPath>().is_ok() {
             Ok(Self::IntoResponses(input.parse()?))
         } else {
             let response;
@@ -330,8 +471,8 @@ impl<'r> ResponseTuple<'r> {
     // Use with the `response` attribute, this will fail if an incompatible attribute has already been set
     fn set_ref_type(&mut self, span: Span, ty: InlineType<'r>) -> syn::Result<()> {
         match &mut self.inner {
-            None => self.inner = Some(ResponseTupleInner::Ref(ty)),
-            Some(ResponseTupleInner::Ref(r)) => *r = ty,
+            None => self.inner = Some(ResponseTupleInner::Ref(ty, None)),
+            Some(ResponseTupleInner::Ref(r, _)) => *r = ty,
             Some(ResponseTupleInner::Value(_)) => {
                 return Err(Error::new(span, RESPONSE_INCOMPATIBLE_ATTRIBUTES_MSG))
             }
@@ -343,7 +484,9 @@ impl<'r> ResponseTuple<'r> {
 #[cfg_attr(feature = "debug", derive(Debug))]
 enum ResponseTupleInner<'r> {
     Value(ResponseValue<'r>),
-    Ref(InlineType<'r>),
+    /// Reference to `ToResponse` type with optional description override used when the
+    /// response is inlined.
+    Ref(InlineType<'r>, Option<String>),
 }
 
 impl Parse for ResponseTuple<'_> {
@@ -427,12 +570,26 @@ pub struct ResponseValue<'r> {
 impl ToTokens for ResponseTuple<'_> {
     fn to_tokens(&self, tokens: &mut TokenStream2) {
         match self.inner.as_ref().unwrap() {
-            ResponseTupleInner::Ref(res) => {
+            ResponseTupleInner::Ref(res, description) => {
                 let path = &res.ty;
                 if res.is_inline {
-                    tokens.extend(quote_spanned! {path.span()=>
+                    let response = quote_spanned! {path.span()=>
                         <#path as utoipa::ToResponse>::response().1
-                    });
+                    };
+
+                    if let Some(description) = description {
+                        tokens.extend(quote! {
+                            {
+                                let mut response = #response;
+                                if let utoipa::openapi::RefOr::T(response) = &mut response {
+                                    response.description = #description.to_string();
+                                }
+                                response
+                            }
+                        })
+                    } else {
+                        tokens.extend(response);
+                    }
                 } else {
                     tokens.extend(quote! {
                         utoipa::openapi::Ref::from_response_name(<#path as utoipa::ToResponse>::response().0)
@@ -440,33 +597,42 @@ impl ToTokens for ResponseTuple<'_> {
                 }
             }
             ResponseTupleInner::Value(val) => {
-                let description = &val.description;
+                let description = if val.description.is_empty() {
+                    self.status_code.reason_phrase()
+                } else {
+                    val.description.to_token_stream()
+                };
                 tokens.extend(quote! {
                     utoipa::openapi::ResponseBuilder::new().description(#description)
                 });
 
-                let create_content = |path_type: &PathType,
+                let create_content = |path_type: Option<&PathType>,
                                       example: &Option<AnyValue>,
                                       examples: &Option<Punctuated<Example, Comma>>|
                  -> TokenStream2 {
-                    let content_schema = match path_type {
-                        PathType::Ref(ref_type) => quote! {
-                            utoipa::openapi::schema::Ref::new(#ref_type)
-                        }
-                        .to_token_stream(),
-                        PathType::MediaType(ref path_type) => {
-                            let type_tree = path_type.as_type_tree();
-                            MediaTypeSchema {
-                                type_tree: &type_tree,
-                                is_inline: path_type.is_inline,
+                    let mut content = quote! { utoipa::openapi::ContentBuilder::new() };
+
+                    if let Some(path_type) = path_type {
+                        let content_schema = match path_type {
+                            PathType::Ref(ref_type) => quote! {
+                                utoipa::openapi::schema::Ref::new(#ref_type)
                             }
-                            .to_token_stream()
-                        }
-                        PathType::InlineSchema(schema, _) => schema.to_token_stream(),
-                    };
+                            .to_token_stream(),
+                            PathType::MediaType(ref path_type) => {
+                                let type_tree = path_type.as_type_tree();
+                                MediaTypeSchema {
+                                    type_tree: &type_tree,
+                                    is_inline: path_type.is_inline,
+                                }
+                                .to_token_stream()
+                            }
+                            PathType::InlineSchema(schema, _) => schema.to_token_stream(),
+                        };
 
-                    let mut content =
-                        quote! { utoipa::openapi::ContentBuilder::new().schema(#content_schema) };
+                        content.extend(quote! { .schema(#content_schema) });
+                    } else {
+                        content.extend(quote! { .no_schema() });
+                    }
 
                     if let Some(ref example) = example {
                         content.extend(quote! {
@@ -492,7 +658,7 @@ impl ToTokens for ResponseTuple<'_> {
                 };
 
                 if let Some(response_type) = &val.response_type {
-                    let content = create_content(response_type, &val.example, &val.examples);
+                    let content = create_content(Some(response_type), &val.example, &val.examples);
 
                     if let Some(content_types) = val.content_type.as_ref() {
                         content_types.iter().for_each(|content_type| {
@@ -523,12 +689,21 @@ impl ToTokens for ResponseTuple<'_> {
                             }
                         }
                     }
+                } else if let Some(content_types) = val.content_type.as_ref() {
+                    // content type without body produces media type without schema
+                    let content = create_content(None, &val.example, &val.examples);
+
+                    content_types.iter().for_each(|content_type| {
+                        tokens.extend(quote! {
+                            .content(#content_type, #content)
+                        })
+                    })
                 }
 
                 val.content
                     .iter()
                     .map(|Content(content_type, body, example, examples)| {
-                        let content = create_content(body, example, examples);
+                        let content = create_content(Some(body), example, examples);
                         (Cow::Borrowed(&**content_type), content)
                     })
                     .for_each(|(content_type, content)| {
@@ -687,6 +862,10 @@ impl Parse for DeriveIntoResponsesValue {
             return Err(Error::new(first_span, MISSING_STATUS_ERROR));
         }
 
+        if !input.is_empty() {
+            input.parse::<Comma>()?;
+        }
+
         while !input.is_empty() {
             let ident = input.parse::<Ident>()?;
             let attribute_name = &*ident.to_string();
@@ -724,14 +903,33 @@ impl Parse for DeriveIntoResponsesValue {
     }
 }
 
-#[derive(Default)]
+/// Status code of a response. Literal status codes and ranges are resolved at compile time
+/// while constants are evaluated as `u16` values at runtime.
 #[cfg_attr(feature = "debug", derive(Debug))]
-struct ResponseStatus(TokenStream2);
+enum ResponseStatus {
+    Literal(LitStr),
+    Const(ExprPath),
+}
+
+impl Default for ResponseStatus {
+    fn default() -> Self {
+        Self::Literal(LitStr::new("", Span::call_site()))
+    }
+}
 
 impl Parse for ResponseStatus {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         fn parse_lit_int(input: ParseStream) -> syn::Result<Cow<'_, str>> {
-            input.parse::<LitInt>()?.base10_parse().map(Cow::Owned)
+            let lit_int = input.parse::<LitInt>()?;
+            let status = lit_int.base10_parse::<u16>()?;
+            if !(100..=599).contains(&status) {
+                return Err(Error::new(
+                    lit_int.span(),
+                    "Invalid status code, expected value between 100 and 599",
+                ));
+            }
+
+            Ok(Cow::Owned(status.to_string()))
         }
 
         fn parse_lit_str_status_range(input: ParseStream) -> syn::Result<Cow<'_, str>> {
@@ -756,50 +954,128 @@ impl Parse for ResponseStatus {
                 .map(Cow::Owned)
         }
 
-        fn parse_http_status_code(input: ParseStream) -> syn::Result<TokenStream2> {
-            let http_status_path = input.parse::<ExprPath>()?;
-            let last_segment = http_status_path
+        /// Parse `http::StatusCode` constant or path to any other `u16` constant.
+        fn parse_status_code_path(input: ParseStream) -> syn::Result<ResponseStatus> {
+            let status_path = input.parse::<ExprPath>()?;
+            let last_segment = status_path
                 .path
                 .segments
                 .last()
-                .expect("Expected at least one segment in http StatusCode");
+                .expect("Expected at least one segment in status code path");
 
-            STATUS_CODES
+            if let Some((code, _, _)) = STATUS_CODES
                 .iter()
-                .find_map(|(code, name)| {
-                    if last_segment.ident == name {
-                        Some(code.to_string().to_token_stream())
-                    } else {
-                        None
-                    }
-                })
-                .ok_or_else(|| {
-                    Error::new(
-                        last_segment.span(),
-                        format!(
-                            "No associate item `{}` found for struct `http::StatusCode`",
-                            last_segment.ident
-                        ),
-                    )
-                })
+                .find(|(_, name, _)| last_segment.ident == name)
+            {
+                let status = LitStr::new(&code.to_string(), last_segment.span());
+                return Ok(ResponseStatus::Literal(status));
+            }
+
+            let is_http_status_code = status_path
+                .path
+                .segments
+                .iter()
+                .any(|segment| segment.ident == "StatusCode");
+            if is_http_status_code {
+                Err(Error::new(
+                    last_segment.span(),
+                    format!(
+                        "No associate item `{}` found for struct `http::StatusCode`",
+                        last_segment.ident
+                    ),
+                ))
+            } else {
+                Ok(ResponseStatus::Const(status_path))
+            }
         }
 
+        let span = input.span();
         let lookahead = input.lookahead1();
-        if lookahead.peek(LitInt) {
-            parse_lit_int(input).map(|status| Self(status.to_token_stream()))
+        let status = if lookahead.peek(LitInt) {
+            parse_lit_int(input)
         } else if lookahead.peek(LitStr) {
-            parse_lit_str_status_range(input).map(|status| Self(status.to_token_stream()))
+            parse_lit_str_status_range(input)
         } else if lookahead.peek(syn::Ident) {
-            parse_http_status_code(input).map(Self)
+            return parse_status_code_path(input);
         } else {
             Err(lookahead.error())
+        }?;
+
+        Ok(Self::Literal(LitStr::new(&status, span)))
+    }
+}
+
+impl ResponseStatus {
+    /// Emit error with spans of both status codes for each status code defined more than once.
+    fn validate_unique<'s, I: IntoIterator<Item = &'s ResponseStatus>>(statuses: I) {
+        let mut defined = Vec::<&ResponseStatus>::new();
+
+        for status in statuses {
+            let code = status.to_token_stream().to_string();
+            if let Some(first) = defined
+                .iter()
+                .find(|first| first.to_token_stream().to_string() == code)
+            {
+                emit_error! {
+                    status.span(), "duplicate response status code: {}", code;
+                    note = first.span() => "status code {} is first defined here", code
+                }
+            } else {
+                defined.push(status);
+            }
+        }
+    }
+
+    /// Status code known at compile time. Constant status codes are evaluated at runtime thus
+    /// they are not part of the compile time status code checks.
+    fn literal(&self) -> Option<&LitStr> {
+        match self {
+            Self::Literal(status) => Some(status),
+            Self::Const(_) => None,
+        }
+    }
+
+    /// Default description of the response resolved from the reason phrase of the status code.
+    fn reason_phrase(&self) -> TokenStream2 {
+        match self {
+            Self::Literal(status) => status::reason_phrase(&status.value()).to_token_stream(),
+            Self::Const(path) => {
+                let codes = STATUS_CODES.iter().map(|(code, _, _)| *code as u16);
+                let phrases = STATUS_CODES.iter().map(|(_, _, phrase)| phrase);
+                let ranges = status::STATUS_RANGES
+                    .iter()
+                    .map(|(class, _, _)| *class as u16);
+                let range_descriptions = status::STATUS_RANGES
+                    .iter()
+                    .map(|(_, _, description)| description);
+                quote_spanned! {path.span()=>
+                    {
+                        let status: u16 = #path;
+                        match status {
+                            #( #codes => #phrases, )*
+                            _ => match status / 100 {
+                                #( #ranges => #range_descriptions, )*
+                                _ => "",
+                            },
+                        }
+                    }
+                }
+            }
         }
     }
 }
 
 impl ToTokens for ResponseStatus {
     fn to_tokens(&self, tokens: &mut TokenStream2) {
-        self.0.to_tokens(tokens);
+        match self {
+            Self::Literal(status) => status.to_tokens(tokens),
+            Self::Const(path) => tokens.extend(quote_spanned! {path.span()=>
+                {
+                    let status: u16 = #path;
+                    status.to_string()
+                }
+            }),
+        }
     }
 }
 
@@ -860,6 +1136,72 @@ impl Parse for Content<'_> {
 
 pub struct Responses<'a>(pub &'a [Response<'a>]);
 
+impl Responses<'_> {
+    /// Validate that response status codes are unique within `responses(...)`.
+    ///
+    /// Tuple responses are validated directly. Status codes of `IntoResponses` types are only
+    /// known by the types themselves, thus assertions against `IntoResponses::STATUS_CODES` are
+    /// generated to be evaluated at compile time instead.
+    pub fn validate_status_codes(&self) -> TokenStream2 {
+        let mut tuple_statuses = Vec::new();
+        let mut into_responses = Vec::new();
+        for response in self.0 {
+            tuple_statuses.extend(
+                response
+                    .tuples()
+                    .into_iter()
+                    .map(|tuple| &tuple.status_code),
+            );
+            if let Response::IntoResponses(path) = response {
+                into_responses.push(path);
+            }
+        }
+
+        ResponseStatus::validate_unique(tuple_statuses.iter().copied());
+        let tuple_statuses = tuple_statuses
+            .into_iter()
+            .filter_map(ResponseStatus::literal)
+            .collect::<Array<_>>();
+
+        into_responses
+            .iter()
+            .enumerate()
+            .map(|(index, path)| {
+                let name = path.to_token_stream().to_string().replace(' ', "");
+                let message = format!(
+                    "`{name}` declares a response status code already defined in `responses(...)`"
+                );
+                let mut assertions = quote_spanned! {path.span()=>
+                    if utoipa::__dev::has_common_status_codes(
+                        &#tuple_statuses,
+                        <#path as utoipa::IntoResponses>::STATUS_CODES
+                    ) {
+                        panic!(#message)
+                    }
+                };
+
+                for other in &into_responses[index + 1..] {
+                    let other_name = other.to_token_stream().to_string().replace(' ', "");
+                    let message =
+                        format!("`{name}` and `{other_name}` declare same response status code");
+                    assertions.extend(quote_spanned! {other.span()=>
+                        if utoipa::__dev::has_common_status_codes(
+                            <#path as utoipa::IntoResponses>::STATUS_CODES,
+                            <#other as utoipa::IntoResponses>::STATUS_CODES
+                        ) {
+                            panic!(#message)
+                        }
+                    })
+                }
+
+                quote! {
+                    const _: () = { #assertions };
+                }
+            })
+            .collect()
+    }
+}
+
 impl ToTokens for Responses<'_> {
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
         tokens.extend(self.0.iter().fold(
@@ -872,9 +1214,18 @@ impl ToTokens for Responses<'_> {
                             .responses_from_into_responses::<#path>()
                         })
                     }
-                    Response::Tuple(response) => {
-                        let code = &response.status_code;
-                        acc.extend(quote! { .response(#code, #response) });
+                    Response::ReturnError(ty) => acc.extend(quote_spanned! {ty.span()=>
+                        .responses_from_iter({
+                            #[allow(unused_imports)]
+                            use utoipa::__dev::{IntoResponsesOfType as _, NoResponsesOfType as _};
+                            (&utoipa::__dev::ResponsesOf::<#ty>(std::marker::PhantomData)).responses()
+                        })
+                    }),
+                    Response::Tuple(_) | Response::FromError(_) => {
+                        for response in response.tuples() {
+                            let code = &response.status_code;
+                            acc.extend(quote! { .response(#code, #response) });
+                        }
                     }
                 }
 
@@ -942,10 +1293,12 @@ impl ToTokens for Responses<'_> {
 /// ```
 #[derive(Default)]
 #[cfg_attr(feature = "debug", derive(Debug))]
-struct Header {
-    name: String,
+pub(super) struct Header {
+    pub(super) name: String,
     value_type: Option<InlineType<'static>>,
     description: Option<String>,
+    required: Option<bool>,
+    deprecated: Option<bool>,
 }
 
 impl Parse for Header {
@@ -970,26 +1323,33 @@ impl Parse for Header {
             input.parse::<Token![,]>()?;
         }
 
-        if input.peek(syn::Ident) {
-            input
-                .parse::<Ident>()
-                .map_err(|error| {
-                    Error::new(
-                        error.span(),
-                        format!("unexpected attribute, expected: description, {}", error),
-                    )
-                })
-                .and_then(|ident| {
-                    if ident != "description" {
-                        return Err(Error::new(
-                            ident.span(),
-                            "unexpected attribute, expected: description",
-                        ));
-                    }
-                    Ok(ident)
-                })?;
-            input.parse::<Token![=]>()?;
-            header.description = Some(input.parse::<LitStr>()?.value());
+        const EXPECTED_ATTRIBUTE_MESSAGE: &str =
+            "unexpected attribute, expected any of: description, required, deprecated";
+
+        while !input.is_empty() {
+            let ident = input.parse::<Ident>().map_err(|error| {
+                Error::new(
+                    error.span(),
+                    format!("{}, {}", EXPECTED_ATTRIBUTE_MESSAGE, error),
+                )
+            })?;
+
+            match &*ident.to_string() {
+                "description" => {
+                    header.description = Some(parse::description(input)?);
+                }
+                "required" => {
+                    header.required = Some(parse_utils::parse_bool_or_true(input)?);
+                }
+                "deprecated" => {
+                    header.deprecated = Some(parse_utils::parse_bool_or_true(input)?);
+                }
+                _ => return Err(Error::new(ident.span(), EXPECTED_ATTRIBUTE_MESSAGE)),
+            }
+
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
         }
 
         Ok(header)
@@ -1022,6 +1382,20 @@ impl ToTokens for Header {
             })
         }
 
+        if let Some(required) = self.required {
+            let required: Required = required.into();
+            tokens.extend(quote! {
+                .required(Some(#required))
+            })
+        }
+
+        if let Some(deprecated) = self.deprecated {
+            let deprecated: Deprecated = deprecated.into();
+            tokens.extend(quote! {
+                .deprecated(Some(#deprecated))
+            })
+        }
+
         tokens.extend(quote! { .build() })
     }
 }
@@ -1,13 +1,13 @@
 use std::borrow::Cow;
-use std::{iter, mem};
+use std::mem;
 
 use proc_macro2::{Ident, TokenStream};
 use proc_macro_error::{abort, emit_error};
-use quote::{quote, ToTokens};
+use quote::{quote, quote_spanned, ToTokens};
 use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
 use syn::token::Comma;
-use syn::{Attribute, Data, Field, Fields, Generics, Path, Type, TypePath};
+use syn::{Attribute, Data, Field, Fields, Generics, Path, Type, TypePath, Variant};
 
 use crate::component::schema::NamedStructSchema;
 use crate::doc_comment::CommentAttributes;
@@ -15,7 +15,8 @@ use crate::path::{InlineType, PathType};
 use crate::Array;
 
 use super::{
-    DeriveIntoResponsesValue, DeriveResponseValue, ResponseTuple, ResponseTupleInner, ResponseValue,
+    DeriveIntoResponsesValue, DeriveResponseValue, ResponseStatus, ResponseTuple,
+    ResponseTupleInner, ResponseValue,
 };
 
 pub struct IntoResponses {
@@ -30,11 +31,7 @@ impl ToTokens for IntoResponses {
         let responses = match &self.data {
             Data::Struct(struct_value) => match &struct_value.fields {
                 Fields::Named(fields) => {
-                    let response =
-                        NamedStructResponse::new(&self.attributes, &self.ident, &fields.named).0;
-                    let status = &response.status_code;
-
-                    Array::from_iter(iter::once(quote!((#status, #response))))
+                    vec![NamedStructResponse::new(&self.attributes, &self.ident, &fields.named).0]
                 }
                 Fields::Unnamed(fields) => {
                     let field = fields
@@ -43,22 +40,14 @@ impl ToTokens for IntoResponses {
                         .next()
                         .expect("Unnamed struct must have 1 field");
 
-                    let response =
-                        UnnamedStructResponse::new(&self.attributes, &field.ty, &field.attrs).0;
-                    let status = &response.status_code;
-
-                    Array::from_iter(iter::once(quote!((#status, #response))))
-                }
-                Fields::Unit => {
-                    let response = UnitStructResponse::new(&self.attributes).0;
-                    let status = &response.status_code;
-
-                    Array::from_iter(iter::once(quote!((#status, #response))))
+                    vec![UnnamedStructResponse::new(&self.attributes, &field.ty, &field.attrs).0]
                 }
+                Fields::Unit => vec![UnitStructResponse::new(&self.attributes).0],
             },
             Data::Enum(enum_value) => enum_value
                 .variants
                 .iter()
+                .filter(|variant| !FlattenedResponses::is_flatten(&variant.attrs))
                 .map(|variant| match &variant.fields {
                     Fields::Named(fields) => {
                         NamedStructResponse::new(&variant.attrs, &variant.ident, &fields.named).0
@@ -73,27 +62,82 @@ impl ToTokens for IntoResponses {
                     }
                     Fields::Unit => UnitStructResponse::new(&variant.attrs).0,
                 })
-                .map(|response| {
-                    let status = &response.status_code;
-                    quote!((#status, utoipa::openapi::RefOr::from(#response)))
-                })
-                .collect::<Array<TokenStream>>(),
+                .collect::<Vec<ResponseTuple>>(),
             Data::Union(_) => abort!(self.ident, "`IntoReponses` does not support `Union` type"),
         };
+        let flattened = match &self.data {
+            Data::Enum(enum_value) => enum_value
+                .variants
+                .iter()
+                .filter(|variant| FlattenedResponses::is_flatten(&variant.attrs))
+                .map(FlattenedResponses::new)
+                .collect::<Vec<_>>(),
+            _ => Vec::new(),
+        };
+
+        ResponseStatus::validate_unique(responses.iter().map(|response| &response.status_code));
+        let status_codes = responses
+            .iter()
+            .filter_map(|response| response.status_code.literal())
+            .collect::<Array<_>>();
+        let responses = responses.iter().map(|response| {
+            let status = &response.status_code;
+            quote!( .response(#status, utoipa::openapi::RefOr::from(#response)) )
+        });
 
         let ident = &self.ident;
         let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
 
-        let responses = if responses.len() > 0 {
-            Some(quote!( .responses_from_iter(#responses)))
+        // generic parameters cannot be used in constant items, thus flattened responses of
+        // generic types are not validated nor included in the status codes
+        let status_codes = if self.generics.params.is_empty() && !flattened.is_empty() {
+            flattened.iter().enumerate().for_each(|(index, response)| {
+                let ty = response.0;
+                let previous = flattened[..index].iter().map(|previous| previous.0);
+                let message = format!(
+                    "`{}` declares a response status code already defined in `{ident}` or in its other flattened responses",
+                    ty.to_token_stream().to_string().replace(' ', "")
+                );
+                tokens.extend(quote_spanned! {ty.span()=>
+                    const _: () = {
+                        if utoipa::__dev::has_common_status_codes(
+                            &#status_codes,
+                            <#ty as utoipa::IntoResponses>::STATUS_CODES
+                        ) #( || utoipa::__dev::has_common_status_codes(
+                            <#previous as utoipa::IntoResponses>::STATUS_CODES,
+                            <#ty as utoipa::IntoResponses>::STATUS_CODES
+                        ) )* {
+                            panic!(#message)
+                        }
+                    };
+                })
+            });
+
+            let types = flattened.iter().map(|flattened| flattened.0);
+            let lengths = types
+                .clone()
+                .map(|ty| quote!(<#ty as utoipa::IntoResponses>::STATUS_CODES.len()));
+            let status_codes_len = status_codes.len();
+            quote! {{
+                const LEN: usize = #status_codes_len #( + #lengths )*;
+                const STATUS_CODES: [&str; LEN] = utoipa::__dev::concat_status_codes(&[
+                    &#status_codes,
+                    #( <#types as utoipa::IntoResponses>::STATUS_CODES ),*
+                ]);
+                &STATUS_CODES
+            }}
         } else {
-            None
+            quote!(&#status_codes)
         };
+
         tokens.extend(quote!{
             impl #impl_generics utoipa::IntoResponses for #ident #ty_generics #where_clause {
+                const STATUS_CODES: &'static [&'static str] = #status_codes;
+
                 fn responses() -> std::collections::BTreeMap<String, utoipa::openapi::RefOr<utoipa::openapi::response::Response>> {
                     utoipa::openapi::response::ResponsesBuilder::new()
-                        #responses
+                        #( #responses )*
+                        #( #flattened )*
                         .build()
                         .into()
                 }
@@ -197,19 +241,35 @@ impl<'u> UnnamedStructResponse<'u> {
                 })
             }
             (true, false) => Self(ResponseTuple {
-                inner: Some(ResponseTupleInner::Ref(InlineType {
-                    ty: Cow::Borrowed(ty),
-                    is_inline: false,
-                })),
-                status_code,
-            }),
-            (false, true) => Self(ResponseTuple {
-                inner: Some(ResponseTupleInner::Ref(InlineType {
-                    ty: Cow::Borrowed(ty),
-                    is_inline: true,
-                })),
+                inner: Some(ResponseTupleInner::Ref(
+                    InlineType {
+                        ty: Cow::Borrowed(ty),
+                        is_inline: false,
+                    },
+                    None,
+                )),
                 status_code,
             }),
+            (false, true) => {
+                // variant doc comment or description attribute overrides the description of the
+                // inlined response
+                let description = if derive_value.description.is_empty() {
+                    description
+                } else {
+                    derive_value.description
+                };
+
+                Self(ResponseTuple {
+                    inner: Some(ResponseTupleInner::Ref(
+                        InlineType {
+                            ty: Cow::Borrowed(ty),
+                            is_inline: true,
+                        },
+                        Some(description).filter(|description| !description.is_empty()),
+                    )),
+                    status_code,
+                })
+            }
             (true, true) => {
                 abort!(
                     ty.span(),
@@ -281,3 +341,53 @@ impl UnitStructResponse<'_> {
         })
     }
 }
+
+/// Enum variant marked with `#[response(flatten)]`. The variant's inner type must implement
+/// `IntoResponses` and its responses are merged to the responses of the parent enum.
+struct FlattenedResponses<'f>(&'f Type);
+
+impl Response for FlattenedResponses<'_> {}
+
+impl<'f> FlattenedResponses<'f> {
+    fn is_flatten(attributes: &[Attribute]) -> bool {
+        attributes.iter().any(Self::is_flatten_attribute)
+    }
+
+    fn is_flatten_attribute(attribute: &Attribute) -> bool {
+        attribute.path.is_ident("response")
+            && attribute
+                .parse_args::<Ident>()
+                .map(|ident| ident == "flatten")
+                .unwrap_or(false)
+    }
+
+    fn new(variant: &'f Variant) -> Self {
+        Self::validate_attributes(&variant.attrs, |attribute| {
+            const ERROR: &str =
+                "`flatten` cannot be used together with other `#[response(...)]` attributes";
+            let is_response = attribute.path.is_ident("response");
+
+            (!is_response || Self::is_flatten_attribute(attribute), ERROR)
+        });
+
+        match &variant.fields {
+            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+                Self(&fields.unnamed.first().unwrap().ty)
+            }
+            _ => abort!(
+                variant,
+                "`#[response(flatten)]` expects unnamed enum variant with exactly one field";
+                help = "Try wrapping the type implementing `IntoResponses`, e.g. `{}(Errors)`", variant.ident
+            ),
+        }
+    }
+}
+
+impl ToTokens for FlattenedResponses<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let ty = self.0;
+        tokens.extend(quote_spanned! {ty.span()=>
+            .responses_from_into_responses::<#ty>()
+        })
+    }
+}
@@ -110,6 +110,26 @@ impl SchemaType<'_> {
     pub fn is_byte(&self) -> bool {
         matches!(&*self.last_segment_to_string(), "u8")
     }
+
+    /// Check whether type is a byte buffer `bytes::Bytes` or `bytes::BytesMut` which is
+    /// represented as binary string. The path must be qualified with the `bytes` crate so user
+    /// defined types named `Bytes` are not treated as byte buffers.
+    pub fn is_bytes(&self) -> bool {
+        let mut segments = self
+            .0
+            .segments
+            .iter()
+            .map(|segment| segment.ident.to_string());
+
+        matches!(
+            (
+                segments.next().as_deref(),
+                segments.next().as_deref(),
+                segments.next()
+            ),
+            (Some("bytes"), Some("Bytes" | "BytesMut"), None)
+        )
+    }
 }
 
 #[inline]
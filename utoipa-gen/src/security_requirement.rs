@@ -10,46 +10,60 @@ use syn::{
 
 use crate::Array;
 
+/// Parsed security requirement holding all security scheme names with scopes required together,
+/// e.g. `("oauth" = ["read"], "api_key" = [])`. Empty requirement `()` makes security optional.
 #[derive(Default)]
 #[cfg_attr(feature = "debug", derive(Debug))]
 pub struct SecurityRequirementAttr {
-    name: Option<String>,
-    scopes: Option<Vec<String>>,
+    schemes: Vec<(String, Vec<String>)>,
 }
 
 impl Parse for SecurityRequirementAttr {
     fn parse(input: ParseStream) -> syn::Result<Self> {
-        if input.is_empty() {
-            return Ok(Self {
-                ..Default::default()
-            });
+        let mut security_requirement = SecurityRequirementAttr::default();
+
+        while !input.is_empty() {
+            let name = input.parse::<LitStr>()?.value();
+            input.parse::<Token![=]>()?;
+
+            let scopes_stream;
+            bracketed!(scopes_stream in input);
+            let scopes = Punctuated::<LitStr, Comma>::parse_terminated(&scopes_stream)?
+                .iter()
+                .map(LitStr::value)
+                .collect::<Vec<_>>();
+
+            security_requirement.schemes.push((name, scopes));
+
+            if !input.is_empty() {
+                input.parse::<Comma>()?;
+            }
         }
-        let name = input.parse::<LitStr>()?.value();
-        input.parse::<Token![=]>()?;
-
-        let scopes_stream;
-        bracketed!(scopes_stream in input);
-        let scopes = Punctuated::<LitStr, Comma>::parse_terminated(&scopes_stream)?
-            .iter()
-            .map(LitStr::value)
-            .collect::<Vec<_>>();
-
-        Ok(Self {
-            name: Some(name),
-            scopes: Some(scopes),
-        })
+
+        Ok(security_requirement)
     }
 }
 
 impl ToTokens for SecurityRequirementAttr {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        if let (Some(name), Some(scopes)) = (&self.name, &self.scopes) {
+        let mut schemes = self.schemes.iter();
+
+        if let Some((name, scopes)) = schemes.next() {
             let scopes_array = scopes.iter().collect::<Array<&String>>();
             let scopes_len = scopes.len();
 
             tokens.extend(quote! {
                 utoipa::openapi::security::SecurityRequirement::new::<&str, [&str; #scopes_len], &str>(#name, #scopes_array)
-            })
+            });
+
+            for (name, scopes) in schemes {
+                let scopes_array = scopes.iter().collect::<Array<&String>>();
+                let scopes_len = scopes.len();
+
+                tokens.extend(quote! {
+                    .add::<&str, [&str; #scopes_len], &str>(#name, #scopes_array)
+                })
+            }
         } else {
             tokens.extend(quote! {
                 utoipa::openapi::security::SecurityRequirement::default()
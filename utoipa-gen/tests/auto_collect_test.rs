@@ -0,0 +1,75 @@
+#![cfg(feature = "auto_collect")]
+
+use assert_json_diff::assert_json_eq;
+use serde_json::json;
+
+mod pets {
+    #[utoipa::path(get, path = "/pets", responses((status = 200, description = "List pets")))]
+    #[allow(unused)]
+    fn list_pets() {}
+
+    #[utoipa::path(post, path = "/pets", responses((status = 201, description = "Pet created")))]
+    #[allow(unused)]
+    fn create_pet() {}
+}
+
+#[utoipa::path(get, path = "/health", responses((status = 200, description = "Healthy")))]
+#[allow(unused)]
+fn health() {}
+
+#[test]
+fn collect_paths_of_annotated_handlers() {
+    let paths = serde_json::to_value(utoipa::collect_paths().paths).unwrap();
+
+    assert_json_eq!(
+        paths,
+        json!({
+            "/health": {
+                "get": {
+                    "tags": ["crate"],
+                    "operationId": "health",
+                    "responses": {
+                        "200": {
+                            "description": "Healthy"
+                        }
+                    },
+                    "deprecated": false
+                }
+            },
+            "/pets": {
+                "get": {
+                    "tags": ["crate"],
+                    "operationId": "list_pets",
+                    "responses": {
+                        "200": {
+                            "description": "List pets"
+                        }
+                    },
+                    "deprecated": false
+                },
+                "post": {
+                    "tags": ["crate"],
+                    "operationId": "create_pet",
+                    "responses": {
+                        "201": {
+                            "description": "Pet created"
+                        }
+                    },
+                    "deprecated": false
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn derive_openapi_with_collected_paths() {
+    #[derive(utoipa::OpenApi)]
+    #[openapi(collect_paths, context_path = "/api")]
+    struct ApiDoc;
+
+    let openapi = <ApiDoc as utoipa::OpenApi>::openapi();
+    let paths = openapi.paths.paths.keys().collect::<Vec<_>>();
+
+    assert_eq!(paths, ["/api/health", "/api/pets"]);
+}
@@ -42,3 +42,32 @@ fn modify_openapi_add_security_schema() {
         "components.securitySchemes.api_jwt_token.bearerFormat" = r###""JWT""###, "api_jwt_token bearerFormat"
     }
 }
+
+#[test]
+fn modify_openapi_with_fn_modifiers_in_order() {
+    #[derive(Default, OpenApi)]
+    #[openapi(modifiers(&ServerAddon, add_server))]
+    struct ApiDoc;
+
+    struct ServerAddon;
+
+    impl Modify for ServerAddon {
+        fn modify(&self, openapi: &mut openapi::OpenApi) {
+            openapi.servers = Some(vec![openapi::Server::new("/api")])
+        }
+    }
+
+    fn add_server(openapi: &mut openapi::OpenApi) {
+        openapi
+            .servers
+            .get_or_insert_with(Vec::new)
+            .push(openapi::Server::new("/api/v2"))
+    }
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+
+    assert_value! {doc=>
+        "servers.[0].url" = r###""/api""###, "first server url"
+        "servers.[1].url" = r###""/api/v2""###, "second server url"
+    }
+}
@@ -27,6 +27,49 @@ fn derive_openapi_with_security_requirement() {
     }
 }
 
+#[test]
+fn derive_openapi_with_global_security_and_public_operation() {
+    mod events {
+        #[utoipa::path(
+            post,
+            webhook = "petAdded",
+            responses(
+                (status = 200, description = "Event was received")
+            )
+        )]
+        #[allow(unused)]
+        pub(super) fn pet_added() {}
+
+        #[utoipa::path(
+            post,
+            webhook = "healthCheck",
+            security(),
+            responses(
+                (status = 200, description = "Service is healthy")
+            )
+        )]
+        #[allow(unused)]
+        pub(super) fn health_check() {}
+    }
+
+    #[derive(OpenApi)]
+    #[openapi(
+        openapi = "3.1",
+        webhooks(events::pet_added, events::health_check),
+        security(("api_key" = []))
+    )]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(&ApiDoc::openapi()).unwrap();
+
+    assert_eq!(doc.pointer("/security"), Some(&json!([{ "api_key": [] }])));
+    assert_eq!(doc.pointer("/webhooks/petAdded/post/security"), None);
+    assert_eq!(
+        doc.pointer("/webhooks/healthCheck/post/security"),
+        Some(&json!([]))
+    );
+}
+
 #[test]
 fn derive_openapi_tags() {
     #[derive(OpenApi)]
@@ -219,6 +262,81 @@ fn derive_openapi_with_custom_info() {
     )
 }
 
+#[test]
+fn derive_openapi_with_info_version_override_and_unset_fields() {
+    #[derive(OpenApi)]
+    #[openapi(info(
+        version = "1.0.0",
+        terms_of_service = "https://example.com/terms",
+        license = None,
+        contact = None
+    ))]
+    struct ApiDoc;
+
+    let value = serde_json::to_value(&ApiDoc::openapi()).unwrap();
+    let info = value.pointer("/info");
+
+    assert_json_eq!(
+        info,
+        json!(
+        {
+            "title": "utoipa-gen",
+            "description": "Code generation implementation for utoipa",
+            "termsOfService": "https://example.com/terms",
+            "version": "1.0.0",
+        }
+        )
+    )
+}
+
+#[test]
+fn derive_openapi_3_1_document() {
+    #[derive(utoipa::ToSchema)]
+    #[allow(unused)]
+    struct Pet {
+        #[schema(example = "Milo", nullable)]
+        name: String,
+    }
+
+    #[derive(OpenApi)]
+    #[openapi(
+        openapi = "3.1",
+        info(
+            title = "Pets",
+            summary = "Pet store",
+            license(name = "MIT or Apache-2.0", identifier = "MIT OR Apache-2.0")
+        ),
+        components(schemas(Pet))
+    )]
+    struct ApiDoc;
+
+    let value = serde_json::to_value(&ApiDoc::openapi()).unwrap();
+
+    assert_eq!(value.pointer("/openapi"), Some(&json!("3.1.0")));
+    assert_eq!(
+        value.pointer("/jsonSchemaDialect"),
+        Some(&json!("https://spec.openapis.org/oas/3.1/dialect/base"))
+    );
+    assert_eq!(value.pointer("/info/summary"), Some(&json!("Pet store")));
+    assert_eq!(
+        value.pointer("/info/license"),
+        Some(&json!({ "name": "MIT or Apache-2.0", "identifier": "MIT OR Apache-2.0" }))
+    );
+    assert_json_eq!(
+        value.pointer("/components/schemas/Pet").unwrap(),
+        json!({
+            "type": "object",
+            "required": ["name"],
+            "properties": {
+                "name": {
+                    "type": ["string", "null"],
+                    "examples": ["Milo"]
+                }
+            }
+        })
+    );
+}
+
 #[test]
 fn derive_openapi_with_include_str_description() {
     #[derive(OpenApi)]
@@ -249,3 +367,534 @@ fn derive_openapi_with_include_str_description() {
         )
     )
 }
+
+#[test]
+fn derive_openapi_with_nested_openapi() {
+    mod admin {
+        use utoipa::{OpenApi, ToSchema};
+
+        #[derive(ToSchema)]
+        #[allow(unused)]
+        pub(super) struct Admin {
+            name: String,
+        }
+
+        #[derive(OpenApi)]
+        #[openapi(
+            components(schemas(Admin)),
+            tags((name = "admin", description = "Admin operations"))
+        )]
+        pub(super) struct AdminApi;
+    }
+
+    #[derive(OpenApi)]
+    #[openapi(
+        tags((name = "api", description = "Api operations")),
+        nest(("/admin" = admin::AdminApi))
+    )]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(&ApiDoc::openapi()).unwrap();
+
+    assert_ne!(
+        doc.pointer("/components/schemas/Admin")
+            .unwrap_or(&Value::Null),
+        &Value::Null,
+        "Expected components.schemas.Admin of nested OpenApi not to be null"
+    );
+    assert_json_eq!(
+        doc.pointer("/tags").unwrap(),
+        json!([
+            {
+                "name": "api",
+                "description": "Api operations"
+            },
+            {
+                "name": "admin",
+                "description": "Admin operations"
+            }
+        ])
+    );
+}
+
+#[test]
+fn derive_openapi_with_nested_openapi_sharing_schemas() {
+    mod admin {
+        use utoipa::{OpenApi, ToSchema};
+
+        pub(super) mod user {
+            #[derive(utoipa::ToSchema)]
+            #[allow(unused)]
+            pub(crate) struct User {
+                name: String,
+            }
+        }
+
+        #[derive(ToSchema)]
+        #[allow(unused)]
+        pub(super) struct Admin {
+            name: String,
+        }
+
+        #[derive(OpenApi)]
+        #[openapi(components(schemas(user::User, Admin)))]
+        pub(super) struct AdminApi;
+    }
+
+    #[derive(OpenApi)]
+    #[openapi(
+        components(schemas(admin::user::User)),
+        nest(("/admin" = admin::AdminApi))
+    )]
+    struct ApiDoc;
+
+    assert_eq!(
+        ApiDoc::SCHEMAS,
+        [
+            ("User", "openapi_derive::admin::user::User"),
+            ("User", "openapi_derive::admin::user::User"),
+            ("Admin", "openapi_derive::admin::Admin"),
+        ]
+    );
+}
+
+#[test]
+fn derive_openapi_with_cfg_gated_items() {
+    #[derive(utoipa::ToSchema)]
+    #[allow(unused)]
+    struct Pet {
+        name: String,
+    }
+
+    #[derive(OpenApi)]
+    #[openapi(
+        openapi = "3.1",
+        components(schemas(Pet, #[cfg(not(test))] missing::AdminUser)),
+        webhooks(#[cfg(not(test))] missing::new_admin)
+    )]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(&ApiDoc::openapi()).unwrap();
+
+    assert_eq!(doc.pointer("/webhooks"), None);
+    assert_json_eq!(
+        doc.pointer("/components/schemas").unwrap(),
+        json!({
+            "Pet": {
+                "type": "object",
+                "required": ["name"],
+                "properties": {
+                    "name": {
+                        "type": "string"
+                    }
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn derive_openapi_with_webhooks() {
+    mod events {
+        /// New pet was added.
+        #[utoipa::path(
+            post,
+            webhook = "newPet",
+            request_body = String,
+            responses(
+                (status = 200, description = "Pet was received")
+            )
+        )]
+        #[allow(unused)]
+        pub(super) fn new_pet() {}
+    }
+
+    #[derive(OpenApi)]
+    #[openapi(openapi = "3.1", webhooks(events::new_pet))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(&ApiDoc::openapi()).unwrap();
+
+    assert_eq!(doc.pointer("/paths"), Some(&json!({})));
+    assert_json_eq!(
+        doc.pointer("/webhooks").unwrap(),
+        json!({
+            "newPet": {
+                "post": {
+                    "tags": ["events"],
+                    "summary": "New pet was added.",
+                    "description": "New pet was added.",
+                    "operationId": "new_pet",
+                    "requestBody": {
+                        "content": {
+                            "text/plain": {
+                                "schema": {
+                                    "type": "string"
+                                }
+                            }
+                        },
+                        "required": true
+                    },
+                    "responses": {
+                        "200": {
+                            "description": "Pet was received"
+                        }
+                    },
+                    "deprecated": false
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn derive_openapi_with_api_tags() {
+    mod pets {
+        /// Operations of the pet store.
+        ///
+        /// Pets can be added and removed.
+        #[derive(utoipa::ApiTag)]
+        #[tag(name = "pets", external_docs(url = "http://localhost/pets"))]
+        pub(super) struct PetsTag;
+
+        #[utoipa::path(
+            post,
+            webhook = "newPet",
+            tag = <PetsTag as utoipa::ApiTag>::NAME,
+            responses(
+                (status = 200, description = "Pet was received")
+            )
+        )]
+        #[allow(unused)]
+        pub(super) fn new_pet() {}
+    }
+
+    #[derive(OpenApi)]
+    #[openapi(
+        openapi = "3.1",
+        webhooks(pets::new_pet),
+        tags((name = "users"), pets::PetsTag)
+    )]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(&ApiDoc::openapi()).unwrap();
+
+    assert_json_eq!(
+        doc.pointer("/tags").unwrap(),
+        json!([
+            {
+                "name": "users"
+            },
+            {
+                "name": "pets",
+                "description": "Operations of the pet store.\n\nPets can be added and removed.",
+                "externalDocs": {
+                    "url": "http://localhost/pets"
+                }
+            }
+        ])
+    );
+    assert_eq!(
+        doc.pointer("/webhooks/newPet/post/tags"),
+        Some(&json!(["pets"]))
+    );
+}
+
+#[test]
+fn derive_openapi_with_server_variable_default_from_enum_values() {
+    #[derive(OpenApi)]
+    #[openapi(servers((
+        url = "https://{region}.api.example.com/{version}",
+        variables(
+            ("region" = (default = "us", enum_values("eu", "us"))),
+            ("version" = (enum_values("v2", "v1"), description = "Api version"))
+        )
+    )))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(&ApiDoc::openapi()).unwrap();
+
+    assert_json_eq!(
+        doc.pointer("/servers/0/variables").unwrap(),
+        json!({
+            "region": {
+                "default": "us",
+                "enum": ["eu", "us"]
+            },
+            "version": {
+                "default": "v2",
+                "enum": ["v2", "v1"],
+                "description": "Api version"
+            }
+        })
+    );
+}
+
+#[test]
+fn derive_openapi_tags_in_declared_order() {
+    #[derive(OpenApi)]
+    #[openapi(tags(
+        (name = "users", description = "User operations"),
+        (name = "admin", description = "Admin operations"),
+        (name = "pets", external_docs(url = "http://localhost/pets", description = "Pets"))
+    ))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(&ApiDoc::openapi()).unwrap();
+
+    assert_json_eq!(
+        doc.pointer("/tags").unwrap(),
+        json!([
+            {
+                "name": "users",
+                "description": "User operations"
+            },
+            {
+                "name": "admin",
+                "description": "Admin operations"
+            },
+            {
+                "name": "pets",
+                "externalDocs": {
+                    "url": "http://localhost/pets",
+                    "description": "Pets"
+                }
+            }
+        ])
+    );
+}
+
+#[test]
+fn derive_openapi_with_reusable_components() {
+    use utoipa::openapi::example::ExampleBuilder;
+    use utoipa::openapi::header::HeaderBuilder;
+    use utoipa::openapi::request_body::RequestBodyBuilder;
+    use utoipa::openapi::Ref;
+
+    #[derive(serde::Deserialize, utoipa::IntoParams)]
+    #[into_params(parameter_in = Query)]
+    #[allow(unused)]
+    struct Pagination {
+        /// Page to fetch.
+        page: i32,
+    }
+
+    fn pet_example() -> ExampleBuilder {
+        ExampleBuilder::new().value(Some(json!({"name": "Milo"})))
+    }
+
+    #[derive(OpenApi)]
+    #[openapi(components(
+        parameters(Pagination),
+        examples(("Pet" = pet_example()), ("OtherPet" = Ref::from_example_name("Pet"))),
+        request_bodies(("Empty" = RequestBodyBuilder::new().description(Some("Empty body")).build())),
+        headers(("X-Rate-Limit" = HeaderBuilder::new().description(Some("Requests left")).build()))
+    ))]
+    struct ApiDoc;
+
+    let value = serde_json::to_value(&ApiDoc::openapi()).unwrap();
+    let components = value.pointer("/components");
+
+    assert_json_eq!(
+        components,
+        json!({
+            "parameters": {
+                "page": {
+                    "name": "page",
+                    "in": "query",
+                    "description": "Page to fetch.",
+                    "required": true,
+                    "schema": {
+                        "type": "integer",
+                        "format": "int32"
+                    }
+                }
+            },
+            "examples": {
+                "OtherPet": {
+                    "$ref": "#/components/examples/Pet"
+                },
+                "Pet": {
+                    "value": {
+                        "name": "Milo"
+                    }
+                }
+            },
+            "requestBodies": {
+                "Empty": {
+                    "description": "Empty body",
+                    "content": {}
+                }
+            },
+            "headers": {
+                "X-Rate-Limit": {
+                    "description": "Requests left",
+                    "schema": {
+                        "type": "string"
+                    }
+                }
+            }
+        })
+    )
+}
+
+#[test]
+fn derive_openapi_with_reusable_path_items() {
+    use utoipa::openapi::path::{OperationBuilder, PathItem, PathItemType};
+    use utoipa::openapi::{OpenApi as OpenApiDoc, PathsBuilder, Ref};
+
+    fn crud() -> PathItem {
+        PathItem::new(
+            PathItemType::Get,
+            OperationBuilder::new().operation_id(Some("list")),
+        )
+    }
+
+    fn crud_paths(openapi: &mut OpenApiDoc) {
+        openapi.paths = PathsBuilder::new()
+            .path("/pets", Ref::from_path_item_name("Crud"))
+            .path("/owners", Ref::from_path_item_name("Crud"))
+            .build();
+    }
+
+    #[derive(OpenApi)]
+    #[openapi(
+        openapi = "3.1",
+        components(path_items(("Crud" = crud()))),
+        modifiers(crud_paths)
+    )]
+    struct ApiDoc;
+
+    let value = serde_json::to_value(&ApiDoc::openapi()).unwrap();
+
+    assert_json_eq!(
+        value.pointer("/paths"),
+        json!({
+            "/owners": {
+                "$ref": "#/components/pathItems/Crud"
+            },
+            "/pets": {
+                "$ref": "#/components/pathItems/Crud"
+            }
+        })
+    );
+    assert_json_eq!(
+        value.pointer("/components/pathItems"),
+        json!({
+            "Crud": {
+                "get": {
+                    "operationId": "list",
+                    "responses": {}
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn derive_openapi_with_security_schemes() {
+    #[derive(OpenApi)]
+    #[openapi(components(security_schemes(
+        ("api_key" = api_key(header = "x-api-key", description = "Api key of the client")),
+        ("session" = api_key(cookie = "session_id")),
+        ("jwt" = http(scheme = "bearer", bearer_format = "JWT")),
+        ("oauth" = oauth2(
+            authorization_code(
+                auth_url = "https://localhost/authorize",
+                token_url = "https://localhost/token",
+                scopes(("read:items" = "Read items"), ("edit:items" = "Edit items"))
+            ),
+            client_credentials(
+                token_url = "https://localhost/token",
+                refresh_url = "https://localhost/refresh"
+            )
+        )),
+        ("oidc" = open_id_connect(url = "https://localhost/.well-known/openid-configuration")),
+        ("tls" = mutual_tls(description = "Client certificate"))
+    )))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(&ApiDoc::openapi()).unwrap();
+
+    assert_json_eq!(
+        doc.pointer("/components/securitySchemes").unwrap(),
+        json!({
+            "api_key": {
+                "type": "apiKey",
+                "in": "header",
+                "name": "x-api-key",
+                "description": "Api key of the client"
+            },
+            "session": {
+                "type": "apiKey",
+                "in": "cookie",
+                "name": "session_id"
+            },
+            "jwt": {
+                "type": "http",
+                "scheme": "bearer",
+                "bearerFormat": "JWT"
+            },
+            "oauth": {
+                "type": "oauth2",
+                "flows": {
+                    "authorizationCode": {
+                        "authorizationUrl": "https://localhost/authorize",
+                        "tokenUrl": "https://localhost/token",
+                        "scopes": {
+                            "read:items": "Read items",
+                            "edit:items": "Edit items"
+                        }
+                    },
+                    "clientCredentials": {
+                        "tokenUrl": "https://localhost/token",
+                        "refreshUrl": "https://localhost/refresh",
+                        "scopes": {}
+                    }
+                }
+            },
+            "oidc": {
+                "type": "openIdConnect",
+                "openIdConnectUrl": "https://localhost/.well-known/openid-configuration"
+            },
+            "tls": {
+                "type": "mutualTLS",
+                "description": "Client certificate"
+            }
+        })
+    );
+}
+
+#[test]
+fn derive_openapi_with_extensions() {
+    fn backend() -> serde_json::Value {
+        json!({ "address": "https://backend.example.com" })
+    }
+
+    #[derive(OpenApi)]
+    #[openapi(
+        info(title = "Pet api", extensions(("x-logo" = json!({ "url": "https://example.com/logo.png" })))),
+        extensions(
+            ("x-google-backend" = backend),
+            ("x-amazon-apigateway-request-validator" = "all"),
+            ("x-internal" = false)
+        )
+    )]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(&ApiDoc::openapi()).unwrap();
+
+    assert_json_eq!(
+        doc.pointer("/info/x-logo").unwrap(),
+        json!({ "url": "https://example.com/logo.png" })
+    );
+    assert_json_eq!(
+        doc.pointer("/x-google-backend").unwrap(),
+        json!({ "address": "https://backend.example.com" })
+    );
+    assert_eq!(
+        doc.pointer("/x-amazon-apigateway-request-validator"),
+        Some(&json!("all"))
+    );
+    assert_eq!(doc.pointer("/x-internal"), Some(&json!(false)));
+}
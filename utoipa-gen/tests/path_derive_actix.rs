@@ -1,6 +1,7 @@
 #![cfg(feature = "actix_extras")]
 
 use actix_web::web::{Path, Query};
+use assert_json_diff::assert_json_eq;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use utoipa::{
@@ -808,3 +809,68 @@ test_derive_path_operations! {
     derive_path_operation_trace, mod_test_trace: trace
     derive_path_operation_patch, mod_test_patch: patch
 }
+
+#[test]
+fn derive_path_with_request_body_from_json_and_form_arguments() {
+    use actix_web::{post, put, web, HttpResponse, Responder};
+
+    #[derive(Deserialize, ToSchema)]
+    #[allow(unused)]
+    struct Pet {
+        name: String,
+    }
+
+    #[utoipa::path(
+        responses(
+            (status = 201, description = "success"),
+        )
+    )]
+    #[post("/pet")]
+    #[allow(unused)]
+    async fn create_pet(pet: web::Json<Pet>) -> impl Responder {
+        HttpResponse::Created()
+    }
+
+    #[utoipa::path(
+        responses(
+            (status = 200, description = "success"),
+        )
+    )]
+    #[put("/pet/{id}")]
+    #[allow(unused)]
+    async fn update_pet(id: Path<i32>, pet: web::Form<Pet>) -> impl Responder {
+        HttpResponse::Ok()
+    }
+
+    let create_pet_item =
+        serde_json::to_value(<__path_create_pet as utoipa::Path>::path_item(None)).unwrap();
+    let update_pet_item =
+        serde_json::to_value(<__path_update_pet as utoipa::Path>::path_item(None)).unwrap();
+
+    assert_json_eq!(
+        create_pet_item.pointer("/post/requestBody").unwrap(),
+        serde_json::json!({
+            "content": {
+                "application/json": {
+                    "schema": {
+                        "$ref": "#/components/schemas/Pet"
+                    }
+                }
+            },
+            "required": true
+        })
+    );
+    assert_json_eq!(
+        update_pet_item.pointer("/put/requestBody").unwrap(),
+        serde_json::json!({
+            "content": {
+                "application/x-www-form-urlencoded": {
+                    "schema": {
+                        "$ref": "#/components/schemas/Pet"
+                    }
+                }
+            },
+            "required": true
+        })
+    );
+}
@@ -4,7 +4,7 @@ use std::sync::{Arc, Mutex};
 
 use assert_json_diff::assert_json_eq;
 use axum::{
-    extract::{Path, Query},
+    extract::{Form, Path, Query},
     Extension,
 };
 use serde::Deserialize;
@@ -226,3 +226,214 @@ fn derive_path_params_into_params_unnamed() {
         ])
     )
 }
+
+#[test]
+fn derive_path_params_and_query_without_params_attribute() {
+    #[derive(Deserialize, IntoParams)]
+    #[allow(unused)]
+    struct Filter {
+        /// Age filter for user
+        age: Option<i32>,
+    }
+
+    #[utoipa::path(
+        get,
+        path = "/person/{id}/{name}",
+        responses(
+            (status = 200, description = "success response")
+        )
+    )]
+    #[allow(unused)]
+    async fn get_person(Path((id, name)): Path<(u32, String)>, query: Query<Filter>) {}
+
+    let path_item = <__path_get_person as utoipa::Path>::path_item(None);
+    let doc = serde_json::to_value(path_item).unwrap();
+    let parameters = doc.pointer("/get/parameters").unwrap();
+
+    assert_json_eq!(
+        parameters,
+        &json!([
+            {
+                "deprecated": false,
+                "in": "path",
+                "name": "id",
+                "required": true,
+                "schema": {
+                    "format": "int32",
+                    "type": "integer",
+                },
+            },
+            {
+                "deprecated": false,
+                "in": "path",
+                "name": "name",
+                "required": true,
+                "schema": {
+                    "type": "string",
+                },
+            },
+            {
+                "description": "Age filter for user",
+                "in": "query",
+                "name": "age",
+                "required": false,
+                "schema": {
+                    "format": "int32",
+                    "type": "integer",
+                },
+            },
+        ])
+    )
+}
+
+#[test]
+fn derive_path_query_map_without_params_attribute() {
+    #[utoipa::path(
+        get,
+        path = "/person",
+        responses(
+            (status = 200, description = "success response")
+        )
+    )]
+    #[allow(unused)]
+    async fn get_persons(filters: Query<std::collections::HashMap<String, String>>) {}
+
+    let path_item = <__path_get_persons as utoipa::Path>::path_item(None);
+    let doc = serde_json::to_value(path_item).unwrap();
+    let parameters = doc.pointer("/get/parameters").unwrap();
+
+    assert_json_eq!(
+        parameters,
+        &json!([
+            {
+                "deprecated": false,
+                "in": "query",
+                "name": "filters",
+                "required": true,
+                "schema": {
+                    "type": "object",
+                    "additionalProperties": {
+                        "type": "string"
+                    }
+                },
+            },
+        ])
+    )
+}
+
+#[test]
+fn derive_path_request_body_from_form_extractor() {
+    #[derive(Deserialize, utoipa::ToSchema)]
+    #[allow(unused)]
+    struct Person {
+        name: String,
+    }
+
+    #[utoipa::path(
+        post,
+        path = "/person",
+        responses(
+            (status = 201, description = "created")
+        )
+    )]
+    #[allow(unused)]
+    async fn create_person(Form(person): Form<Person>) {}
+
+    let path_item = <__path_create_person as utoipa::Path>::path_item(None);
+    let doc = serde_json::to_value(path_item).unwrap();
+    let request_body = doc.pointer("/post/requestBody").unwrap();
+
+    assert_json_eq!(
+        request_body,
+        &json!({
+            "content": {
+                "application/x-www-form-urlencoded": {
+                    "schema": {
+                        "$ref": "#/components/schemas/Person"
+                    }
+                }
+            },
+            "required": true
+        })
+    )
+}
+
+#[test]
+fn derive_path_optional_request_body_from_json_extractor() {
+    #[derive(Deserialize, utoipa::ToSchema)]
+    #[allow(unused)]
+    struct Person {
+        name: String,
+    }
+
+    #[utoipa::path(
+        put,
+        path = "/person",
+        responses(
+            (status = 200, description = "success")
+        )
+    )]
+    #[allow(unused)]
+    async fn update_person(person: Option<axum::Json<Person>>) {}
+
+    let path_item = <__path_update_person as utoipa::Path>::path_item(None);
+    let doc = serde_json::to_value(path_item).unwrap();
+    let request_body = doc.pointer("/put/requestBody").unwrap();
+
+    assert_json_eq!(
+        request_body,
+        &json!({
+            "content": {
+                "application/json": {
+                    "schema": {
+                        "$ref": "#/components/schemas/Person"
+                    }
+                }
+            },
+            "required": false
+        })
+    )
+}
+
+#[test]
+fn derive_path_params_without_into_params_when_params_defined() {
+    #[derive(Deserialize, IntoParams)]
+    #[allow(unused)]
+    struct Filter {
+        age: Option<i32>,
+    }
+
+    #[utoipa::path(
+        get,
+        path = "/person/{id}",
+        params(
+            ("id", description = "Person id")
+        ),
+        responses(
+            (status = 200, description = "success response")
+        )
+    )]
+    #[allow(unused)]
+    async fn get_person(Path(id): Path<u32>, query: Query<Filter>) {}
+
+    let path_item = <__path_get_person as utoipa::Path>::path_item(None);
+    let doc = serde_json::to_value(path_item).unwrap();
+    let parameters = doc.pointer("/get/parameters").unwrap();
+
+    assert_json_eq!(
+        parameters,
+        &json!([
+            {
+                "deprecated": false,
+                "description": "Person id",
+                "in": "path",
+                "name": "id",
+                "required": true,
+                "schema": {
+                    "format": "int32",
+                    "type": "integer",
+                },
+            },
+        ])
+    )
+}
@@ -0,0 +1,129 @@
+#![cfg(feature = "poem_extras")]
+
+use assert_json_diff::assert_json_eq;
+use serde::Deserialize;
+use serde_json::json;
+use utoipa::IntoParams;
+
+use self::web::{Json, Path, Query};
+
+/// Extractors with the same shape as the ones in _`poem::web`_. Arguments are resolved by the
+/// names of the extractors.
+#[allow(unused)]
+mod web {
+    pub struct Path<T>(pub T);
+
+    pub struct Query<T>(pub T);
+
+    pub struct Json<T>(pub T);
+}
+
+#[test]
+fn derive_path_params_and_query_from_poem_extractors() {
+    #[derive(Deserialize, IntoParams)]
+    #[allow(unused)]
+    struct Filter {
+        /// Age filter for user
+        age: Option<i32>,
+    }
+
+    #[utoipa::path(
+        get,
+        path = "/person/{id}/{name}",
+        responses(
+            (status = 200, description = "success response")
+        )
+    )]
+    #[allow(unused)]
+    async fn get_person(Path((id, name)): Path<(u32, String)>, query: Query<Filter>) {}
+
+    let path_item = <__path_get_person as utoipa::Path>::path_item(None);
+    let doc = serde_json::to_value(path_item).unwrap();
+    let parameters = doc.pointer("/get/parameters").unwrap();
+
+    assert_json_eq!(
+        parameters,
+        &json!([
+            {
+                "deprecated": false,
+                "in": "path",
+                "name": "id",
+                "required": true,
+                "schema": {
+                    "format": "int32",
+                    "type": "integer",
+                },
+            },
+            {
+                "deprecated": false,
+                "in": "path",
+                "name": "name",
+                "required": true,
+                "schema": {
+                    "type": "string",
+                },
+            },
+            {
+                "description": "Age filter for user",
+                "in": "query",
+                "name": "age",
+                "required": false,
+                "schema": {
+                    "format": "int32",
+                    "type": "integer",
+                },
+            },
+        ])
+    )
+}
+
+#[test]
+fn derive_path_request_body_from_poem_json_extractor() {
+    #[derive(Deserialize, utoipa::ToSchema)]
+    #[allow(unused)]
+    struct Person {
+        name: String,
+    }
+
+    #[utoipa::path(
+        put,
+        path = "/person/{id}",
+        responses(
+            (status = 200, description = "success")
+        )
+    )]
+    #[allow(unused)]
+    async fn update_person(Path(id): Path<i64>, Json(person): Json<Person>) {}
+
+    let path_item = <__path_update_person as utoipa::Path>::path_item(None);
+    let doc = serde_json::to_value(path_item).unwrap();
+
+    assert_json_eq!(
+        doc.pointer("/put/parameters").unwrap(),
+        &json!([
+            {
+                "deprecated": false,
+                "in": "path",
+                "name": "id",
+                "required": true,
+                "schema": {
+                    "format": "int64",
+                    "type": "integer",
+                },
+            },
+        ])
+    );
+    assert_json_eq!(
+        doc.pointer("/put/requestBody").unwrap(),
+        &json!({
+            "content": {
+                "application/json": {
+                    "schema": {
+                        "$ref": "#/components/schemas/Person"
+                    }
+                }
+            },
+            "required": true
+        })
+    )
+}
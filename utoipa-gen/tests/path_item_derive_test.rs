@@ -0,0 +1,2266 @@
+//! Tests for `#[utoipa::path]` using the generated `utoipa::Path` implementation directly
+//! instead of `OpenApi` derive which resolves handlers from the `src/routes` directory.
+use assert_json_diff::assert_json_eq;
+use serde_json::{json, Value};
+
+macro_rules! path_item {
+    ( $handler:ident ) => {{
+        paste::paste! {
+            let path_item = <[<__path_ $handler>] as utoipa::Path>::path_item(None);
+        }
+        serde_json::to_value(path_item).unwrap()
+    }};
+}
+
+/// Get operation of given path item
+fn get_operation(path_item: Value) -> Value {
+    path_item.pointer("/get").cloned().unwrap_or(Value::Null)
+}
+
+#[test]
+fn path_with_tuple_and_into_responses_with_unique_status_codes() {
+    #[derive(utoipa::IntoResponses)]
+    #[allow(unused)]
+    enum ErrorResponses {
+        /// Not found
+        #[response(status = 404)]
+        NotFound,
+
+        /// Server error
+        #[response(status = 500)]
+        ServerError,
+    }
+
+    #[utoipa::path(
+        get,
+        path = "/foo",
+        responses(
+            (status = 200, description = "success"),
+            ErrorResponses
+        )
+    )]
+    #[allow(unused)]
+    fn get_foo() {}
+
+    let operation = get_operation(path_item!(get_foo));
+
+    assert_json_eq!(
+        operation.pointer("/responses").unwrap(),
+        json!({
+            "200": {
+                "description": "success"
+            },
+            "404": {
+                "description": "Not found"
+            },
+            "500": {
+                "description": "Server error"
+            }
+        })
+    );
+}
+
+#[test]
+fn path_response_headers_with_required_and_deprecated() {
+    #[utoipa::path(
+        get,
+        path = "/foo",
+        responses(
+            (status = 200, description = "success", headers(
+                ("x-csrf-token" = String, required, description = "New csrf token"),
+                ("x-legacy-token", deprecated),
+                ("x-optional" = i32, required = false, deprecated = false)
+            ))
+        )
+    )]
+    #[allow(unused)]
+    fn get_foo() {}
+
+    let operation = get_operation(path_item!(get_foo));
+
+    assert_json_eq!(
+        operation.pointer("/responses/200/headers").unwrap(),
+        json!({
+            "x-csrf-token": {
+                "schema": {
+                    "type": "string"
+                },
+                "description": "New csrf token",
+                "required": true
+            },
+            "x-legacy-token": {
+                "schema": {
+                    "type": "string"
+                },
+                "deprecated": true
+            },
+            "x-optional": {
+                "schema": {
+                    "type": "integer",
+                    "format": "int32"
+                },
+                "required": false,
+                "deprecated": false
+            }
+        })
+    );
+}
+
+#[test]
+fn path_with_from_error_responses() {
+    #[utoipa::path(
+        get,
+        path = "/foo",
+        responses(
+            (status = 200, description = "success"),
+            from_error(Box<dyn std::error::Error> => [
+                (status = 404, description = "Not found"),
+                (status = 500, description = "Server error")
+            ])
+        )
+    )]
+    #[allow(unused)]
+    fn get_foo() {}
+
+    let operation = get_operation(path_item!(get_foo));
+
+    assert_json_eq!(
+        operation.pointer("/responses").unwrap(),
+        json!({
+            "200": {
+                "description": "success"
+            },
+            "404": {
+                "description": "Not found"
+            },
+            "500": {
+                "description": "Server error"
+            }
+        })
+    );
+}
+
+#[test]
+fn path_with_responses_from_return_type() {
+    #[derive(serde::Serialize, utoipa::ToSchema)]
+    #[allow(unused)]
+    struct User {
+        name: String,
+    }
+
+    #[derive(utoipa::IntoResponses)]
+    #[allow(unused)]
+    enum ApiError {
+        /// User not found
+        #[response(status = 404)]
+        NotFound,
+    }
+
+    #[allow(unused)]
+    struct Json<T>(T);
+
+    #[utoipa::path(get, path = "/user")]
+    #[allow(unused)]
+    fn get_user() -> Result<Json<User>, ApiError> {
+        Ok(Json(User {
+            name: String::new(),
+        }))
+    }
+
+    #[utoipa::path(get, path = "/user/name")]
+    #[allow(unused)]
+    fn get_user_name() -> Result<Json<String>, std::io::Error> {
+        Ok(Json(String::new()))
+    }
+
+    assert_json_eq!(
+        get_operation(path_item!(get_user))
+            .pointer("/responses")
+            .unwrap(),
+        json!({
+            "200": {
+                "description": "OK",
+                "content": {
+                    "application/json": {
+                        "schema": {
+                            "$ref": "#/components/schemas/User"
+                        }
+                    }
+                }
+            },
+            "404": {
+                "description": "User not found"
+            }
+        })
+    );
+    assert_json_eq!(
+        get_operation(path_item!(get_user_name))
+            .pointer("/responses")
+            .unwrap(),
+        json!({
+            "200": {
+                "description": "OK",
+                "content": {
+                    "application/json": {
+                        "schema": {
+                            "type": "string"
+                        }
+                    }
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn path_with_responses_from_wrapper_return_types() {
+    #[allow(unused)]
+    struct Html<T>(T);
+    #[allow(unused)]
+    struct Redirect;
+    #[allow(unused)]
+    struct StatusCode;
+
+    #[utoipa::path(get, path = "/")]
+    #[allow(unused)]
+    fn index() -> Html<&'static str> {
+        Html("<h1>Hello</h1>")
+    }
+
+    #[utoipa::path(get, path = "/old")]
+    #[allow(unused)]
+    fn old() -> Redirect {
+        Redirect
+    }
+
+    #[utoipa::path(delete, path = "/item")]
+    #[allow(unused)]
+    fn delete_item() -> StatusCode {
+        StatusCode
+    }
+
+    assert_json_eq!(
+        get_operation(path_item!(index))
+            .pointer("/responses")
+            .unwrap(),
+        json!({
+            "200": {
+                "description": "OK",
+                "content": {
+                    "text/html": {
+                        "schema": {
+                            "type": "string"
+                        }
+                    }
+                }
+            }
+        })
+    );
+    assert_json_eq!(
+        get_operation(path_item!(old))
+            .pointer("/responses")
+            .unwrap(),
+        json!({
+            "3XX": {
+                "description": "Redirection",
+                "headers": {
+                    "Location": {
+                        "description": "Target of the redirect",
+                        "schema": {
+                            "type": "string"
+                        }
+                    }
+                }
+            }
+        })
+    );
+    assert_json_eq!(
+        path_item!(delete_item)
+            .pointer("/delete/responses")
+            .unwrap(),
+        json!({
+            "default": {
+                "description": "Default response"
+            }
+        })
+    );
+}
+
+#[test]
+fn path_with_cookie_parameters() {
+    #[derive(serde::Deserialize, utoipa::IntoParams)]
+    #[into_params(parameter_in = Query)]
+    #[allow(unused)]
+    struct Filter {
+        /// Search by name
+        name: Option<String>,
+        /// Session of the user
+        #[param(parameter_in = Cookie)]
+        session: String,
+    }
+
+    #[utoipa::path(
+        get,
+        path = "/foo",
+        params(
+            ("csrf" = String, Cookie, description = "Csrf token"),
+            Filter
+        )
+    )]
+    #[allow(unused)]
+    fn get_foo() {}
+
+    let operation = get_operation(path_item!(get_foo));
+
+    assert_json_eq!(
+        operation.pointer("/parameters").unwrap(),
+        json!([
+            {
+                "name": "csrf",
+                "in": "cookie",
+                "description": "Csrf token",
+                "required": true,
+                "deprecated": false,
+                "schema": {
+                    "type": "string"
+                }
+            },
+            {
+                "name": "name",
+                "in": "query",
+                "description": "Search by name",
+                "required": false,
+                "schema": {
+                    "type": "string"
+                }
+            },
+            {
+                "name": "session",
+                "in": "cookie",
+                "description": "Session of the user",
+                "required": true,
+                "schema": {
+                    "type": "string"
+                }
+            }
+        ])
+    );
+}
+
+#[test]
+fn path_with_multipart_request_body_encoding() {
+    #[derive(utoipa::ToSchema)]
+    #[allow(unused)]
+    struct Upload {
+        name: String,
+        file: Vec<u8>,
+        #[schema(rename = "thumbnail")]
+        thumbnail_image: Option<Vec<u8>>,
+        #[schema(value_type = String)]
+        checksum: Vec<u8>,
+    }
+
+    #[utoipa::path(
+        post,
+        path = "/upload",
+        request_body(
+            content = Upload,
+            content_type = "multipart/form-data",
+            encoding(
+                ("file", content_type = "image/png", headers(("x-checksum" = String))),
+                ("name", content_type = "text/plain")
+            )
+        ),
+        responses(
+            (status = 200, description = "success")
+        )
+    )]
+    #[allow(unused)]
+    fn post_upload() {}
+
+    let path_item = path_item!(post_upload);
+
+    assert_json_eq!(
+        path_item
+            .pointer("/post/requestBody/content/multipart~1form-data/encoding")
+            .unwrap(),
+        json!({
+            "file": {
+                "contentType": "image/png",
+                "headers": {
+                    "x-checksum": {
+                        "schema": {
+                            "type": "string"
+                        }
+                    }
+                }
+            },
+            "name": {
+                "contentType": "text/plain"
+            }
+        })
+    );
+    assert_json_eq!(
+        path_item
+            .pointer("/post/requestBody/content/multipart~1form-data/schema")
+            .unwrap(),
+        json!({
+            "type": "object",
+            "properties": {
+                "name": {
+                    "type": "string"
+                },
+                "file": {
+                    "type": "string",
+                    "format": "binary"
+                },
+                "thumbnail": {
+                    "type": "string",
+                    "format": "binary"
+                },
+                "checksum": {
+                    "type": "string"
+                }
+            },
+            "required": ["name", "file", "checksum"]
+        })
+    );
+}
+
+#[test]
+fn path_with_upload_request_body() {
+    #[utoipa::path(
+        post,
+        path = "/upload",
+        request_body = upload,
+        responses(
+            (status = 200, description = "success")
+        )
+    )]
+    #[allow(unused)]
+    fn post_upload() {}
+
+    #[utoipa::path(
+        post,
+        path = "/avatar",
+        request_body(upload = "avatar", description = "Avatar image"),
+        responses(
+            (status = 200, description = "success")
+        )
+    )]
+    #[allow(unused)]
+    fn post_avatar() {}
+
+    let upload = path_item!(post_upload);
+    let avatar = path_item!(post_avatar);
+
+    assert_json_eq!(
+        upload.pointer("/post/requestBody").unwrap(),
+        json!({
+            "content": {
+                "multipart/form-data": {
+                    "schema": {
+                        "type": "object",
+                        "properties": {
+                            "file": {
+                                "type": "string",
+                                "format": "binary"
+                            }
+                        },
+                        "required": ["file"]
+                    }
+                }
+            },
+            "required": true
+        })
+    );
+    assert_json_eq!(
+        avatar.pointer("/post/requestBody").unwrap(),
+        json!({
+            "description": "Avatar image",
+            "content": {
+                "multipart/form-data": {
+                    "schema": {
+                        "type": "object",
+                        "properties": {
+                            "avatar": {
+                                "type": "string",
+                                "format": "binary"
+                            }
+                        },
+                        "required": ["avatar"]
+                    }
+                }
+            },
+            "required": true
+        })
+    );
+}
+
+#[test]
+fn path_with_operation_servers() {
+    #[utoipa::path(
+        get,
+        path = "/files/{id}",
+        servers(
+            (url = "https://files.example.com", description = "CDN"),
+            (url = "https://{region}.example.com", variables(
+                ("region" = (default = "eu", enum_values("eu", "us")))
+            ))
+        ),
+        responses(
+            (status = 200, description = "success")
+        )
+    )]
+    #[allow(unused)]
+    fn get_file() {}
+
+    let operation = get_operation(path_item!(get_file));
+
+    assert_json_eq!(
+        operation.pointer("/servers").unwrap(),
+        json!([
+            {
+                "url": "https://files.example.com",
+                "description": "CDN"
+            },
+            {
+                "url": "https://{region}.example.com",
+                "variables": {
+                    "region": {
+                        "default": "eu",
+                        "enum": ["eu", "us"]
+                    }
+                }
+            }
+        ])
+    );
+}
+
+#[test]
+fn path_with_callbacks() {
+    /// Notify about new data
+    #[utoipa::path(
+        post,
+        path = "{$request.body#/callbackUrl}",
+        request_body = String,
+        responses(
+            (status = 200, description = "callback received")
+        )
+    )]
+    #[allow(unused)]
+    fn on_data() {}
+
+    #[utoipa::path(
+        post,
+        path = "/subscribe",
+        callbacks(
+            ("onData" = on_data)
+        ),
+        responses(
+            (status = 201, description = "subscribed")
+        )
+    )]
+    #[allow(unused)]
+    fn subscribe() {}
+
+    let path_item = path_item!(subscribe);
+
+    assert_json_eq!(
+        path_item.pointer("/post/callbacks").unwrap(),
+        json!({
+            "onData": {
+                "{$request.body#/callbackUrl}": {
+                    "post": {
+                        "tags": ["crate"],
+                        "summary": "Notify about new data",
+                        "description": "Notify about new data",
+                        "operationId": "on_data",
+                        "requestBody": {
+                            "content": {
+                                "text/plain": {
+                                    "schema": {
+                                        "type": "string"
+                                    }
+                                }
+                            },
+                            "required": true
+                        },
+                        "responses": {
+                            "200": {
+                                "description": "callback received"
+                            }
+                        },
+                        "deprecated": false
+                    }
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn path_with_external_docs() {
+    #[utoipa::path(
+        get,
+        path = "/foo",
+        external_docs(url = "https://docs.example.com/foo", description = "Foo in depth"),
+        responses(
+            (status = 200, description = "success")
+        )
+    )]
+    #[allow(unused)]
+    fn get_foo() {}
+
+    let operation = get_operation(path_item!(get_foo));
+
+    assert_json_eq!(
+        operation.pointer("/externalDocs").unwrap(),
+        json!({
+            "url": "https://docs.example.com/foo",
+            "description": "Foo in depth"
+        })
+    );
+}
+
+#[test]
+fn path_with_deprecated_note() {
+    /// Get users
+    ///
+    /// Lists all users.
+    #[utoipa::path(
+        get,
+        path = "/users",
+        deprecated(note = "use /v2/users"),
+        responses(
+            (status = 200, description = "success")
+        )
+    )]
+    #[allow(unused)]
+    fn get_users() {}
+
+    #[utoipa::path(
+        get,
+        path = "/user",
+        deprecated,
+        responses(
+            (status = 200, description = "success")
+        )
+    )]
+    #[allow(unused)]
+    fn get_user() {}
+
+    let users = get_operation(path_item!(get_users));
+    let user = get_operation(path_item!(get_user));
+
+    assert_json_eq!(users.pointer("/deprecated").unwrap(), json!(true));
+    assert_json_eq!(
+        users.pointer("/description").unwrap(),
+        json!("Get users\n\nLists all users.\n\n**Deprecated:** use /v2/users")
+    );
+    assert_json_eq!(user.pointer("/deprecated").unwrap(), json!(true));
+    assert!(user.pointer("/description").is_none());
+}
+
+#[test]
+fn path_with_request_body_examples() {
+    #[derive(serde::Deserialize, utoipa::ToSchema)]
+    #[allow(unused)]
+    struct User {
+        name: String,
+        email: Option<String>,
+    }
+
+    #[utoipa::path(
+        post,
+        path = "/users",
+        request_body(
+            content = User,
+            examples(
+                ("minimal" = (summary = "Only required fields", value = json!({"name": "bob"}))),
+                ("full" = (external_value = "https://example.com/examples/user.json"))
+            )
+        ),
+        responses(
+            (status = 201, description = "created")
+        )
+    )]
+    #[allow(unused)]
+    fn post_user() {}
+
+    let path_item = path_item!(post_user);
+
+    assert_json_eq!(
+        path_item
+            .pointer("/post/requestBody/content/application~1json")
+            .unwrap(),
+        json!({
+            "schema": {
+                "$ref": "#/components/schemas/User"
+            },
+            "examples": {
+                "minimal": {
+                    "summary": "Only required fields",
+                    "value": {
+                        "name": "bob"
+                    }
+                },
+                "full": {
+                    "externalValue": "https://example.com/examples/user.json"
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn path_with_and_or_security_requirements() {
+    #[utoipa::path(
+        get,
+        path = "/foo",
+        security(
+            ("api_key" = []),
+            ("oauth" = ["read"], "basic" = []),
+            ()
+        ),
+        responses(
+            (status = 200, description = "success")
+        )
+    )]
+    #[allow(unused)]
+    fn get_foo() {}
+
+    #[utoipa::path(
+        get,
+        path = "/public",
+        security(),
+        responses(
+            (status = 200, description = "success")
+        )
+    )]
+    #[allow(unused)]
+    fn get_public() {}
+
+    let foo = get_operation(path_item!(get_foo));
+    let public = get_operation(path_item!(get_public));
+
+    assert_json_eq!(
+        foo.pointer("/security").unwrap(),
+        json!([
+            {
+                "api_key": []
+            },
+            {
+                "oauth": ["read"],
+                "basic": []
+            },
+            {}
+        ])
+    );
+    assert_json_eq!(public.pointer("/security").unwrap(), json!([]));
+}
+
+#[test]
+fn path_on_impl_and_trait_methods() {
+    #[allow(unused)]
+    struct UserService;
+
+    #[utoipa::path]
+    #[allow(unused)]
+    impl UserService {
+        /// Get user by id
+        #[utoipa::path(
+            get,
+            path = "/users/{id}",
+            params(("id" = u64, Path, description = "Id of the user")),
+            responses(
+                (status = 200, description = "User found")
+            )
+        )]
+        fn get_user(&self, id: u64) {}
+
+        fn not_a_handler(&self) {}
+    }
+
+    #[utoipa::path]
+    #[allow(unused)]
+    trait Hooks {
+        #[utoipa::path(
+            post,
+            path = "{$request.body#/callbackUrl}",
+            responses(
+                (status = 200, description = "Event received")
+            )
+        )]
+        fn on_event() {}
+    }
+
+    #[utoipa::path(
+        post,
+        path = "/subscribe",
+        callbacks(("onEvent" = Hooks::on_event)),
+        responses(
+            (status = 201, description = "Subscribed")
+        )
+    )]
+    #[allow(unused)]
+    fn subscribe() {}
+
+    let get_user = get_operation(path_item!(UserService_get_user));
+    let subscribe = path_item!(subscribe);
+
+    assert_eq!(
+        <__path_UserService_get_user as utoipa::Path>::path(),
+        "/users/{id}"
+    );
+    assert_json_eq!(
+        get_user,
+        json!({
+            "tags": ["crate"],
+            "summary": "Get user by id",
+            "description": "Get user by id",
+            "operationId": "get_user",
+            "parameters": [
+                {
+                    "name": "id",
+                    "in": "path",
+                    "description": "Id of the user",
+                    "required": true,
+                    "deprecated": false,
+                    "schema": {
+                        "type": "integer",
+                        "format": "int64"
+                    }
+                }
+            ],
+            "responses": {
+                "200": {
+                    "description": "User found"
+                }
+            },
+            "deprecated": false
+        })
+    );
+    assert_json_eq!(
+        subscribe
+            .pointer("/post/callbacks/onEvent/{$request.body#~1callbackUrl}/post/operationId")
+            .unwrap(),
+        json!("on_event")
+    );
+}
+
+#[test]
+fn path_with_wildcard_path_parameters() {
+    #[utoipa::path(
+        get,
+        path = "/files/{tail:.*}",
+        params(("tail" = String, Path, description = "Path of the file")),
+        responses(
+            (status = 200, description = "success")
+        )
+    )]
+    #[allow(unused)]
+    fn get_file() {}
+
+    #[utoipa::path(
+        get,
+        path = "/assets/{version:v\\d{1,2}}/*rest",
+        params(
+            ("version" = String, Path),
+            ("rest" = String, Path, description = "Rest of the asset path")
+        ),
+        responses(
+            (status = 200, description = "success")
+        )
+    )]
+    #[allow(unused)]
+    fn get_asset() {}
+
+    #[utoipa::path(
+        get,
+        path = "/files/*",
+        responses(
+            (status = 200, description = "success")
+        )
+    )]
+    #[allow(unused)]
+    fn get_any_file() {}
+
+    assert_eq!(<__path_get_file as utoipa::Path>::path(), "/files/{tail}");
+    assert_eq!(
+        <__path_get_asset as utoipa::Path>::path(),
+        "/assets/{version}/{rest}"
+    );
+    assert_eq!(
+        <__path_get_any_file as utoipa::Path>::path(),
+        "/files/{rest}"
+    );
+
+    let operation = get_operation(path_item!(get_asset));
+    assert_json_eq!(
+        operation.pointer("/parameters/1").unwrap(),
+        json!({
+            "name": "rest",
+            "in": "path",
+            "description": "Rest of the asset path",
+            "required": true,
+            "deprecated": false,
+            "schema": {
+                "type": "string"
+            }
+        })
+    );
+}
+
+#[test]
+fn path_with_parameter_style_explode_and_allow_reserved() {
+    #[derive(serde::Deserialize, utoipa::IntoParams)]
+    #[into_params(parameter_in = Query)]
+    #[allow(unused)]
+    struct Filter {
+        #[param(style = DeepObject, explode)]
+        range: std::collections::HashMap<String, i32>,
+        #[param(style = PipeDelimited, explode = false, allow_reserved)]
+        fields: Vec<String>,
+    }
+
+    #[utoipa::path(
+        get,
+        path = "/items",
+        params(
+            ("ids" = [i32], Query, style = Form, explode = false),
+            Filter
+        ),
+        responses(
+            (status = 200, description = "success")
+        )
+    )]
+    #[allow(unused)]
+    fn get_items() {}
+
+    let operation = get_operation(path_item!(get_items));
+    let parameters = operation.pointer("/parameters").unwrap();
+
+    assert_json_eq!(
+        parameters
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|parameter| json!({
+                "name": parameter["name"],
+                "style": parameter["style"],
+                "explode": parameter["explode"],
+                "allowReserved": parameter["allowReserved"],
+            }))
+            .collect::<Vec<_>>(),
+        json!([
+            {
+                "name": "ids",
+                "style": "form",
+                "explode": false,
+                "allowReserved": null
+            },
+            {
+                "name": "range",
+                "style": "deepObject",
+                "explode": true,
+                "allowReserved": null
+            },
+            {
+                "name": "fields",
+                "style": "pipeDelimited",
+                "explode": false,
+                "allowReserved": true
+            }
+        ])
+    );
+}
+
+#[test]
+fn path_with_parameter_content() {
+    #[derive(utoipa::ToSchema)]
+    #[allow(unused)]
+    struct FilterSpec {
+        name: String,
+    }
+
+    #[utoipa::path(
+        get,
+        path = "/items",
+        params(
+            ("filter", Query, content("application/json" = FilterSpec), description = "Filter as json"),
+            ("sort", Query, content("application/json" = inline(Option<Vec<String>>)))
+        ),
+        responses(
+            (status = 200, description = "success")
+        )
+    )]
+    #[allow(unused)]
+    fn get_items() {}
+
+    let operation = get_operation(path_item!(get_items));
+    let parameters = operation.pointer("/parameters").unwrap();
+
+    assert_json_eq!(
+        parameters,
+        json!([
+            {
+                "name": "filter",
+                "in": "query",
+                "required": true,
+                "deprecated": false,
+                "description": "Filter as json",
+                "content": {
+                    "application/json": {
+                        "schema": {
+                            "$ref": "#/components/schemas/FilterSpec"
+                        }
+                    }
+                }
+            },
+            {
+                "name": "sort",
+                "in": "query",
+                "required": false,
+                "deprecated": false,
+                "content": {
+                    "application/json": {
+                        "schema": {
+                            "type": "array",
+                            "items": {
+                                "type": "string"
+                            }
+                        }
+                    }
+                }
+            }
+        ])
+    );
+}
+
+#[test]
+fn path_with_tag_from_module() {
+    mod users {
+        #[utoipa::path(
+            get,
+            path = "/users",
+            tag_from_module,
+            responses(
+                (status = 200, description = "success")
+            )
+        )]
+        #[allow(unused)]
+        pub fn list_users() {}
+
+        #[utoipa::path(
+            get,
+            path = "/users/admins",
+            tag_from_module,
+            tag = "admins",
+            responses(
+                (status = 200, description = "success")
+            )
+        )]
+        #[allow(unused)]
+        pub fn list_admins() {}
+    }
+    use users::*;
+
+    assert_json_eq!(
+        get_operation(path_item!(list_users)).pointer("/tags"),
+        json!(["users"])
+    );
+    assert_json_eq!(
+        get_operation(path_item!(list_admins)).pointer("/tags"),
+        json!(["admins"])
+    );
+}
+
+#[test]
+fn path_with_multiple_operations() {
+    #[utoipa::path(
+        get,
+        head,
+        path = "/health",
+        responses(
+            (status = 200, description = "healthy")
+        )
+    )]
+    #[allow(unused)]
+    fn health() {}
+
+    let path_item = path_item!(health);
+
+    assert_json_eq!(
+        path_item,
+        json!({
+            "get": {
+                "tags": ["crate"],
+                "operationId": "health",
+                "responses": {
+                    "200": {
+                        "description": "healthy"
+                    }
+                },
+                "deprecated": false
+            },
+            "head": {
+                "tags": ["crate"],
+                "operationId": "health_head",
+                "responses": {
+                    "200": {
+                        "description": "healthy"
+                    }
+                },
+                "deprecated": false
+            }
+        })
+    );
+}
+
+#[test]
+fn path_with_shared_path_params() {
+    #[utoipa::path(
+        get,
+        delete,
+        path = "/orgs/{org_id}",
+        path_params(
+            ("org_id" = u64, Path, description = "Id of organization")
+        ),
+        params(
+            ("dry_run" = Option<bool>, Query)
+        ),
+        responses(
+            (status = 200, description = "success")
+        )
+    )]
+    #[allow(unused)]
+    fn organization() {}
+
+    let path_item = path_item!(organization);
+
+    assert_json_eq!(
+        &path_item["parameters"],
+        json!([
+            {
+                "in": "path",
+                "name": "org_id",
+                "description": "Id of organization",
+                "required": true,
+                "deprecated": false,
+                "schema": {
+                    "type": "integer",
+                    "format": "int64"
+                }
+            }
+        ])
+    );
+    for method in ["get", "delete"] {
+        let parameters = path_item[method]["parameters"].as_array().unwrap();
+        assert_eq!(parameters.len(), 1);
+        assert_eq!(parameters[0]["name"], "dry_run");
+        assert_eq!(parameters[0]["in"], "query");
+    }
+}
+
+#[test]
+fn path_with_shared_path_params_of_multiple_into_params() {
+    #[derive(utoipa::IntoParams)]
+    #[into_params(parameter_in = Path)]
+    #[allow(unused)]
+    struct OrgPath {
+        org_id: u64,
+    }
+
+    #[derive(utoipa::IntoParams)]
+    #[into_params(parameter_in = Path)]
+    #[allow(unused)]
+    struct UserPath {
+        user_id: u64,
+    }
+
+    #[utoipa::path(
+        get,
+        path = "/orgs/{org_id}/users/{user_id}",
+        path_params(OrgPath, UserPath),
+        responses(
+            (status = 200, description = "success")
+        )
+    )]
+    #[allow(unused)]
+    fn org_user() {}
+
+    let path_item = path_item!(org_user);
+
+    let names = path_item["parameters"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|parameter| parameter["name"].as_str().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(names, ["org_id", "user_id"]);
+}
+
+#[test]
+fn path_with_non_standard_status_codes() {
+    const TOO_MANY_BOOKINGS: u16 = 599;
+
+    #[utoipa::path(
+        get,
+        path = "/bookings",
+        responses(
+            (status = 201, description = "created"),
+            (status = 499, description = "client closed request"),
+            (status = TOO_MANY_BOOKINGS, description = "too many bookings"),
+        )
+    )]
+    #[allow(unused)]
+    fn get_bookings() {}
+
+    let operation = get_operation(path_item!(get_bookings));
+
+    assert_json_eq!(
+        &operation["responses"],
+        json!({
+            "201": {
+                "description": "created"
+            },
+            "499": {
+                "description": "client closed request"
+            },
+            "599": {
+                "description": "too many bookings"
+            }
+        })
+    );
+}
+
+#[test]
+fn path_with_response_descriptions_from_status_codes() {
+    const ITEM_MISSING: u16 = 404;
+
+    #[utoipa::path(
+        get,
+        path = "/items",
+        responses(
+            (status = 200),
+            (status = ITEM_MISSING),
+            (status = 499),
+            (status = "5XX"),
+            (status = "default"),
+            (status = 418, description = "custom description"),
+        )
+    )]
+    #[allow(unused)]
+    fn get_items() {}
+
+    let operation = get_operation(path_item!(get_items));
+
+    assert_json_eq!(
+        &operation["responses"],
+        json!({
+            "200": {
+                "description": "OK"
+            },
+            "404": {
+                "description": "Not Found"
+            },
+            "418": {
+                "description": "custom description"
+            },
+            "499": {
+                "description": "Client Error"
+            },
+            "5XX": {
+                "description": "Server Error"
+            },
+            "default": {
+                "description": "Default response"
+            }
+        })
+    );
+}
+
+#[test]
+fn path_with_inline_response_content() {
+    #[derive(utoipa::ToSchema)]
+    #[allow(unused)]
+    struct Item {
+        id: u64,
+    }
+
+    #[utoipa::path(
+        get,
+        path = "/item",
+        responses(
+            (status = 200, content(
+                ("application/json" = inline(Item)),
+                ("application/xml" = Item),
+            ))
+        )
+    )]
+    #[allow(unused)]
+    fn get_item() {}
+
+    let operation = get_operation(path_item!(get_item));
+
+    assert_json_eq!(
+        &operation["responses"]["200"]["content"],
+        json!({
+            "application/json": {
+                "schema": {
+                    "type": "object",
+                    "properties": {
+                        "id": {
+                            "type": "integer",
+                            "format": "int64"
+                        }
+                    },
+                    "required": ["id"]
+                }
+            },
+            "application/xml": {
+                "schema": {
+                    "$ref": "#/components/schemas/Item"
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn path_with_free_form_query_map_parameter() {
+    #[utoipa::path(
+        get,
+        path = "/items",
+        params(
+            ("filters" = HashMap<String, String>, Query, style = DeepObject, description = "Free form filters")
+        ),
+        responses(
+            (status = 200, description = "success")
+        )
+    )]
+    #[allow(unused)]
+    fn get_items() {}
+
+    let operation = get_operation(path_item!(get_items));
+
+    assert_json_eq!(
+        operation.pointer("/parameters"),
+        json!([
+            {
+                "name": "filters",
+                "in": "query",
+                "required": true,
+                "deprecated": false,
+                "description": "Free form filters",
+                "style": "deepObject",
+                "schema": {
+                    "type": "object",
+                    "additionalProperties": {
+                        "type": "string"
+                    }
+                }
+            }
+        ])
+    );
+}
+
+#[test]
+fn path_with_string_and_bytes_default_content_types() {
+    mod bytes {
+        #[allow(unused)]
+        pub struct Bytes(Vec<u8>);
+    }
+
+    #[utoipa::path(
+        post,
+        path = "/text",
+        request_body = String,
+        responses(
+            (status = 200, description = "success", body = bytes::Bytes)
+        )
+    )]
+    #[allow(unused)]
+    fn post_text() {}
+
+    #[utoipa::path(
+        post,
+        path = "/bytes",
+        request_body = bytes::Bytes,
+        responses(
+            (status = 200, description = "success", body = Vec<u8>)
+        )
+    )]
+    #[allow(unused)]
+    fn post_bytes() {}
+
+    let binary = json!({
+        "type": "string",
+        "format": "binary"
+    });
+
+    let operation = path_item!(post_text).pointer("/post").cloned().unwrap();
+    assert_json_eq!(
+        operation.pointer("/requestBody/content"),
+        json!({
+            "text/plain": {
+                "schema": {
+                    "type": "string"
+                }
+            }
+        })
+    );
+    assert_json_eq!(
+        operation.pointer("/responses/200/content"),
+        json!({
+            "application/octet-stream": {
+                "schema": binary
+            }
+        })
+    );
+
+    let operation = path_item!(post_bytes).pointer("/post").cloned().unwrap();
+    assert_json_eq!(
+        operation.pointer("/requestBody/content"),
+        json!({
+            "application/octet-stream": {
+                "schema": binary
+            }
+        })
+    );
+    assert_json_eq!(
+        operation.pointer("/responses/200/content"),
+        json!({
+            "application/octet-stream": {
+                "schema": binary
+            }
+        })
+    );
+}
+
+#[test]
+fn path_with_user_defined_bytes_type() {
+    #[derive(utoipa::ToSchema)]
+    #[allow(unused)]
+    struct Bytes {
+        value: Vec<u8>,
+    }
+
+    #[utoipa::path(
+        get,
+        path = "/bytes",
+        responses(
+            (status = 200, description = "success", body = Bytes)
+        )
+    )]
+    #[allow(unused)]
+    fn get_bytes() {}
+
+    let operation = get_operation(path_item!(get_bytes));
+    assert_json_eq!(
+        operation.pointer("/responses/200/content"),
+        json!({
+            "application/json": {
+                "schema": {
+                    "$ref": "#/components/schemas/Bytes"
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn path_with_optional_request_body() {
+    #[derive(utoipa::ToSchema)]
+    #[allow(unused)]
+    struct Patch {
+        name: Option<String>,
+    }
+
+    #[utoipa::path(
+        patch,
+        path = "/items/{id}",
+        request_body(content = Patch, description = "Optional patch", required = false),
+        responses(
+            (status = 200, description = "success")
+        )
+    )]
+    #[allow(unused)]
+    fn patch_item() {}
+
+    let path_item = path_item!(patch_item);
+
+    assert_json_eq!(
+        path_item.pointer("/patch/requestBody"),
+        json!({
+            "content": {
+                "application/json": {
+                    "schema": {
+                        "$ref": "#/components/schemas/Patch"
+                    }
+                }
+            },
+            "description": "Optional patch",
+            "required": false
+        })
+    );
+}
+
+#[test]
+fn path_with_nested_into_params() {
+    #[derive(utoipa::IntoParams)]
+    #[allow(unused)]
+    struct Pagination {
+        /// Page number
+        page: u32,
+        /// Items per page
+        per_page: Option<u32>,
+    }
+
+    #[derive(utoipa::ToSchema)]
+    #[allow(unused)]
+    struct Filter {
+        name: String,
+    }
+
+    #[derive(utoipa::IntoParams)]
+    #[into_params(parameter_in = Query)]
+    #[allow(unused)]
+    struct ListQuery {
+        #[param(flatten)]
+        pagination: Pagination,
+        /// Filter items by fields
+        #[param(style = DeepObject)]
+        filter: Option<Filter>,
+    }
+
+    #[utoipa::path(get, path = "/items", params(ListQuery))]
+    #[allow(unused)]
+    fn get_items() {}
+
+    let operation = get_operation(path_item!(get_items));
+
+    assert_json_eq!(
+        operation.pointer("/parameters").unwrap(),
+        json!([
+            {
+                "name": "page",
+                "in": "query",
+                "description": "Page number",
+                "required": true,
+                "schema": {
+                    "type": "integer",
+                    "format": "int32"
+                }
+            },
+            {
+                "name": "per_page",
+                "in": "query",
+                "description": "Items per page",
+                "required": false,
+                "schema": {
+                    "type": "integer",
+                    "format": "int32"
+                }
+            },
+            {
+                "name": "filter",
+                "in": "query",
+                "description": "Filter items by fields",
+                "required": false,
+                "style": "deepObject",
+                "explode": true,
+                "schema": {
+                    "$ref": "#/components/schemas/Filter"
+                }
+            }
+        ])
+    );
+}
+
+#[test]
+fn path_with_into_params_serde_attributes() {
+    #[derive(serde::Deserialize, utoipa::IntoParams)]
+    #[serde(rename_all = "camelCase")]
+    #[allow(unused)]
+    struct Pagination {
+        page_number: u32,
+    }
+
+    #[derive(serde::Deserialize, utoipa::IntoParams)]
+    #[into_params(parameter_in = Query)]
+    #[serde(rename_all = "camelCase")]
+    #[allow(unused)]
+    struct ListQuery {
+        sort_by: String,
+        #[serde(rename = "q")]
+        search_term: Option<String>,
+        #[serde(skip)]
+        internal: String,
+        #[serde(flatten)]
+        pagination: Pagination,
+    }
+
+    #[utoipa::path(get, path = "/items", params(ListQuery))]
+    #[allow(unused)]
+    fn get_items() {}
+
+    let operation = get_operation(path_item!(get_items));
+
+    assert_json_eq!(
+        operation.pointer("/parameters").unwrap(),
+        json!([
+            {
+                "name": "sortBy",
+                "in": "query",
+                "required": true,
+                "schema": {
+                    "type": "string"
+                }
+            },
+            {
+                "name": "q",
+                "in": "query",
+                "required": false,
+                "schema": {
+                    "type": "string"
+                }
+            },
+            {
+                "name": "pageNumber",
+                "in": "query",
+                "required": true,
+                "schema": {
+                    "type": "integer",
+                    "format": "int32"
+                }
+            }
+        ])
+    );
+}
+
+#[test]
+#[cfg(feature = "validator")]
+fn path_with_into_params_validator_constraints() {
+    struct Regex(&'static str);
+
+    impl Regex {
+        fn as_str(&self) -> &str {
+            self.0
+        }
+    }
+
+    static RE_NAME: Regex = Regex("^[a-z]+$");
+
+    #[derive(utoipa::IntoParams)]
+    #[into_params(parameter_in = Query)]
+    #[allow(unused)]
+    struct ListQuery {
+        #[validate(range(min = 1, max = 100))]
+        limit: i32,
+        #[validate(length(min = 2, max = 20), regex = "RE_NAME")]
+        name: String,
+        #[validate(length(max = 5))]
+        #[param(max_items = 3)]
+        tags: Option<Vec<String>>,
+        #[validate(email)]
+        email: String,
+    }
+
+    #[utoipa::path(get, path = "/items", params(ListQuery))]
+    #[allow(unused)]
+    fn get_items() {}
+
+    let operation = get_operation(path_item!(get_items));
+
+    assert_json_eq!(
+        operation.pointer("/parameters").unwrap(),
+        json!([
+            {
+                "name": "limit",
+                "in": "query",
+                "required": true,
+                "schema": {
+                    "type": "integer",
+                    "format": "int32",
+                    "minimum": 1.0,
+                    "maximum": 100.0
+                }
+            },
+            {
+                "name": "name",
+                "in": "query",
+                "required": true,
+                "schema": {
+                    "type": "string",
+                    "minLength": 2,
+                    "maxLength": 20,
+                    "pattern": "^[a-z]+$"
+                }
+            },
+            {
+                "name": "tags",
+                "in": "query",
+                "required": false,
+                "schema": {
+                    "type": "array",
+                    "items": {
+                        "type": "string"
+                    },
+                    "maxItems": 3
+                }
+            },
+            {
+                "name": "email",
+                "in": "query",
+                "required": true,
+                "schema": {
+                    "type": "string"
+                }
+            }
+        ])
+    );
+}
+
+#[test]
+fn path_with_into_params_header_parameters() {
+    #[derive(utoipa::IntoParams)]
+    #[into_params(parameter_in = Header)]
+    #[allow(unused)]
+    struct AuthHeaders {
+        /// Api key of the client
+        authorization: String,
+    }
+
+    #[derive(utoipa::IntoParams)]
+    #[into_params(parameter_in = Query)]
+    #[allow(unused)]
+    struct ListQuery {
+        /// Correlation id of the request
+        #[param(header = "x-correlation-id")]
+        correlation_id: Option<String>,
+        #[param(header)]
+        tenant: String,
+        page: u32,
+    }
+
+    #[utoipa::path(get, path = "/items", params(AuthHeaders, ListQuery))]
+    #[allow(unused)]
+    fn get_items() {}
+
+    let operation = get_operation(path_item!(get_items));
+
+    assert_json_eq!(
+        operation.pointer("/parameters").unwrap(),
+        json!([
+            {
+                "name": "authorization",
+                "in": "header",
+                "description": "Api key of the client",
+                "required": true,
+                "schema": {
+                    "type": "string"
+                }
+            },
+            {
+                "name": "x-correlation-id",
+                "in": "header",
+                "description": "Correlation id of the request",
+                "required": false,
+                "schema": {
+                    "type": "string"
+                }
+            },
+            {
+                "name": "tenant",
+                "in": "header",
+                "required": true,
+                "schema": {
+                    "type": "string"
+                }
+            },
+            {
+                "name": "page",
+                "in": "query",
+                "required": true,
+                "schema": {
+                    "type": "integer",
+                    "format": "int32"
+                }
+            }
+        ])
+    );
+}
+
+#[test]
+fn path_with_into_params_per_field_style_and_explode() {
+    #[derive(utoipa::IntoParams)]
+    #[into_params(parameter_in = Query, style = Form)]
+    #[allow(unused)]
+    struct ListQuery {
+        #[param(style = PipeDelimited, explode = false)]
+        ids: Vec<i64>,
+        #[param(style = SpaceDelimited, explode = false)]
+        fields: Option<Vec<String>>,
+        #[param(style = DeepObject, explode = false)]
+        filter: std::collections::HashMap<String, String>,
+        tags: Vec<String>,
+    }
+
+    #[utoipa::path(get, path = "/items", params(ListQuery))]
+    #[allow(unused)]
+    fn get_items() {}
+
+    let operation = get_operation(path_item!(get_items));
+    let parameters = operation.pointer("/parameters").unwrap();
+
+    let style_and_explode = |index: usize| {
+        (
+            parameters.pointer(&format!("/{index}/style")).cloned(),
+            parameters.pointer(&format!("/{index}/explode")).cloned(),
+        )
+    };
+
+    assert_eq!(
+        style_and_explode(0),
+        (Some(json!("pipeDelimited")), Some(json!(false)))
+    );
+    assert_eq!(
+        style_and_explode(1),
+        (Some(json!("spaceDelimited")), Some(json!(false)))
+    );
+    assert_eq!(
+        style_and_explode(2),
+        (Some(json!("deepObject")), Some(json!(false)))
+    );
+    assert_eq!(style_and_explode(3), (Some(json!("form")), None));
+}
+
+#[test]
+fn path_with_into_params_default_from_default_impl() {
+    #[derive(serde::Deserialize, utoipa::IntoParams)]
+    #[into_params(parameter_in = Query)]
+    #[serde(default)]
+    #[allow(unused)]
+    struct Pagination {
+        #[param(default)]
+        page: u32,
+        #[param(default)]
+        sort: String,
+        #[param(default = 50)]
+        per_page: u32,
+    }
+
+    impl Default for Pagination {
+        fn default() -> Self {
+            Self {
+                page: 1,
+                sort: "name".to_string(),
+                per_page: 20,
+            }
+        }
+    }
+
+    #[derive(utoipa::IntoParams, Default)]
+    #[into_params(names("id"), parameter_in = Path)]
+    #[allow(unused)]
+    struct Id(#[param(default)] u64);
+
+    #[utoipa::path(get, path = "/items/{id}", params(Id, Pagination))]
+    #[allow(unused)]
+    fn get_items() {}
+
+    let operation = get_operation(path_item!(get_items));
+    let parameters = operation.pointer("/parameters").unwrap();
+
+    assert_eq!(parameters.pointer("/0/schema/default"), Some(&json!(0)));
+    assert_eq!(parameters.pointer("/1/schema/default"), Some(&json!(1)));
+    assert_eq!(
+        parameters.pointer("/2/schema/default"),
+        Some(&json!("name"))
+    );
+    assert_eq!(parameters.pointer("/3/schema/default"), Some(&json!(50)));
+}
+
+#[test]
+fn path_with_allow_empty_value_and_allow_reserved_parameters() {
+    #[derive(utoipa::IntoParams)]
+    #[into_params(parameter_in = Query)]
+    #[allow(unused)]
+    struct Flags {
+        #[param(allow_empty_value)]
+        verbose: Option<bool>,
+        #[param(allow_reserved, allow_empty_value = false)]
+        redirect: Option<String>,
+    }
+
+    #[utoipa::path(
+        get,
+        path = "/items",
+        params(
+            ("dry_run" = Option<bool>, Query, allow_empty_value),
+            Flags
+        )
+    )]
+    #[allow(unused)]
+    fn get_items() {}
+
+    let operation = get_operation(path_item!(get_items));
+    let parameters = operation.pointer("/parameters").unwrap();
+
+    let flags = |index: usize| {
+        (
+            parameters
+                .pointer(&format!("/{index}/allowEmptyValue"))
+                .cloned(),
+            parameters
+                .pointer(&format!("/{index}/allowReserved"))
+                .cloned(),
+        )
+    };
+
+    assert_eq!(flags(0), (Some(json!(true)), None));
+    assert_eq!(flags(1), (Some(json!(true)), None));
+    assert_eq!(flags(2), (Some(json!(false)), Some(json!(true))));
+}
+
+#[test]
+fn path_with_multiple_into_params_and_tuples() {
+    #[derive(utoipa::IntoParams)]
+    #[into_params(parameter_in = Query)]
+    #[allow(unused)]
+    struct Pagination {
+        page: u32,
+    }
+
+    #[derive(utoipa::IntoParams)]
+    #[into_params(parameter_in = Query)]
+    #[allow(unused)]
+    struct Sorting {
+        sort_by: String,
+    }
+
+    #[utoipa::path(
+        get,
+        path = "/owners/{id}/pets",
+        params(Pagination, Sorting, ("id" = u32, Path), ("id" = String, Query))
+    )]
+    #[allow(unused)]
+    fn get_pets() {}
+
+    let operation = get_operation(path_item!(get_pets));
+    let parameters = operation
+        .pointer("/parameters")
+        .and_then(Value::as_array)
+        .unwrap()
+        .iter()
+        .map(|parameter| {
+            format!(
+                "{} in {}",
+                parameter["name"].as_str().unwrap(),
+                parameter["in"].as_str().unwrap()
+            )
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        parameters,
+        [
+            "page in query",
+            "sort_by in query",
+            "id in path",
+            "id in query"
+        ]
+    );
+}
+
+#[test]
+fn path_with_named_parameter_examples() {
+    #[derive(utoipa::IntoParams)]
+    #[into_params(parameter_in = Query)]
+    #[allow(unused)]
+    struct Filter {
+        #[param(examples(
+            ("active" = (summary = "Active pets", value = json!("active"))),
+            ("sold" = (value = json!("sold")))
+        ))]
+        status: String,
+    }
+
+    #[utoipa::path(
+        get,
+        path = "/pets/{id}",
+        params(
+            ("id" = String, Path, examples(
+                ("uuid" = (summary = "Uuid id", description = "Id as uuid", value = json!("6a2f41a3-c54c-fce8-32d2-0324e1c32e22"))),
+                ("external" = (external_value = "https://example.com/pet-id.json"))
+            )),
+            Filter
+        )
+    )]
+    #[allow(unused)]
+    fn get_pet() {}
+
+    let operation = get_operation(path_item!(get_pet));
+
+    assert_json_eq!(
+        operation.pointer("/parameters/0/examples").unwrap(),
+        json!({
+            "external": {
+                "externalValue": "https://example.com/pet-id.json"
+            },
+            "uuid": {
+                "summary": "Uuid id",
+                "description": "Id as uuid",
+                "value": "6a2f41a3-c54c-fce8-32d2-0324e1c32e22"
+            }
+        })
+    );
+    assert_json_eq!(
+        operation.pointer("/parameters/1/examples").unwrap(),
+        json!({
+            "active": {
+                "summary": "Active pets",
+                "value": "active"
+            },
+            "sold": {
+                "value": "sold"
+            }
+        })
+    );
+}
+
+#[cfg(all(feature = "chrono", feature = "uuid"))]
+#[test]
+fn path_with_into_params_known_formats() {
+    use chrono::{DateTime, NaiveDate, Utc};
+    use uuid::Uuid;
+
+    #[derive(utoipa::IntoParams)]
+    #[into_params(parameter_in = Query)]
+    #[allow(unused)]
+    struct Filter {
+        id: Uuid,
+        ids: Vec<Uuid>,
+        since: Option<DateTime<Utc>>,
+        day: NaiveDate,
+        #[param(format = Uuid)]
+        external_id: String,
+        #[param(format = Date)]
+        until: Option<DateTime<Utc>>,
+        #[param(format = "ulid")]
+        order_ids: Vec<String>,
+    }
+
+    #[utoipa::path(get, path = "/orders", params(Filter))]
+    #[allow(unused)]
+    fn get_orders() {}
+
+    let operation = get_operation(path_item!(get_orders));
+    let parameters = operation.pointer("/parameters").unwrap();
+
+    let schema = |index: usize| parameters.pointer(&format!("/{index}/schema")).unwrap();
+
+    assert_json_eq!(schema(0), json!({"type": "string", "format": "uuid"}));
+    assert_json_eq!(
+        schema(1),
+        json!({"type": "array", "items": {"type": "string", "format": "uuid"}})
+    );
+    assert_json_eq!(schema(2), json!({"type": "string", "format": "date-time"}));
+    assert_json_eq!(schema(3), json!({"type": "string", "format": "date"}));
+    assert_json_eq!(schema(4), json!({"type": "string", "format": "uuid"}));
+    assert_json_eq!(schema(5), json!({"type": "string", "format": "date"}));
+    assert_json_eq!(
+        schema(6),
+        json!({"type": "array", "items": {"type": "string", "format": "ulid"}})
+    );
+}
+
+#[cfg(feature = "actix_extras")]
+#[test]
+fn path_with_operations_from_actix_web_attributes() {
+    use actix_web::{route, routes, HttpResponse, Responder};
+
+    #[utoipa::path(responses((status = 200, description = "Pet found")))]
+    #[actix_web::get("/pets/{id}")]
+    #[allow(unused)]
+    async fn get_pet() -> impl Responder {
+        HttpResponse::Ok()
+    }
+
+    #[utoipa::path(responses((status = 200, description = "Pets listed")))]
+    #[route("/pets", method = "GET", method = "HEAD", name = "list_pets")]
+    #[allow(unused)]
+    async fn list_pets() -> impl Responder {
+        HttpResponse::Ok()
+    }
+
+    #[utoipa::path(responses((status = 204, description = "Pet removed")))]
+    #[routes]
+    #[delete("/pets/{id}")]
+    #[post("/pets/{id}")]
+    #[allow(unused)]
+    async fn remove_pet() -> impl Responder {
+        HttpResponse::NoContent()
+    }
+
+    let operations = |path_item: Value| {
+        path_item
+            .as_object()
+            .unwrap()
+            .keys()
+            .cloned()
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(<__path_get_pet as utoipa::Path>::path(), "/pets/{id}");
+    assert_eq!(operations(path_item!(get_pet)), ["get"]);
+    assert_eq!(<__path_list_pets as utoipa::Path>::path(), "/pets");
+    assert_eq!(operations(path_item!(list_pets)), ["get", "head"]);
+    assert_eq!(<__path_remove_pet as utoipa::Path>::path(), "/pets/{id}");
+    assert_eq!(operations(path_item!(remove_pet)), ["delete", "post"]);
+}
+
+#[cfg(feature = "rocket_extras")]
+#[test]
+fn path_with_parameters_from_rocket_route_attribute() {
+    use rocket::FromForm;
+
+    #[derive(FromForm, utoipa::IntoParams)]
+    #[allow(unused)]
+    struct Filter {
+        name: Option<String>,
+        limit: u32,
+    }
+
+    #[utoipa::path(responses((status = 200, description = "Item found")))]
+    #[rocket::get("/items/<id>?<q>&<filter..>")]
+    #[allow(unused)]
+    fn get_item(id: u64, q: Option<String>, filter: Filter) -> String {
+        String::new()
+    }
+
+    assert_eq!(<__path_get_item as utoipa::Path>::path(), "/items/{id}");
+
+    let operation = get_operation(path_item!(get_item));
+    let parameters = operation
+        .pointer("/parameters")
+        .and_then(Value::as_array)
+        .unwrap()
+        .iter()
+        .map(|parameter| {
+            (
+                parameter["name"].as_str().unwrap(),
+                parameter["in"].as_str().unwrap(),
+                parameter["required"].as_bool().unwrap(),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        parameters,
+        [
+            ("id", "path", true),
+            ("q", "query", false),
+            ("name", "query", false),
+            ("limit", "query", true)
+        ]
+    );
+}
+
+#[test]
+fn path_with_inline_parameter_examples_override_example() {
+    #[derive(utoipa::IntoParams)]
+    #[into_params(parameter_in = Query)]
+    #[allow(unused)]
+    struct Filter {
+        #[param(example = json!("available"), examples(("sold" = json!("sold"))))]
+        status: String,
+    }
+
+    #[utoipa::path(
+        get,
+        path = "/pets/{id}",
+        params(
+            ("id" = String, Path, example = json!("1"), examples(
+                ("uuid" = json!("6a2f41a3-c54c-fce8-32d2-0324e1c32e22"), description = "Id as uuid"),
+                ("number" = json!("1"), summary = "Id as number")
+            )),
+            Filter
+        )
+    )]
+    #[allow(unused)]
+    fn get_pet() {}
+
+    let operation = get_operation(path_item!(get_pet));
+
+    assert_json_eq!(
+        operation.pointer("/parameters").unwrap(),
+        json!([
+            {
+                "name": "id",
+                "in": "path",
+                "required": true,
+                "deprecated": false,
+                "schema": {
+                    "type": "string"
+                },
+                "examples": {
+                    "number": {
+                        "summary": "Id as number",
+                        "value": "1"
+                    },
+                    "uuid": {
+                        "description": "Id as uuid",
+                        "value": "6a2f41a3-c54c-fce8-32d2-0324e1c32e22"
+                    }
+                }
+            },
+            {
+                "name": "status",
+                "in": "query",
+                "required": true,
+                "schema": {
+                    "type": "string"
+                },
+                "examples": {
+                    "sold": {
+                        "value": "sold"
+                    }
+                }
+            }
+        ])
+    );
+}
+
+#[test]
+fn derive_into_params_parameters_with_flattened_fields() {
+    use utoipa::{openapi::path::ParameterIn, IntoParams};
+
+    #[derive(utoipa::IntoParams)]
+    #[into_params(parameter_in = Query)]
+    #[allow(unused)]
+    struct Pagination {
+        page: u32,
+    }
+
+    #[derive(utoipa::IntoParams)]
+    #[allow(unused)]
+    struct PetParams {
+        id: u64,
+        #[param(rename = "X-Request-Id", parameter_in = Header)]
+        request_id: String,
+        #[param(flatten)]
+        pagination: Pagination,
+    }
+
+    assert!(
+        PetParams::PARAMETERS
+            == [
+                ("id", None),
+                ("X-Request-Id", Some(ParameterIn::Header)),
+                ("page", Some(ParameterIn::Query))
+            ]
+    );
+}
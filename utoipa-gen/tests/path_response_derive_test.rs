@@ -391,7 +391,7 @@ fn derive_path_with_multiple_responses_via_content_attribute() {
                         },
                     },
                 },
-                "description": "",
+                "description": "OK",
             },
         })
     )
@@ -455,7 +455,7 @@ fn derive_path_with_mutiple_examples() {
                         },
                     },
                 },
-                "description": "",
+                "description": "OK",
             },
         })
     )
@@ -547,7 +547,7 @@ fn derive_path_with_mutliple_resposnes_with_multiple_examples() {
                         },
                     },
                 },
-                "description": "",
+                "description": "OK",
             },
         })
     )
@@ -584,7 +584,7 @@ fn path_response_with_external_ref() {
                         },
                     },
                 },
-                "description": "",
+                "description": "OK",
             },
         })
     )
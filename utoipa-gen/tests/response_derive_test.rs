@@ -457,6 +457,28 @@ fn derive_response_with_unit_type() {
     )
 }
 
+#[test]
+fn derive_response_with_unit_type_and_content_type() {
+    /// Plain text response without body
+    #[derive(ToResponse)]
+    #[response(content_type = "text/plain")]
+    #[allow(unused)]
+    struct PlainTextResponse;
+
+    let (_, v) = <PlainTextResponse as utoipa::ToResponse>::response();
+    let value = serde_json::to_value(v).unwrap();
+
+    assert_json_eq!(
+        value,
+        json!({
+            "content": {
+                "text/plain": {}
+            },
+            "description": "Plain text response without body"
+        })
+    )
+}
+
 #[test]
 fn derive_response_with_inline_unnamed_schema() {
     #[allow(unused)]
@@ -592,7 +614,7 @@ fn derive_into_responses_unnamed_struct_inline_schema() {
                         }
                     }
                 },
-                "description": ""
+                "description": "Created"
             }
         })
     )
@@ -616,7 +638,7 @@ fn derive_into_responses_unnamed_struct_with_primitive_schema() {
                         }
                     }
                 },
-                "description": ""
+                "description": "Created"
             }
         })
     )
@@ -646,7 +668,7 @@ fn derive_into_responses_unnamed_struct_ref_schema() {
                         }
                     }
                 },
-                "description": ""
+                "description": "Created"
             }
         })
     )
@@ -773,10 +795,10 @@ fn derive_into_responses_enum_with_multiple_responses() {
                         }
                     }
                 },
-                "description": "",
+                "description": "Bad Request",
             },
             "404": {
-                "description": ""
+                "description": "Not Found"
             },
             "418": {
                 "content": {
@@ -800,3 +822,150 @@ fn derive_into_responses_enum_with_multiple_responses() {
         })
     )
 }
+
+#[test]
+fn derive_into_responses_enum_with_flattened_responses() {
+    #[derive(utoipa::IntoResponses)]
+    #[allow(unused)]
+    enum CommonErrors {
+        /// Unauthorized
+        #[response(status = 401)]
+        Unauthorized,
+
+        /// Server error
+        #[response(status = 500)]
+        ServerError,
+    }
+
+    let responses = into_responses! {
+        enum UserResponses {
+            /// Success response
+            #[response(status = 200)]
+            Success(String),
+
+            #[response(flatten)]
+            Errors(CommonErrors),
+        }
+    };
+
+    assert_json_eq!(
+        responses,
+        json!({
+            "200": {
+                "content": {
+                    "text/plain": {
+                        "schema": {
+                            "type": "string"
+                        }
+                    }
+                },
+                "description": "Success response"
+            },
+            "401": {
+                "description": "Unauthorized"
+            },
+            "500": {
+                "description": "Server error"
+            }
+        })
+    )
+}
+
+#[test]
+fn derive_into_responses_unit_struct_with_content_type() {
+    let responses = into_responses! {
+        /// No content
+        #[response(status = 204, content_type = ["application/json", "text/plain"])]
+        struct NoContent;
+    };
+
+    assert_json_eq!(
+        responses,
+        json!({
+            "204": {
+                "content": {
+                    "application/json": {},
+                    "text/plain": {}
+                },
+                "description": "No content"
+            }
+        })
+    )
+}
+
+#[test]
+fn derive_into_responses_enum_variant_doc_comment_description() {
+    /// Response description
+    #[derive(utoipa::ToResponse)]
+    #[allow(unused)]
+    struct Response {
+        message: String,
+    }
+
+    let responses = into_responses! {
+        enum UserResponses {
+            /// Bad request
+            #[response(status = 400)]
+            BadRequest(String),
+
+            /// Conflict with existing user
+            #[response(status = 409)]
+            Conflict(#[to_response] Response),
+
+            #[response(status = 418, description = "I'm a teapot")]
+            TeaPot(#[to_response] Response),
+
+            #[response(status = 500)]
+            ServerError(#[to_response] Response),
+        }
+    };
+
+    let descriptions = ["400", "409", "418", "500"].map(|status| {
+        responses
+            .pointer(&format!("/{status}/description"))
+            .and_then(|description| description.as_str())
+            .unwrap()
+            .to_string()
+    });
+
+    assert_eq!(
+        descriptions,
+        [
+            "Bad request",
+            "Conflict with existing user",
+            "I'm a teapot",
+            "Response description"
+        ]
+    )
+}
+
+#[test]
+fn derive_into_responses_status_codes() {
+    #[derive(utoipa::IntoResponses)]
+    #[allow(unused)]
+    enum CommonErrors {
+        #[response(status = 401)]
+        Unauthorized,
+    }
+
+    #[derive(utoipa::IntoResponses)]
+    #[allow(unused)]
+    enum UserResponses {
+        #[response(status = 200)]
+        Success(String),
+
+        #[response(status = NOT_FOUND)]
+        NotFound,
+
+        #[response(status = "5XX")]
+        ServerError,
+
+        #[response(flatten)]
+        Errors(CommonErrors),
+    }
+
+    assert_eq!(
+        <UserResponses as utoipa::IntoResponses>::STATUS_CODES,
+        ["200", "404", "5XX", "401"]
+    );
+}
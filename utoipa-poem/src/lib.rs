@@ -0,0 +1,425 @@
+#![cfg_attr(doc_cfg, feature(doc_cfg))]
+//! This crate implements bindings between [`utoipa`][utoipa] and [`poem`][poem] for registering
+//! the routes and their OpenAPI documentation together and for serving the OpenAPI document with
+//! Swagger UI.
+//!
+//! Handlers annotated with [`#[utoipa::path(...)]`][path] are registered to [`OpenApiRoute`]
+//! with [`routes!`] macro. The route uses the path and the HTTP methods of the
+//! [`#[utoipa::path(...)]`][path] attribute to route the requests and collects the operations to
+//! [`OpenApi`] document. Parameters and request body of the handlers are resolved from poem
+//! extractors with **poem_extras** feature of utoipa which this crate enables.
+//!
+//! [utoipa]: <https://docs.rs/utoipa/>
+//! [poem]: <https://docs.rs/poem/>
+//! [path]: <https://docs.rs/utoipa/latest/utoipa/attr.path.html>
+//!
+//! # Install
+//!
+//! ```toml
+//! [dependencies]
+//! utoipa = "2"
+//! utoipa-poem = "0.1"
+//! ```
+//!
+//! # Examples
+//!
+//! _**Register users routes and serve the OpenAPI document with Swagger UI.**_
+//! ```no_run
+//! # use poem::{handler, web::Path, Route};
+//! # use utoipa::OpenApi;
+//! # use utoipa_poem::{routes, OpenApiRoute};
+//! #[derive(OpenApi)]
+//! #[openapi(info(title = "Users API"))]
+//! struct ApiDoc;
+//!
+//! #[utoipa::path(get, path = "/users/{id}", responses((status = 200, description = "Get user")))]
+//! #[handler]
+//! async fn get_user(Path(id): Path<u64>) -> String {
+//!     format!("user {id}")
+//! }
+//!
+//! let users = OpenApiRoute::new().routes(routes!(get_user));
+//!
+//! let (route, openapi) = OpenApiRoute::with_openapi(ApiDoc::openapi())
+//!     .nest("/api/v1", users)
+//!     .split_for_parts();
+//!
+//! let route = route
+//!     .at("/api-doc.json", utoipa_poem::openapi_json(&openapi))
+//!     .nest(
+//!         "/swagger-ui",
+//!         utoipa_poem::swagger_ui(utoipa_swagger_ui::Config::from("/api-doc.json")),
+//!     );
+//! ```
+use std::sync::Arc;
+
+use poem::{
+    endpoint::make_sync,
+    http::{Method, StatusCode},
+    Endpoint, IntoEndpoint, Request, Response, Route, RouteMethod,
+};
+use utoipa::openapi::{
+    path::{PathItemType, Paths},
+    OpenApi,
+};
+use utoipa_swagger_ui::Config;
+
+/// Handlers of a single path created with [`routes!`] macro. It holds the OpenAPI [`Paths`] of
+/// the handlers and the [`RouteMethod`] routing requests to them.
+pub type UtoipaRouteMethod = (Paths, RouteMethod);
+
+/// Wrapper around [`poem::Route`] collecting OpenAPI documentation of the routes registered with
+/// [`OpenApiRoute::routes`].
+///
+/// Routes are registered by the path defined in [`#[utoipa::path(...)]`][path] of the handlers
+/// thus the served route and the documented path cannot drift apart. When the route is ready use
+/// [`OpenApiRoute::split_for_parts`] to get the [`poem::Route`] and the collected [`OpenApi`] for
+/// serving.
+///
+/// [path]: https://docs.rs/utoipa/latest/utoipa/attr.path.html
+pub struct OpenApiRoute(Route, OpenApi);
+
+impl OpenApiRoute {
+    /// Construct a new [`OpenApiRoute`] with empty [`OpenApi`] document.
+    pub fn new() -> Self {
+        Self::with_openapi(OpenApi::default())
+    }
+
+    /// Construct a new [`OpenApiRoute`] with given [`OpenApi`] e.g. one derived with
+    /// _`#[derive(OpenApi)]`_ holding info, components and tags of the API. Paths of the
+    /// registered routes are added to this document.
+    pub fn with_openapi(openapi: OpenApi) -> Self {
+        Self(Route::new(), openapi)
+    }
+
+    /// Register handlers created with [`routes!`] macro. Handlers are routed by the path and
+    /// methods defined in their [`#[utoipa::path(...)]`][path] attribute and the operations are
+    /// added to the [`OpenApi`] of the route.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the handlers do not share the same path.
+    ///
+    /// [path]: https://docs.rs/utoipa/latest/utoipa/attr.path.html
+    pub fn routes(self, (paths, route_method): UtoipaRouteMethod) -> Self {
+        let mut path_keys = paths.paths.keys();
+        let path = path_keys
+            .next()
+            .expect("routes!(...) must have at least one handler");
+        if let Some(other_path) = path_keys.next() {
+            panic!(
+                "handlers of routes!(...) must share the same path, found: {path} and {other_path}"
+            );
+        }
+
+        let route = self.0.at(to_poem_path(path), route_method);
+
+        let mut openapi = self.1;
+        openapi.paths.merge(paths);
+
+        Self(route, openapi)
+    }
+
+    /// Register undocumented endpoint with [`poem::Route::at`]. The endpoint is not added to the
+    /// [`OpenApi`] of the route.
+    pub fn at<E>(self, path: &str, endpoint: E) -> Self
+    where
+        E: IntoEndpoint,
+        E::Endpoint: 'static,
+    {
+        Self(self.0.at(path, endpoint), self.1)
+    }
+
+    /// Nest `route` under given `path` with [`poem::Route::nest`]. Paths of the nested route's
+    /// [`OpenApi`] are prefixed with the `path` and merged with [`OpenApi::nest`].
+    pub fn nest(self, path: &str, route: OpenApiRoute) -> Self {
+        let mut openapi = self.1;
+        openapi.nest(to_openapi_path(path), route.1);
+
+        Self(self.0.nest(path, route.0), openapi)
+    }
+
+    /// Get reference to the [`OpenApi`] collected so far.
+    pub fn get_openapi(&self) -> &OpenApi {
+        &self.1
+    }
+
+    /// Consume the route and return the collected [`OpenApi`].
+    pub fn into_openapi(self) -> OpenApi {
+        self.1
+    }
+
+    /// Split the route to the [`poem::Route`] and the collected [`OpenApi`].
+    pub fn split_for_parts(self) -> (Route, OpenApi) {
+        (self.0, self.1)
+    }
+}
+
+impl Default for OpenApiRoute {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<Route> for OpenApiRoute {
+    fn from(route: Route) -> Self {
+        Self(route, OpenApi::default())
+    }
+}
+
+impl From<OpenApiRoute> for Route {
+    fn from(route: OpenApiRoute) -> Self {
+        route.0
+    }
+}
+
+/// Collect handlers annotated with [`#[utoipa::path(...)]`][path] and poem's _`#[handler]`_ to
+/// [`UtoipaRouteMethod`] for [`OpenApiRoute::routes`].
+///
+/// All the handlers must share the same path but they can be defined for different HTTP methods
+/// e.g. _`routes!(get_user, update_user, delete_user)`_. Handlers can be referred with module
+/// path e.g. _`routes!(users::get_user)`_.
+///
+/// [path]: <https://docs.rs/utoipa/latest/utoipa/attr.path.html>
+#[macro_export]
+macro_rules! routes {
+    ( $( $( $handler:ident )::+ ),+ $(,)? ) => {
+        {
+            let mut paths = $crate::__dev::utoipa::openapi::path::Paths::new();
+            let mut route_method = $crate::__dev::poem::RouteMethod::new();
+            $(
+                let (path, path_item) = $crate::routes!( @path_item [] $( $handler )+ );
+                for path_item_type in path_item.operations.keys() {
+                    route_method = route_method.method($crate::method(path_item_type), $( $handler )::+);
+                }
+                paths.add_path(path, path_item);
+            )+
+            (paths, route_method)
+        }
+    };
+    ( @path_item [ $( $module:ident )* ] $name:ident $( $rest:ident )+ ) => {
+        $crate::routes!( @path_item [ $( $module )* $name ] $( $rest )+ )
+    };
+    ( @path_item [ $( $module:ident )* ] $name:ident ) => {
+        $crate::__dev::paste! {
+            (
+                <$( $module:: )* [<__path_ $name>] as $crate::__dev::utoipa::Path>::path(),
+                <$( $module:: )* [<__path_ $name>] as $crate::__dev::utoipa::Path>::path_item(None),
+            )
+        }
+    };
+}
+
+/// Get HTTP [`Method`] of given [`PathItemType`].
+pub fn method(path_item_type: &PathItemType) -> Method {
+    match path_item_type {
+        PathItemType::Get => Method::GET,
+        PathItemType::Post => Method::POST,
+        PathItemType::Put => Method::PUT,
+        PathItemType::Delete => Method::DELETE,
+        PathItemType::Options => Method::OPTIONS,
+        PathItemType::Head => Method::HEAD,
+        PathItemType::Patch => Method::PATCH,
+        PathItemType::Trace => Method::TRACE,
+        PathItemType::Connect => Method::CONNECT,
+    }
+}
+
+/// Create endpoint serving given [`OpenApi`] as JSON e.g.
+/// _`route.at("/api-doc.json", openapi_json(&openapi))`_.
+///
+/// # Panics
+///
+/// Panics if the [`OpenApi`] cannot be serialized to JSON.
+pub fn openapi_json(openapi: &OpenApi) -> impl Endpoint<Output = Response> {
+    let json = openapi.to_json().expect("OpenApi should serialize to JSON");
+
+    make_sync(move |_| {
+        Response::builder()
+            .content_type("application/json")
+            .body(json.clone())
+    })
+}
+
+/// Create endpoint serving Swagger UI with given [`Config`]. The endpoint must be nested under
+/// the path of the Swagger UI e.g. _`route.nest("/swagger-ui", swagger_ui(config))`_. Request to
+/// the path itself is redirected to the path with trailing slash.
+pub fn swagger_ui(config: Config<'static>) -> impl Endpoint<Output = Response> {
+    let config = Arc::new(config);
+
+    make_sync(move |request| serve_swagger_ui(&request, Arc::clone(&config)))
+}
+
+fn serve_swagger_ui(request: &Request, config: Arc<Config<'static>>) -> Response {
+    let tail = request.uri().path().trim_start_matches('/');
+    let original_path = request.original_uri().path();
+    if tail.is_empty() && !original_path.ends_with('/') {
+        return Response::builder()
+            .status(StatusCode::FOUND)
+            .header("Location", format!("{original_path}/"))
+            .finish();
+    }
+
+    match utoipa_swagger_ui::serve(tail, config) {
+        Ok(Some(file)) => Response::builder()
+            .content_type(file.content_type)
+            .body(file.bytes.to_vec()),
+        Ok(None) => Response::builder().status(StatusCode::NOT_FOUND).finish(),
+        Err(error) => Response::builder()
+            .status(StatusCode::INTERNAL_SERVER_ERROR)
+            .body(error.to_string()),
+    }
+}
+
+/// Format OpenAPI path template to [`poem`] route path e.g. _`/users/{id}`_ to _`/users/:id`_.
+fn to_poem_path(path: &str) -> String {
+    path.split('/')
+        .map(|segment| {
+            match segment
+                .strip_prefix('{')
+                .and_then(|segment| segment.strip_suffix('}'))
+            {
+                Some(name) => format!(":{name}"),
+                None => segment.to_string(),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Format [`poem`] route path to OpenAPI path template e.g. _`/users/:id`_ to _`/users/{id}`_.
+fn to_openapi_path(path: &str) -> String {
+    path.split('/')
+        .map(|segment| {
+            match segment
+                .strip_prefix(':')
+                .or_else(|| segment.strip_prefix('*'))
+            {
+                Some(name) => format!("{{{name}}}"),
+                None => segment.to_string(),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+#[doc(hidden)]
+pub mod __dev {
+    pub use paste::paste;
+    pub use poem;
+    pub use utoipa;
+}
+
+#[cfg(test)]
+mod tests {
+    use poem::{
+        handler,
+        http::uri::Scheme,
+        test::TestClient,
+        web::{LocalAddr, Path, RemoteAddr},
+    };
+
+    use super::*;
+
+    /// Call `route` with request having the original uri set the same way as the server does.
+    async fn get(route: &Route, uri: &str) -> Response {
+        let request = poem::http::Request::get(uri)
+            .body(hyper::Body::empty())
+            .unwrap();
+
+        route
+            .get_response(Request::from((
+                request,
+                LocalAddr::default(),
+                RemoteAddr::default(),
+                Scheme::HTTP,
+            )))
+            .await
+    }
+
+    mod users {
+        use poem::{handler, web::Path};
+
+        #[utoipa::path(get, path = "/users/{id}")]
+        #[handler]
+        pub async fn get_user(Path(id): Path<u64>) -> String {
+            format!("user {id}")
+        }
+
+        #[utoipa::path(delete, path = "/users/{id}")]
+        #[handler]
+        pub async fn delete_user(Path(id): Path<u64>) -> String {
+            format!("deleted {id}")
+        }
+    }
+
+    #[utoipa::path(get, path = "/pets/{id}")]
+    #[handler]
+    async fn get_pet(Path(id): Path<u64>) -> String {
+        format!("pet {id}")
+    }
+
+    #[tokio::test]
+    async fn route_handlers_by_their_path_attributes() {
+        let users = OpenApiRoute::new()
+            .routes(routes!(users::get_user, users::delete_user))
+            .routes(routes!(get_pet));
+        let (route, openapi) = OpenApiRoute::new().nest("/api", users).split_for_parts();
+
+        let client = TestClient::new(route);
+
+        let response = client.get("/api/users/1").send().await;
+        response.assert_status_is_ok();
+        response.assert_text("user 1").await;
+
+        let response = client.delete("/api/users/2").send().await;
+        response.assert_text("deleted 2").await;
+
+        client
+            .post("/api/users/2")
+            .send()
+            .await
+            .assert_status(StatusCode::METHOD_NOT_ALLOWED);
+
+        assert_eq!(
+            openapi.paths.paths.keys().collect::<Vec<_>>(),
+            ["/api/pets/{id}", "/api/users/{id}"]
+        );
+        assert!(openapi
+            .paths
+            .get_path_operation("/api/users/{id}", PathItemType::Delete)
+            .is_some_and(|operation| !operation.parameters.as_ref().unwrap().is_empty()));
+    }
+
+    #[tokio::test]
+    async fn serve_openapi_json_and_swagger_ui() {
+        let openapi = OpenApi::default();
+        let route = Route::new()
+            .at("/api-doc.json", openapi_json(&openapi))
+            .nest("/swagger-ui", swagger_ui(Config::from("/api-doc.json")));
+
+        let response = get(&route, "/api-doc.json").await;
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.content_type(), Some("application/json"));
+
+        let response = get(&route, "/swagger-ui").await;
+        assert_eq!(response.status(), StatusCode::FOUND);
+        assert_eq!(response.headers()["Location"], "/swagger-ui/");
+
+        let response = get(&route, "/swagger-ui/").await;
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.content_type(), Some("text/html"));
+
+        let response = get(&route, "/swagger-ui/swagger-initializer.js").await;
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(response
+            .into_body()
+            .into_string()
+            .await
+            .unwrap()
+            .contains("/api-doc.json"));
+
+        let response = get(&route, "/swagger-ui/missing.js").await;
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+}
@@ -0,0 +1,42 @@
+#![cfg(feature = "actix-web")]
+
+use actix_web::{dev::HttpServiceFactory, web};
+use utoipa::serve::MountDocs;
+
+use crate::RapiDoc;
+
+impl HttpServiceFactory for RapiDoc {
+    fn register(self, config: &mut actix_web::dev::AppService) {
+        web::scope("").mount_docs(self).register(config);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::{
+        http::StatusCode,
+        test::{call_service, init_service, read_body, TestRequest},
+        App,
+    };
+
+    use utoipa::openapi::OpenApi;
+
+    use super::*;
+
+    #[actix_web::test]
+    async fn serve_rapidoc_and_openapi() {
+        let app = init_service(
+            App::new().service(RapiDoc::with_openapi("/openapi.json", OpenApi::default())),
+        )
+        .await;
+
+        let response = call_service(&app, TestRequest::get().uri("/rapidoc").to_request()).await;
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(String::from_utf8_lossy(&read_body(response).await).contains("<rapi-doc"));
+
+        let response =
+            call_service(&app, TestRequest::get().uri("/openapi.json").to_request()).await;
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(String::from_utf8_lossy(&read_body(response).await).contains("\"paths\""));
+    }
+}
@@ -0,0 +1,441 @@
+#![cfg_attr(doc_cfg, feature(doc_cfg))]
+//! This crate implements necessary boiler plate code to serve [RapiDoc][rapidoc] via web server.
+//! It works as a bridge for serving the OpenAPI documentation created with [`utoipa`][utoipa]
+//! library in the RapiDoc.
+//!
+//! [utoipa]: <https://docs.rs/utoipa/>
+//! [rapidoc]: <https://rapidocweb.com/>
+//!
+//! **Currently implemented boiler plate for:**
+//!
+//! * **actix-web** `version >= 4`
+//! * **axum** `version >=0.6`
+//!
+//! Serving RapiDoc is framework independent thus RapiDoc can be served with other frameworks as
+//! well by serving the HTML of [`RapiDoc::to_html`] and the OpenAPI document as JSON.
+//!
+//! [`RapiDoc`] implements [`ServeOpenApi`] thus it can be mounted with [`utoipa::serve::MountDocs`].
+//! The **actix-web** and **axum** features of this crate are shorthands mounting it the same way.
+//!
+//! RapiDoc itself is loaded from _`unpkg.com`_ CDN by default. Use [`RapiDoc::custom_html`] to
+//! load it from elsewhere e.g. from self hosted files.
+//!
+//! # Features
+//!
+//! * **actix-web** Enables `actix-web` integration with pre-configured service factory serving
+//!   RapiDoc and the api doc.
+//! * **axum** Enables `axum` integration with pre-configured Router serving RapiDoc and the api doc.
+//!
+//! # Install
+//!
+//! ```toml
+//! [dependencies]
+//! utoipa-rapidoc = { version = "0.1", features = ["axum"] }
+//! ```
+//!
+//! # Examples
+//!
+//! _**Serve RapiDoc with api doc via `actix-web`.**_
+//! ```no_run
+//! # use actix_web::{App, HttpServer};
+//! # use utoipa::OpenApi;
+//! # use utoipa_rapidoc::RapiDoc;
+//! # #[derive(OpenApi)]
+//! # #[openapi()]
+//! # struct ApiDoc;
+//! # #[cfg(feature = "actix-web")]
+//! HttpServer::new(move || {
+//!     App::new().service(
+//!         RapiDoc::with_openapi("/api-docs/openapi.json", ApiDoc::openapi()).path("/rapidoc"),
+//!     )
+//! });
+//! ```
+//!
+//! _**Serve RapiDoc with api doc via `axum`.**_
+//! ```no_run
+//! # use utoipa::OpenApi;
+//! # use utoipa_rapidoc::{RapiDoc, RenderStyle, Theme};
+//! # #[derive(OpenApi)]
+//! # #[openapi()]
+//! # struct ApiDoc;
+//! # #[cfg(feature = "axum")]
+//! let app = axum::Router::<()>::new().merge(
+//!     RapiDoc::with_openapi("/api-docs/openapi.json", ApiDoc::openapi())
+//!         .path("/rapidoc")
+//!         .theme(Theme::Dark)
+//!         .render_style(RenderStyle::View)
+//!         .allow_try(false),
+//! );
+//! ```
+use std::{borrow::Cow, fmt::Display};
+
+use utoipa::{
+    openapi::OpenApi,
+    serve::{
+        auth::Guard,
+        csp::{self, Nonce},
+        Document, ServeOpenApi,
+    },
+};
+
+mod actix;
+mod axum;
+
+const DEFAULT_HTML: &str = r#"<!doctype html>
+<html>
+  <head>
+    <meta charset="utf-8">
+    <title>$title</title>
+    <script type="module" src="https://unpkg.com/rapidoc/dist/rapidoc-min.js"></script>
+  </head>
+  <body>
+    <rapi-doc spec-url="$specUrl"$attributes></rapi-doc>
+  </body>
+</html>
+"#;
+
+/// RapiDoc serving the OpenAPI document from given spec url.
+///
+/// The HTML of RapiDoc is created from a template where _`$specUrl`_, _`$title`_ and
+/// _`$attributes`_ are replaced with the spec url, the page title and the attributes of the
+/// _`<rapi-doc>`_ element created from the typed configuration e.g. [`RapiDoc::theme`].
+///
+/// # Examples
+///
+/// _**Create RapiDoc HTML for api doc served elsewhere.**_
+/// ```rust
+/// # use utoipa_rapidoc::{RapiDoc, Theme};
+/// let html = RapiDoc::new("/api-docs/openapi.json")
+///     .theme(Theme::Dark)
+///     .to_html();
+///
+/// assert!(html.contains(r#"spec-url="/api-docs/openapi.json" theme="dark""#));
+/// ```
+#[non_exhaustive]
+#[derive(Clone)]
+pub struct RapiDoc {
+    path: Cow<'static, str>,
+    spec_url: Cow<'static, str>,
+    html: Cow<'static, str>,
+    title: Cow<'static, str>,
+    theme: Option<Theme>,
+    render_style: Option<RenderStyle>,
+    allow_try: Option<bool>,
+    openapi: Option<OpenApi>,
+    nonce: Option<Nonce>,
+    guard: Option<Guard>,
+}
+
+impl RapiDoc {
+    /// Construct a new [`RapiDoc`] loading the OpenAPI document from `spec_url`. The document is
+    /// expected to be served elsewhere.
+    ///
+    /// RapiDoc is served at _`"/rapidoc"`_ by default with framework integrations. Use
+    /// [`RapiDoc::path`] to change it.
+    pub fn new<U: Into<Cow<'static, str>>>(spec_url: U) -> Self {
+        Self {
+            path: Cow::Borrowed("/rapidoc"),
+            spec_url: spec_url.into(),
+            html: Cow::Borrowed(DEFAULT_HTML),
+            title: Cow::Borrowed("RapiDoc"),
+            theme: None,
+            render_style: None,
+            allow_try: None,
+            openapi: None,
+            nonce: None,
+            guard: None,
+        }
+    }
+
+    /// Construct a new [`RapiDoc`] serving given [`OpenApi`] as JSON from `spec_url` with
+    /// framework integrations.
+    pub fn with_openapi<U: Into<Cow<'static, str>>>(spec_url: U, openapi: OpenApi) -> Self {
+        Self {
+            openapi: Some(openapi),
+            ..Self::new(spec_url)
+        }
+    }
+
+    /// Set `path` where RapiDoc is served with framework integrations e.g. _`"/rapidoc"`_.
+    pub fn path<P: Into<Cow<'static, str>>>(mut self, path: P) -> Self {
+        self.path = path.into();
+
+        self
+    }
+
+    /// Replace the default HTML template with `html`. The template must contain
+    /// _`<rapi-doc spec-url="$specUrl"$attributes>`_ element and load RapiDoc e.g. from self hosted
+    /// files. _`$title`_ is replaced with the page title if present.
+    pub fn custom_html<H: Into<Cow<'static, str>>>(mut self, html: H) -> Self {
+        self.html = html.into();
+
+        self
+    }
+
+    /// Set `title` of the RapiDoc page. Default title is _`RapiDoc`_.
+    pub fn title<T: Into<Cow<'static, str>>>(mut self, title: T) -> Self {
+        self.title = title.into();
+
+        self
+    }
+
+    /// Set color [`Theme`] of RapiDoc. Default theme is [`Theme::Light`].
+    pub fn theme(mut self, theme: Theme) -> Self {
+        self.theme = Some(theme);
+
+        self
+    }
+
+    /// Set [`RenderStyle`] of the operations. Default style is [`RenderStyle::View`].
+    pub fn render_style(mut self, render_style: RenderStyle) -> Self {
+        self.render_style = Some(render_style);
+
+        self
+    }
+
+    /// Set whether operations can be tried out from RapiDoc. Default value is `true`.
+    pub fn allow_try(mut self, allow_try: bool) -> Self {
+        self.allow_try = Some(allow_try);
+
+        self
+    }
+
+    /// Attach `nonce` to the _`<script>`_, _`<style>`_ and stylesheet _`<link>`_ tags of the HTML
+    /// template so that RapiDoc can be served under strict _`Content-Security-Policy`_.
+    ///
+    /// RapiDoc served with [`ServeOpenApi`] is responded with _`Content-Security-Policy`_ header
+    /// allowing only the scripts and styles with the nonce. Otherwise the same nonce must be
+    /// allowed by the header of the response e.g. _`script-src 'nonce-{nonce}'`_. Use
+    /// [`RapiDoc::nonce_fn`] to create a new nonce for each request instead.
+    pub fn nonce<N: Into<Nonce>>(mut self, nonce: N) -> Self {
+        self.nonce = Some(nonce.into());
+
+        self
+    }
+
+    /// Create a new nonce with `nonce_fn` for each request of the RapiDoc page and attach it to the
+    /// same tags as [`RapiDoc::nonce`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use utoipa::openapi::OpenApi;
+    /// # use utoipa_rapidoc::RapiDoc;
+    /// # fn random_nonce() -> String { String::from("rAnd0m") }
+    /// let rapidoc = RapiDoc::new("/api-docs/openapi.json").nonce_fn(random_nonce);
+    /// ```
+    pub fn nonce_fn<F: Fn() -> String + Send + Sync + 'static>(mut self, nonce_fn: F) -> Self {
+        self.nonce = Some(Nonce::new(nonce_fn));
+
+        self
+    }
+
+    /// Protect RapiDoc and the OpenAPI document served with it with [`Guard`]. Requests not passing
+    /// the guard are responded with _`401 Unauthorized`_ by the framework integrations.
+    ///
+    /// # Examples
+    ///
+    /// _**Require basic auth for accessing RapiDoc.**_
+    /// ```rust
+    /// # use utoipa::{openapi::OpenApi, serve::auth::Guard};
+    /// # use utoipa_rapidoc::RapiDoc;
+    /// let rapidoc = RapiDoc::with_openapi("/api-docs/openapi.json", OpenApi::default())
+    ///     .guard(Guard::basic("admin", "secret"));
+    /// ```
+    pub fn guard(mut self, guard: Guard) -> Self {
+        self.guard = Some(guard);
+
+        self
+    }
+
+    /// Create the HTML of RapiDoc from the template. Nonce created with [`RapiDoc::nonce_fn`] is
+    /// not known to the caller thus RapiDoc with it should be served with [`ServeOpenApi`].
+    pub fn to_html(&self) -> String {
+        self.html(self.nonce.as_ref().map(Nonce::create).as_deref())
+    }
+
+    /// Create the HTML of RapiDoc from the template with `nonce` attached to it.
+    fn html(&self, nonce: Option<&str>) -> String {
+        let attributes = [
+            ("theme", self.theme.as_ref().map(ToString::to_string)),
+            (
+                "render-style",
+                self.render_style.as_ref().map(ToString::to_string),
+            ),
+            (
+                "allow-try",
+                self.allow_try.map(|allow_try| allow_try.to_string()),
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(name, value)| value.map(|value| format!(r#" {name}="{value}""#)))
+        .collect::<String>();
+
+        let html = match nonce {
+            Some(nonce) => Cow::Owned(csp::attach_nonce(&self.html, nonce)),
+            None => Cow::Borrowed(self.html.as_ref()),
+        };
+
+        html.replace("$specUrl", &csp::escape_html(&self.spec_url))
+            .replace("$title", &csp::escape_html(&self.title))
+            .replace("$attributes", &attributes)
+    }
+}
+
+impl ServeOpenApi for RapiDoc {
+    fn paths(&self) -> Vec<Cow<'static, str>> {
+        let mut paths = vec![self.path.clone()];
+        if self.openapi.is_some() {
+            paths.push(self.spec_url.clone());
+        }
+
+        paths
+    }
+
+    fn serve(&self, path: &str) -> Option<Document> {
+        if path == self.path {
+            let nonce = self.nonce.as_ref().map(Nonce::create);
+            let document = Document::html(self.html(nonce.as_deref()));
+
+            return Some(match nonce {
+                Some(nonce) => document.content_security_policy(&nonce),
+                None => document,
+            });
+        }
+
+        self.openapi
+            .as_ref()
+            .filter(|_| path == self.spec_url)
+            .map(Document::json)
+    }
+
+    fn guard(&self) -> Option<&Guard> {
+        self.guard.as_ref()
+    }
+}
+
+/// Color theme of [`RapiDoc`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Theme {
+    /// Light theme.
+    Light,
+    /// Dark theme.
+    Dark,
+}
+
+impl Display for Theme {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Light => f.write_str("light"),
+            Self::Dark => f.write_str("dark"),
+        }
+    }
+}
+
+/// Render style of the operations in [`RapiDoc`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum RenderStyle {
+    /// Operations are rendered as a single page document to read.
+    Read,
+    /// Operations are rendered as expandable list.
+    View,
+    /// Only the selected operation is rendered.
+    Focused,
+}
+
+impl Display for RenderStyle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Read => f.write_str("read"),
+            Self::View => f.write_str("view"),
+            Self::Focused => f.write_str("focused"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rapidoc_html_with_default_config() {
+        let html = RapiDoc::new("/api-docs/openapi.json").to_html();
+
+        assert!(html.contains("<title>RapiDoc</title>"));
+        assert!(html.contains(r#"<rapi-doc spec-url="/api-docs/openapi.json"></rapi-doc>"#));
+    }
+
+    #[test]
+    fn rapidoc_html_with_typed_config() {
+        let html = RapiDoc::new("/api-docs/openapi.json?v=\"1\"")
+            .title("Acme API")
+            .theme(Theme::Dark)
+            .render_style(RenderStyle::Focused)
+            .allow_try(false)
+            .to_html();
+
+        assert!(html.contains("<title>Acme API</title>"));
+        assert!(html.contains(
+            r#"<rapi-doc spec-url="/api-docs/openapi.json?v=&quot;1&quot;" theme="dark" render-style="focused" allow-try="false"></rapi-doc>"#
+        ));
+    }
+
+    #[test]
+    fn rapidoc_html_with_nonce() {
+        let html = RapiDoc::new("/api-docs/openapi.json")
+            .nonce("n0nce")
+            .to_html();
+
+        assert!(html.contains(r#"<script nonce="n0nce" type="module" src="#));
+    }
+
+    #[test]
+    fn serve_rapidoc_with_new_nonce_for_each_request() {
+        let counter = std::sync::atomic::AtomicUsize::new(0);
+        let rapidoc = RapiDoc::new("/api-docs/openapi.json").nonce_fn(move || {
+            let count = counter.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+            format!("n{count}")
+        });
+
+        assert!(rapidoc.to_html().contains(r#"<script nonce="n0""#));
+        let document = rapidoc.serve("/rapidoc").unwrap();
+        assert!(String::from_utf8_lossy(&document.body).contains(r#"<script nonce="n1""#));
+        assert_eq!(
+            document.headers,
+            [(
+                "content-security-policy",
+                "script-src 'nonce-n1'; style-src 'nonce-n1'".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn serve_rapidoc_and_openapi() {
+        let rapidoc = RapiDoc::with_openapi("/openapi.json", OpenApi::default()).path("/docs");
+
+        assert_eq!(rapidoc.paths(), ["/docs", "/openapi.json"]);
+        let html = rapidoc.serve("/docs").unwrap();
+        assert!(String::from_utf8_lossy(&html.body).contains("<rapi-doc"));
+        assert_eq!(
+            rapidoc.serve("/openapi.json").unwrap().content_type,
+            "application/json"
+        );
+
+        let rapidoc = RapiDoc::new("/openapi.json");
+        assert_eq!(rapidoc.paths(), ["/rapidoc"]);
+        assert!(rapidoc.serve("/openapi.json").is_none());
+    }
+
+    #[test]
+    fn rapidoc_custom_html() {
+        let html = RapiDoc::new("/openapi.json")
+            .custom_html(r#"<rapi-doc spec-url="$specUrl"$attributes></rapi-doc>"#)
+            .allow_try(true)
+            .to_html();
+
+        assert_eq!(
+            html,
+            r#"<rapi-doc spec-url="/openapi.json" allow-try="true"></rapi-doc>"#
+        );
+    }
+}
@@ -0,0 +1,51 @@
+#![cfg(feature = "actix-web")]
+
+use actix_web::{dev::HttpServiceFactory, web};
+use utoipa::serve::MountDocs;
+
+use crate::Redoc;
+
+impl HttpServiceFactory for Redoc {
+    fn register(self, config: &mut actix_web::dev::AppService) {
+        web::scope("").mount_docs(self).register(config);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::{
+        http::StatusCode,
+        test::{call_service, init_service, read_body, TestRequest},
+        App,
+    };
+
+    use utoipa::openapi::OpenApi;
+
+    use super::*;
+
+    #[actix_web::test]
+    async fn serve_redoc_with_embedded_openapi() {
+        let app = init_service(App::new().service(Redoc::new(OpenApi::default()))).await;
+
+        let response = call_service(&app, TestRequest::get().uri("/redoc").to_request()).await;
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(String::from_utf8_lossy(&read_body(response).await).contains("\"paths\""));
+    }
+
+    #[actix_web::test]
+    async fn serve_redoc_and_openapi() {
+        let app =
+            init_service(App::new().service(Redoc::with_url("/openapi.json", OpenApi::default())))
+                .await;
+
+        let response = call_service(&app, TestRequest::get().uri("/redoc").to_request()).await;
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(String::from_utf8_lossy(&read_body(response).await)
+            .contains(r#"Redoc.init("/openapi.json", {}, "#));
+
+        let response =
+            call_service(&app, TestRequest::get().uri("/openapi.json").to_request()).await;
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(String::from_utf8_lossy(&read_body(response).await).contains("\"paths\""));
+    }
+}
@@ -0,0 +1,82 @@
+#![cfg(feature = "axum")]
+
+use axum::{body::HttpBody, Router};
+use utoipa::serve::MountDocs;
+
+use crate::Redoc;
+
+impl<S, B> From<Redoc> for Router<S, B>
+where
+    S: Clone + Send + Sync + 'static,
+    B: HttpBody + Send + 'static,
+{
+    fn from(redoc: Redoc) -> Self {
+        Router::new().mount_docs(redoc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{
+        body::Body,
+        http::{header, Request, StatusCode},
+    };
+    use tower::ServiceExt;
+    use utoipa::{openapi::OpenApi, serve::auth::Guard};
+
+    use super::*;
+
+    async fn get(router: Router, uri: &str) -> (StatusCode, String) {
+        let response = router
+            .oneshot(Request::get(uri).body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        let status = response.status();
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+
+        (status, String::from_utf8(body.to_vec()).unwrap())
+    }
+
+    #[tokio::test]
+    async fn serve_redoc_and_openapi() {
+        let router =
+            Router::new().merge(Redoc::with_url("/openapi.json", OpenApi::default()).path("/docs"));
+
+        let (status, html) = get(router.clone(), "/docs").await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(html.contains(r#"Redoc.init("/openapi.json", {}, "#));
+
+        let (status, json) = get(router, "/openapi.json").await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(json.contains("\"paths\""));
+    }
+
+    #[tokio::test]
+    async fn serve_guarded_redoc_and_openapi() {
+        let router = Router::new().merge(
+            Redoc::with_url("/openapi.json", OpenApi::default())
+                .path("/docs")
+                .guard(Guard::bearer("token")),
+        );
+
+        assert_eq!(
+            get(router.clone(), "/docs").await.0,
+            StatusCode::UNAUTHORIZED
+        );
+        assert_eq!(
+            get(router.clone(), "/openapi.json").await.0,
+            StatusCode::UNAUTHORIZED
+        );
+
+        let response = router
+            .oneshot(
+                Request::get("/openapi.json")
+                    .header(header::AUTHORIZATION, "Bearer token")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+}
@@ -0,0 +1,514 @@
+#![cfg_attr(doc_cfg, feature(doc_cfg))]
+//! This crate implements necessary boiler plate code to serve [Redoc][redoc] via web server. It
+//! works as a bridge for serving the OpenAPI documentation created with [`utoipa`][utoipa] library
+//! in the Redoc.
+//!
+//! [utoipa]: <https://docs.rs/utoipa/>
+//! [redoc]: <https://redocly.com/>
+//!
+//! **Currently implemented boiler plate for:**
+//!
+//! * **actix-web** `version >= 4`
+//! * **axum** `version >=0.6`
+//!
+//! By default the OpenAPI document is embedded to the HTML of Redoc thus the page is self contained
+//! and can be served with any framework as is by serving the HTML of [`Redoc::to_html`]. Use
+//! [`Redoc::with_url`] to serve the OpenAPI document from its own url instead.
+//!
+//! [`Redoc`] implements [`ServeOpenApi`] thus it can be mounted with [`utoipa::serve::MountDocs`].
+//! The **actix-web** and **axum** features of this crate are shorthands mounting it the same way.
+//!
+//! Redoc itself is loaded from _`cdn.redoc.ly`_ by default. Use [`Redoc::custom_html`] to load it
+//! from elsewhere e.g. from self hosted files.
+//!
+//! # Features
+//!
+//! * **actix-web** Enables `actix-web` integration with pre-configured service factory serving
+//!   Redoc and the api doc.
+//! * **axum** Enables `axum` integration with pre-configured Router serving Redoc and the api doc.
+//!
+//! # Install
+//!
+//! ```toml
+//! [dependencies]
+//! utoipa-redoc = { version = "0.1", features = ["axum"] }
+//! ```
+//!
+//! # Examples
+//!
+//! _**Serve Redoc via `actix-web`.**_
+//! ```no_run
+//! # use actix_web::{App, HttpServer};
+//! # use utoipa::OpenApi;
+//! # use utoipa_redoc::Redoc;
+//! # #[derive(OpenApi)]
+//! # #[openapi()]
+//! # struct ApiDoc;
+//! # #[cfg(feature = "actix-web")]
+//! HttpServer::new(move || App::new().service(Redoc::new(ApiDoc::openapi()).path("/redoc")));
+//! ```
+//!
+//! _**Serve Redoc with api doc at its own url via `axum`.**_
+//! ```no_run
+//! # use utoipa::OpenApi;
+//! # use utoipa_redoc::{Config, Redoc};
+//! # #[derive(OpenApi)]
+//! # #[openapi()]
+//! # struct ApiDoc;
+//! # #[cfg(feature = "axum")]
+//! let app = axum::Router::<()>::new().merge(
+//!     Redoc::with_url("/api-docs/openapi.json", ApiDoc::openapi())
+//!         .path("/redoc")
+//!         .title("Acme API")
+//!         .config(Config::default().hide_download_button(true).expand_responses("200,201")),
+//! );
+//! ```
+use std::borrow::Cow;
+
+use serde::Serialize;
+use utoipa::{
+    openapi::OpenApi,
+    serve::{
+        auth::Guard,
+        csp::{self, Nonce},
+        Document, ServeOpenApi,
+    },
+};
+
+mod actix;
+mod axum;
+
+const DEFAULT_HTML: &str = r#"<!DOCTYPE html>
+<html>
+  <head>
+    <title>$title</title>
+    <meta charset="utf-8"/>
+    <meta name="viewport" content="width=device-width, initial-scale=1">
+    <style>
+      body {
+        margin: 0;
+        padding: 0;
+      }
+    </style>
+  </head>
+  <body>
+    <div id="redoc-container"></div>
+    <script src="https://cdn.redoc.ly/redoc/latest/bundles/redoc.standalone.js"></script>
+    <script>
+      Redoc.init($spec, $config, document.getElementById("redoc-container"));
+    </script>
+  </body>
+</html>
+"#;
+
+/// Redoc serving the OpenAPI document either embedded to the HTML or from given spec url.
+///
+/// The HTML of Redoc is created from a template where _`$spec`_, _`$config`_ and _`$title`_ are
+/// replaced with the embedded OpenAPI document or its url, the [`Config`] as JSON and the page
+/// title.
+///
+/// # Examples
+///
+/// _**Create self contained Redoc HTML.**_
+/// ```rust
+/// # use utoipa::openapi::{Info, OpenApi, Paths};
+/// # use utoipa_redoc::Redoc;
+/// let html = Redoc::new(OpenApi::new(Info::new("Acme API", "1.0.0"), Paths::new())).to_html();
+///
+/// assert!(html.contains(r#""title":"Acme API""#));
+/// ```
+#[non_exhaustive]
+#[derive(Clone)]
+pub struct Redoc {
+    path: Cow<'static, str>,
+    spec_url: Option<Cow<'static, str>>,
+    openapi: OpenApi,
+    html: Cow<'static, str>,
+    title: Cow<'static, str>,
+    config: Config,
+    nonce: Option<Nonce>,
+    guard: Option<Guard>,
+}
+
+impl Redoc {
+    /// Construct a new [`Redoc`] with given [`OpenApi`] embedded to the HTML.
+    ///
+    /// Redoc is served at _`"/redoc"`_ by default with framework integrations. Use
+    /// [`Redoc::path`] to change it.
+    pub fn new(openapi: OpenApi) -> Self {
+        Self {
+            path: Cow::Borrowed("/redoc"),
+            spec_url: None,
+            openapi,
+            html: Cow::Borrowed(DEFAULT_HTML),
+            title: Cow::Borrowed("Redoc"),
+            config: Config::default(),
+            nonce: None,
+            guard: None,
+        }
+    }
+
+    /// Construct a new [`Redoc`] loading given [`OpenApi`] from `spec_url`. The [`OpenApi`] is
+    /// served as JSON from the `spec_url` with framework integrations.
+    pub fn with_url<U: Into<Cow<'static, str>>>(spec_url: U, openapi: OpenApi) -> Self {
+        Self {
+            spec_url: Some(spec_url.into()),
+            ..Self::new(openapi)
+        }
+    }
+
+    /// Set `path` where Redoc is served with framework integrations e.g. _`"/redoc"`_.
+    pub fn path<P: Into<Cow<'static, str>>>(mut self, path: P) -> Self {
+        self.path = path.into();
+
+        self
+    }
+
+    /// Replace the default HTML template with `html`. The template must load Redoc e.g. from self
+    /// hosted files and call _`Redoc.init($spec, $config, element)`_. _`$title`_ is replaced with
+    /// the page title if present.
+    pub fn custom_html<H: Into<Cow<'static, str>>>(mut self, html: H) -> Self {
+        self.html = html.into();
+
+        self
+    }
+
+    /// Set `title` of the Redoc page. Default title is _`Redoc`_.
+    pub fn title<T: Into<Cow<'static, str>>>(mut self, title: T) -> Self {
+        self.title = title.into();
+
+        self
+    }
+
+    /// Set [`Config`] of Redoc.
+    pub fn config(mut self, config: Config) -> Self {
+        self.config = config;
+
+        self
+    }
+
+    /// Attach `nonce` to the _`<script>`_, _`<style>`_ and stylesheet _`<link>`_ tags of the HTML
+    /// template so that Redoc can be served under strict _`Content-Security-Policy`_.
+    ///
+    /// Redoc served with [`ServeOpenApi`] is responded with _`Content-Security-Policy`_ header
+    /// allowing only the scripts and styles with the nonce. Otherwise the same nonce must be
+    /// allowed by the header of the response e.g. _`script-src 'nonce-{nonce}'`_. Use
+    /// [`Redoc::nonce_fn`] to create a new nonce for each request instead.
+    pub fn nonce<N: Into<Nonce>>(mut self, nonce: N) -> Self {
+        self.nonce = Some(nonce.into());
+
+        self
+    }
+
+    /// Create a new nonce with `nonce_fn` for each request of the Redoc page and attach it to the
+    /// same tags as [`Redoc::nonce`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use utoipa::openapi::OpenApi;
+    /// # use utoipa_redoc::Redoc;
+    /// # fn random_nonce() -> String { String::from("rAnd0m") }
+    /// let redoc = Redoc::new(OpenApi::default()).nonce_fn(random_nonce);
+    /// ```
+    pub fn nonce_fn<F: Fn() -> String + Send + Sync + 'static>(mut self, nonce_fn: F) -> Self {
+        self.nonce = Some(Nonce::new(nonce_fn));
+
+        self
+    }
+
+    /// Protect Redoc and the OpenAPI document served with it with [`Guard`]. Requests not passing
+    /// the guard are responded with _`401 Unauthorized`_ by the framework integrations.
+    ///
+    /// # Examples
+    ///
+    /// _**Require basic auth for accessing Redoc.**_
+    /// ```rust
+    /// # use utoipa::{openapi::OpenApi, serve::auth::Guard};
+    /// # use utoipa_redoc::Redoc;
+    /// let redoc = Redoc::new(OpenApi::default())
+    ///     .guard(Guard::basic("admin", "secret"));
+    /// ```
+    pub fn guard(mut self, guard: Guard) -> Self {
+        self.guard = Some(guard);
+
+        self
+    }
+
+    /// Create the HTML of Redoc from the template. Nonce created with [`Redoc::nonce_fn`] is
+    /// not known to the caller thus Redoc with it should be served with [`ServeOpenApi`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the [`OpenApi`] cannot be serialized to JSON.
+    pub fn to_html(&self) -> String {
+        self.html(self.nonce.as_ref().map(Nonce::create).as_deref())
+    }
+
+    /// Create the HTML of Redoc from the template with `nonce` attached to it.
+    fn html(&self, nonce: Option<&str>) -> String {
+        let spec = match &self.spec_url {
+            Some(spec_url) => serde_json::to_string(spec_url),
+            None => serde_json::to_string(&self.openapi),
+        }
+        .expect("OpenApi should serialize to JSON");
+        let config = serde_json::to_string(&self.config).expect("Config should serialize to JSON");
+
+        let html = match nonce {
+            Some(nonce) => Cow::Owned(csp::attach_nonce(&self.html, nonce)),
+            None => Cow::Borrowed(self.html.as_ref()),
+        };
+
+        render(
+            &html,
+            &[
+                ("$title", &csp::escape_html(&self.title)),
+                ("$spec", &escape_script(&spec)),
+                ("$config", &escape_script(&config)),
+            ],
+        )
+    }
+}
+
+impl ServeOpenApi for Redoc {
+    fn paths(&self) -> Vec<Cow<'static, str>> {
+        let mut paths = vec![self.path.clone()];
+        paths.extend(self.spec_url.clone());
+
+        paths
+    }
+
+    fn serve(&self, path: &str) -> Option<Document> {
+        if path == self.path {
+            let nonce = self.nonce.as_ref().map(Nonce::create);
+            let document = Document::html(self.html(nonce.as_deref()));
+
+            return Some(match nonce {
+                Some(nonce) => document.content_security_policy(&nonce),
+                None => document,
+            });
+        }
+
+        self.spec_url
+            .as_ref()
+            .filter(|spec_url| path == *spec_url)
+            .map(|_| Document::json(&self.openapi))
+    }
+
+    fn guard(&self) -> Option<&Guard> {
+        self.guard.as_ref()
+    }
+}
+
+/// Object used to alter [Redoc settings][settings].
+///
+/// Only the settings set are serialized thus Redoc uses its defaults for the rest.
+///
+/// # Examples
+///
+/// ```rust
+/// # use utoipa_redoc::Config;
+/// let config = Config::default()
+///     .hide_download_button(true)
+///     .expand_responses("all")
+///     .required_props_first(true);
+/// ```
+///
+/// [settings]: <https://redocly.com/docs/redoc/config/>
+#[non_exhaustive]
+#[derive(Serialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct Config {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    hide_download_button: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    expand_responses: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    hide_hostname: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    native_scrollbars: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    path_in_middle_panel: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    required_props_first: Option<bool>,
+}
+
+impl Config {
+    /// Set `true` to hide the button for downloading the OpenAPI document.
+    pub fn hide_download_button(mut self, hide_download_button: bool) -> Self {
+        self.hide_download_button = Some(hide_download_button);
+
+        self
+    }
+
+    /// Set responses expanded by default. Value is either _`"all"`_ or comma separated list of
+    /// status codes e.g. _`"200,201"`_.
+    pub fn expand_responses<S: Into<String>>(mut self, expand_responses: S) -> Self {
+        self.expand_responses = Some(expand_responses.into());
+
+        self
+    }
+
+    /// Set `true` to hide the protocol and hostname of the operation urls.
+    pub fn hide_hostname(mut self, hide_hostname: bool) -> Self {
+        self.hide_hostname = Some(hide_hostname);
+
+        self
+    }
+
+    /// Set `true` to use native scrollbars instead of the custom ones.
+    pub fn native_scrollbars(mut self, native_scrollbars: bool) -> Self {
+        self.native_scrollbars = Some(native_scrollbars);
+
+        self
+    }
+
+    /// Set `true` to show the path of the operation in the middle panel instead of the right one.
+    pub fn path_in_middle_panel(mut self, path_in_middle_panel: bool) -> Self {
+        self.path_in_middle_panel = Some(path_in_middle_panel);
+
+        self
+    }
+
+    /// Set `true` to show required properties first ordered the same way as in the _`required`_
+    /// array.
+    pub fn required_props_first(mut self, required_props_first: bool) -> Self {
+        self.required_props_first = Some(required_props_first);
+
+        self
+    }
+}
+
+/// Replace the `variables` of the `template` in single pass thus values containing variable names
+/// e.g. descriptions of the OpenAPI document are left as is.
+fn render(template: &str, variables: &[(&str, &str)]) -> String {
+    let mut html = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(index) = rest.find('$') {
+        html.push_str(&rest[..index]);
+        rest = &rest[index..];
+
+        match variables
+            .iter()
+            .find(|(variable, _)| rest.starts_with(variable))
+        {
+            Some((variable, value)) => {
+                html.push_str(value);
+                rest = &rest[variable.len()..];
+            }
+            None => {
+                html.push('$');
+                rest = &rest[1..];
+            }
+        }
+    }
+    html.push_str(rest);
+
+    html
+}
+
+/// Escape JSON embedded to _`<script>`_ element so that it cannot close the element.
+fn escape_script(json: &str) -> String {
+    json.replace("</", "<\\/")
+}
+
+#[cfg(test)]
+mod tests {
+    use utoipa::openapi::{Info, Paths};
+
+    use super::*;
+
+    fn openapi() -> OpenApi {
+        OpenApi::new(Info::new("</script> $config API", "1.0.0"), Paths::new())
+    }
+
+    #[test]
+    fn redoc_html_with_embedded_openapi() {
+        let html = Redoc::new(openapi()).to_html();
+
+        assert!(html.contains("<title>Redoc</title>"));
+        assert!(html.contains(r#""title":"<\/script> $config API""#));
+        assert!(html.contains(", {}, document.getElementById"));
+    }
+
+    #[test]
+    fn redoc_html_with_spec_url_and_config() {
+        let html = Redoc::with_url("/api-docs/openapi.json", openapi())
+            .title("Acme API")
+            .config(
+                Config::default()
+                    .hide_download_button(true)
+                    .expand_responses("200,201"),
+            )
+            .to_html();
+
+        assert!(html.contains("<title>Acme API</title>"));
+        assert!(html.contains(
+            r#"Redoc.init("/api-docs/openapi.json", {"hideDownloadButton":true,"expandResponses":"200,201"}, "#
+        ));
+    }
+
+    #[test]
+    fn redoc_html_with_nonce() {
+        let html = Redoc::new(openapi()).nonce("n0nce").to_html();
+
+        assert!(html.contains(r#"<style nonce="n0nce">"#));
+        assert!(html.contains(r#"<script nonce="n0nce" src="#));
+        assert!(html.contains("<script nonce=\"n0nce\">\n      Redoc.init("));
+    }
+
+    #[test]
+    fn serve_redoc_with_new_nonce_for_each_request() {
+        let counter = std::sync::atomic::AtomicUsize::new(0);
+        let redoc = Redoc::new(openapi()).nonce_fn(move || {
+            let count = counter.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+            format!("n{count}")
+        });
+
+        assert!(redoc.to_html().contains(r#"<script nonce="n0""#));
+        let document = redoc.serve("/redoc").unwrap();
+        assert!(String::from_utf8_lossy(&document.body).contains(r#"<script nonce="n1""#));
+        assert_eq!(
+            document.headers,
+            [(
+                "content-security-policy",
+                "script-src 'nonce-n1'; style-src 'nonce-n1'".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn serve_redoc_and_openapi() {
+        let redoc = Redoc::new(openapi()).path("/docs");
+        assert_eq!(redoc.paths(), ["/docs"]);
+        assert!(
+            String::from_utf8_lossy(&redoc.serve("/docs").unwrap().body).contains("Redoc.init(")
+        );
+
+        let redoc = Redoc::with_url("/openapi.json", openapi());
+        assert_eq!(redoc.paths(), ["/redoc", "/openapi.json"]);
+        assert_eq!(
+            redoc.serve("/openapi.json").unwrap().content_type,
+            "application/json"
+        );
+        assert!(redoc.serve("/other.json").is_none());
+    }
+
+    #[test]
+    fn redoc_custom_html() {
+        let html = Redoc::with_url("/openapi.json", openapi())
+            .custom_html("<title>$title</title>Redoc.init($spec, $config)")
+            .config(Config::default().native_scrollbars(true))
+            .to_html();
+
+        assert_eq!(
+            html,
+            r#"<title>Redoc</title>Redoc.init("/openapi.json", {"nativeScrollbars":true})"#
+        );
+    }
+}
@@ -0,0 +1,180 @@
+#![cfg_attr(doc_cfg, feature(doc_cfg))]
+//! This crate implements bindings between [`utoipa`][utoipa] and [`salvo`][salvo] for serving the
+//! OpenAPI documentation and Swagger UI with [`RouterExt`] extension of [`salvo::Router`].
+//!
+//! Parameters and request body of the handlers are resolved from salvo extractors with
+//! **salvo_extras** feature of utoipa which this crate enables.
+//!
+//! [utoipa]: <https://docs.rs/utoipa/>
+//! [salvo]: <https://docs.rs/salvo/>
+//!
+//! # Install
+//!
+//! ```toml
+//! [dependencies]
+//! utoipa = "2"
+//! utoipa-salvo = "0.1"
+//! ```
+//!
+//! # Examples
+//!
+//! _**Serve OpenAPI document with Swagger UI.**_
+//! ```no_run
+//! # use salvo::{oapi::extract::PathParam, handler, Router};
+//! # use utoipa::OpenApi;
+//! # use utoipa_salvo::RouterExt;
+//! #[utoipa::path(get, path = "/users/{id}", responses((status = 200, description = "Get user")))]
+//! #[handler]
+//! async fn get_user(id: PathParam<u64>) -> String {
+//!     format!("user {}", id.into_inner())
+//! }
+//!
+//! #[derive(OpenApi)]
+//! #[openapi(paths(get_user))]
+//! struct ApiDoc;
+//!
+//! let router = Router::new()
+//!     .push(Router::with_path("users/<id>").get(get_user))
+//!     .openapi("/api-doc.json", &ApiDoc::openapi())
+//!     .swagger_ui(
+//!         "/swagger-ui",
+//!         utoipa_swagger_ui::Config::from("/api-doc.json"),
+//!     );
+//! ```
+use std::sync::Arc;
+
+use salvo::{
+    async_trait,
+    http::{header, StatusCode},
+    writer::Text,
+    Depot, FlowCtrl, Handler, Request, Response, Router,
+};
+use utoipa::openapi::OpenApi;
+use utoipa_swagger_ui::Config;
+
+/// Extension of [`salvo::Router`] for serving the OpenAPI document and Swagger UI.
+pub trait RouterExt {
+    /// Serve given [`OpenApi`] as JSON with _`GET`_ request to given `path` e.g.
+    /// _`"/api-doc/openapi.json"`_.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the [`OpenApi`] cannot be serialized to JSON.
+    fn openapi(self, path: &str, openapi: &OpenApi) -> Self;
+
+    /// Serve Swagger UI under given `path` e.g. _`"/swagger-ui"`_ with given [`Config`]. Request
+    /// to the `path` itself is redirected to the `path` with trailing slash.
+    fn swagger_ui(self, path: &str, config: Config<'static>) -> Self;
+}
+
+impl RouterExt for Router {
+    fn openapi(self, path: &str, openapi: &OpenApi) -> Self {
+        let json = openapi.to_json().expect("OpenApi should serialize to JSON");
+
+        self.push(Router::with_path(path.trim_start_matches('/')).get(OpenApiHandler(json)))
+    }
+
+    fn swagger_ui(self, path: &str, config: Config<'static>) -> Self {
+        let path = path.trim_matches('/');
+
+        self.push(
+            Router::with_path(format!("{path}/<**rest>")).get(SwaggerUiHandler(Arc::new(config))),
+        )
+    }
+}
+
+struct OpenApiHandler(String);
+
+#[async_trait]
+impl Handler for OpenApiHandler {
+    async fn handle(
+        &self,
+        _: &mut Request,
+        _: &mut Depot,
+        response: &mut Response,
+        _: &mut FlowCtrl,
+    ) {
+        response.render(Text::Json(self.0.clone()));
+    }
+}
+
+struct SwaggerUiHandler(Arc<Config<'static>>);
+
+#[async_trait]
+impl Handler for SwaggerUiHandler {
+    async fn handle(
+        &self,
+        request: &mut Request,
+        _: &mut Depot,
+        response: &mut Response,
+        _: &mut FlowCtrl,
+    ) {
+        serve_swagger_ui(request, response, Arc::clone(&self.0));
+    }
+}
+
+fn serve_swagger_ui(request: &Request, response: &mut Response, config: Arc<Config<'static>>) {
+    let tail = request.param::<String>("**rest").unwrap_or_default();
+    let path = request.uri().path();
+    if tail.is_empty() && !path.ends_with('/') {
+        response.status_code(StatusCode::FOUND);
+        let _ = response.add_header(header::LOCATION, format!("{path}/"), true);
+        return;
+    }
+
+    match utoipa_swagger_ui::serve(&tail, config) {
+        Ok(Some(file)) => {
+            let _ = response.add_header(header::CONTENT_TYPE, file.content_type, true);
+            let _ = response.write_body(file.bytes.to_vec());
+        }
+        Ok(None) => {
+            response.status_code(StatusCode::NOT_FOUND);
+        }
+        Err(error) => {
+            response.status_code(StatusCode::INTERNAL_SERVER_ERROR);
+            response.render(Text::Plain(error.to_string()));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use salvo::{
+        test::{ResponseExt, TestClient},
+        Service,
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    async fn serve_openapi_and_swagger_ui() {
+        let service = Service::new(
+            Router::new()
+                .openapi("/api-doc.json", &OpenApi::default())
+                .swagger_ui("/swagger-ui", Config::from("/api-doc.json")),
+        );
+
+        let mut response = TestClient::get("http://localhost/api-doc.json")
+            .send(&service)
+            .await;
+        assert_eq!(response.status_code, Some(StatusCode::OK));
+        assert!(response.take_string().await.unwrap().contains("\"paths\""));
+
+        let response = TestClient::get("http://localhost/swagger-ui")
+            .send(&service)
+            .await;
+        assert_eq!(response.status_code, Some(StatusCode::FOUND));
+        assert_eq!(response.headers()[header::LOCATION], "/swagger-ui/");
+
+        let response = TestClient::get("http://localhost/swagger-ui/")
+            .send(&service)
+            .await;
+        assert_eq!(response.status_code, Some(StatusCode::OK));
+        assert_eq!(response.headers()[header::CONTENT_TYPE], "text/html");
+
+        let response = TestClient::get("http://localhost/swagger-ui/missing.js")
+            .send(&service)
+            .await;
+        assert_eq!(response.status_code, Some(StatusCode::NOT_FOUND));
+    }
+}
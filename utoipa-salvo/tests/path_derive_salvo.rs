@@ -0,0 +1,101 @@
+use assert_json_diff::assert_json_eq;
+use salvo::oapi::extract::{JsonBody, PathParam, QueryParam};
+use serde::Deserialize;
+use serde_json::json;
+
+#[test]
+fn derive_path_params_and_query_from_salvo_extractors() {
+    #[utoipa::path(
+        get,
+        path = "/person/{id}",
+        params(
+            ("id", description = "Person id")
+        ),
+        responses(
+            (status = 200, description = "success response")
+        )
+    )]
+    #[allow(unused)]
+    async fn get_person(
+        id: PathParam<u32>,
+        name: QueryParam<String>,
+        age: QueryParam<i32, false>,
+    ) {
+    }
+
+    let path_item = <__path_get_person as utoipa::Path>::path_item(None);
+    let doc = serde_json::to_value(path_item).unwrap();
+    let parameters = doc.pointer("/get/parameters").unwrap();
+
+    assert_json_eq!(
+        parameters,
+        &json!([
+            {
+                "deprecated": false,
+                "description": "Person id",
+                "in": "path",
+                "name": "id",
+                "required": true,
+                "schema": {
+                    "format": "int32",
+                    "type": "integer",
+                },
+            },
+            {
+                "deprecated": false,
+                "in": "query",
+                "name": "name",
+                "required": true,
+                "schema": {
+                    "type": "string",
+                },
+            },
+            {
+                "deprecated": false,
+                "in": "query",
+                "name": "age",
+                "required": false,
+                "schema": {
+                    "format": "int32",
+                    "type": "integer",
+                },
+            },
+        ])
+    )
+}
+
+#[test]
+fn derive_path_request_body_from_salvo_json_body_extractor() {
+    #[derive(Deserialize, utoipa::ToSchema)]
+    #[allow(unused)]
+    struct Person {
+        name: String,
+    }
+
+    #[utoipa::path(
+        post,
+        path = "/person",
+        responses(
+            (status = 201, description = "created")
+        )
+    )]
+    #[allow(unused)]
+    async fn create_person(person: JsonBody<Person>) {}
+
+    let path_item = <__path_create_person as utoipa::Path>::path_item(None);
+    let doc = serde_json::to_value(path_item).unwrap();
+
+    assert_json_eq!(
+        doc.pointer("/post/requestBody").unwrap(),
+        &json!({
+            "content": {
+                "application/json": {
+                    "schema": {
+                        "$ref": "#/components/schemas/Person"
+                    }
+                }
+            },
+            "required": true
+        })
+    )
+}
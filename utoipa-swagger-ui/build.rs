@@ -10,28 +10,61 @@ use regex::Regex;
 use zip::{result::ZipError, ZipArchive};
 
 const SWAGGER_UI_DIST_ZIP: &str = "swagger-ui-4.14.0";
+const SWAGGER_UI_ZIP: &str = "SWAGGER_UI_ZIP";
 
 fn main() {
-    println!("cargo:rerun-if-changed=res/{}.zip", SWAGGER_UI_DIST_ZIP);
+    println!("cargo:rerun-if-env-changed={}", SWAGGER_UI_ZIP);
+
+    let swagger_ui_zip_path = match env::var_os(SWAGGER_UI_ZIP) {
+        Some(path) => PathBuf::from(path),
+        None => ["res", &format!("{}.zip", SWAGGER_UI_DIST_ZIP)]
+            .iter()
+            .collect::<PathBuf>(),
+    };
+    println!("cargo:rerun-if-changed={}", swagger_ui_zip_path.display());
+
+    let swagger_ui_zip = File::open(&swagger_ui_zip_path).unwrap_or_else(|error| {
+        panic!(
+            "failed to open Swagger UI zip: {}: {}",
+            swagger_ui_zip_path.display(),
+            error
+        )
+    });
+    let mut zip = ZipArchive::new(swagger_ui_zip).unwrap();
+    let swagger_ui_dist = get_root_dir_name(&mut zip).unwrap();
+
     println!(
         "cargo:rustc-env=UTOIPA_SWAGGER_UI_VERSION={}",
-        SWAGGER_UI_DIST_ZIP
+        swagger_ui_dist
     );
 
     let target_dir = env::var("OUT_DIR").unwrap();
     println!("cargo:rustc-env=UTOIPA_SWAGGER_DIR={}", &target_dir);
 
-    let swagger_ui_zip = File::open(
-        ["res", &format!("{}.zip", SWAGGER_UI_DIST_ZIP)]
+    extract_within_path(&mut zip, [&swagger_ui_dist, "dist"], &target_dir).unwrap();
+
+    replace_default_url_with_config(&target_dir, &swagger_ui_dist);
+
+    #[cfg(feature = "compression")]
+    compress_static_files(
+        &[&target_dir, &swagger_ui_dist, "dist"]
             .iter()
             .collect::<PathBuf>(),
     )
     .unwrap();
+}
 
-    let mut zip = ZipArchive::new(swagger_ui_zip).unwrap();
-    extract_within_path(&mut zip, [SWAGGER_UI_DIST_ZIP, "dist"], &target_dir).unwrap();
-
-    replace_default_url_with_config(&target_dir);
+/// Get name of the root directory of the Swagger UI release zip e.g. _`swagger-ui-4.14.0`_
+/// holding the _`dist`_ directory.
+fn get_root_dir_name(zip: &mut ZipArchive<File>) -> Result<String, ZipError> {
+    let file = zip.by_index(0)?;
+    file.enclosed_name()
+        .and_then(|path| path.iter().next())
+        .and_then(|name| name.to_str())
+        .map(ToString::to_string)
+        .ok_or(ZipError::InvalidArchive(
+            "missing Swagger UI root directory",
+        ))
 }
 
 fn extract_within_path<const N: usize>(
@@ -80,12 +113,12 @@ fn extract_within_path<const N: usize>(
     Ok(())
 }
 
-fn replace_default_url_with_config(target_dir: &str) {
+fn replace_default_url_with_config(target_dir: &str, swagger_ui_dist: &str) {
     let regex = Regex::new(r#"(?ms)url:.*deep.*true,"#).unwrap();
 
     let path = [
         target_dir,
-        SWAGGER_UI_DIST_ZIP,
+        swagger_ui_dist,
         "dist",
         "swagger-initializer.js",
     ]
@@ -99,3 +132,46 @@ fn replace_default_url_with_config(target_dir: &str) {
 
     fs::write(&path, replaced_swagger_initializer.as_ref()).unwrap();
 }
+
+/// Write gzip and brotli compressed variants next to the static Swagger UI files e.g.
+/// _`swagger-ui-bundle.js.gz`_ and _`swagger-ui-bundle.js.br`_. Files formatted at runtime are not
+/// compressed.
+#[cfg(feature = "compression")]
+fn compress_static_files(dist_dir: &std::path::Path) -> io::Result<()> {
+    use std::io::Write;
+
+    for entry in fs::read_dir(dist_dir)? {
+        let path = entry?.path();
+        let name = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .unwrap_or_default();
+        let compressible = [".js", ".css", ".html"]
+            .iter()
+            .any(|extension| name.ends_with(extension));
+        if !compressible || name == "index.html" || name == "swagger-initializer.js" {
+            continue;
+        }
+
+        let content = fs::read(&path)?;
+        let compressed_path = |extension: &str| {
+            let mut compressed_path = path.clone().into_os_string();
+            compressed_path.push(extension);
+            compressed_path
+        };
+
+        let mut gzip = flate2::write::GzEncoder::new(
+            File::create(compressed_path(".gz"))?,
+            flate2::Compression::best(),
+        );
+        gzip.write_all(&content)?;
+        gzip.finish()?;
+
+        let mut brotli =
+            brotli::CompressorWriter::new(File::create(compressed_path(".br"))?, 4096, 11, 22);
+        brotli.write_all(&content)?;
+        brotli.into_inner();
+    }
+
+    Ok(())
+}
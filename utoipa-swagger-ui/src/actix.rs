@@ -1,22 +1,31 @@
 #![cfg(feature = "actix-web")]
 
 use actix_web::{
-    dev::HttpServiceFactory, guard::Get, web, web::Data, HttpResponse, Resource,
-    Responder as ActixResponder,
+    dev::HttpServiceFactory,
+    guard::Get,
+    http::{header, Method},
+    web,
+    web::Data,
+    HttpRequest, HttpResponse, Resource,
 };
 
-use utoipa::openapi::OpenApi;
-
-use crate::{Config, SwaggerUi};
+use crate::{
+    auth::{self, Guard},
+    cache::{ApiDocBody, Format, Validators},
+    reload::ApiDocSource,
+    spec::{SpecDocument, SpecEndpoint},
+    Config, SwaggerUi,
+};
 
 impl HttpServiceFactory for SwaggerUi {
     fn register(self, config: &mut actix_web::dev::AppService) {
+        let guard = self.guard;
         let urls = self
             .urls
             .into_iter()
             .map(|url| {
                 let (url, openapi) = url;
-                register_api_doc_url_resource(url.url.as_ref(), openapi, config);
+                register_api_doc_url_resource(url.url.as_ref(), openapi, guard.clone(), config);
                 url
             })
             .collect::<Vec<_>>();
@@ -32,33 +41,190 @@ impl HttpServiceFactory for SwaggerUi {
             } else {
                 Config::new(urls)
             }))
+            .app_data(Data::new(guard))
             .to(serve_swagger_ui);
 
         HttpServiceFactory::register(swagger_resource, config);
     }
 }
 
-fn register_api_doc_url_resource(url: &str, api: OpenApi, config: &mut actix_web::dev::AppService) {
-    pub async fn get_api_doc(api_doc: web::Data<OpenApi>) -> impl ActixResponder {
-        HttpResponse::Ok().json(api_doc.as_ref())
+fn register_api_doc_url_resource(
+    url: &str,
+    api: ApiDocSource,
+    guard: Option<Guard>,
+    config: &mut actix_web::dev::AppService,
+) {
+    pub async fn get_api_doc(
+        request: HttpRequest,
+        api_doc: web::Data<ApiDocBody>,
+        guard: web::Data<Option<Guard>>,
+    ) -> HttpResponse {
+        if let Err(response) = authorize(&request, guard.as_ref().as_ref()).await {
+            return response;
+        }
+
+        let doc = api_doc.get();
+        if let Some(response) = not_modified(&request, &doc.validators, doc.validators.headers()) {
+            return response;
+        }
+
+        let mut response = HttpResponse::Ok();
+        response.content_type(api_doc.content_type());
+        for header in doc.validators.headers() {
+            response.insert_header(header);
+        }
+
+        response.body(doc.body.clone())
     }
 
     let url_resource = Resource::new(url)
         .guard(Get())
-        .app_data(Data::new(api))
+        .app_data(Data::new(ApiDocBody::new(&api, Format::Json)))
+        .app_data(Data::new(guard))
         .to(get_api_doc);
     HttpServiceFactory::register(url_resource, config);
 }
 
-async fn serve_swagger_ui(path: web::Path<String>, data: web::Data<Config<'_>>) -> HttpResponse {
-    match super::serve(&path.into_inner(), data.into_inner()) {
+impl HttpServiceFactory for SpecEndpoint {
+    fn register(self, config: &mut actix_web::dev::AppService) {
+        for document in self.into_documents() {
+            let mut resource =
+                Resource::new(document.path.as_str()).route(web::get().to(serve_spec_document));
+            if document.allow_origin.is_some() {
+                resource = resource.route(web::method(Method::OPTIONS).to(serve_spec_preflight));
+            }
+
+            HttpServiceFactory::register(resource.app_data(Data::new(document)), config);
+        }
+    }
+}
+
+async fn serve_spec_document(
+    request: HttpRequest,
+    document: web::Data<SpecDocument>,
+) -> HttpResponse {
+    if let Err(mut response) = authorize(&request, document.guard.as_ref()).await {
+        // Unauthorized response must be readable by the allowed origin as well.
+        for (name, value) in document.cors_headers(false) {
+            if let Ok(value) = header::HeaderValue::from_str(&value) {
+                response
+                    .headers_mut()
+                    .append(header::HeaderName::from_static(name), value);
+            }
+        }
+
+        return response;
+    }
+
+    if let Some(response) = not_modified(&request, &document.validators, document.headers(false)) {
+        return response;
+    }
+
+    let mut response = HttpResponse::Ok();
+    for header in document.headers(false) {
+        response.insert_header(header);
+    }
+
+    response.body(document.body.clone())
+}
+
+async fn serve_spec_preflight(document: web::Data<SpecDocument>) -> HttpResponse {
+    let mut response = HttpResponse::NoContent();
+    for header in document.headers(true) {
+        response.insert_header(header);
+    }
+
+    response.finish()
+}
+
+async fn serve_swagger_ui(
+    request: HttpRequest,
+    path: web::Path<String>,
+    data: web::Data<Config<'_>>,
+    guard: web::Data<Option<Guard>>,
+) -> HttpResponse {
+    if let Err(response) = authorize(&request, guard.as_ref().as_ref()).await {
+        return response;
+    }
+
+    let accept_encoding = request
+        .headers()
+        .get(header::ACCEPT_ENCODING)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or_default();
+    match super::serve_compressed(&path.into_inner(), data.into_inner(), accept_encoding) {
         Ok(swagger_file) => swagger_file
             .map(|file| {
-                HttpResponse::Ok()
-                    .content_type(file.content_type)
-                    .body(file.bytes.to_vec())
+                let policy = file.content_security_policy();
+                let mut response = HttpResponse::Ok();
+                response.content_type(file.content_type);
+                if let Some(encoding) = file.content_encoding {
+                    response
+                        .insert_header((header::CONTENT_ENCODING, encoding))
+                        .insert_header((header::VARY, "accept-encoding"));
+                }
+                if let Some(policy) = policy {
+                    response.insert_header((header::CONTENT_SECURITY_POLICY, policy));
+                }
+
+                response.body(file.bytes.to_vec())
             })
             .unwrap_or_else(|| HttpResponse::NotFound().finish()),
         Err(error) => HttpResponse::InternalServerError().body(error.to_string()),
     }
 }
+
+/// Create _`304 Not Modified`_ response with `headers` if the client of the `request` already has
+/// the document with the `validators`.
+fn not_modified(
+    request: &HttpRequest,
+    validators: &Validators,
+    headers: Vec<(&'static str, String)>,
+) -> Option<HttpResponse> {
+    let header = |name| {
+        request
+            .headers()
+            .get(name)
+            .and_then(|value| value.to_str().ok())
+    };
+    if !validators.is_not_modified(
+        header(header::IF_NONE_MATCH),
+        header(header::IF_MODIFIED_SINCE),
+    ) {
+        return None;
+    }
+
+    let mut response = HttpResponse::NotModified();
+    for header in headers {
+        response.insert_header(header);
+    }
+
+    Some(response.finish())
+}
+
+/// Check the `request` with the `guard` if present and create _`401 Unauthorized`_ response if it
+/// does not pass.
+async fn authorize(request: &HttpRequest, guard: Option<&Guard>) -> Result<(), HttpResponse> {
+    let guard = match guard {
+        Some(guard) => guard,
+        None => return Ok(()),
+    };
+
+    let headers = request
+        .headers()
+        .iter()
+        .map(|(name, value)| (name.as_str(), value.as_bytes()));
+    if guard
+        .authorize(auth::Request::new(request.path(), headers))
+        .await
+    {
+        return Ok(());
+    }
+
+    let mut response = HttpResponse::Unauthorized();
+    for header in guard.unauthorized_headers() {
+        response.insert_header(header);
+    }
+
+    Err(response.finish())
+}
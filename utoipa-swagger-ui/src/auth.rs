@@ -0,0 +1,11 @@
+//! Implements [`Guard`] for protecting Swagger UI and api docs e.g. in production.
+//!
+//! The guard is checked for each request before serving Swagger UI files or api docs with
+//! [`SwaggerUi::guard`][crate::SwaggerUi::guard] and
+//! [`SpecEndpoint::guard`][crate::SpecEndpoint::guard]. Requests not passing the guard are
+//! responded with _`401 Unauthorized`_.
+//!
+//! The [`Guard`] is shared with other documentation UIs through [`utoipa::serve::auth`] thus the
+//! same guard can protect e.g. Swagger UI and RapiDoc.
+
+pub use utoipa::serve::auth::{Guard, Request};
@@ -1,13 +1,21 @@
 #![cfg(feature = "axum")]
 
-use std::sync::Arc;
+use std::{convert::Infallible, sync::Arc};
 
 use axum::{
-    body::HttpBody, extract::Path, http::StatusCode, response::IntoResponse, routing, Extension,
-    Json, Router,
+    body::HttpBody,
+    extract::Path,
+    http::{header, HeaderMap, HeaderName, HeaderValue, StatusCode, Uri},
+    response::{IntoResponse, Response},
+    routing, Extension, Router,
 };
 
-use crate::{Config, SwaggerUi, Url};
+use crate::{
+    auth::{self, Guard},
+    cache::{ApiDocBody, Format, Validators},
+    spec::{SpecDocument, SpecEndpoint},
+    Config, SwaggerUi, Url,
+};
 
 impl<S, B> From<SwaggerUi> for Router<S, B>
 where
@@ -16,6 +24,7 @@ where
 {
     fn from(swagger_ui: SwaggerUi) -> Self {
         let urls_capacity = swagger_ui.urls.len();
+        let guard = swagger_ui.guard;
 
         let (router, urls) = swagger_ui.urls.into_iter().fold(
             (
@@ -24,10 +33,17 @@ where
             ),
             |(router, mut urls), url| {
                 let (url, openapi) = url;
+                let api_doc = ApiDocBody::new(&openapi, Format::Json);
+                let guard = guard.clone();
                 (
                     router.route(
                         url.url.as_ref(),
-                        routing::get(move || async { Json(openapi) }),
+                        routing::get(move |uri: Uri, headers: HeaderMap| async move {
+                            match authorize(guard.as_ref(), &uri, &headers).await {
+                                Ok(_) => serve_api_doc(&api_doc, &headers),
+                                Err(response) => response,
+                            }
+                        }),
                     ),
                     {
                         urls.push(url);
@@ -47,7 +63,9 @@ where
             Config::new(urls)
         };
 
-        let handler = routing::get(serve_swagger_ui).layer(Extension(Arc::new(config)));
+        let handler = routing::get(serve_swagger_ui)
+            .layer::<_, B, Infallible>(Extension(Arc::new(config)))
+            .layer::<_, B, Infallible>(Extension(guard));
         let path: &str = swagger_ui.path.as_ref();
         let slash_path = format!("{}/", path);
 
@@ -61,26 +79,160 @@ where
     }
 }
 
+impl<S, B> From<SpecEndpoint> for Router<S, B>
+where
+    S: Clone + Send + Sync + 'static,
+    B: HttpBody + Send + 'static,
+{
+    fn from(spec: SpecEndpoint) -> Self {
+        spec.into_documents()
+            .into_iter()
+            .fold(Router::<S, B>::new(), |router, document| {
+                let document = Arc::new(document);
+                let mut method_router = routing::get({
+                    let document = Arc::clone(&document);
+                    move |uri: Uri, headers: HeaderMap| async move {
+                        match authorize(document.guard.as_ref(), &uri, &headers).await {
+                            Ok(_) => serve_spec_document(&document, &headers, false),
+                            // Unauthorized response must be readable by the allowed origin as well.
+                            Err(response) => with_headers(response, document.cors_headers(false)),
+                        }
+                    }
+                });
+                if document.allow_origin.is_some() {
+                    let document = Arc::clone(&document);
+                    method_router = method_router.options(move |headers: HeaderMap| async move {
+                        serve_spec_document(&document, &headers, true)
+                    });
+                }
+
+                router.route(&document.path, method_router)
+            })
+    }
+}
+
+fn serve_api_doc(api_doc: &ApiDocBody, headers: &HeaderMap) -> Response {
+    let doc = api_doc.get();
+    let response = if is_not_modified(&doc.validators, headers) {
+        StatusCode::NOT_MODIFIED.into_response()
+    } else {
+        (
+            [(header::CONTENT_TYPE, api_doc.content_type())],
+            doc.body.clone(),
+        )
+            .into_response()
+    };
+
+    with_headers(response, doc.validators.headers())
+}
+
+fn serve_spec_document(document: &SpecDocument, headers: &HeaderMap, preflight: bool) -> Response {
+    let response = if preflight {
+        StatusCode::NO_CONTENT.into_response()
+    } else if is_not_modified(&document.validators, headers) {
+        StatusCode::NOT_MODIFIED.into_response()
+    } else {
+        document.body.clone().into_response()
+    };
+
+    with_headers(response, document.headers(preflight))
+}
+
 async fn serve_swagger_ui(
     path: Option<Path<String>>,
+    uri: Uri,
+    headers: HeaderMap,
     Extension(state): Extension<Arc<Config<'static>>>,
-) -> impl IntoResponse {
+    Extension(guard): Extension<Option<Guard>>,
+) -> Response {
+    if let Err(response) = authorize(guard.as_ref(), &uri, &headers).await {
+        return response;
+    }
+
     let tail = match path.as_ref() {
         Some(tail) => tail,
         None => "",
     };
 
-    match super::serve(tail, state) {
+    let accept_encoding = headers
+        .get(header::ACCEPT_ENCODING)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or_default();
+    match super::serve_compressed(tail, state, accept_encoding) {
         Ok(file) => file
             .map(|file| {
-                (
+                let policy = file.content_security_policy();
+                let mut response = (
                     StatusCode::OK,
                     [("Content-Type", file.content_type)],
                     file.bytes,
                 )
-                    .into_response()
+                    .into_response();
+                if let Some(encoding) = file.content_encoding {
+                    response
+                        .headers_mut()
+                        .insert(header::CONTENT_ENCODING, HeaderValue::from_static(encoding));
+                    response
+                        .headers_mut()
+                        .insert(header::VARY, HeaderValue::from_static("accept-encoding"));
+                }
+                if let Some(policy) = policy.and_then(|policy| HeaderValue::from_str(&policy).ok())
+                {
+                    response
+                        .headers_mut()
+                        .insert(header::CONTENT_SECURITY_POLICY, policy);
+                }
+
+                response
             })
             .unwrap_or_else(|| StatusCode::NOT_FOUND.into_response()),
         Err(error) => (StatusCode::INTERNAL_SERVER_ERROR, error.to_string()).into_response(),
     }
 }
+
+/// Check whether client already has the document with the `validators` based on the request
+/// `headers`.
+fn is_not_modified(validators: &Validators, headers: &HeaderMap) -> bool {
+    let header = |name| headers.get(name).and_then(|value| value.to_str().ok());
+
+    validators.is_not_modified(
+        header(header::IF_NONE_MATCH),
+        header(header::IF_MODIFIED_SINCE),
+    )
+}
+
+/// Check the request with the `guard` if present and create _`401 Unauthorized`_ response if it
+/// does not pass.
+async fn authorize(guard: Option<&Guard>, uri: &Uri, headers: &HeaderMap) -> Result<(), Response> {
+    let guard = match guard {
+        Some(guard) => guard,
+        None => return Ok(()),
+    };
+
+    let headers = headers
+        .iter()
+        .map(|(name, value)| (name.as_str(), value.as_bytes()));
+    if guard
+        .authorize(auth::Request::new(uri.path(), headers))
+        .await
+    {
+        return Ok(());
+    }
+
+    Err(with_headers(
+        StatusCode::UNAUTHORIZED.into_response(),
+        guard.unauthorized_headers(),
+    ))
+}
+
+fn with_headers(mut response: Response, headers: Vec<(&'static str, String)>) -> Response {
+    for (name, value) in headers {
+        if let Ok(value) = HeaderValue::from_str(&value) {
+            response
+                .headers_mut()
+                .insert(HeaderName::from_static(name), value);
+        }
+    }
+
+    response
+}
@@ -0,0 +1,224 @@
+#![cfg(any(
+    feature = "actix-web",
+    feature = "rocket",
+    feature = "axum",
+    feature = "tower"
+))]
+//! Implements HTTP caching of the served api docs with _`ETag`_ and _`Last-Modified`_ headers.
+//!
+//! Swagger UI and other tools fetch the api doc frequently and it can be large thus the api doc is
+//! hashed once when it is serialized and requests with matching _`If-None-Match`_ or
+//! _`If-Modified-Since`_ header are responded with _`304 Not Modified`_ without the body.
+
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+    sync::Arc,
+    time::{Duration, SystemTime},
+};
+
+use crate::reload::{ApiDocSource, Reload};
+
+/// Cache validators of a serialized api doc.
+#[derive(Clone, Debug)]
+pub(crate) struct Validators {
+    etag: String,
+    last_modified: Option<SystemTime>,
+}
+
+impl Validators {
+    /// Create validators for `body` with content hash as _`ETag`_ and optional `last_modified`
+    /// time.
+    pub(crate) fn new(body: &[u8], last_modified: Option<SystemTime>) -> Self {
+        let mut hasher = DefaultHasher::new();
+        body.hash(&mut hasher);
+
+        Self {
+            etag: format!("\"{:016x}\"", hasher.finish()),
+            // HTTP dates have only second precision.
+            last_modified: last_modified.map(|time| {
+                let seconds = time
+                    .duration_since(SystemTime::UNIX_EPOCH)
+                    .map_or(0, |duration| duration.as_secs());
+                SystemTime::UNIX_EPOCH + Duration::from_secs(seconds)
+            }),
+        }
+    }
+
+    /// Headers of both _`200 OK`_ and _`304 Not Modified`_ responses. The api doc is always
+    /// revalidated by clients with _`Cache-Control: no-cache`_ since it may change any time the
+    /// application is restarted.
+    pub(crate) fn headers(&self) -> Vec<(&'static str, String)> {
+        let mut headers = vec![
+            ("etag", self.etag.clone()),
+            ("cache-control", "no-cache".to_string()),
+        ];
+        if let Some(last_modified) = self.last_modified {
+            headers.push(("last-modified", httpdate::fmt_http_date(last_modified)));
+        }
+
+        headers
+    }
+
+    /// Check whether client already has the current api doc based on the `if_none_match` and
+    /// `if_modified_since` request headers. _`If-Modified-Since`_ is ignored when
+    /// _`If-None-Match`_ is present.
+    pub(crate) fn is_not_modified(
+        &self,
+        if_none_match: Option<&str>,
+        if_modified_since: Option<&str>,
+    ) -> bool {
+        if let Some(if_none_match) = if_none_match {
+            return if_none_match
+                .split(',')
+                .map(str::trim)
+                .any(|etag| etag == "*" || etag.strip_prefix("W/").unwrap_or(etag) == self.etag);
+        }
+
+        match (self.last_modified, if_modified_since) {
+            (Some(last_modified), Some(if_modified_since)) => {
+                httpdate::parse_http_date(if_modified_since).map_or(false, |if_modified_since| {
+                    last_modified <= if_modified_since
+                })
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Format the api doc is serialized to.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) enum Format {
+    Json,
+    #[cfg(feature = "yaml")]
+    Yaml,
+}
+
+impl Format {
+    pub(crate) fn content_type(self) -> &'static str {
+        match self {
+            Self::Json => "application/json",
+            #[cfg(feature = "yaml")]
+            Self::Yaml => "application/yaml",
+        }
+    }
+}
+
+/// Api doc serialized to [`Format`] with [`Validators`] of the serialized body.
+#[derive(Debug)]
+pub(crate) struct SerializedApiDoc {
+    pub(crate) body: String,
+    pub(crate) validators: Validators,
+}
+
+/// Api doc served by [`SwaggerUi`][crate::SwaggerUi] serialized once at startup or for each
+/// request if it is rebuilt with [`Reload`].
+#[derive(Clone)]
+pub(crate) enum ApiDocBody {
+    Serialized(Format, Arc<SerializedApiDoc>),
+    Reload(Format, Reload),
+}
+
+impl ApiDocBody {
+    /// Create [`ApiDocBody`] serialized to `format`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the api doc cannot be serialized.
+    pub(crate) fn new(source: &ApiDocSource, format: Format) -> Self {
+        match source {
+            ApiDocSource::OpenApi(openapi) => {
+                let body = serialize(openapi, format);
+                let validators = Validators::new(body.as_bytes(), Some(SystemTime::now()));
+                Self::Serialized(format, Arc::new(SerializedApiDoc { body, validators }))
+            }
+            ApiDocSource::Reload(reload) => Self::Reload(format, reload.clone()),
+        }
+    }
+
+    pub(crate) fn content_type(&self) -> &'static str {
+        match self {
+            Self::Serialized(format, _) | Self::Reload(format, _) => format.content_type(),
+        }
+    }
+
+    /// Get the current serialized api doc. Rebuilt api doc has only _`ETag`_ validator since its
+    /// modification time is not known.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the rebuilt api doc cannot be serialized.
+    pub(crate) fn get(&self) -> Arc<SerializedApiDoc> {
+        match self {
+            Self::Serialized(_, doc) => Arc::clone(doc),
+            Self::Reload(format, reload) => {
+                let body = serialize(&reload.openapi(), *format);
+                let validators = Validators::new(body.as_bytes(), None);
+                Arc::new(SerializedApiDoc { body, validators })
+            }
+        }
+    }
+}
+
+fn serialize(openapi: &utoipa::openapi::OpenApi, format: Format) -> String {
+    match format {
+        Format::Json => openapi.to_json().expect("OpenApi should serialize to JSON"),
+        #[cfg(feature = "yaml")]
+        Format::Yaml => openapi.to_yaml().expect("OpenApi should serialize to YAML"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn validators() -> Validators {
+        Validators::new(
+            b"{}",
+            Some(SystemTime::UNIX_EPOCH + Duration::from_millis(784_111_777_500)),
+        )
+    }
+
+    #[test]
+    fn validators_headers() {
+        let validators = validators();
+        let headers = validators.headers();
+
+        assert_eq!(headers[0].0, "etag");
+        assert!(headers[0].1.starts_with('"') && headers[0].1.ends_with('"'));
+        assert_eq!(headers[1], ("cache-control", "no-cache".to_string()));
+        assert_eq!(
+            headers[2],
+            ("last-modified", "Sun, 06 Nov 1994 08:49:37 GMT".to_string())
+        );
+        assert_eq!(Validators::new(b"{}", None).headers().len(), 2);
+    }
+
+    #[test]
+    fn validators_not_modified_with_etag() {
+        let validators = validators();
+        let etag = validators.etag.clone();
+
+        assert!(validators.is_not_modified(Some(&etag), None));
+        assert!(validators.is_not_modified(Some(&format!("\"other\", W/{etag}")), None));
+        assert!(validators.is_not_modified(Some("*"), None));
+        assert!(!validators.is_not_modified(Some("\"other\""), None));
+        assert!(
+            !validators.is_not_modified(Some("\"other\""), Some("Sun, 06 Nov 1994 08:49:37 GMT"))
+        );
+        assert!(!Validators::new(b"[]", None).is_not_modified(Some(&etag), None));
+    }
+
+    #[test]
+    fn validators_not_modified_since() {
+        let validators = validators();
+
+        assert!(validators.is_not_modified(None, Some("Sun, 06 Nov 1994 08:49:37 GMT")));
+        assert!(validators.is_not_modified(None, Some("Mon, 07 Nov 1994 08:49:37 GMT")));
+        assert!(!validators.is_not_modified(None, Some("Sun, 06 Nov 1994 08:49:36 GMT")));
+        assert!(!validators.is_not_modified(None, Some("invalid")));
+        assert!(!validators.is_not_modified(None, None));
+        assert!(!Validators::new(b"{}", None)
+            .is_not_modified(None, Some("Sun, 06 Nov 1994 08:49:37 GMT")));
+    }
+}
@@ -15,6 +15,9 @@
 //! other frameworks as well. With other frameworks there is bit more manual implementation to be done. See
 //! more details at [`serve`] or [`examples`][examples].
 //!
+//! [`SwaggerUi`] also implements [`ServeOpenApi`][utoipa::serve::ServeOpenApi] thus it can be
+//! mounted with [`utoipa::serve::MountDocs`].
+//!
 //! [examples]: <https://github.com/juhaku/utoipa/tree/master/examples>
 //!
 //! # Features
@@ -25,6 +28,13 @@
 //!   and api doc without a hassle.
 //! * **axum** Enables `axum` integration with pre-configured Router serving Swagger UI and OpenAPI specs
 //!   hazzle free.
+//! * **tower** Enables framework independent [`SwaggerUiService`] implementing `tower::Service` for
+//!   serving Swagger UI and OpenAPI specs as JSON and YAML with `hyper`, `tonic-web` or other stacks
+//!   built on `http` types.
+//! * **yaml** Enables serving the OpenAPI spec also as YAML with [`SpecEndpoint`]. Enabled by
+//!   **tower** feature.
+//! * **compression** Enables serving the static Swagger UI files pre-compressed with brotli or gzip
+//!   based on _`Accept-Encoding`_ header of the request. See [`serve_compressed`] for more details.
 //! * **debug-embed** Enables `debug-embed` feature on `rust_embed` crate to allow embedding files in debug
 //!   builds as well.
 //!
@@ -44,6 +54,17 @@
 //!
 //! **Note!** Also remember that you already have defined `utoipa` dependency in your `Cargo.toml`
 //!
+//! # Offline builds
+//!
+//! Swagger UI release zip is vendored within this crate and embedded to the binary at build time
+//! thus building does not need network access. Use `SWAGGER_UI_ZIP` environment variable with
+//! absolute path to replace it with another Swagger UI release zip e.g. a newer version. The zip must
+//! have the same layout as the releases at [GitHub](https://github.com/swagger-api/swagger-ui/releases)
+//! with the `dist` directory inside a root directory.
+//! ```sh
+//! SWAGGER_UI_ZIP=/opt/swagger-ui/swagger-ui-4.15.5.zip cargo build
+//! ```
+//!
 //! # Examples
 //!
 //! Serve Swagger UI with api doc via **`actix-web`**. See full example from
@@ -112,14 +133,33 @@
 use std::{borrow::Cow, error::Error, mem, sync::Arc};
 
 mod actix;
+pub mod auth;
 mod axum;
+mod cache;
 pub mod oauth;
+pub mod reload;
 mod rocket;
+mod spec;
+mod tower;
 
 use rust_embed::RustEmbed;
 use serde::Serialize;
+use utoipa::serve::csp::{self, Nonce};
+#[cfg(any(
+    feature = "actix-web",
+    feature = "rocket",
+    feature = "axum",
+    feature = "tower"
+))]
+use utoipa::{
+    openapi::OpenApi,
+    serve::{Document, ServeOpenApi},
+};
+
 #[cfg(any(feature = "actix-web", feature = "rocket", feature = "axum"))]
-use utoipa::openapi::OpenApi;
+pub use self::spec::SpecEndpoint;
+#[cfg(feature = "tower")]
+pub use self::tower::SwaggerUiService;
 
 #[derive(RustEmbed)]
 #[folder = "$UTOIPA_SWAGGER_DIR/$UTOIPA_SWAGGER_UI_VERSION/dist/"]
@@ -156,21 +196,42 @@ struct SwaggerUiDist;
 ///
 #[non_exhaustive]
 #[derive(Clone)]
-#[cfg(any(feature = "actix-web", feature = "rocket", feature = "axum"))]
+#[cfg(any(
+    feature = "actix-web",
+    feature = "rocket",
+    feature = "axum",
+    feature = "tower"
+))]
 #[cfg_attr(
     doc_cfg,
-    doc(cfg(any(feature = "actix-web", feature = "rocket", feature = "axum")))
+    doc(cfg(any(
+        feature = "actix-web",
+        feature = "rocket",
+        feature = "axum",
+        feature = "tower"
+    )))
 )]
 pub struct SwaggerUi {
     path: Cow<'static, str>,
-    urls: Vec<(Url<'static>, OpenApi)>,
+    urls: Vec<(Url<'static>, reload::ApiDocSource)>,
     config: Option<Config<'static>>,
+    guard: Option<auth::Guard>,
 }
 
-#[cfg(any(feature = "actix-web", feature = "rocket", feature = "axum"))]
+#[cfg(any(
+    feature = "actix-web",
+    feature = "rocket",
+    feature = "axum",
+    feature = "tower"
+))]
 #[cfg_attr(
     doc_cfg,
-    doc(cfg(any(feature = "actix-web", feature = "rocket", feature = "axum")))
+    doc(cfg(any(
+        feature = "actix-web",
+        feature = "rocket",
+        feature = "axum",
+        feature = "tower"
+    )))
 )]
 impl SwaggerUi {
     /// Create a new [`SwaggerUi`] for given path.
@@ -191,6 +252,7 @@ impl SwaggerUi {
             path: path.into(),
             urls: Vec::new(),
             config: None,
+            guard: None,
         }
     }
 
@@ -224,7 +286,33 @@ impl SwaggerUi {
     ///     .url("/api-doc/openapi.json", ApiDoc::openapi());
     /// ```
     pub fn url<U: Into<Url<'static>>>(mut self, url: U, openapi: OpenApi) -> Self {
-        self.urls.push((url.into(), openapi));
+        self.urls
+            .push((url.into(), reload::ApiDocSource::OpenApi(Box::new(openapi))));
+
+        self
+    }
+
+    /// Add api doc [`Url`] into [`SwaggerUi`] rebuilding the api doc with [`reload::Reload`]
+    /// instead of building it once at startup.
+    ///
+    /// This allows changes to the api doc to show up in Swagger UI without restarting the
+    /// application e.g. during development. See [`reload::Reload`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// Rebuild derived OpenAPI doc for each request.
+    /// ```rust
+    /// # use utoipa_swagger_ui::{SwaggerUi, reload::Reload};
+    /// # use utoipa::OpenApi;
+    /// # #[derive(OpenApi)]
+    /// # #[openapi()]
+    /// # struct ApiDoc;
+    /// let swagger = SwaggerUi::new("/swagger-ui/{_:.*}")
+    ///     .reload_url("/api-doc/openapi.json", Reload::new(ApiDoc::openapi));
+    /// ```
+    pub fn reload_url<U: Into<Url<'static>>>(mut self, url: U, reload: reload::Reload) -> Self {
+        self.urls
+            .push((url.into(), reload::ApiDocSource::Reload(reload)));
 
         self
     }
@@ -234,7 +322,10 @@ impl SwaggerUi {
     /// Takes one [`Vec`] argument containing tuples of [`Url`] and [`OpenApi`].
     ///
     /// Situations where this comes handy is when there is a need or wish to separate different parts
-    /// of the api to separate api docs.
+    /// of the api to separate api docs e.g. different versions of the api. Swagger UI shows a
+    /// dropdown for selecting the api doc by name of the [`Url`] and the primary [`Url`] is
+    /// selected by default. Urls without name are shown by their url. Replaces [`Url`]s added
+    /// before with [`SwaggerUi::url`].
     ///
     /// # Examples
     ///
@@ -257,7 +348,10 @@ impl SwaggerUi {
     /// );
     /// ```
     pub fn urls(mut self, urls: Vec<(Url<'static>, OpenApi)>) -> Self {
-        self.urls = urls;
+        self.urls = urls
+            .into_iter()
+            .map(|(url, openapi)| (url, reload::ApiDocSource::OpenApi(Box::new(openapi))))
+            .collect();
 
         self
     }
@@ -266,6 +360,11 @@ impl SwaggerUi {
     ///
     /// Method takes one argument which exposes the [`oauth::Config`] to the user.
     ///
+    /// The _`oauth2-redirect.html`_ page completing the authorization flow is served along with
+    /// the other Swagger UI files e.g. at _`/swagger-ui/oauth2-redirect.html`_ which is the
+    /// default redirect url of Swagger UI. Register it as redirect url of the client in the
+    /// authorization server or override it with [`Config::oauth2_redirect_url`].
+    ///
     /// # Examples
     ///
     /// Enable pkce with default client_id.
@@ -314,6 +413,125 @@ impl SwaggerUi {
 
         self
     }
+
+    /// Protect Swagger UI and the api docs with [`auth::Guard`].
+    ///
+    /// The guard is checked for each request to Swagger UI files and api docs and requests not
+    /// passing it are responded with _`401 Unauthorized`_.
+    ///
+    /// # Examples
+    ///
+    /// Require basic auth for accessing Swagger UI.
+    /// ```rust
+    /// # use utoipa_swagger_ui::{SwaggerUi, auth::Guard};
+    /// # use utoipa::OpenApi;
+    /// # #[derive(OpenApi)]
+    /// # #[openapi()]
+    /// # struct ApiDoc;
+    /// let swagger = SwaggerUi::new("/swagger-ui/{_:.*}")
+    ///     .url("/api-doc/openapi.json", ApiDoc::openapi())
+    ///     .guard(Guard::basic("admin", "secret"));
+    /// ```
+    pub fn guard(mut self, guard: auth::Guard) -> Self {
+        self.guard = Some(guard);
+
+        self
+    }
+}
+
+/// [`SwaggerUi`] serves the Swagger UI files under its path with trailing slash e.g.
+/// _`"/swagger-ui/"`_ and _`"/swagger-ui/swagger-ui.css"`_ and the api docs from their urls.
+/// Framework specific wildcard segment at the end of the path such as _`"{_:.*}"`_ of actix-web or
+/// _`"*rest"`_ of axum is ignored.
+///
+/// Requests not passing the [`SwaggerUi::guard`] are responded with _`401 Unauthorized`_ the same
+/// way as with the framework integrations.
+///
+/// # Examples
+///
+/// _**Mount Swagger UI to actix-web scope.**_
+/// ```rust
+/// # use utoipa::serve::MountDocs;
+/// # use utoipa_swagger_ui::SwaggerUi;
+/// # #[cfg(feature = "actix-web")]
+/// let scope = actix_web::web::scope("/docs").mount_docs(
+///     SwaggerUi::new("/swagger-ui")
+///         .url("/api-docs/openapi.json", utoipa::openapi::OpenApi::default()),
+/// );
+/// ```
+#[cfg(any(
+    feature = "actix-web",
+    feature = "rocket",
+    feature = "axum",
+    feature = "tower"
+))]
+impl ServeOpenApi for SwaggerUi {
+    fn paths(&self) -> Vec<Cow<'static, str>> {
+        let base_path = self.base_path();
+        self.urls
+            .iter()
+            .map(|(url, _)| Cow::Owned(url.url.to_string()))
+            .chain(std::iter::once(Cow::Owned(format!("{base_path}/"))))
+            .chain(
+                SwaggerUiDist::iter()
+                    .filter(|file| !is_compressed_variant(file))
+                    .map(|file| Cow::Owned(format!("{base_path}/{file}"))),
+            )
+            .collect()
+    }
+
+    fn serve(&self, path: &str) -> Option<Document> {
+        if let Some((_, api_doc)) = self.urls.iter().find(|(url, _)| url.url == path) {
+            return Some(match api_doc {
+                reload::ApiDocSource::OpenApi(openapi) => Document::json(openapi),
+                reload::ApiDocSource::Reload(reload) => Document::json(&reload.openapi()),
+            });
+        }
+
+        let tail = path.strip_prefix(self.base_path())?.strip_prefix('/')?;
+        let file = serve(tail, Arc::new(self.swagger_config())).ok()??;
+        let content_type = mime_guess::from_path(if tail.is_empty() { "index.html" } else { tail })
+            .first_raw()
+            .unwrap_or("application/octet-stream");
+
+        let content_security_policy = file.content_security_policy();
+        let document = Document::new(content_type, file.bytes.into_owned());
+        Some(match content_security_policy {
+            Some(policy) => document.header("content-security-policy", policy),
+            None => document,
+        })
+    }
+
+    fn guard(&self) -> Option<&auth::Guard> {
+        self.guard.as_ref()
+    }
+}
+
+#[cfg(any(
+    feature = "actix-web",
+    feature = "rocket",
+    feature = "axum",
+    feature = "tower"
+))]
+impl SwaggerUi {
+    /// Path of the Swagger UI without trailing slash and framework specific wildcard segment.
+    fn base_path(&self) -> &str {
+        let path = self.path.trim_end_matches('/');
+        match path.rsplit_once('/') {
+            Some((base_path, segment)) if segment.starts_with(['{', '*']) => base_path,
+            _ => path,
+        }
+    }
+
+    /// Swagger UI [`Config`] defaulting to the urls of the api docs.
+    fn swagger_config(&self) -> Config<'static> {
+        let urls = self.urls.iter().map(|(url, _)| url.clone());
+        match &self.config {
+            Some(config) if config.url.is_some() || !config.urls.is_empty() => config.clone(),
+            Some(config) => config.clone().configure_defaults(urls),
+            None => Config::new(urls),
+        }
+    }
 }
 
 /// Rust type for Swagger UI url configuration object.
@@ -551,10 +769,38 @@ pub struct Config<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     persist_authorization: Option<bool>,
 
+    /// Defines syntax highlighting of the payloads and cURL commands.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    syntax_highlight: Option<SyntaxHighlight>,
+
     /// [`oauth::Config`] the Swagger UI is using for auth flow.
     #[serde(skip)]
     oauth: Option<oauth::Config>,
 
+    /// Custom _`index.html`_ template served instead of the Swagger UI default.
+    #[serde(skip)]
+    index_html: Option<String>,
+
+    /// Title of the Swagger UI page.
+    #[serde(skip)]
+    title: Option<String>,
+
+    /// Custom CSS injected to the _`<head>`_ of the Swagger UI page.
+    #[serde(skip)]
+    custom_css: Option<String>,
+
+    /// Custom JavaScript injected to the end of _`<body>`_ of the Swagger UI page.
+    #[serde(skip)]
+    custom_js: Option<String>,
+
+    /// Url of the favicon of the Swagger UI page.
+    #[serde(skip)]
+    favicon: Option<String>,
+
+    /// Nonce attached to the scripts and styles of the Swagger UI page.
+    #[serde(skip)]
+    nonce: Option<Nonce>,
+
     /// The layout of Swagger UI uses, default is `"StandaloneLayout"`
     layout: &'a str,
 }
@@ -666,10 +912,20 @@ impl<'a> Config<'a> {
     /// is called on.
     ///
     /// Current config will be returned with configured default values.
-    #[cfg(any(feature = "actix-web", feature = "rocket", feature = "axum"))]
+    #[cfg(any(
+        feature = "actix-web",
+        feature = "rocket",
+        feature = "axum",
+        feature = "tower"
+    ))]
     #[cfg_attr(
         doc_cfg,
-        doc(cfg(any(feature = "actix-web", feature = "rocket", feature = "axum")))
+        doc(cfg(any(
+            feature = "actix-web",
+            feature = "rocket",
+            feature = "axum",
+            feature = "tower"
+        )))
     )]
     fn configure_defaults<I: IntoIterator<Item = U>, U: Into<Url<'a>>>(mut self, urls: I) -> Self {
         let Config {
@@ -1135,6 +1391,167 @@ impl<'a> Config<'a> {
 
         self
     }
+
+    /// Set [`SyntaxHighlight`] of the payloads and cURL commands. It can be either `bool`
+    /// telling whether syntax highlighting is activated or [`SyntaxHighlight`] with a theme.
+    ///
+    /// Default value is `true` with _`agate`_ theme.
+    ///
+    /// # Examples
+    ///
+    /// Disable syntax highlighting.
+    /// ```rust
+    /// # use utoipa_swagger_ui::Config;
+    /// let config = Config::new(["/api-doc/openapi.json"])
+    ///     .syntax_highlight(false);
+    /// ```
+    ///
+    /// Use _`monokai`_ theme.
+    /// ```rust
+    /// # use utoipa_swagger_ui::{Config, SyntaxHighlight};
+    /// let config = Config::new(["/api-doc/openapi.json"])
+    ///     .syntax_highlight(SyntaxHighlight::default().theme("monokai"));
+    /// ```
+    pub fn syntax_highlight<S: Into<SyntaxHighlight>>(mut self, syntax_highlight: S) -> Self {
+        self.syntax_highlight = Some(syntax_highlight.into());
+
+        self
+    }
+
+    /// Replace the served _`index.html`_ with custom `index_html` template.
+    ///
+    /// The template must load the Swagger UI files relative to itself the same way as the default
+    /// template does e.g. _`./swagger-ui-bundle.js`_ and _`./swagger-initializer.js`_. Title,
+    /// custom CSS, custom JavaScript and favicon are applied to the template as well.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use utoipa_swagger_ui::Config;
+    /// let config = Config::new(["/api-doc/openapi.json"])
+    ///     .index_html(r#"<!DOCTYPE html>
+    /// <html>
+    ///   <head>
+    ///     <title>Docs</title>
+    ///     <link rel="stylesheet" type="text/css" href="./swagger-ui.css" />
+    ///   </head>
+    ///   <body>
+    ///     <div id="swagger-ui"></div>
+    ///     <script src="./swagger-ui-bundle.js"></script>
+    ///     <script src="./swagger-ui-standalone-preset.js"></script>
+    ///     <script src="./swagger-initializer.js"></script>
+    ///   </body>
+    /// </html>"#);
+    /// ```
+    pub fn index_html<S: Into<String>>(mut self, index_html: S) -> Self {
+        self.index_html = Some(index_html.into());
+
+        self
+    }
+
+    /// Set `title` of the Swagger UI page. Default title is _`Swagger UI`_.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use utoipa_swagger_ui::Config;
+    /// let config = Config::new(["/api-doc/openapi.json"])
+    ///     .title("Acme API");
+    /// ```
+    pub fn title<S: Into<String>>(mut self, title: S) -> Self {
+        self.title = Some(title.into());
+
+        self
+    }
+
+    /// Inject `custom_css` to the _`<head>`_ of the Swagger UI page after the Swagger UI styles.
+    ///
+    /// # Examples
+    ///
+    /// Hide the top bar.
+    /// ```rust
+    /// # use utoipa_swagger_ui::Config;
+    /// let config = Config::new(["/api-doc/openapi.json"])
+    ///     .custom_css(".swagger-ui .topbar { display: none; }");
+    /// ```
+    pub fn custom_css<S: Into<String>>(mut self, custom_css: S) -> Self {
+        self.custom_css = Some(custom_css.into());
+
+        self
+    }
+
+    /// Inject `custom_js` to the end of _`<body>`_ of the Swagger UI page after the Swagger UI
+    /// scripts.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use utoipa_swagger_ui::Config;
+    /// let config = Config::new(["/api-doc/openapi.json"])
+    ///     .custom_js("console.log('Swagger UI loaded');");
+    /// ```
+    pub fn custom_js<S: Into<String>>(mut self, custom_js: S) -> Self {
+        self.custom_js = Some(custom_js.into());
+
+        self
+    }
+
+    /// Replace the Swagger UI favicons with `favicon` url e.g. _`"/static/favicon.ico"`_.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use utoipa_swagger_ui::Config;
+    /// let config = Config::new(["/api-doc/openapi.json"])
+    ///     .favicon("/static/favicon.ico");
+    /// ```
+    pub fn favicon<S: Into<String>>(mut self, favicon: S) -> Self {
+        self.favicon = Some(favicon.into());
+
+        self
+    }
+
+    /// Attach `nonce` to the _`<script>`_, _`<style>`_ and stylesheet _`<link>`_ tags of the
+    /// Swagger UI page, including the ones of [`Config::custom_css`] and [`Config::custom_js`], so
+    /// that Swagger UI can be served under strict _`Content-Security-Policy`_.
+    ///
+    /// The same nonce must be allowed by the _`Content-Security-Policy`_ header of the response
+    /// e.g. _`script-src 'nonce-{nonce}'`_. Use [`Config::nonce_fn`] to create a new nonce for
+    /// each request instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use utoipa_swagger_ui::Config;
+    /// let config = Config::new(["/api-doc/openapi.json"])
+    ///     .nonce("rAnd0m");
+    /// ```
+    pub fn nonce<S: Into<String>>(mut self, nonce: S) -> Self {
+        self.nonce = Some(Nonce::from(nonce.into()));
+
+        self
+    }
+
+    /// Create a new nonce with `nonce_fn` for each request of the Swagger UI page and attach it
+    /// to the same tags as [`Config::nonce`].
+    ///
+    /// The created nonce is available from [`SwaggerFile::nonce`]. The framework integrations
+    /// respond the page with _`Content-Security-Policy`_ header allowing only the scripts and
+    /// styles with the nonce.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use utoipa_swagger_ui::Config;
+    /// # fn random_nonce() -> String { String::from("rAnd0m") }
+    /// let config = Config::new(["/api-doc/openapi.json"])
+    ///     .nonce_fn(random_nonce);
+    /// ```
+    pub fn nonce_fn<F: Fn() -> String + Send + Sync + 'static>(mut self, nonce_fn: F) -> Self {
+        self.nonce = Some(Nonce::new(nonce_fn));
+
+        self
+    }
 }
 
 impl Default for Config<'_> {
@@ -1165,7 +1582,14 @@ impl Default for Config<'_> {
             validator_url: Default::default(),
             with_credentials: Default::default(),
             persist_authorization: Default::default(),
+            syntax_highlight: Default::default(),
             oauth: Default::default(),
+            index_html: Default::default(),
+            title: Default::default(),
+            custom_css: Default::default(),
+            custom_js: Default::default(),
+            favicon: Default::default(),
+            nonce: Default::default(),
             layout: SWAGGER_STANDALONE_LAYOUT,
         }
     }
@@ -1183,6 +1607,54 @@ impl From<String> for Config<'_> {
     }
 }
 
+/// Represents settings related to syntax highlighting of payloads and cURL commands.
+///
+/// Syntax highlighting can be disabled with `false` and enabled with `true` which is
+/// converted to [`SyntaxHighlight`] with default theme.
+#[derive(Serialize, Clone)]
+#[non_exhaustive]
+pub struct SyntaxHighlight {
+    /// Boolean telling whether syntax highlighting should be activated or not. Defaults to `true`.
+    pub activated: bool,
+    /// Highlight.js syntax coloring theme to use e.g. _`"monokai"`_. Defaults to `None`
+    /// which uses the Swagger UI default theme _`"agate"`_.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub theme: Option<&'static str>,
+}
+
+impl Default for SyntaxHighlight {
+    fn default() -> Self {
+        Self {
+            activated: true,
+            theme: None,
+        }
+    }
+}
+
+impl From<bool> for SyntaxHighlight {
+    fn from(activated: bool) -> Self {
+        Self {
+            activated,
+            ..Default::default()
+        }
+    }
+}
+
+impl SyntaxHighlight {
+    /// Explicitly set whether syntax highlighting is activated or not.
+    pub fn activated(mut self, activated: bool) -> Self {
+        self.activated = activated;
+        self
+    }
+
+    /// Set Highlight.js syntax coloring theme e.g. _`"agate"`_, _`"arta"`_, _`"monokai"`_,
+    /// _`"nord"`_, _`"obsidian"`_ or _`"tomorrow-night"`_.
+    pub fn theme(mut self, theme: &'static str) -> Self {
+        self.theme = Some(theme);
+        self
+    }
+}
+
 /// Represents servable file of Swagger UI. This is used together with [`serve`] function
 /// to serve Swagger UI files via web server.
 #[non_exhaustive]
@@ -1191,6 +1663,29 @@ pub struct SwaggerFile<'a> {
     pub bytes: Cow<'a, [u8]>,
     /// Content type of the file e.g `"text/xml"`.
     pub content_type: String,
+    /// Content encoding of the bytes e.g. `"gzip"` if the file is served pre-compressed with
+    /// [`serve_compressed`].
+    pub content_encoding: Option<&'static str>,
+    /// Nonce attached to the scripts and styles of the Swagger UI page if set with
+    /// [`Config::nonce`] or [`Config::nonce_fn`]. It should be allowed by the
+    /// _`Content-Security-Policy`_ header of the response.
+    pub nonce: Option<String>,
+}
+
+impl SwaggerFile<'_> {
+    /// _`Content-Security-Policy`_ header value allowing only the scripts and styles with the nonce
+    /// of the file if it has one.
+    #[cfg(any(
+        feature = "actix-web",
+        feature = "rocket",
+        feature = "axum",
+        feature = "tower"
+    ))]
+    pub(crate) fn content_security_policy(&self) -> Option<String> {
+        self.nonce
+            .as_ref()
+            .map(|nonce| csp::content_security_policy(nonce))
+    }
 }
 
 /// User friendly way to serve Swagger UI and its content via web server.
@@ -1240,6 +1735,39 @@ pub struct SwaggerFile<'a> {
 pub fn serve<'a>(
     path: &str,
     config: Arc<Config<'a>>,
+) -> Result<Option<SwaggerFile<'a>>, Box<dyn Error>> {
+    serve_compressed(path, config, "")
+}
+
+/// Serve Swagger UI files the same way as [`serve`] but pre-compressed if the client accepts it.
+///
+/// * **accept_encoding** Value of the _`Accept-Encoding`_ header of the request e.g.
+///   _`"gzip, deflate, br"`_.
+///
+/// With **compression** feature the static Swagger UI files are compressed with brotli and gzip at
+/// build time. The file is served compressed with brotli if accepted by the client, otherwise with
+/// gzip if accepted and uncompressed if neither is accepted. [`SwaggerFile::content_encoding`]
+/// should be set as _`Content-Encoding`_ header of the response. Files formatted with [`Config`]
+/// are always served uncompressed as well as all files without **compression** feature. The
+/// compressed variants e.g. _`swagger-ui.css.gz`_ are not served from their own paths.
+///
+/// # Examples
+///
+/// _**Serve Swagger UI bundle compressed with gzip.**_
+/// ```rust
+/// # use std::sync::Arc;
+/// # use utoipa_swagger_ui::Config;
+/// let config = Arc::new(Config::from("/api-doc.json"));
+/// let file = utoipa_swagger_ui::serve_compressed("swagger-ui-bundle.js", config, "gzip")
+///     .unwrap()
+///     .unwrap();
+/// # #[cfg(feature = "compression")]
+/// assert_eq!(file.content_encoding, Some("gzip"));
+/// ```
+pub fn serve_compressed<'a>(
+    path: &str,
+    config: Arc<Config<'a>>,
+    accept_encoding: &str,
 ) -> Result<Option<SwaggerFile<'a>>, Box<dyn Error>> {
     let mut file_path = path;
 
@@ -1247,10 +1775,24 @@ pub fn serve<'a>(
         file_path = "index.html";
     }
 
-    if let Some(file) = SwaggerUiDist::get(file_path) {
+    if let Some(file) = SwaggerUiDist::get(file_path).filter(|_| !is_compressed_variant(file_path))
+    {
         let mut bytes = file.data;
+        let mut content_encoding = None;
+        let mut nonce = None;
+
+        if file_path == "index.html" {
+            let file = match &config.index_html {
+                Some(index_html) => index_html.clone(),
+                None => match String::from_utf8(bytes.to_vec()) {
+                    Ok(file) => file,
+                    Err(error) => return Err(Box::new(error)),
+                },
+            };
 
-        if file_path == "swagger-initializer.js" {
+            nonce = config.nonce.as_ref().map(Nonce::create);
+            bytes = Cow::Owned(format_index(config.as_ref(), file, nonce.as_deref()).into_bytes());
+        } else if file_path == "swagger-initializer.js" {
             let mut file = match String::from_utf8(bytes.to_vec()) {
                 Ok(file) => file,
                 Err(error) => return Err(Box::new(error)),
@@ -1266,6 +1808,9 @@ pub fn serve<'a>(
             }
 
             bytes = Cow::Owned(file.as_bytes().to_vec())
+        } else if let Some((encoding, compressed)) = get_compressed(file_path, accept_encoding) {
+            bytes = compressed;
+            content_encoding = Some(encoding);
         };
 
         Ok(Some(SwaggerFile {
@@ -1273,12 +1818,122 @@ pub fn serve<'a>(
             content_type: mime_guess::from_path(file_path)
                 .first_or_octet_stream()
                 .to_string(),
+            content_encoding,
+            nonce,
         }))
     } else {
         Ok(None)
     }
 }
 
+/// Pre-compressed variants of Swagger UI files as content encoding and file extension in order of
+/// preference.
+const COMPRESSED_VARIANTS: [(&str, &str); 2] = [("br", "br"), ("gzip", "gz")];
+
+/// Get pre-compressed variant of the file at `file_path` with the most preferred encoding accepted
+/// by `accept_encoding`.
+fn get_compressed(
+    file_path: &str,
+    accept_encoding: &str,
+) -> Option<(&'static str, Cow<'static, [u8]>)> {
+    COMPRESSED_VARIANTS
+        .iter()
+        .filter(|(encoding, _)| accepts_encoding(accept_encoding, encoding))
+        .find_map(|(encoding, extension)| {
+            SwaggerUiDist::get(&format!("{}.{}", file_path, extension))
+                .map(|file| (*encoding, file.data))
+        })
+}
+
+/// Check whether `file_path` is a pre-compressed variant of another Swagger UI file. The variants
+/// are only served in place of the original file with [`serve_compressed`].
+fn is_compressed_variant(file_path: &str) -> bool {
+    COMPRESSED_VARIANTS.iter().any(|(_, extension)| {
+        file_path
+            .strip_suffix(extension)
+            .and_then(|file_path| file_path.strip_suffix('.'))
+            .is_some_and(|file_path| SwaggerUiDist::get(file_path).is_some())
+    })
+}
+
+/// Check whether _`Accept-Encoding`_ header value accepts the `encoding`. Encodings with
+/// _`q=0`_ are not accepted.
+fn accepts_encoding(accept_encoding: &str, encoding: &str) -> bool {
+    accept_encoding.split(',').any(|value| {
+        let mut parts = value.split(';');
+        let name = parts.next().unwrap_or_default().trim();
+        let rejected = parts.any(|parameter| {
+            parameter
+                .trim()
+                .strip_prefix("q=")
+                .and_then(|quality| quality.trim().parse::<f32>().ok())
+                .is_some_and(|quality| quality == 0.0)
+        });
+
+        (name.eq_ignore_ascii_case(encoding) || name == "*") && !rejected
+    })
+}
+
+fn format_index(config: &Config, mut file: String, nonce: Option<&str>) -> String {
+    if let Some(title) = &config.title {
+        if let (Some(start), Some(end)) = (file.find("<title>"), file.find("</title>")) {
+            file.replace_range(start + "<title>".len()..end, &csp::escape_html(title));
+        }
+    }
+
+    if let Some(favicon) = &config.favicon {
+        file = file
+            .lines()
+            .filter(|line| !line.contains(r#"rel="icon""#))
+            .collect::<Vec<_>>()
+            .join("\n");
+        insert_before(
+            &mut file,
+            "</head>",
+            &format!(
+                r#"<link rel="icon" href="{}" />"#,
+                csp::escape_html(favicon)
+            ),
+        );
+    }
+
+    if let Some(custom_css) = &config.custom_css {
+        insert_before(
+            &mut file,
+            "</head>",
+            &format!("<style>{custom_css}</style>"),
+        );
+    }
+
+    if let Some(custom_js) = &config.custom_js {
+        insert_before(
+            &mut file,
+            "</body>",
+            &format!("<script>{custom_js}</script>"),
+        );
+    }
+
+    match nonce {
+        Some(nonce) => csp::attach_nonce(&file, nonce),
+        None => file,
+    }
+}
+
+/// Insert `content` before the last `tag` of the `file`. If the `tag` is on its own line the
+/// `content` is inserted on a new line indented one level deeper than the `tag`.
+fn insert_before(file: &mut String, tag: &str, content: &str) {
+    if let Some(index) = file.rfind(tag) {
+        let line_start = file[..index].rfind('\n').map_or(0, |newline| newline + 1);
+        let indentation = &file[line_start..index];
+        if indentation.trim().is_empty() {
+            let content = format!("{indentation}  {content}\n");
+            file.insert_str(line_start, &content);
+        } else {
+            file.insert_str(index, content);
+        }
+    }
+}
+
 #[inline]
 fn format_config(config: &Config, file: String) -> Result<String, Box<dyn Error>> {
     let config_json = match serde_json::to_string_pretty(&config) {
@@ -1561,4 +2216,214 @@ window.ui = SwaggerUIBundle({
 
         assert_diff_equal(EXPECTED, &formatted_config);
     }
+
+    #[test]
+    fn format_swagger_config_with_syntax_highlight() {
+        let formatted_config = match format_config(
+            &Config::new(["/api-doc/openapi1.json"])
+                .syntax_highlight(SyntaxHighlight::from(false).theme("monokai")),
+            String::from(TEST_INITIAL_CONFIG),
+        ) {
+            Ok(file) => file,
+            Err(error) => panic!("{error}"),
+        };
+
+        const EXPECTED: &str = r###"
+window.ui = SwaggerUIBundle({
+    "dom_id": "#swagger-ui",
+  "url": "/api-doc/openapi1.json",
+  "deepLinking": true,
+  "syntaxHighlight": {
+    "activated": false,
+    "theme": "monokai"
+  },
+  "layout": "StandaloneLayout",
+  presets: [
+    SwaggerUIBundle.presets.apis,
+    SwaggerUIStandalonePreset
+  ],
+  plugins: [
+    SwaggerUIBundle.plugins.DownloadUrl
+  ],
+});"###;
+
+        assert_diff_equal(EXPECTED, &formatted_config);
+    }
+
+    #[test]
+    fn serve_oauth2_redirect_page() {
+        let config = Arc::new(Config::from("/api-doc/openapi.json"));
+
+        let file = serve("oauth2-redirect.html", config)
+            .unwrap()
+            .expect("oauth2-redirect.html should be served");
+
+        assert_eq!(file.content_type, "text/html");
+        assert!(String::from_utf8_lossy(&file.bytes).contains("oauth2"));
+    }
+
+    #[test]
+    fn format_index_with_title_favicon_css_and_js() {
+        let index = format_index(
+            &Config::from("/api-doc/openapi.json")
+                .title("Acme <API>")
+                .favicon("/favicon.ico")
+                .custom_css("body { margin: 0; }")
+                .custom_js("console.log(1);"),
+            String::from(
+                r#"<html>
+  <head>
+    <title>Swagger UI</title>
+    <link rel="icon" type="image/png" href="./favicon-32x32.png" sizes="32x32" />
+  </head>
+  <body>
+    <div id="swagger-ui"></div>
+  </body>
+</html>"#,
+            ),
+            None,
+        );
+
+        const EXPECTED: &str = r#"<html>
+  <head>
+    <title>Acme &lt;API&gt;</title>
+    <link rel="icon" href="/favicon.ico" />
+    <style>body { margin: 0; }</style>
+  </head>
+  <body>
+    <div id="swagger-ui"></div>
+    <script>console.log(1);</script>
+  </body>
+</html>"#;
+
+        assert_diff_equal(EXPECTED, &index);
+    }
+
+    #[test]
+    fn serve_index_with_nonce() {
+        let config = Arc::new(
+            Config::from("/api-doc/openapi.json")
+                .custom_css("body { margin: 0; }")
+                .nonce_fn(|| String::from("n0nce")),
+        );
+
+        let file = serve("", config).unwrap().unwrap();
+        let index = String::from_utf8_lossy(&file.bytes);
+
+        assert_eq!(file.nonce.as_deref(), Some("n0nce"));
+        assert!(index.contains(r#"<script nonce="n0nce" src="./swagger-ui-bundle.js""#));
+        assert!(index.contains(r#"<script nonce="n0nce" src="./swagger-initializer.js""#));
+        assert!(index.contains(r#"<link nonce="n0nce" rel="stylesheet""#));
+        assert!(index.contains(r#"<style nonce="n0nce">body { margin: 0; }</style>"#));
+        assert!(!index.contains("<script src"));
+    }
+
+    #[test]
+    fn serve_custom_index_html() {
+        let config = Arc::new(
+            Config::from("/api-doc/openapi.json")
+                .index_html("<html><head><title>Docs</title></head><body></body></html>")
+                .title("Acme API"),
+        );
+
+        let file = serve("", config).unwrap().unwrap();
+
+        assert_eq!(
+            String::from_utf8_lossy(&file.bytes),
+            "<html><head><title>Acme API</title></head><body></body></html>"
+        );
+    }
+
+    #[test]
+    fn accept_encoding_with_quality() {
+        assert!(accepts_encoding("gzip, deflate, br", "br"));
+        assert!(accepts_encoding("deflate, GZIP;q=0.5", "gzip"));
+        assert!(accepts_encoding("*", "gzip"));
+        assert!(!accepts_encoding("gzip;q=0, deflate", "gzip"));
+        assert!(!accepts_encoding("", "gzip"));
+    }
+
+    #[test]
+    fn serve_compressed_files() {
+        let config = Arc::new(Config::from("/api-doc/openapi.json"));
+
+        let file = serve_compressed("swagger-ui.css", Arc::clone(&config), "")
+            .unwrap()
+            .unwrap();
+        assert_eq!(file.content_encoding, None);
+        assert_eq!(file.content_type, "text/css");
+
+        let file = serve_compressed("", Arc::clone(&config), "gzip, br")
+            .unwrap()
+            .unwrap();
+        assert_eq!(file.content_encoding, None);
+
+        #[cfg(feature = "compression")]
+        {
+            let file = serve_compressed("swagger-ui.css", Arc::clone(&config), "gzip, br")
+                .unwrap()
+                .unwrap();
+            assert_eq!(file.content_encoding, Some("br"));
+            assert_eq!(file.content_type, "text/css");
+
+            let file = serve_compressed("swagger-ui.css", Arc::clone(&config), "gzip, br;q=0")
+                .unwrap()
+                .unwrap();
+            assert_eq!(file.content_encoding, Some("gzip"));
+            assert_eq!(&file.bytes[..2], [0x1f, 0x8b]);
+
+            assert!(
+                serve_compressed("swagger-ui.css.gz", Arc::clone(&config), "gzip")
+                    .unwrap()
+                    .is_none()
+            );
+            assert!(serve("swagger-ui.css.br", config).unwrap().is_none());
+        }
+    }
+
+    #[cfg(any(
+        feature = "actix-web",
+        feature = "rocket",
+        feature = "axum",
+        feature = "tower"
+    ))]
+    #[test]
+    fn serve_swagger_ui_files_and_api_docs() {
+        let swagger_ui = SwaggerUi::new("/swagger-ui/{_:.*}")
+            .url("/api-docs/openapi.json", OpenApi::default())
+            .config(Config::default().nonce("n0nce"));
+
+        let paths = swagger_ui.paths();
+        assert_eq!(paths[..2], ["/api-docs/openapi.json", "/swagger-ui/"]);
+        assert!(paths
+            .iter()
+            .any(|path| path == "/swagger-ui/swagger-ui.css"));
+        assert!(!paths
+            .iter()
+            .any(|path| path.ends_with(".gz") || path.ends_with(".br")));
+
+        let index = swagger_ui.serve("/swagger-ui/").unwrap();
+        assert_eq!(index.content_type, "text/html");
+        assert_eq!(
+            index.headers,
+            [(
+                "content-security-policy",
+                "script-src 'nonce-n0nce'; style-src 'nonce-n0nce'".to_string()
+            )]
+        );
+
+        let initializer = swagger_ui
+            .serve("/swagger-ui/swagger-initializer.js")
+            .unwrap();
+        assert!(String::from_utf8_lossy(&initializer.body).contains("/api-docs/openapi.json"));
+
+        let api_doc = swagger_ui.serve("/api-docs/openapi.json").unwrap();
+        assert_eq!(api_doc.content_type, "application/json");
+        assert!(swagger_ui.serve("/swagger-ui/missing.js").is_none());
+        assert!(swagger_ui.serve("/other/swagger-ui.css").is_none());
+
+        let swagger_ui = swagger_ui.guard(auth::Guard::basic("admin", "secret"));
+        assert_eq!(swagger_ui.paths(), paths);
+        assert!(ServeOpenApi::guard(&swagger_ui).is_some());
+    }
 }
@@ -0,0 +1,171 @@
+#![cfg(any(
+    feature = "actix-web",
+    feature = "rocket",
+    feature = "axum",
+    feature = "tower"
+))]
+//! Implements [`Reload`] for rebuilding api docs while the application is running.
+//!
+//! Api docs added with [`SwaggerUi::url`][crate::SwaggerUi::url] are built once at startup. Api
+//! docs added with [`SwaggerUi::reload_url`][crate::SwaggerUi::reload_url] are rebuilt when they
+//! are requested thus changes to the docs show up in Swagger UI without restarting the application
+//! e.g. during development.
+
+use std::{
+    fs,
+    path::PathBuf,
+    sync::{Arc, Mutex, PoisonError},
+    time::SystemTime,
+};
+
+use utoipa::openapi::OpenApi;
+
+type Build = dyn Fn() -> OpenApi + Send + Sync;
+
+type Cache = Option<(Vec<Option<SystemTime>>, OpenApi)>;
+
+/// Api doc rebuilt by calling a function instead of being built once at startup.
+///
+/// By default the api doc is rebuilt for each request. If files are watched with
+/// [`Reload::watch`] the api doc is rebuilt only when modification time of any of the watched files
+/// has changed since it was last built e.g. when the api doc reads examples from files.
+///
+/// # Examples
+///
+/// _**Rebuild the api doc for each request in debug builds only.**_
+/// ```rust
+/// # use utoipa_swagger_ui::{SwaggerUi, reload::Reload};
+/// # use utoipa::OpenApi;
+/// # #[derive(OpenApi)]
+/// # #[openapi()]
+/// # struct ApiDoc;
+/// let swagger = SwaggerUi::new("/swagger-ui/{_:.*}");
+/// let swagger = if cfg!(debug_assertions) {
+///     swagger.reload_url("/api-doc/openapi.json", Reload::new(ApiDoc::openapi))
+/// } else {
+///     swagger.url("/api-doc/openapi.json", ApiDoc::openapi())
+/// };
+/// ```
+///
+/// _**Rebuild the api doc when example files change.**_
+/// ```rust
+/// # use utoipa_swagger_ui::reload::Reload;
+/// # use utoipa::OpenApi;
+/// # #[derive(OpenApi)]
+/// # #[openapi()]
+/// # struct ApiDoc;
+/// let reload = Reload::new(ApiDoc::openapi)
+///     .watch("examples/pet.json")
+///     .watch("examples/order.json");
+/// ```
+#[derive(Clone)]
+pub struct Reload {
+    build: Arc<Build>,
+    watch: Vec<PathBuf>,
+    cache: Arc<Mutex<Cache>>,
+}
+
+impl Reload {
+    /// Construct a new [`Reload`] rebuilding the api doc with `build` function e.g.
+    /// _`ApiDoc::openapi`_.
+    pub fn new<F: Fn() -> OpenApi + Send + Sync + 'static>(build: F) -> Self {
+        Self {
+            build: Arc::new(build),
+            watch: Vec::new(),
+            cache: Arc::default(),
+        }
+    }
+
+    /// Watch a file at `path` and rebuild the api doc only when modification time of the file has
+    /// changed. Calling this again will add another file to watch.
+    ///
+    /// Missing files are watched as well thus creating or removing a watched file also rebuilds
+    /// the api doc.
+    pub fn watch<P: Into<PathBuf>>(mut self, path: P) -> Self {
+        self.watch.push(path.into());
+
+        self
+    }
+
+    /// Get the current api doc, rebuilding it if needed.
+    pub(crate) fn openapi(&self) -> OpenApi {
+        if self.watch.is_empty() {
+            return (self.build)();
+        }
+
+        let modified = self
+            .watch
+            .iter()
+            .map(|path| {
+                fs::metadata(path)
+                    .and_then(|metadata| metadata.modified())
+                    .ok()
+            })
+            .collect::<Vec<_>>();
+
+        let mut cache = self.cache.lock().unwrap_or_else(PoisonError::into_inner);
+        match &*cache {
+            Some((cached_modified, openapi)) if *cached_modified == modified => openapi.clone(),
+            _ => {
+                let openapi = (self.build)();
+                *cache = Some((modified, openapi.clone()));
+                openapi
+            }
+        }
+    }
+}
+
+/// Api doc served by [`SwaggerUi`][crate::SwaggerUi] either built once or rebuilt with [`Reload`].
+#[derive(Clone)]
+pub(crate) enum ApiDocSource {
+    OpenApi(Box<OpenApi>),
+    Reload(Reload),
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use utoipa::openapi::{Info, Paths};
+
+    use super::*;
+
+    fn counting_reload(builds: &Arc<AtomicUsize>) -> Reload {
+        let builds = Arc::clone(builds);
+        Reload::new(move || {
+            let version = builds.fetch_add(1, Ordering::SeqCst) + 1;
+            OpenApi::new(Info::new("title", &version.to_string()), Paths::new())
+        })
+    }
+
+    #[test]
+    fn reload_api_doc_on_each_call() {
+        let builds = Arc::new(AtomicUsize::new(0));
+        let reload = counting_reload(&builds);
+
+        assert_eq!(reload.openapi().info.version, "1");
+        assert_eq!(reload.openapi().info.version, "2");
+    }
+
+    #[test]
+    fn reload_api_doc_when_watched_file_changes() {
+        let path = std::env::temp_dir().join(format!(
+            "utoipa-swagger-ui-reload-{}.json",
+            std::process::id()
+        ));
+        let _ = fs::remove_file(&path);
+        let builds = Arc::new(AtomicUsize::new(0));
+        let reload = counting_reload(&builds).watch(&path);
+
+        assert_eq!(reload.openapi().info.version, "1");
+        assert_eq!(reload.openapi().info.version, "1");
+
+        fs::write(&path, "{}").unwrap();
+        assert_eq!(reload.openapi().info.version, "2");
+        assert_eq!(reload.clone().openapi().info.version, "2");
+
+        fs::remove_file(&path).unwrap();
+        assert_eq!(reload.openapi().info.version, "3");
+        assert_eq!(builds.load(Ordering::SeqCst), 3);
+    }
+}
@@ -3,28 +3,33 @@
 use std::{borrow::Cow, io::Cursor, sync::Arc};
 
 use rocket::{
-    http::{Header, Status},
+    http::{Header, Method, Status},
     response::{
         status::{self, NotFound},
         Responder as RocketResponder,
     },
     route::{Handler, Outcome},
-    serde::json::Json,
     Data as RocketData, Request, Response, Route,
 };
 
-use crate::{Config, SwaggerFile, SwaggerUi};
+use crate::{
+    auth::{self, Guard},
+    cache::{ApiDocBody, Format, Validators},
+    spec::{SpecDocument, SpecEndpoint},
+    Config, SwaggerFile, SwaggerUi,
+};
 
 impl From<SwaggerUi> for Vec<Route> {
     fn from(swagger_ui: SwaggerUi) -> Self {
         let mut routes = Vec::<Route>::with_capacity(swagger_ui.urls.len() + 1);
         let mut api_docs = Vec::<Route>::with_capacity(swagger_ui.urls.len());
 
+        let guard = swagger_ui.guard;
         let urls = swagger_ui.urls.into_iter().map(|(url, openapi)| {
             api_docs.push(Route::new(
                 rocket::http::Method::Get,
                 url.url.as_ref(),
-                ServeApiDoc(openapi),
+                ServeApiDoc(ApiDocBody::new(&openapi, Format::Json), guard.clone()),
             ));
             url
         });
@@ -43,6 +48,7 @@ impl From<SwaggerUi> for Vec<Route> {
                 } else {
                     Config::new(urls)
                 }),
+                guard.clone(),
             ),
         ));
         routes.extend(api_docs);
@@ -51,28 +57,120 @@ impl From<SwaggerUi> for Vec<Route> {
     }
 }
 
+impl From<SpecEndpoint> for Vec<Route> {
+    fn from(spec: SpecEndpoint) -> Self {
+        spec.into_documents()
+            .into_iter()
+            .flat_map(|document| {
+                let document = Arc::new(document);
+                let preflight = document.allow_origin.as_ref().map(|_| {
+                    Route::new(
+                        Method::Options,
+                        &document.path,
+                        ServeSpecDocument(Arc::clone(&document), true),
+                    )
+                });
+
+                [Route::new(
+                    Method::Get,
+                    &document.path,
+                    ServeSpecDocument(Arc::clone(&document), false),
+                )]
+                .into_iter()
+                .chain(preflight)
+            })
+            .collect()
+    }
+}
+
+#[derive(Clone)]
+struct ServeSpecDocument(Arc<SpecDocument>, bool);
+
+#[rocket::async_trait]
+impl Handler for ServeSpecDocument {
+    async fn handle<'r>(&self, request: &'r Request<'_>, _: RocketData<'r>) -> Outcome<'r> {
+        let ServeSpecDocument(document, preflight) = self;
+        if !preflight {
+            if let Err(mut response) = authorize(document.guard.as_ref(), request).await {
+                // Unauthorized response must be readable by the allowed origin as well.
+                for (name, value) in document.cors_headers(false) {
+                    response.adjoin_header(Header::new(name, value));
+                }
+
+                return Outcome::Success(response);
+            }
+        }
+
+        let mut response = Response::build();
+        for (name, value) in document.headers(*preflight) {
+            response.header(Header::new(name, value));
+        }
+        if *preflight {
+            response.status(Status::NoContent);
+        } else if is_not_modified(&document.validators, request) {
+            response.status(Status::NotModified);
+        } else {
+            response
+                .status(Status::Ok)
+                .sized_body(document.body.len(), Cursor::new(document.body.clone()));
+        }
+
+        Outcome::Success(response.finalize())
+    }
+}
+
 #[derive(Clone)]
-struct ServeApiDoc(utoipa::openapi::OpenApi);
+struct ServeApiDoc(ApiDocBody, Option<Guard>);
 
 #[rocket::async_trait]
 impl Handler for ServeApiDoc {
     async fn handle<'r>(&self, request: &'r Request<'_>, _: RocketData<'r>) -> Outcome<'r> {
-        Outcome::from(request, Json(self.0.clone()))
+        if let Err(response) = authorize(self.1.as_ref(), request).await {
+            return Outcome::Success(response);
+        }
+
+        let doc = self.0.get();
+        let mut response = Response::build();
+        for (name, value) in doc.validators.headers() {
+            response.header(Header::new(name, value));
+        }
+        if is_not_modified(&doc.validators, request) {
+            response.status(Status::NotModified);
+        } else {
+            response
+                .status(Status::Ok)
+                .header(Header::new("Content-Type", self.0.content_type()))
+                .sized_body(doc.body.len(), Cursor::new(doc.body.clone()));
+        }
+
+        Outcome::Success(response.finalize())
     }
 }
 
 #[derive(Clone)]
-struct ServeSwagger(Cow<'static, str>, Arc<Config<'static>>);
+struct ServeSwagger(Cow<'static, str>, Arc<Config<'static>>, Option<Guard>);
 
 #[rocket::async_trait]
 impl Handler for ServeSwagger {
     async fn handle<'r>(&self, request: &'r Request<'_>, _: RocketData<'r>) -> Outcome<'r> {
+        if let Err(response) = authorize(self.2.as_ref(), request).await {
+            return Outcome::Success(response);
+        }
+
         let mut path = self.0.as_ref();
         if let Some(index) = self.0.find('<') {
             path = &path[..index];
         }
 
-        match super::serve(&request.uri().path().as_str()[path.len()..], self.1.clone()) {
+        let accept_encoding = request
+            .headers()
+            .get_one("Accept-Encoding")
+            .unwrap_or_default();
+        match super::serve_compressed(
+            &request.uri().path().as_str()[path.len()..],
+            self.1.clone(),
+            accept_encoding,
+        ) {
             Ok(swagger_file) => swagger_file
                 .map(|file| Outcome::from(request, file))
                 .unwrap_or_else(|| Outcome::from(request, NotFound("Swagger UI file not found"))),
@@ -84,11 +182,58 @@ impl Handler for ServeSwagger {
     }
 }
 
+/// Check the `request` with the `guard` if present and create _`401 Unauthorized`_ response if it
+/// does not pass.
+/// Check whether client already has the document with the `validators` based on the `request`
+/// headers.
+fn is_not_modified(validators: &Validators, request: &Request<'_>) -> bool {
+    validators.is_not_modified(
+        request.headers().get_one("If-None-Match"),
+        request.headers().get_one("If-Modified-Since"),
+    )
+}
+
+async fn authorize<'r>(guard: Option<&Guard>, request: &Request<'_>) -> Result<(), Response<'r>> {
+    let guard = match guard {
+        Some(guard) => guard,
+        None => return Ok(()),
+    };
+
+    let headers = request.headers().iter().collect::<Vec<_>>();
+    let headers = headers
+        .iter()
+        .map(|header| (header.name().as_str(), header.value().as_bytes()));
+    if guard
+        .authorize(auth::Request::new(request.uri().path().as_str(), headers))
+        .await
+    {
+        return Ok(());
+    }
+
+    let mut response = Response::build();
+    response.status(Status::Unauthorized);
+    for (name, value) in guard.unauthorized_headers() {
+        response.header(Header::new(name, value));
+    }
+
+    Err(response.finalize())
+}
+
 impl<'r, 'o: 'r> RocketResponder<'r, 'o> for SwaggerFile<'o> {
     fn respond_to(self, _: &'r Request<'_>) -> rocket::response::Result<'o> {
+        let mut response = Response::build();
+        if let Some(policy) = self.content_security_policy() {
+            response.header(Header::new("Content-Security-Policy", policy));
+        }
+        response.header(Header::new("Content-Type", self.content_type));
+        if let Some(encoding) = self.content_encoding {
+            response
+                .header(Header::new("Content-Encoding", encoding))
+                .header(Header::new("Vary", "Accept-Encoding"));
+        }
+
         rocket::response::Result::Ok(
-            Response::build()
-                .header(Header::new("Content-Type", self.content_type))
+            response
                 .sized_body(self.bytes.len(), Cursor::new(self.bytes.to_vec()))
                 .status(Status::Ok)
                 .finalize(),
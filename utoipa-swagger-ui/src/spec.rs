@@ -0,0 +1,465 @@
+#![cfg(any(feature = "actix-web", feature = "rocket", feature = "axum"))]
+
+use std::{borrow::Cow, time::SystemTime};
+
+use utoipa::openapi::OpenApi;
+
+use crate::{auth::Guard, cache::Validators};
+
+/// Endpoint serving the raw OpenAPI document without Swagger UI.
+///
+/// The document is served as JSON from the path with _`.json`_ extension and as YAML from the path
+/// with _`.yaml`_ extension when **yaml** feature is enabled. By default the path is
+/// _`/api-docs/openapi`_ thus the document is served from _`/api-docs/openapi.json`_ and
+/// _`/api-docs/openapi.yaml`_.
+///
+/// It can be used with the frameworks the same way as [`SwaggerUi`][crate::SwaggerUi] e.g. when
+/// the document is consumed by other tools than Swagger UI or from other origins.
+///
+/// The document is served with _`ETag`_ and _`Last-Modified`_ headers computed once when the
+/// document is serialized. Requests with matching _`If-None-Match`_ or _`If-Modified-Since`_
+/// header are responded with _`304 Not Modified`_ without the document.
+///
+/// # Examples
+///
+/// _**Serve the document for browsers at any origin.**_
+/// ```rust
+/// # use utoipa_swagger_ui::SpecEndpoint;
+/// # use utoipa::OpenApi;
+/// # #[derive(OpenApi)]
+/// # #[openapi()]
+/// # struct ApiDoc;
+/// let spec = SpecEndpoint::new(ApiDoc::openapi())
+///     .path("/api-docs/v1")
+///     .allow_origin("*");
+/// ```
+#[non_exhaustive]
+#[derive(Clone)]
+#[cfg_attr(
+    doc_cfg,
+    doc(cfg(any(feature = "actix-web", feature = "rocket", feature = "axum")))
+)]
+pub struct SpecEndpoint {
+    path: Cow<'static, str>,
+    openapi: OpenApi,
+    allow_origin: Option<Cow<'static, str>>,
+    guard: Option<Guard>,
+}
+
+impl SpecEndpoint {
+    /// Construct a new [`SpecEndpoint`] serving given [`OpenApi`] from _`/api-docs/openapi`_.
+    pub fn new(openapi: OpenApi) -> Self {
+        Self {
+            path: Cow::Borrowed("/api-docs/openapi"),
+            openapi,
+            allow_origin: None,
+            guard: None,
+        }
+    }
+
+    /// Set `path` without extension where the document is served e.g. _`"/api-docs/v1"`_.
+    pub fn path<P: Into<Cow<'static, str>>>(mut self, path: P) -> Self {
+        self.path = path.into();
+
+        self
+    }
+
+    /// Allow browsers to fetch the document from given `origin` e.g. _`"https://example.com"`_ or
+    /// _`"*"`_ for any origin.
+    ///
+    /// Responses will have _`Access-Control-Allow-Origin`_ header and _`OPTIONS`_ preflight requests
+    /// are responded with _`204 No Content`_ allowing _`GET`_ requests with _`Authorization`_ and
+    /// conditional request headers. Responses to a specific origin have _`Vary: Origin`_ header
+    /// thus caches do not serve them to other origins.
+    pub fn allow_origin<O: Into<Cow<'static, str>>>(mut self, origin: O) -> Self {
+        self.allow_origin = Some(origin.into());
+
+        self
+    }
+
+    /// Protect the document with [`Guard`]. Requests not passing the guard are responded with
+    /// _`401 Unauthorized`_. _`OPTIONS`_ preflight requests are not guarded since browsers do not
+    /// send credentials with them.
+    pub fn guard(mut self, guard: Guard) -> Self {
+        self.guard = Some(guard);
+
+        self
+    }
+
+    /// Serialize the document to the formats it is served in.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the [`OpenApi`] cannot be serialized.
+    pub(crate) fn into_documents(self) -> Vec<SpecDocument> {
+        let json = self
+            .openapi
+            .to_json()
+            .expect("OpenApi should serialize to JSON");
+        let now = SystemTime::now();
+        #[allow(unused_mut)]
+        let mut documents = vec![SpecDocument {
+            path: format!("{}.json", self.path),
+            content_type: "application/json",
+            validators: Validators::new(json.as_bytes(), Some(now)),
+            body: json,
+            allow_origin: self.allow_origin.clone(),
+            guard: self.guard.clone(),
+        }];
+
+        #[cfg(feature = "yaml")]
+        {
+            let yaml = self
+                .openapi
+                .to_yaml()
+                .expect("OpenApi should serialize to YAML");
+            documents.push(SpecDocument {
+                path: format!("{}.yaml", self.path),
+                content_type: "application/yaml",
+                validators: Validators::new(yaml.as_bytes(), Some(now)),
+                body: yaml,
+                allow_origin: self.allow_origin,
+                guard: self.guard,
+            });
+        }
+
+        documents
+    }
+}
+
+/// Serialized document of [`SpecEndpoint`] served from its own path.
+#[derive(Clone)]
+pub(crate) struct SpecDocument {
+    pub(crate) path: String,
+    pub(crate) content_type: &'static str,
+    pub(crate) body: String,
+    pub(crate) validators: Validators,
+    pub(crate) allow_origin: Option<Cow<'static, str>>,
+    pub(crate) guard: Option<Guard>,
+}
+
+impl SpecDocument {
+    /// Headers of the response to _`GET`_ request or to _`OPTIONS`_ request if `preflight` is true.
+    pub(crate) fn headers(&self, preflight: bool) -> Vec<(&'static str, String)> {
+        let mut headers = Vec::with_capacity(8);
+        if !preflight {
+            headers.push(("content-type", self.content_type.to_string()));
+            headers.extend(self.validators.headers());
+        }
+        headers.extend(self.cors_headers(preflight));
+
+        headers
+    }
+
+    /// CORS headers of the response to _`GET`_ request or to _`OPTIONS`_ request if `preflight` is
+    /// true. Responses to a specific origin vary by the _`Origin`_ header of the request.
+    pub(crate) fn cors_headers(&self, preflight: bool) -> Vec<(&'static str, String)> {
+        let mut headers = Vec::with_capacity(4);
+        if let Some(origin) = &self.allow_origin {
+            headers.push(("access-control-allow-origin", origin.to_string()));
+            if origin != "*" {
+                headers.push(("vary", "origin".to_string()));
+            }
+            if preflight {
+                headers.push(("access-control-allow-methods", "GET, OPTIONS".to_string()));
+                headers.push((
+                    "access-control-allow-headers",
+                    "authorization, if-none-match, if-modified-since".to_string(),
+                ));
+            }
+        }
+
+        headers
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use utoipa::openapi::{Info, Paths};
+
+    use super::*;
+
+    fn spec() -> SpecEndpoint {
+        SpecEndpoint::new(OpenApi::new(Info::new("title", "1.0.0"), Paths::new()))
+    }
+
+    #[test]
+    fn spec_documents_with_headers() {
+        let documents = spec().allow_origin("*").into_documents();
+
+        assert_eq!(documents[0].path, "/api-docs/openapi.json");
+        assert!(documents[0].body.contains(r#""title":"title""#));
+        let mut expected = vec![("content-type", "application/json".to_string())];
+        expected.extend(documents[0].validators.headers());
+        expected.push(("access-control-allow-origin", "*".to_string()));
+        assert_eq!(documents[0].headers(false), expected);
+        assert_eq!(
+            documents[0].headers(true),
+            [
+                ("access-control-allow-origin", "*".to_string()),
+                ("access-control-allow-methods", "GET, OPTIONS".to_string()),
+                (
+                    "access-control-allow-headers",
+                    "authorization, if-none-match, if-modified-since".to_string()
+                )
+            ]
+        );
+
+        #[cfg(feature = "yaml")]
+        {
+            assert_eq!(documents[1].path, "/api-docs/openapi.yaml");
+            assert_eq!(documents[1].content_type, "application/yaml");
+            assert!(documents[1].body.contains("title: title"));
+        }
+    }
+
+    #[test]
+    fn spec_documents_vary_by_specific_origin() {
+        let documents = spec().allow_origin("https://example.com").into_documents();
+
+        assert_eq!(
+            documents[0].cors_headers(false),
+            [
+                (
+                    "access-control-allow-origin",
+                    "https://example.com".to_string()
+                ),
+                ("vary", "origin".to_string())
+            ]
+        );
+        assert!(documents[0]
+            .headers(true)
+            .contains(&("vary", "origin".to_string())));
+    }
+
+    #[test]
+    fn spec_documents_without_cors() {
+        let documents = spec().path("/api-docs/v1").into_documents();
+
+        assert_eq!(documents[0].path, "/api-docs/v1.json");
+        let mut expected = vec![("content-type", "application/json".to_string())];
+        expected.extend(documents[0].validators.headers());
+        assert_eq!(documents[0].headers(false), expected);
+        assert!(documents[0].headers(true).is_empty());
+    }
+
+    #[cfg(feature = "actix-web")]
+    #[actix_web::test]
+    async fn serve_spec_with_actix_web() {
+        use actix_web::{
+            http::{header, Method, StatusCode},
+            test::{call_service, init_service, read_body, TestRequest},
+            App,
+        };
+
+        let app =
+            init_service(App::new().service(spec().allow_origin("https://example.com"))).await;
+
+        let response = call_service(
+            &app,
+            TestRequest::get()
+                .uri("/api-docs/openapi.json")
+                .to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response
+                .headers()
+                .get(header::ACCESS_CONTROL_ALLOW_ORIGIN)
+                .unwrap(),
+            "https://example.com"
+        );
+        let etag = response.headers().get(header::ETAG).unwrap().clone();
+        assert!(String::from_utf8_lossy(&read_body(response).await).contains("\"paths\""));
+
+        let response = call_service(
+            &app,
+            TestRequest::get()
+                .uri("/api-docs/openapi.json")
+                .insert_header((header::IF_NONE_MATCH, etag))
+                .to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+        assert!(read_body(response).await.is_empty());
+
+        let response = call_service(
+            &app,
+            TestRequest::default()
+                .method(Method::OPTIONS)
+                .uri("/api-docs/openapi.json")
+                .to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+        assert_eq!(
+            response
+                .headers()
+                .get(header::ACCESS_CONTROL_ALLOW_METHODS)
+                .unwrap(),
+            "GET, OPTIONS"
+        );
+    }
+
+    #[cfg(feature = "axum")]
+    #[tokio::test]
+    async fn serve_spec_with_axum() {
+        use axum::{
+            body::Body,
+            http::{header, Method, Request, StatusCode},
+            Router,
+        };
+        use hyper::service::Service;
+
+        let mut router: Router = Router::new().merge(spec());
+
+        let response = router
+            .call(
+                Request::get("/api-docs/openapi.json")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[header::CONTENT_TYPE], "application/json");
+        assert!(!response
+            .headers()
+            .contains_key(header::ACCESS_CONTROL_ALLOW_ORIGIN));
+        let last_modified = response.headers()[header::LAST_MODIFIED].clone();
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        assert!(String::from_utf8_lossy(&body).contains("\"paths\""));
+
+        let response = router
+            .call(
+                Request::get("/api-docs/openapi.json")
+                    .header(header::IF_MODIFIED_SINCE, last_modified)
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+        assert_eq!(response.headers()[header::CACHE_CONTROL], "no-cache");
+
+        let response = router
+            .call(
+                Request::builder()
+                    .method(Method::OPTIONS)
+                    .uri("/api-docs/openapi.json")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::METHOD_NOT_ALLOWED);
+    }
+
+    #[cfg(feature = "axum")]
+    #[tokio::test]
+    async fn serve_guarded_spec_with_axum() {
+        use axum::{
+            body::Body,
+            http::{header, Request, StatusCode},
+            Router,
+        };
+        use hyper::service::Service;
+
+        let mut router: Router = Router::new().merge(spec().guard(Guard::basic("admin", "secret")));
+
+        let response = router
+            .call(
+                Request::get("/api-docs/openapi.json")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+        assert!(response.headers()[header::WWW_AUTHENTICATE]
+            .to_str()
+            .unwrap()
+            .starts_with("Basic"));
+
+        let response = router
+            .call(
+                Request::get("/api-docs/openapi.json")
+                    .header(header::AUTHORIZATION, "Basic YWRtaW46c2VjcmV0")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[cfg(feature = "axum")]
+    #[tokio::test]
+    async fn serve_guarded_spec_with_cors_with_axum() {
+        use axum::{
+            body::Body,
+            http::{header, Method, Request, StatusCode},
+            Router,
+        };
+        use hyper::service::Service;
+
+        let mut router: Router = Router::new().merge(
+            spec()
+                .guard(Guard::bearer("token"))
+                .allow_origin("https://example.com"),
+        );
+
+        let response = router
+            .call(
+                Request::builder()
+                    .method(Method::OPTIONS)
+                    .uri("/api-docs/openapi.json")
+                    .header(header::ORIGIN, "https://example.com")
+                    .header(header::ACCESS_CONTROL_REQUEST_HEADERS, "authorization")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+        assert_eq!(
+            response.headers()[header::ACCESS_CONTROL_ALLOW_HEADERS],
+            "authorization, if-none-match, if-modified-since"
+        );
+        assert_eq!(response.headers()[header::VARY], "origin");
+
+        let response = router
+            .call(
+                Request::get("/api-docs/openapi.json")
+                    .header(header::ORIGIN, "https://example.com")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+        assert_eq!(
+            response.headers()[header::ACCESS_CONTROL_ALLOW_ORIGIN],
+            "https://example.com"
+        );
+        assert_eq!(response.headers()[header::VARY], "origin");
+
+        let response = router
+            .call(
+                Request::get("/api-docs/openapi.json")
+                    .header(header::ORIGIN, "https://example.com")
+                    .header(header::AUTHORIZATION, "Bearer token")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers()[header::ACCESS_CONTROL_ALLOW_ORIGIN],
+            "https://example.com"
+        );
+        assert_eq!(response.headers()[header::VARY], "origin");
+    }
+}
@@ -0,0 +1,421 @@
+#![cfg(feature = "tower")]
+
+use std::{
+    collections::HashMap,
+    convert::Infallible,
+    future::Future,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+};
+
+use bytes::Bytes;
+use http::{header, HeaderMap, Method, Request, Response, StatusCode};
+use http_body::Full;
+use tower_service::Service;
+
+use crate::{
+    auth::{self, Guard},
+    cache::{ApiDocBody, Format},
+    Config, SwaggerUi, Url,
+};
+
+/// Framework independent [`tower_service::Service`] serving the Swagger UI and the api docs of
+/// [`SwaggerUi`] for [`hyper`][hyper], _`tonic-web`_ or any other stack built on [`http`] types.
+///
+/// Each api doc is served as JSON from its [`Url`] and as YAML from the same url with the _`.json`_
+/// extension replaced with _`.yaml`_ e.g. _`/api-doc/openapi.yaml`_ for
+/// _`/api-doc/openapi.json`_. YAML is not served for urls not ending to _`.json`_.
+///
+/// The path of [`SwaggerUi`] must be a plain path e.g. _`"/swagger-ui"`_. Request to the path
+/// itself is redirected to the path with trailing slash. Requests to any other paths are
+/// responded with _`404 Not Found`_ and requests with other methods than _`GET`_ with
+/// _`405 Method Not Allowed`_. If [`SwaggerUi::guard`] is set all requests not passing it are
+/// responded with _`401 Unauthorized`_.
+///
+/// The api docs are served with _`ETag`_ and _`Cache-Control: no-cache`_ headers and requests
+/// with matching _`If-None-Match`_ or _`If-Modified-Since`_ header are responded with
+/// _`304 Not Modified`_.
+///
+/// # Examples
+///
+/// _**Serve Swagger UI with hyper.**_
+/// ```rust
+/// # use utoipa_swagger_ui::{SwaggerUi, SwaggerUiService};
+/// # use utoipa::OpenApi;
+/// # #[derive(OpenApi)]
+/// # #[openapi()]
+/// # struct ApiDoc;
+/// let service = SwaggerUiService::from(
+///     SwaggerUi::new("/swagger-ui").url("/api-doc/openapi.json", ApiDoc::openapi()),
+/// );
+/// ```
+///
+/// [hyper]: <https://docs.rs/hyper/>
+#[derive(Clone)]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "tower")))]
+pub struct SwaggerUiService {
+    path: Arc<str>,
+    docs: Arc<HashMap<String, ApiDocBody>>,
+    config: Arc<Config<'static>>,
+    guard: Option<Guard>,
+}
+
+impl SwaggerUiService {
+    fn respond(&self, method: &Method, path: &str, headers: &HeaderMap) -> Response<Full<Bytes>> {
+        let header = |name| headers.get(name).and_then(|value| value.to_str().ok());
+        let doc = self.docs.get(path);
+        let tail = path.strip_prefix(self.path.as_ref());
+        if doc.is_none() && !tail.is_some_and(|tail| tail.is_empty() || tail.starts_with('/')) {
+            return status_response(StatusCode::NOT_FOUND);
+        }
+
+        if method != Method::GET {
+            return status_response(StatusCode::METHOD_NOT_ALLOWED);
+        }
+
+        if let Some(api_doc) = doc {
+            let doc = api_doc.get();
+            let not_modified = doc.validators.is_not_modified(
+                header(header::IF_NONE_MATCH),
+                header(header::IF_MODIFIED_SINCE),
+            );
+
+            let mut response = Response::builder();
+            for (name, value) in doc.validators.headers() {
+                response = response.header(name, value);
+            }
+            let response = if not_modified {
+                response
+                    .status(StatusCode::NOT_MODIFIED)
+                    .body(Full::default())
+            } else {
+                response
+                    .header(header::CONTENT_TYPE, api_doc.content_type())
+                    .body(Full::new(Bytes::from(doc.body.clone())))
+            };
+
+            return response.expect("api doc response should be valid");
+        }
+
+        let tail = match tail.and_then(|tail| tail.strip_prefix('/')) {
+            Some(tail) => tail,
+            None => {
+                return Response::builder()
+                    .status(StatusCode::SEE_OTHER)
+                    .header(header::LOCATION, format!("{}/", self.path))
+                    .body(Full::default())
+                    .expect("redirect response should be valid")
+            }
+        };
+
+        let accept_encoding = header(header::ACCEPT_ENCODING).unwrap_or_default();
+        match super::serve_compressed(tail, Arc::clone(&self.config), accept_encoding) {
+            Ok(Some(file)) => {
+                let policy = file.content_security_policy();
+                let mut response =
+                    Response::builder().header(header::CONTENT_TYPE, file.content_type);
+                if let Some(encoding) = file.content_encoding {
+                    response = response
+                        .header(header::CONTENT_ENCODING, encoding)
+                        .header(header::VARY, "accept-encoding");
+                }
+                if let Some(policy) = policy {
+                    response = response.header(header::CONTENT_SECURITY_POLICY, policy);
+                }
+
+                response
+                    .body(Full::new(Bytes::from(file.bytes.into_owned())))
+                    .expect("Swagger UI file response should be valid")
+            }
+            Ok(None) => status_response(StatusCode::NOT_FOUND),
+            Err(error) => {
+                let mut response = Response::new(Full::new(Bytes::from(error.to_string())));
+                *response.status_mut() = StatusCode::INTERNAL_SERVER_ERROR;
+                response
+            }
+        }
+    }
+}
+
+fn status_response(status: StatusCode) -> Response<Full<Bytes>> {
+    let mut response = Response::new(Full::default());
+    *response.status_mut() = status;
+    response
+}
+
+impl From<SwaggerUi> for SwaggerUiService {
+    /// Create [`SwaggerUiService`] from [`SwaggerUi`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if an api doc cannot be serialized to JSON or YAML.
+    fn from(swagger_ui: SwaggerUi) -> Self {
+        let mut docs = HashMap::with_capacity(swagger_ui.urls.len() * 2);
+        let urls = swagger_ui
+            .urls
+            .into_iter()
+            .map(|(url, doc)| {
+                docs.insert(url.url.to_string(), ApiDocBody::new(&doc, Format::Json));
+
+                if let Some(stem) = url.url.strip_suffix(".json") {
+                    docs.insert(format!("{stem}.yaml"), ApiDocBody::new(&doc, Format::Yaml));
+                }
+
+                url
+            })
+            .collect::<Vec<Url>>();
+
+        let config = if let Some(config) = swagger_ui.config {
+            if config.url.is_some() || !config.urls.is_empty() {
+                config
+            } else {
+                config.configure_defaults(urls)
+            }
+        } else {
+            Config::new(urls)
+        };
+
+        Self {
+            path: Arc::from(swagger_ui.path.trim_end_matches('/')),
+            docs: Arc::new(docs),
+            config: Arc::new(config),
+            guard: swagger_ui.guard,
+        }
+    }
+}
+
+impl<B> Service<Request<B>> for SwaggerUiService {
+    type Response = Response<Full<Bytes>>;
+    type Error = Infallible;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: Request<B>) -> Self::Future {
+        let service = self.clone();
+        let method = request.method().clone();
+        let path = request.uri().path().to_string();
+        let headers = request.headers().clone();
+        let guard_request = service.guard.as_ref().map(|_| {
+            let headers = headers
+                .iter()
+                .map(|(name, value)| (name.as_str(), value.as_bytes()));
+            auth::Request::new(&path, headers)
+        });
+
+        Box::pin(async move {
+            if let (Some(guard), Some(guard_request)) = (&service.guard, guard_request) {
+                if !guard.authorize(guard_request).await {
+                    let mut response = status_response(StatusCode::UNAUTHORIZED);
+                    for (name, value) in guard.unauthorized_headers() {
+                        if let Ok(value) = header::HeaderValue::from_str(&value) {
+                            response.headers_mut().insert(name, value);
+                        }
+                    }
+                    return Ok(response);
+                }
+            }
+
+            Ok(service.respond(&method, &path, &headers))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use utoipa::openapi::{Info, OpenApi, Paths};
+
+    use super::*;
+    use crate::reload::Reload;
+
+    async fn get(service: &mut SwaggerUiService, path: &str) -> (StatusCode, String) {
+        let response = service
+            .call(Request::get(path).body(()).unwrap())
+            .await
+            .unwrap();
+        let status = response.status();
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+
+        (status, String::from_utf8(body.to_vec()).unwrap())
+    }
+
+    fn service() -> SwaggerUiService {
+        SwaggerUiService::from(SwaggerUi::new("/swagger-ui").url(
+            "/api-doc/openapi.json",
+            OpenApi::new(Info::new("title", "1.0.0"), Paths::new()),
+        ))
+    }
+
+    #[tokio::test]
+    async fn serve_api_doc_as_json_and_yaml() {
+        let mut service = service();
+
+        let (status, json) = get(&mut service, "/api-doc/openapi.json").await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(json.starts_with('{') && json.contains(r#""title":"title""#));
+
+        let (status, yaml) = get(&mut service, "/api-doc/openapi.yaml").await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(yaml.contains("title: title"));
+    }
+
+    #[tokio::test]
+    async fn serve_api_doc_not_modified() {
+        let service = service();
+
+        let response = service.respond(&Method::GET, "/api-doc/openapi.yaml", &HeaderMap::new());
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[header::CACHE_CONTROL], "no-cache");
+        let etag = response.headers()[header::ETAG].clone();
+
+        let mut headers = HeaderMap::new();
+        headers.insert(header::IF_NONE_MATCH, etag.clone());
+        let response = service.respond(&Method::GET, "/api-doc/openapi.yaml", &headers);
+        assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+        assert_eq!(response.headers()[header::ETAG], etag);
+
+        let response = service.respond(&Method::GET, "/api-doc/openapi.json", &headers);
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn serve_swagger_ui() {
+        let mut service = service();
+
+        let response = service.respond(&Method::GET, "/swagger-ui", &HeaderMap::new());
+        assert_eq!(response.status(), StatusCode::SEE_OTHER);
+        assert_eq!(response.headers()[header::LOCATION], "/swagger-ui/");
+
+        let (status, index) = get(&mut service, "/swagger-ui/").await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(index.contains("swagger-ui"));
+
+        let (status, initializer) = get(&mut service, "/swagger-ui/swagger-initializer.js").await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(initializer.contains("/api-doc/openapi.json"));
+
+        assert_eq!(
+            get(&mut service, "/swagger-ui/missing.js").await.0,
+            StatusCode::NOT_FOUND
+        );
+        assert_eq!(get(&mut service, "/other").await.0, StatusCode::NOT_FOUND);
+        assert_eq!(
+            service
+                .respond(&Method::POST, "/swagger-ui/", &HeaderMap::new())
+                .status(),
+            StatusCode::METHOD_NOT_ALLOWED
+        );
+    }
+
+    #[tokio::test]
+    async fn serve_multiple_api_docs_with_primary_url() {
+        let mut service = SwaggerUiService::from(SwaggerUi::new("/docs").urls(vec![
+            (
+                Url::new("v1", "/api-docs/v1.json"),
+                OpenApi::new(Info::new("api", "1.0.0"), Paths::new()),
+            ),
+            (
+                Url::with_primary("v2", "/api-docs/v2.json", true),
+                OpenApi::new(Info::new("api", "2.0.0"), Paths::new()),
+            ),
+        ]));
+
+        let (status, v1) = get(&mut service, "/api-docs/v1.json").await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(v1.contains(r#""version":"1.0.0""#));
+        let (status, v2) = get(&mut service, "/api-docs/v2.yaml").await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(v2.contains("version: 2.0.0"));
+
+        let (_, initializer) = get(&mut service, "/docs/swagger-initializer.js").await;
+        assert!(initializer.contains(r#""urls.primaryName": "v2""#));
+        assert!(initializer.contains(r#""name": "v1""#));
+        assert!(initializer.contains(r#""url": "/api-docs/v2.json""#));
+    }
+
+    #[tokio::test]
+    async fn serve_swagger_ui_with_guard() {
+        let mut service = SwaggerUiService::from(
+            SwaggerUi::new("/swagger-ui")
+                .url(
+                    "/api-doc/openapi.json",
+                    OpenApi::new(Info::new("title", "1.0.0"), Paths::new()),
+                )
+                .guard(Guard::bearer("token")),
+        );
+
+        for path in ["/swagger-ui/", "/api-doc/openapi.json"] {
+            let response = service
+                .call(Request::get(path).body(()).unwrap())
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+            assert_eq!(response.headers()[header::WWW_AUTHENTICATE], "Bearer");
+
+            let response = service
+                .call(
+                    Request::get(path)
+                        .header(header::AUTHORIZATION, "Bearer token")
+                        .body(())
+                        .unwrap(),
+                )
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+        }
+    }
+
+    #[tokio::test]
+    async fn serve_reloaded_api_doc() {
+        let version = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let reload = Reload::new({
+            let version = Arc::clone(&version);
+            move || {
+                let version = version.load(std::sync::atomic::Ordering::SeqCst);
+                OpenApi::new(Info::new("title", &version.to_string()), Paths::new())
+            }
+        });
+        let mut service = SwaggerUiService::from(
+            SwaggerUi::new("/swagger-ui").reload_url("/api-doc/openapi.json", reload),
+        );
+
+        let (_, json) = get(&mut service, "/api-doc/openapi.json").await;
+        assert!(json.contains(r#""version":"0""#));
+
+        version.store(1, std::sync::atomic::Ordering::SeqCst);
+        let (_, json) = get(&mut service, "/api-doc/openapi.json").await;
+        assert!(json.contains(r#""version":"1""#));
+        let (_, yaml) = get(&mut service, "/api-doc/openapi.yaml").await;
+        assert!(yaml.contains("version: '1'"));
+    }
+
+    #[tokio::test]
+    async fn serve_swagger_ui_with_nonce() {
+        let service = SwaggerUiService::from(
+            SwaggerUi::new("/swagger-ui")
+                .url(
+                    "/api-doc/openapi.json",
+                    OpenApi::new(Info::new("title", "1.0.0"), Paths::new()),
+                )
+                .config(Config::default().nonce("n0nce")),
+        );
+
+        let response = service.respond(&Method::GET, "/swagger-ui/", &HeaderMap::new());
+        assert_eq!(
+            response.headers()[header::CONTENT_SECURITY_POLICY],
+            "script-src 'nonce-n0nce'; style-src 'nonce-n0nce'"
+        );
+
+        let response = service.respond(
+            &Method::GET,
+            "/swagger-ui/swagger-ui.css",
+            &HeaderMap::new(),
+        );
+        assert!(!response
+            .headers()
+            .contains_key(header::CONTENT_SECURITY_POLICY));
+    }
+}
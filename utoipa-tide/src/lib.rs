@@ -0,0 +1,210 @@
+#![cfg_attr(doc_cfg, feature(doc_cfg))]
+//! This crate implements bindings between [`utoipa`][utoipa] and [`tide`][tide] for serving the
+//! OpenAPI documentation and Swagger UI with [`ServerExt`] extension of [`tide::Server`].
+//!
+//! It also provides [`to_openapi_path`] and [`to_tide_path`] for translating between tide's
+//! _`:param`_ route syntax and OpenAPI path templates e.g. when nesting documentation of nested
+//! tide servers with [`OpenApi::nest`].
+//!
+//! [utoipa]: <https://docs.rs/utoipa/>
+//! [tide]: <https://docs.rs/tide/>
+//!
+//! # Install
+//!
+//! ```toml
+//! [dependencies]
+//! utoipa = "2"
+//! utoipa-tide = "0.1"
+//! ```
+//!
+//! # Examples
+//!
+//! _**Serve OpenAPI document with Swagger UI.**_
+//! ```no_run
+//! # use utoipa::OpenApi;
+//! # use utoipa_tide::ServerExt;
+//! #[derive(OpenApi)]
+//! #[openapi(info(title = "Todo API"))]
+//! struct ApiDoc;
+//!
+//! let mut app = tide::new();
+//! app.openapi("/api-doc.json", &ApiDoc::openapi())
+//!     .swagger_ui("/swagger-ui", utoipa_swagger_ui::Config::from("/api-doc.json"));
+//! ```
+use std::sync::Arc;
+
+use tide::{http::mime, Redirect, Request, Response, Server};
+use utoipa::openapi::OpenApi;
+use utoipa_swagger_ui::Config;
+
+/// Extension of [`tide::Server`] for serving the OpenAPI document and Swagger UI.
+pub trait ServerExt {
+    /// Serve given [`OpenApi`] as JSON with _`GET`_ request to given `path` e.g.
+    /// _`"/api-doc/openapi.json"`_.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the [`OpenApi`] cannot be serialized to JSON.
+    fn openapi(&mut self, path: &str, openapi: &OpenApi) -> &mut Self;
+
+    /// Serve Swagger UI under given `path` e.g. _`"/swagger-ui"`_ with given [`Config`]. Request
+    /// to the `path` itself is redirected to the `path` with trailing slash.
+    fn swagger_ui(&mut self, path: &str, config: Config<'static>) -> &mut Self;
+}
+
+impl<State> ServerExt for Server<State>
+where
+    State: Clone + Send + Sync + 'static,
+{
+    fn openapi(&mut self, path: &str, openapi: &OpenApi) -> &mut Self {
+        let json = openapi.to_json().expect("OpenApi should serialize to JSON");
+
+        self.at(path).get(move |_: Request<State>| {
+            let json = json.clone();
+            async move {
+                Ok(Response::builder(200)
+                    .body(json)
+                    .content_type(mime::JSON)
+                    .build())
+            }
+        });
+
+        self
+    }
+
+    fn swagger_ui(&mut self, path: &str, config: Config<'static>) -> &mut Self {
+        let path = path.trim_end_matches('/');
+        let config = Arc::new(config);
+
+        self.at(path).get(|request: Request<State>| async move {
+            Ok(Redirect::new(format!("{}/", request.url().path())))
+        });
+        // Named wildcard matches only non empty tail thus the index is routed separately.
+        for route in [format!("{path}/"), format!("{path}/*tail")] {
+            let config = Arc::clone(&config);
+            self.at(&route).get(move |request: Request<State>| {
+                let config = Arc::clone(&config);
+                async move {
+                    Ok(serve_swagger_ui(
+                        request.param("tail").unwrap_or_default(),
+                        config,
+                    ))
+                }
+            });
+        }
+
+        self
+    }
+}
+
+fn serve_swagger_ui(tail: &str, config: Arc<Config<'static>>) -> Response {
+    match utoipa_swagger_ui::serve(tail, config) {
+        Ok(Some(file)) => {
+            let mut response = Response::builder(200).body(file.bytes.to_vec());
+            if let Ok(content_type) = file.content_type.parse::<mime::Mime>() {
+                response = response.content_type(content_type);
+            }
+            response.build()
+        }
+        Ok(None) => Response::new(404),
+        Err(error) => Response::builder(500).body(error.to_string()).build(),
+    }
+}
+
+/// Format [`tide`] route path to OpenAPI path template e.g. _`/users/:id`_ to _`/users/{id}`_.
+/// Named wildcards e.g. _`*path`_ are formatted the same way.
+pub fn to_openapi_path(path: &str) -> String {
+    path.split('/')
+        .map(|segment| {
+            match segment
+                .strip_prefix(':')
+                .or_else(|| segment.strip_prefix('*').filter(|name| !name.is_empty()))
+            {
+                Some(name) => format!("{{{name}}}"),
+                None => segment.to_string(),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Format OpenAPI path template to [`tide`] route path e.g. _`/users/{id}`_ to _`/users/:id`_.
+/// Use it to register handlers by the path of their [`utoipa::Path`] e.g.
+/// _`app.at(&to_tide_path(&__path_get_user::path()))`_.
+pub fn to_tide_path(path: &str) -> String {
+    path.split('/')
+        .map(|segment| {
+            match segment
+                .strip_prefix('{')
+                .and_then(|segment| segment.strip_suffix('}'))
+            {
+                Some(name) => format!(":{name}"),
+                None => segment.to_string(),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+#[cfg(test)]
+mod tests {
+    use tide::http::{Method, Request, Response, StatusCode, Url};
+
+    use super::*;
+
+    async fn get(app: &Server<()>, path: &str) -> Response {
+        let request = Request::new(
+            Method::Get,
+            Url::parse(&format!("http://localhost{path}")).unwrap(),
+        );
+
+        app.respond(request).await.unwrap()
+    }
+
+    #[test]
+    fn format_paths_between_tide_and_openapi() {
+        assert_eq!(
+            to_openapi_path("/users/:id/pets/:pet_id"),
+            "/users/{id}/pets/{pet_id}"
+        );
+        assert_eq!(to_openapi_path("/files/*path"), "/files/{path}");
+        assert_eq!(to_openapi_path("/files/*"), "/files/*");
+        assert_eq!(to_tide_path("/users/{id}"), "/users/:id");
+        assert_eq!(to_tide_path("/users"), "/users");
+    }
+
+    #[async_std::test]
+    async fn serve_openapi_and_swagger_ui() {
+        let mut app = tide::new();
+        app.openapi("/api-doc.json", &OpenApi::default())
+            .swagger_ui("/swagger-ui", Config::from("/api-doc.json"));
+
+        let mut response = get(&app, "/api-doc.json").await;
+        assert_eq!(response.status(), StatusCode::Ok);
+        assert!(response.body_string().await.unwrap().contains("\"paths\""));
+
+        let response = get(&app, "/swagger-ui").await;
+        assert_eq!(response.status(), StatusCode::Found);
+        assert_eq!(
+            response
+                .header("Location")
+                .map(|location| location.last().as_str()),
+            Some("/swagger-ui/")
+        );
+
+        let response = get(&app, "/swagger-ui/").await;
+        assert_eq!(response.status(), StatusCode::Ok);
+        assert_eq!(response.content_type().unwrap().essence(), "text/html");
+
+        let mut response = get(&app, "/swagger-ui/swagger-initializer.js").await;
+        assert_eq!(response.status(), StatusCode::Ok);
+        assert!(response
+            .body_string()
+            .await
+            .unwrap()
+            .contains("/api-doc.json"));
+
+        let response = get(&app, "/swagger-ui/missing.js").await;
+        assert_eq!(response.status(), StatusCode::NotFound);
+    }
+}
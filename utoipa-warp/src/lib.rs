@@ -0,0 +1,331 @@
+#![cfg_attr(doc_cfg, feature(doc_cfg))]
+//! This crate implements bindings between [`utoipa`][utoipa] and [`warp`][warp] for serving the
+//! OpenAPI documentation and Swagger UI, and for keeping the documentation of handlers together
+//! with the warp filters routing to them.
+//!
+//! [utoipa]: <https://docs.rs/utoipa/>
+//! [warp]: <https://docs.rs/warp/>
+//!
+//! # Install
+//!
+//! ```toml
+//! [dependencies]
+//! utoipa = "2"
+//! utoipa-warp = "0.1"
+//! ```
+//!
+//! # Examples
+//!
+//! _**Serve OpenAPI document of documented filters with Swagger UI.**_
+//! ```no_run
+//! # use utoipa::OpenApi;
+//! # use utoipa_warp::documented;
+//! # use warp::Filter;
+//! #[derive(OpenApi)]
+//! #[openapi(info(title = "Todo API"))]
+//! struct ApiDoc;
+//!
+//! #[utoipa::path(get, path = "/todo", responses((status = 200, description = "List todos")))]
+//! async fn list_todos() -> Result<impl warp::Reply, warp::Rejection> {
+//!     Ok(warp::reply::json(&Vec::<String>::new()))
+//! }
+//!
+//! #[utoipa::path(delete, path = "/todo/{id}", params(("id" = i64, Path)))]
+//! async fn delete_todo(id: i64) -> Result<impl warp::Reply, warp::Rejection> {
+//!     Ok(warp::reply())
+//! }
+//!
+//! let list = documented!(
+//!     warp::path("todo").and(warp::get()).and(warp::path::end()).and_then(list_todos)
+//!         => list_todos
+//! );
+//! let delete = documented!(
+//!     warp::path!("todo" / i64).and(warp::delete()).and_then(delete_todo) => delete_todo
+//! );
+//!
+//! let (todos, paths) = list.combine(delete, |list, delete| list.or(delete)).split_for_parts();
+//!
+//! let mut openapi = ApiDoc::openapi();
+//! openapi.paths.merge(paths);
+//!
+//! # async {
+//! warp::serve(todos.or(utoipa_warp::docs("/swagger-ui", "/api-doc.json", openapi)))
+//!     .run(([127, 0, 0, 1], 8080))
+//!     .await;
+//! # };
+//! ```
+use std::sync::Arc;
+
+use utoipa::openapi::{path::Paths, OpenApi};
+use utoipa_swagger_ui::Config;
+use warp::{
+    http::{Response, StatusCode, Uri},
+    path::FullPath,
+    reply::Json,
+    Filter, Rejection, Reply,
+};
+
+/// Serve given [`OpenApi`] as JSON with _`GET`_ request to given `path` e.g.
+/// _`"/api-doc/openapi.json"`_.
+pub fn openapi(
+    path: &'static str,
+    openapi: OpenApi,
+) -> impl Filter<Extract = (Json,), Error = Rejection> + Clone {
+    let openapi = Arc::new(openapi);
+
+    warp::get()
+        .and(warp::path::full())
+        .and_then(move |full_path: FullPath| {
+            let openapi = Arc::clone(&openapi);
+            async move {
+                if full_path.as_str() == path {
+                    Ok(warp::reply::json(&*openapi))
+                } else {
+                    Err(warp::reject::not_found())
+                }
+            }
+        })
+}
+
+/// Serve Swagger UI under given `path` e.g. _`"/swagger-ui"`_ with given [`Config`]. Request to
+/// the `path` itself is redirected to the `path` with trailing slash.
+pub fn swagger_ui(
+    path: &'static str,
+    config: Config<'static>,
+) -> impl Filter<Extract = (Box<dyn Reply>,), Error = Rejection> + Clone {
+    let config = Arc::new(config);
+
+    warp::get()
+        .and(warp::path::full())
+        .and_then(move |full_path: FullPath| {
+            let config = Arc::clone(&config);
+            async move { serve_swagger_ui(path, full_path.as_str(), config) }
+        })
+}
+
+/// Serve [`OpenApi`] as JSON at `openapi_path` and Swagger UI showing it under `swagger_ui_path`.
+/// This combines [`openapi`] and [`swagger_ui`] filters.
+pub fn docs(
+    swagger_ui_path: &'static str,
+    openapi_path: &'static str,
+    openapi: OpenApi,
+) -> impl Filter<Extract = (Box<dyn Reply>,), Error = Rejection> + Clone {
+    self::openapi(openapi_path, openapi)
+        .map(|json: Json| Box::new(json) as Box<dyn Reply>)
+        .or(swagger_ui(swagger_ui_path, Config::from(openapi_path)))
+        .unify()
+}
+
+fn serve_swagger_ui(
+    path: &str,
+    full_path: &str,
+    config: Arc<Config<'static>>,
+) -> Result<Box<dyn Reply>, Rejection> {
+    let path = path.trim_end_matches('/');
+    if full_path == path {
+        let location = format!("{path}/")
+            .parse::<Uri>()
+            .map_err(|_| warp::reject::not_found())?;
+        return Ok(Box::new(warp::redirect::found(location)));
+    }
+
+    let tail = full_path
+        .strip_prefix(path)
+        .and_then(|tail| tail.strip_prefix('/'))
+        .ok_or_else(warp::reject::not_found)?;
+
+    match utoipa_swagger_ui::serve(tail, config) {
+        Ok(Some(file)) => Ok(Box::new(
+            Response::builder()
+                .header("Content-Type", file.content_type)
+                .body(file.bytes),
+        )),
+        Ok(None) => Err(warp::reject::not_found()),
+        Err(error) => Ok(Box::new(
+            Response::builder()
+                .status(StatusCode::INTERNAL_SERVER_ERROR)
+                .body(error.to_string()),
+        )),
+    }
+}
+
+/// [`warp`] filter together with the OpenAPI [`Paths`] of the handlers it routes to. Create one
+/// with [`documented!`] macro.
+///
+/// Paths of warp filters are built with combinators thus the path of the documentation comes
+/// from the [`#[utoipa::path(...)]`][path] attribute of the handlers. Documented filters can be
+/// combined with [`DocumentedFilter::combine`] which merges their paths the same way the filters
+/// are combined.
+///
+/// [path]: <https://docs.rs/utoipa/latest/utoipa/attr.path.html>
+pub struct DocumentedFilter<F> {
+    filter: F,
+    paths: Paths,
+}
+
+impl<F> DocumentedFilter<F> {
+    /// Construct a new [`DocumentedFilter`] from `filter` and [`Paths`] of its handlers.
+    pub fn new(filter: F, paths: Paths) -> Self {
+        Self { filter, paths }
+    }
+
+    /// Combine this filter with `other` filter using `combine` function e.g.
+    /// _`|left, right| left.or(right)`_. [`Paths`] of both filters are merged.
+    pub fn combine<G, H, C>(self, other: DocumentedFilter<G>, combine: C) -> DocumentedFilter<H>
+    where
+        C: FnOnce(F, G) -> H,
+    {
+        let mut paths = self.paths;
+        paths.merge(other.paths);
+
+        DocumentedFilter {
+            filter: combine(self.filter, other.filter),
+            paths,
+        }
+    }
+
+    /// Map the filter with `map` function e.g. to add common filters such as
+    /// _`|filter| warp::path("api").and(filter)`_. The [`Paths`] are kept as is.
+    pub fn map<G, M>(self, map: M) -> DocumentedFilter<G>
+    where
+        M: FnOnce(F) -> G,
+    {
+        DocumentedFilter {
+            filter: map(self.filter),
+            paths: self.paths,
+        }
+    }
+
+    /// Get reference to the [`Paths`] of the filter.
+    pub fn paths(&self) -> &Paths {
+        &self.paths
+    }
+
+    /// Split to the filter and its [`Paths`].
+    pub fn split_for_parts(self) -> (F, Paths) {
+        (self.filter, self.paths)
+    }
+}
+
+/// Create [`DocumentedFilter`] from a warp filter and the handlers annotated with
+/// [`#[utoipa::path(...)]`][path] the filter routes to e.g.
+/// _`documented!(warp::path("todo").and_then(list_todos) => list_todos)`_.
+///
+/// Handlers can be referred with module path e.g. _`documented!(filter => todo::list_todos)`_.
+///
+/// [path]: <https://docs.rs/utoipa/latest/utoipa/attr.path.html>
+#[macro_export]
+macro_rules! documented {
+    ( $filter:expr => $( $( $handler:ident )::+ ),+ $(,)? ) => {
+        {
+            let mut paths = $crate::__dev::utoipa::openapi::path::Paths::new();
+            $(
+                let (path, path_item) = $crate::documented!( @path_item [] $( $handler )+ );
+                paths.add_path(path, path_item);
+            )+
+            $crate::DocumentedFilter::new($filter, paths)
+        }
+    };
+    ( @path_item [ $( $module:ident )* ] $name:ident $( $rest:ident )+ ) => {
+        $crate::documented!( @path_item [ $( $module )* $name ] $( $rest )+ )
+    };
+    ( @path_item [ $( $module:ident )* ] $name:ident ) => {
+        $crate::__dev::paste! {
+            (
+                <$( $module:: )* [<__path_ $name>] as $crate::__dev::utoipa::Path>::path(),
+                <$( $module:: )* [<__path_ $name>] as $crate::__dev::utoipa::Path>::path_item(None),
+            )
+        }
+    };
+}
+
+#[doc(hidden)]
+pub mod __dev {
+    pub use paste::paste;
+    pub use utoipa;
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::Value;
+    use utoipa::openapi::path::PathItemType;
+
+    use super::*;
+
+    mod todo {
+        #[utoipa::path(get, path = "/todo")]
+        pub async fn list_todos() -> Result<impl warp::Reply, warp::Rejection> {
+            Ok(warp::reply::json(&vec!["buy milk"]))
+        }
+    }
+
+    #[utoipa::path(delete, path = "/todo/{id}", params(("id" = i64, Path)))]
+    async fn delete_todo(id: i64) -> Result<impl warp::Reply, warp::Rejection> {
+        Ok(warp::reply::json(&id))
+    }
+
+    #[tokio::test]
+    async fn combine_documented_filters() {
+        let list = documented!(
+            warp::path("todo").and(warp::path::end()).and(warp::get()).and_then(todo::list_todos)
+                => todo::list_todos
+        );
+        let delete = documented!(
+            warp::path!("todo" / i64).and(warp::delete()).and_then(delete_todo) => delete_todo
+        );
+
+        let (filter, paths) = list
+            .combine(delete, |list, delete| list.or(delete))
+            .split_for_parts();
+
+        let response = warp::test::request()
+            .method("DELETE")
+            .path("/todo/1")
+            .reply(&filter)
+            .await;
+        assert_eq!(response.body().as_ref(), b"1");
+
+        assert!(paths
+            .get_path_operation("/todo", PathItemType::Get)
+            .is_some());
+        assert!(paths
+            .get_path_operation("/todo/{id}", PathItemType::Delete)
+            .is_some());
+    }
+
+    #[tokio::test]
+    async fn serve_openapi_and_swagger_ui() {
+        let openapi = OpenApi::default();
+        let filter = docs("/swagger-ui", "/api-doc.json", openapi);
+
+        let response = warp::test::request()
+            .path("/api-doc.json")
+            .reply(&filter)
+            .await;
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(serde_json::from_slice::<Value>(response.body())
+            .unwrap()
+            .get("paths")
+            .is_some());
+
+        let response = warp::test::request()
+            .path("/swagger-ui")
+            .reply(&filter)
+            .await;
+        assert_eq!(response.status(), StatusCode::FOUND);
+        assert_eq!(response.headers()["location"], "/swagger-ui/");
+
+        let response = warp::test::request()
+            .path("/swagger-ui/")
+            .reply(&filter)
+            .await;
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()["content-type"], "text/html");
+
+        let response = warp::test::request()
+            .path("/swagger-ui/missing.js")
+            .reply(&filter)
+            .await;
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+}
@@ -0,0 +1,253 @@
+//! Implements [`Modify`] for injecting build metadata to the [`Info`][info] of the API.
+//!
+//! [`BuildInfo`] adds git commit, build timestamp and rustc version of the build as
+//! _`x-build-git-sha`_, _`x-build-timestamp`_ and _`x-build-rustc-version`_ extensions.
+//!
+//! The values must be captured when the API crate is compiled. Call [`emit_cargo_env`] from the
+//! build script of the crate and create [`BuildInfo`] with [`build_info!`][crate::build_info!]
+//! macro which reads the values emitted by the build script. Alternatively set the values of
+//! the [`BuildInfoBuilder`] from any other source.
+//!
+//! # Examples
+//!
+//! _**Capture build metadata in `main` of `build.rs` with `utoipa` as build dependency.**_
+//! ```rust,no_run
+//! utoipa::build_info::emit_cargo_env();
+//! ```
+//!
+//! _**Add captured build metadata to the API.**_
+//! ```rust
+//! # use utoipa::{Modify, OpenApi};
+//! #[derive(OpenApi)]
+//! #[openapi(modifiers(add_build_info))]
+//! struct ApiDoc;
+//!
+//! fn add_build_info(openapi: &mut utoipa::openapi::OpenApi) {
+//!     utoipa::build_info!().modify(openapi);
+//! }
+//! ```
+//!
+//! [info]: crate::openapi::info::Info
+use std::{
+    env,
+    path::Path,
+    process::Command,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use crate::{
+    openapi::{builder, set_value, Extensions, OpenApi},
+    Modify,
+};
+
+/// Name of the environment variable holding git commit of the build.
+pub const GIT_SHA_ENV: &str = "UTOIPA_BUILD_GIT_SHA";
+/// Name of the environment variable holding timestamp of the build.
+pub const TIMESTAMP_ENV: &str = "UTOIPA_BUILD_TIMESTAMP";
+/// Name of the environment variable holding rustc version of the build.
+pub const RUSTC_VERSION_ENV: &str = "UTOIPA_BUILD_RUSTC_VERSION";
+
+builder! {
+    BuildInfoBuilder;
+
+    /// Build metadata of the API added to [`Info::extensions`][extensions] when used as
+    /// [`Modify`]. Values which are `None` are not added.
+    ///
+    /// [extensions]: crate::openapi::info::Info::extensions
+    #[non_exhaustive]
+    #[derive(Default, Clone, PartialEq, Eq)]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    pub struct BuildInfo {
+        /// Git commit of the build added as _`x-build-git-sha`_.
+        pub git_sha: Option<String>,
+
+        /// Timestamp of the build added as _`x-build-timestamp`_.
+        pub timestamp: Option<String>,
+
+        /// Version of the rustc used in the build added as _`x-build-rustc-version`_.
+        pub rustc_version: Option<String>,
+    }
+}
+
+impl BuildInfoBuilder {
+    /// Add git commit of the build.
+    pub fn git_sha<S: Into<String>>(mut self, git_sha: Option<S>) -> Self {
+        set_value!(self git_sha git_sha.map(|git_sha| git_sha.into()))
+    }
+
+    /// Add timestamp of the build e.g. _`2023-11-14T22:13:20Z`_.
+    pub fn timestamp<S: Into<String>>(mut self, timestamp: Option<S>) -> Self {
+        set_value!(self timestamp timestamp.map(|timestamp| timestamp.into()))
+    }
+
+    /// Add version of the rustc used in the build.
+    pub fn rustc_version<S: Into<String>>(mut self, rustc_version: Option<S>) -> Self {
+        set_value!(self rustc_version rustc_version.map(|rustc_version| rustc_version.into()))
+    }
+}
+
+impl Modify for BuildInfo {
+    fn modify(&self, openapi: &mut OpenApi) {
+        let extensions = openapi
+            .info
+            .extensions
+            .get_or_insert_with(Extensions::default);
+
+        for (name, value) in [
+            ("x-build-git-sha", &self.git_sha),
+            ("x-build-timestamp", &self.timestamp),
+            ("x-build-rustc-version", &self.rustc_version),
+        ] {
+            if let Some(value) = value {
+                extensions.insert(name, value.clone());
+            }
+        }
+    }
+}
+
+/// Create [`BuildInfo`] from the environment variables emitted by
+/// [`emit_cargo_env`][crate::build_info::emit_cargo_env] when the calling crate was compiled.
+#[macro_export]
+macro_rules! build_info {
+    () => {
+        $crate::build_info::BuildInfoBuilder::new()
+            .git_sha(option_env!("UTOIPA_BUILD_GIT_SHA"))
+            .timestamp(option_env!("UTOIPA_BUILD_TIMESTAMP"))
+            .rustc_version(option_env!("UTOIPA_BUILD_RUSTC_VERSION"))
+            .build()
+    };
+}
+
+/// Capture build metadata in a build script and pass it to the compiled crate as
+/// [`GIT_SHA_ENV`], [`TIMESTAMP_ENV`] and [`RUSTC_VERSION_ENV`] environment variables.
+///
+/// * Git commit is resolved with _`git rev-parse HEAD`_ and left out when git is not available.
+/// * Timestamp is the current time or _`SOURCE_DATE_EPOCH`_ for reproducible builds.
+/// * Rustc version is resolved with _`$RUSTC --version`_.
+///
+/// The build script is rerun when _`SOURCE_DATE_EPOCH`_ changes or a commit is checked out or
+/// made i.e. when _`.git/HEAD`_ or the ref file of the current branch changes.
+pub fn emit_cargo_env() {
+    let git_sha = command_output("git", &["rev-parse", "HEAD"]);
+    let rustc = env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string());
+    let rustc_version = command_output(&rustc, &["--version"]);
+    let seconds = env::var("SOURCE_DATE_EPOCH")
+        .ok()
+        .and_then(|epoch| epoch.parse().ok())
+        .or_else(|| {
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .ok()
+                .map(|duration| duration.as_secs())
+        });
+
+    println!("cargo:rerun-if-env-changed=SOURCE_DATE_EPOCH");
+    for path in git_paths() {
+        println!("cargo:rerun-if-changed={path}");
+    }
+    for (name, value) in [
+        (GIT_SHA_ENV, git_sha),
+        (TIMESTAMP_ENV, seconds.map(format_timestamp)),
+        (RUSTC_VERSION_ENV, rustc_version),
+    ] {
+        if let Some(value) = value {
+            println!("cargo:rustc-env={name}={value}");
+        }
+    }
+}
+
+/// Existing git files changing with the current commit: _`HEAD`_, the ref file of the checked out
+/// branch and _`packed-refs`_. Missing files are left out as cargo would rerun on every build.
+fn git_paths() -> Vec<String> {
+    let branch = command_output("git", &["symbolic-ref", "-q", "HEAD"]);
+
+    ["HEAD", "packed-refs"]
+        .into_iter()
+        .map(str::to_string)
+        .chain(branch)
+        .filter_map(|name| command_output("git", &["rev-parse", "--git-path", &name]))
+        .filter(|path| Path::new(path).exists())
+        .collect()
+}
+
+fn command_output(program: &str, args: &[&str]) -> Option<String> {
+    Command::new(program)
+        .args(args)
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .map(|output| output.trim().to_string())
+        .filter(|output| !output.is_empty())
+}
+
+/// Format seconds since Unix epoch as RFC 3339 UTC timestamp.
+fn format_timestamp(seconds: u64) -> String {
+    let (days, seconds) = (seconds / 86_400, seconds % 86_400);
+    let (hour, minute, second) = (seconds / 3600, seconds % 3600 / 60, seconds % 60);
+
+    // Convert days since epoch to civil date, see http://howardhinnant.github.io/date_algorithms.html
+    let days = days + 719_468;
+    let era = days / 146_097;
+    let day_of_era = days % 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + u64::from(month <= 2);
+
+    format!("{year:04}-{month:02}-{day:02}T{hour:02}:{minute:02}:{second:02}Z")
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::openapi::{Info, Paths};
+
+    use super::*;
+
+    #[test]
+    fn add_build_info_to_info_extensions() {
+        let mut openapi = OpenApi::new(Info::new("Pet api", "1.0.0"), Paths::new());
+
+        BuildInfoBuilder::new()
+            .git_sha(Some("4a03e44"))
+            .rustc_version(Some("rustc 1.70.0"))
+            .build()
+            .modify(&mut openapi);
+
+        assert_eq!(
+            serde_json::to_value(&openapi.info).unwrap(),
+            json!({
+                "title": "Pet api",
+                "version": "1.0.0",
+                "x-build-git-sha": "4a03e44",
+                "x-build-rustc-version": "rustc 1.70.0"
+            })
+        );
+    }
+
+    #[test]
+    fn format_build_timestamp() {
+        assert_eq!(format_timestamp(0), "1970-01-01T00:00:00Z");
+        assert_eq!(format_timestamp(951_782_400), "2000-02-29T00:00:00Z");
+        assert_eq!(format_timestamp(1_700_000_000), "2023-11-14T22:13:20Z");
+    }
+
+    #[test]
+    fn git_paths_of_current_commit() {
+        let paths = git_paths();
+
+        if command_output("git", &["rev-parse", "HEAD"]).is_some() {
+            assert!(paths.iter().any(|path| path.ends_with("HEAD")));
+        }
+        assert!(paths.iter().all(|path| Path::new(path).exists()));
+    }
+}
@@ -0,0 +1,120 @@
+//! Write [`OpenApi`] documents to files e.g. to commit or publish the document as part of the
+//! build.
+//!
+//! The document of the API is only available once the crate is compiled thus it cannot be
+//! written from the build script of the same crate. Instead write it from a test or a small
+//! binary which are run as part of the build pipeline.
+//!
+//! # Examples
+//!
+//! _**Write the document from a test when [`OUTPUT_DIR_ENV`] is set.**_
+//! ```rust
+//! # use utoipa::OpenApi;
+//! #[derive(OpenApi)]
+//! #[openapi(info(title = "Pet api"))]
+//! struct ApiDoc;
+//!
+//! // In a test run with e.g. `UTOIPA_SPEC_DIR=target/openapi cargo test`.
+//! utoipa::emit::write_spec_to_output_dir("openapi.json", &ApiDoc::openapi()).unwrap();
+//! ```
+//!
+//! _**Write the document to a fixed location from a binary.**_
+//! ```rust,no_run
+//! # use utoipa::OpenApi;
+//! # #[derive(OpenApi)]
+//! # #[openapi(info(title = "Pet api"))]
+//! # struct ApiDoc;
+//! fn main() -> std::io::Result<()> {
+//!     utoipa::emit::write_spec_to("docs/openapi.json", &ApiDoc::openapi())
+//! }
+//! ```
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::openapi::OpenApi;
+
+/// Name of the environment variable defining the directory
+/// [`write_spec_to_output_dir`] writes documents to.
+pub const OUTPUT_DIR_ENV: &str = "UTOIPA_SPEC_DIR";
+
+/// Write [`OpenApi`] document to the file of the `path`. Missing parent directories are created.
+///
+/// The format of the document is chosen by the extension of the file. Pretty JSON is written for
+/// _`.json`_ and YAML for _`.yaml`_ and _`.yml`_ files with the **yaml** feature. Other
+/// extensions are reported as [`io::ErrorKind::InvalidInput`] error.
+pub fn write_spec_to<P: AsRef<Path>>(path: P, openapi: &OpenApi) -> io::Result<()> {
+    let path = path.as_ref();
+    let spec = match path.extension().and_then(|extension| extension.to_str()) {
+        Some("json") => openapi.to_pretty_json().map_err(invalid_data)?,
+        #[cfg(feature = "yaml")]
+        Some("yaml" | "yml") => openapi.to_yaml().map_err(invalid_data)?,
+        _ => {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "unsupported OpenAPI document file: {}, expected .json file or .yaml file with yaml feature",
+                    path.display()
+                ),
+            ))
+        }
+    };
+
+    if let Some(parent) = path
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+    {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, spec)
+}
+
+/// Write [`OpenApi`] document with [`write_spec_to`] to the file of the `file_name` in the
+/// directory defined by [`OUTPUT_DIR_ENV`] environment variable.
+///
+/// Nothing is written when the environment variable is not set or is empty thus this can be
+/// called unconditionally e.g. from a test. Returns path of the written file.
+pub fn write_spec_to_output_dir<P: AsRef<Path>>(
+    file_name: P,
+    openapi: &OpenApi,
+) -> io::Result<Option<PathBuf>> {
+    let output_dir = match env::var_os(OUTPUT_DIR_ENV).filter(|dir| !dir.is_empty()) {
+        Some(output_dir) => PathBuf::from(output_dir),
+        None => return Ok(None),
+    };
+
+    let path = output_dir.join(file_name);
+    write_spec_to(&path, openapi)?;
+
+    Ok(Some(path))
+}
+
+fn invalid_data<E: Into<Box<dyn std::error::Error + Send + Sync>>>(error: E) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, error)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::openapi::{Info, Paths};
+
+    use super::*;
+
+    #[test]
+    fn write_spec_to_file_by_extension() {
+        let dir = env::temp_dir().join(format!("utoipa-emit-{}", std::process::id()));
+        let openapi = OpenApi::new(Info::new("Pet api", "1.0.0"), Paths::new());
+
+        let path = dir.join("nested/openapi.json");
+        write_spec_to(&path, &openapi).unwrap();
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            openapi.to_pretty_json().unwrap()
+        );
+
+        let error = write_spec_to(dir.join("openapi.txt"), &openapi).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
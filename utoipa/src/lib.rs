@@ -52,6 +52,12 @@
 //! * **axum_extras** Enhances [axum](https://github.com/tokio-rs/axum) framework integration allowing users to use `IntoParams`
 //!   without defining the `parameter_in` attribute. See [axum extras support][axum_path]
 //!   or [examples](https://github.com/juhaku/utoipa/tree/master/examples) for more details.
+//! * **poem_extras** Enhances [poem](https://github.com/poem-web/poem) framework integration by resolving
+//!   parameters and request body from `Path`, `Query`, `Json` and `Form` extractors the same way as with
+//!   **axum_extras**. See [poem extras support][poem_path] for more details.
+//! * **salvo_extras** Enhances [salvo](https://github.com/salvo-rs/salvo) framework integration by resolving
+//!   parameters from `PathParam` and `QueryParam` and request body from `JsonBody` and `FormBody` extractors.
+//!   See [salvo extras support][salvo_path] for more details.
 //! * **debug** Add extra traits such as debug traits to openapi definitions and elsewhere.
 //! * **chrono** Add support for [chrono](https://crates.io/crates/chrono) `DateTime`, `Date`, `NaiveDate` and `Duration`
 //!   types. By default these types are parsed to `string` types with additional `format` information.
@@ -74,6 +80,23 @@
 //!   [`response`](https://docs.rs/utoipa/latest/utoipa/openapi/response/index.html) docs for examples.
 //! * **repr** Add support for [repr_serde](https://github.com/dtolnay/serde-repr)'s `repr(u*)` and `repr(i*)` attributes to unit type enums for
 //!   C-like enum representation. See [docs](https://docs.rs/utoipa/latest/utoipa/derive.ToSchema.html) for more details.
+//! * **auto_collect** Register every handler annotated with `#[utoipa::path(...)]` with
+//!   [inventory](https://crates.io/crates/inventory) so that all paths can be gathered with
+#![cfg_attr(
+    feature = "auto_collect",
+    doc = "  [`collect_paths`] or _`collect_paths`_ attribute of [`OpenApi`][openapi_derive] derive"
+)]
+#![cfg_attr(
+    not(feature = "auto_collect"),
+    doc = "  `collect_paths` or _`collect_paths`_ attribute of [`OpenApi`][openapi_derive] derive"
+)]
+//!   instead of listing them manually.
+//! * **validator** Translate [validator](https://crates.io/crates/validator)'s `#[validate(range, length, regex)]`
+//!   field attributes to parameter schema constraints in `IntoParams` derive. See
+//!   [docs](https://docs.rs/utoipa/latest/utoipa/derive.IntoParams.html) for more details.
+//! * **axum_06**, **axum_07**, **actix_web_3**, **actix_web_4** Implement [`serve::MountDocs`] for
+//!   the given framework version allowing docs implementing [`serve::ServeOpenApi`] to be mounted
+//!   to it. See [`serve`] module for more details.
 //!
 //! Utoipa implicitly has partial support for `serde` attributes. See [`ToSchema` derive][serde] for more details.
 //!
@@ -207,12 +230,18 @@
 //! [rocket_path]: attr.path.html#rocket_extras-support-for-rocket
 //! [actix_path]: attr.path.html#actix_extras-support-for-actix-web
 //! [axum_path]: attr.path.html#axum_extras-suppport-for-axum
+//! [poem_path]: attr.path.html#poem_extras-support-for-poem
+//! [salvo_path]: attr.path.html#salvo_extras-support-for-salvo
 //! [serde]: derive.ToSchema.html#partial-serde-attributes-support
 //!
 //! [security]: openapi/security/index.html
 //! [to_schema_derive]: derive.ToSchema.html
+//! [openapi_derive]: derive.OpenApi.html
 
+pub mod build_info;
+pub mod emit;
 pub mod openapi;
+pub mod serve;
 
 use std::collections::BTreeMap;
 
@@ -263,6 +292,14 @@ pub use utoipa_gen::*;
 /// ```
 /// [derive]: derive.OpenApi.html
 pub trait OpenApi {
+    /// Names of the schemas of [`OpenApi::openapi`] components and paths of the types defining
+    /// them e.g. _`("Pet", "my_crate::pets::Pet")`_ including schemas of the nested [`OpenApi`]s.
+    ///
+    /// Used by [`#[derive(OpenApi)]`][derive] to detect nested [`OpenApi`]s defining schemas with
+    /// same name by different types at compile time. Empty by default which leaves the type out
+    /// from the detection.
+    const SCHEMAS: &'static [(&'static str, &'static str)] = &[];
+
     fn openapi() -> openapi::OpenApi;
 }
 
@@ -325,6 +362,22 @@ pub trait OpenApi {
 /// }
 /// ```
 pub trait ToSchema {
+    /// Path of the type implementing the schema e.g. _`"my_crate::pets::Pet"`_.
+    ///
+    /// Used by [`#[derive(OpenApi)]`][openapi] to report where schemas with same name are
+    /// defined. Empty by default which leaves the type out from the detection.
+    ///
+    /// [openapi]: derive.OpenApi.html
+    const TYPE_PATH: &'static str = "";
+
+    /// Names of the properties holding binary content such as _`Vec<u8>`_ fields.
+    ///
+    /// Used by [`#[utoipa::path]`][path] to define the properties as binary parts of
+    /// _`multipart/form-data`_ request body. Empty by default which leaves the properties as is.
+    ///
+    /// [path]: attr.path.html
+    const BINARY_PROPERTIES: &'static [&'static str] = &[];
+
     fn schema() -> openapi::RefOr<openapi::schema::Schema>;
 
     fn aliases() -> Vec<(&'static str, openapi::schema::Schema)> {
@@ -414,11 +467,75 @@ pub trait ToSchema {
 ///
 /// [derive]: attr.path.html
 pub trait Path {
+    /// Operation ids of the operations of the path item returned by [`Path::path_item`].
+    ///
+    /// Used by [`#[derive(OpenApi)]`][openapi] with _`operation_id_format`_ to detect duplicate
+    /// operation ids at compile time. Empty by default which leaves the path out from the
+    /// detection.
+    ///
+    /// [openapi]: derive.OpenApi.html
+    const OPERATION_IDS: &'static [&'static str] = &[];
+
+    /// Whether [`Path::OPERATION_IDS`] are generated from the name of the handler function
+    /// instead of being explicitly defined. Only generated operation ids are formatted with
+    /// _`operation_id_format`_ of [`#[derive(OpenApi)]`][openapi]. False by default.
+    ///
+    /// [openapi]: derive.OpenApi.html
+    const GENERATED_OPERATION_IDS: bool = false;
+
+    /// HTTP methods of the operations of the path item returned by [`Path::path_item`].
+    ///
+    /// Used by web framework integrations to detect methods they cannot route at compile time.
+    /// Empty by default which leaves the path out from the detection.
+    const PATH_ITEM_TYPES: &'static [openapi::PathItemType] = &[];
+
     fn path() -> &'static str;
 
     fn path_item(default_tag: Option<&str>) -> openapi::path::PathItem;
 }
 
+/// Collect [`Paths`][paths] of all handlers annotated with [`#[utoipa::path(...)]`][derive].
+///
+/// Every handler registers itself when the **auto_collect** feature is enabled, so there is no
+/// need to list the handlers manually. Handlers with same path are merged to single
+/// [`PathItem`][path_item].
+///
+/// # Examples
+///
+/// Replace paths of the [`OpenApi`] with all collected paths.
+/// ```rust
+/// # use utoipa::OpenApi;
+/// #[derive(OpenApi)]
+/// #[openapi()]
+/// struct ApiDoc;
+///
+/// let mut openapi = ApiDoc::openapi();
+/// openapi.paths = utoipa::collect_paths();
+/// ```
+///
+/// Alternatively let the [`OpenApi`][openapi_derive] derive add the collected paths to the
+/// document with _`collect_paths`_ attribute.
+/// ```rust
+/// # use utoipa::OpenApi;
+/// #[derive(OpenApi)]
+/// #[openapi(collect_paths)]
+/// struct ApiDoc;
+/// ```
+///
+/// [derive]: attr.path.html
+/// [openapi_derive]: derive.OpenApi.html
+/// [paths]: openapi/path/struct.Paths.html
+/// [path_item]: openapi/path/struct.PathItem.html
+#[cfg(feature = "auto_collect")]
+pub fn collect_paths() -> openapi::path::Paths {
+    inventory::iter::<__dev::CollectedPath>
+        .into_iter()
+        .fold(openapi::path::PathsBuilder::new(), |paths, collected| {
+            paths.path((collected.path)(), (collected.path_item)(None))
+        })
+        .build()
+}
+
 /// Trait that allows OpenApi modification at runtime.
 ///
 /// Implement this trait if you wish to modify the OpenApi at runtime before it is being consumed
@@ -481,11 +598,33 @@ pub trait Path {
 /// }
 /// ```
 ///
+/// Function `fn(&mut utoipa::openapi::OpenApi)` can be given to `modifiers(...)` directly
+/// without the `&` for one-off modifications. Modifiers are applied in the order they are listed.
+/// ```rust
+/// # use utoipa::OpenApi;
+/// # use utoipa::openapi::Server;
+/// #[derive(OpenApi)]
+/// #[openapi(modifiers(add_server))]
+/// struct ApiDoc;
+///
+/// fn add_server(openapi: &mut utoipa::openapi::OpenApi) {
+///     openapi.servers = Some(vec![Server::new("/api")])
+/// }
+/// ```
+///
 /// [server]: https://spec.openapis.org/oas/latest.html#server-object
 pub trait Modify {
     fn modify(&self, openapi: &mut openapi::OpenApi);
 }
 
+/// Functions taking mutable reference to the [`openapi::OpenApi`] can be used as modifiers
+/// without a separate type implementing [`Modify`].
+impl<F: Fn(&mut openapi::OpenApi)> Modify for F {
+    fn modify(&self, openapi: &mut openapi::OpenApi) {
+        self(openapi)
+    }
+}
+
 /// Trait used to convert implementing type to OpenAPI parameters.
 ///
 /// This trait is [derivable][derive] for structs which are used to describe `path` or `query` parameters.
@@ -548,6 +687,15 @@ pub trait Modify {
 /// ```
 /// [derive]: derive.IntoParams.html
 pub trait IntoParams {
+    /// Names and locations of the parameters returned by [`IntoParams::into_params`]. Location is
+    /// `None` if it is resolved with the `parameter_in_provider`.
+    ///
+    /// Used by [`#[utoipa::path]`][path] to detect duplicate parameters at compile time. Empty by
+    /// default which leaves the type out from the detection.
+    ///
+    /// [path]: attr.path.html
+    const PARAMETERS: &'static [(&'static str, Option<openapi::path::ParameterIn>)] = &[];
+
     /// Provide [`Vec`] of [`openapi::path::Parameter`]s to caller. The result is used in `utoipa-gen` library to
     /// provide OpenAPI parameter information for the endpoint using the parameters.
     fn into_params(
@@ -583,6 +731,15 @@ pub trait IntoParams {
 /// }
 /// ```
 pub trait IntoResponses {
+    /// Status codes of the responses returned by [`IntoResponses::responses`].
+    ///
+    /// Used by [`#[utoipa::path]`][path] to detect duplicate status codes at compile time when
+    /// responses are defined with multiple [`IntoResponses`] types or tuples. Empty by default
+    /// which leaves the type out from the detection.
+    ///
+    /// [path]: attr.path.html
+    const STATUS_CODES: &'static [&'static str] = &[];
+
     /// Returns an ordered map of response codes to responses.
     fn responses() -> BTreeMap<String, openapi::RefOr<openapi::response::Response>>;
 }
@@ -617,3 +774,483 @@ pub trait ToResponse {
     /// Returns a map of response component name (to be referenced) to a response.
     fn response() -> (String, openapi::RefOr<openapi::response::Response>);
 }
+
+/// Trait for types defining a [`openapi::tag::Tag`] of the API. The type can be listed in
+/// _`tags(...)`_ of [`#[derive(OpenApi)]`][openapi] to add the tag to the document.
+///
+/// _`ApiTag`_ trait can also be derived with [`#[derive(ApiTag)]`][derive] which uses the doc
+/// comment of the type as description of the tag.
+///
+/// # Examples
+///
+/// ```
+/// use utoipa::openapi::tag::{Tag, TagBuilder};
+///
+/// struct PetsTag;
+///
+/// impl utoipa::ApiTag for PetsTag {
+///     const NAME: &'static str = "pets";
+///
+///     fn tag() -> Tag {
+///         TagBuilder::new()
+///             .name(Self::NAME)
+///             .description(Some("Pet store operations"))
+///             .build()
+///     }
+/// }
+/// ```
+///
+/// [openapi]: derive.OpenApi.html
+/// [derive]: derive.ApiTag.html
+pub trait ApiTag {
+    /// Name of the tag usable as _`tag = ...`_ of [`#[utoipa::path(...)]`][path] e.g.
+    /// _`tag = <PetsTag as utoipa::ApiTag>::NAME`_ or _`tag = PetsTag::NAME`_ when the trait is in
+    /// scope.
+    ///
+    /// [path]: attr.path.html
+    const NAME: &'static str;
+
+    /// Returns the [`openapi::tag::Tag`] defined by the type.
+    fn tag() -> openapi::tag::Tag;
+}
+
+/// Internals used by the code generated with utoipa-gen. Not part of the public API.
+#[doc(hidden)]
+pub mod __dev {
+    use crate::openapi::{
+        path::ParameterIn, KnownFormat, ObjectBuilder, RefOr, Schema, SchemaFormat, SchemaType,
+    };
+
+    const fn eq(left: &str, right: &str) -> bool {
+        let (left, right) = (left.as_bytes(), right.as_bytes());
+        if left.len() != right.len() {
+            return false;
+        }
+
+        let mut index = 0;
+        while index < left.len() {
+            if left[index] != right[index] {
+                return false;
+            }
+            index += 1;
+        }
+
+        true
+    }
+
+    const fn has_common_values(left: &[&str], right: &[&str]) -> bool {
+        let mut left_index = 0;
+        while left_index < left.len() {
+            let mut right_index = 0;
+            while right_index < right.len() {
+                if eq(left[left_index], right[right_index]) {
+                    return true;
+                }
+                right_index += 1;
+            }
+            left_index += 1;
+        }
+
+        false
+    }
+
+    /// Check whether given status code slices have any status code in common. This is evaluated
+    /// at compile time to detect duplicate response status codes.
+    pub const fn has_common_status_codes(left: &[&str], right: &[&str]) -> bool {
+        has_common_values(left, right)
+    }
+
+    /// Check whether given tag name slices have any tag name in common. This is evaluated at
+    /// compile time to detect duplicate tag names of [`ApiTag`][crate::ApiTag]s.
+    pub const fn has_common_tag_names(left: &[&str], right: &[&str]) -> bool {
+        has_common_values(left, right)
+    }
+
+    /// Check whether given operation id slices have any operation id in common. This is
+    /// evaluated at compile time to detect duplicate operation ids.
+    pub const fn has_common_operation_ids(left: &[&str], right: &[&str]) -> bool {
+        has_common_values(left, right)
+    }
+
+    /// Format `N` operation ids with the _`(operation_id, formatted)`_ pairs of `formats` if
+    /// the operation ids are `generated` from the name of the handler function. Operation ids
+    /// without format and explicitly defined operation ids are returned as is.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is not the length of the operation ids.
+    pub const fn format_operation_ids<const N: usize>(
+        operation_ids: &[&'static str],
+        generated: bool,
+        formats: &[(&'static str, &'static str)],
+    ) -> [&'static str; N] {
+        assert!(
+            operation_ids.len() == N,
+            "N must be the length of operation ids"
+        );
+        let mut formatted = [""; N];
+        let mut index = 0;
+        while index < N {
+            formatted[index] = operation_ids[index];
+            let mut format_index = 0;
+            while generated && format_index < formats.len() {
+                if eq(operation_ids[index], formats[format_index].0) {
+                    formatted[index] = formats[format_index].1;
+                    break;
+                }
+                format_index += 1;
+            }
+            index += 1;
+        }
+
+        formatted
+    }
+
+    /// Parameter name and location of [`IntoParams::PARAMETERS`][crate::IntoParams::PARAMETERS].
+    pub type Parameter = (&'static str, Option<ParameterIn>);
+
+    /// Resolve location of the parameter the same way as _`IntoParams`_ derive does, the location
+    /// of the parameter takes precedence over the location given by `parameter_in_provider` which
+    /// defaults to [`ParameterIn::Path`].
+    const fn resolve_parameter_in(
+        parameter_in: Option<ParameterIn>,
+        provided: Option<ParameterIn>,
+    ) -> ParameterIn {
+        match (parameter_in, provided) {
+            (Some(parameter_in), _) | (None, Some(parameter_in)) => parameter_in,
+            (None, None) => ParameterIn::Path,
+        }
+    }
+
+    /// Check whether given parameter slices have any parameter with the same name and location
+    /// in common. Locations missing from the parameters are resolved with the `left_in` and
+    /// `right_in` of the `parameter_in_provider`. This is evaluated at compile time to detect
+    /// duplicate parameters.
+    pub const fn has_common_parameters(
+        left: &[Parameter],
+        left_in: Option<ParameterIn>,
+        right: &[Parameter],
+        right_in: Option<ParameterIn>,
+    ) -> bool {
+        let mut left_index = 0;
+        while left_index < left.len() {
+            let mut right_index = 0;
+            while right_index < right.len() {
+                let (left_name, left_parameter_in) = left[left_index];
+                let (right_name, right_parameter_in) = right[right_index];
+                if eq(left_name, right_name)
+                    && resolve_parameter_in(left_parameter_in, left_in) as u8
+                        == resolve_parameter_in(right_parameter_in, right_in) as u8
+                {
+                    return true;
+                }
+                right_index += 1;
+            }
+            left_index += 1;
+        }
+
+        false
+    }
+
+    /// Check whether given parameters have multiple parameters with the same name and location.
+    /// This is evaluated at compile time to detect duplicate parameters of _`IntoParams`_ type.
+    pub const fn has_duplicate_parameters(parameters: &[Parameter]) -> bool {
+        let mut index = 1;
+        while index < parameters.len() {
+            let (previous, rest) = parameters.split_at(index);
+            if has_common_parameters(previous, None, rest.split_at(1).0, None) {
+                return true;
+            }
+            index += 1;
+        }
+
+        false
+    }
+
+    /// Concatenate given parameter slices to an array of `N` parameters. Locations missing from
+    /// the parameters of a slice are set to the location given with the slice. This is evaluated
+    /// at compile time to include the parameters of flattened fields to
+    /// [`IntoParams::PARAMETERS`][crate::IntoParams::PARAMETERS].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is not the total length of the slices.
+    pub const fn concat_parameters<const N: usize>(
+        parameters: &[(&[Parameter], Option<ParameterIn>)],
+    ) -> [Parameter; N] {
+        let mut concatenated = [("", None); N];
+        let mut index = 0;
+        let mut slice_index = 0;
+        while slice_index < parameters.len() {
+            let (slice, parameter_in) = parameters[slice_index];
+            let mut parameter_index = 0;
+            while parameter_index < slice.len() {
+                let (name, parameter_parameter_in) = slice[parameter_index];
+                concatenated[index] = match parameter_parameter_in {
+                    Some(_) => (name, parameter_parameter_in),
+                    None => (name, parameter_in),
+                };
+                index += 1;
+                parameter_index += 1;
+            }
+            slice_index += 1;
+        }
+        assert!(index == N, "N must be the total length of parameters");
+
+        concatenated
+    }
+
+    /// Concatenate given status code slices to an array of `N` status codes. This is evaluated at
+    /// compile time to include the status codes of flattened responses to
+    /// [`IntoResponses::STATUS_CODES`][crate::IntoResponses::STATUS_CODES].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is not the total length of the slices.
+    pub const fn concat_status_codes<const N: usize>(
+        status_codes: &[&[&'static str]],
+    ) -> [&'static str; N] {
+        let mut concatenated = [""; N];
+        let mut index = 0;
+        let mut slice_index = 0;
+        while slice_index < status_codes.len() {
+            let slice = status_codes[slice_index];
+            let mut code_index = 0;
+            while code_index < slice.len() {
+                concatenated[index] = slice[code_index];
+                index += 1;
+                code_index += 1;
+            }
+            slice_index += 1;
+        }
+        assert!(index == N, "N must be the total length of status codes");
+
+        concatenated
+    }
+
+    /// Schema name and path of the type defining it of [`OpenApi::SCHEMAS`][crate::OpenApi::SCHEMAS].
+    pub type SchemaDefinition = (&'static str, &'static str);
+
+    /// Concatenate given schema definition slices to an array of `N` schema definitions. This is
+    /// evaluated at compile time to include schemas of nested _`OpenApi`_s to
+    /// [`OpenApi::SCHEMAS`][crate::OpenApi::SCHEMAS].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is not the total length of the slices.
+    pub const fn concat_schemas<const N: usize>(
+        schemas: &[&[SchemaDefinition]],
+    ) -> [SchemaDefinition; N] {
+        let mut concatenated = [("", ""); N];
+        let mut index = 0;
+        let mut slice_index = 0;
+        while slice_index < schemas.len() {
+            let slice = schemas[slice_index];
+            let mut schema_index = 0;
+            while schema_index < slice.len() {
+                concatenated[index] = slice[schema_index];
+                index += 1;
+                schema_index += 1;
+            }
+            slice_index += 1;
+        }
+        assert!(index == N, "N must be the total length of schemas");
+
+        concatenated
+    }
+
+    /// Assert that given schema definition slices do not define schemas with same name by
+    /// different types. This is evaluated at compile time to detect nested _`OpenApi`_s
+    /// overriding each others schemas. Schemas of which type path is unknown are skipped.
+    ///
+    /// # Panics
+    ///
+    /// Panics with the name of the schema and the paths of both types if such schema is found.
+    pub const fn assert_no_conflicting_schemas(
+        left: &[SchemaDefinition],
+        right: &[SchemaDefinition],
+    ) {
+        let mut left_index = 0;
+        while left_index < left.len() {
+            let mut right_index = 0;
+            while right_index < right.len() {
+                let (name, left_path) = left[left_index];
+                let (right_name, right_path) = right[right_index];
+                if eq(name, right_name)
+                    && !left_path.is_empty()
+                    && !right_path.is_empty()
+                    && !eq(left_path, right_path)
+                {
+                    let mut message = Message::new();
+                    message.push("schema `");
+                    message.push(name);
+                    message.push("` is defined by both `");
+                    message.push(left_path);
+                    message.push("` and `");
+                    message.push(right_path);
+                    message.push("`");
+
+                    panic!("{}", message.as_str());
+                }
+                right_index += 1;
+            }
+            left_index += 1;
+        }
+    }
+
+    /// Fixed size buffer for formatting panic messages at compile time. Content exceeding the
+    /// buffer is truncated.
+    struct Message {
+        bytes: [u8; 512],
+        len: usize,
+    }
+
+    impl Message {
+        const fn new() -> Self {
+            Self {
+                bytes: [0; 512],
+                len: 0,
+            }
+        }
+
+        const fn push(&mut self, value: &str) {
+            let value = value.as_bytes();
+            let mut index = 0;
+            while index < value.len() && self.len < self.bytes.len() {
+                self.bytes[self.len] = value[index];
+                self.len += 1;
+                index += 1;
+            }
+            // do not leave truncated multi byte character to the end
+            while self.len > 0
+                && index < value.len()
+                && self.len == self.bytes.len()
+                && value[index] & 0b1100_0000 == 0b1000_0000
+            {
+                self.len -= 1;
+                index -= 1;
+            }
+        }
+
+        const fn as_str(&self) -> &str {
+            match core::str::from_utf8(self.bytes.split_at(self.len).0) {
+                Ok(message) => message,
+                Err(_) => "",
+            }
+        }
+    }
+
+    /// Check that schemas defined with the same name are of the same type. Schemas of different
+    /// types fail to compile with mismatched types.
+    pub fn assert_same_schema_type<T: ?Sized>(
+        _: core::marker::PhantomData<T>,
+        _: core::marker::PhantomData<T>,
+    ) {
+    }
+
+    /// Create schema of _`multipart/form-data`_ request body from the `schema` of a form type
+    /// changing the `binary_properties` to binary strings. Descriptions of the properties are
+    /// kept.
+    pub fn multipart_form_schema(
+        mut schema: RefOr<Schema>,
+        binary_properties: &[&str],
+    ) -> RefOr<Schema> {
+        if let RefOr::T(Schema::Object(object)) = &mut schema {
+            for name in binary_properties {
+                if let Some(property) = object.properties.get_mut(*name) {
+                    let description = match property {
+                        RefOr::T(Schema::Object(property)) => property.description.take(),
+                        _ => None,
+                    };
+                    *property = ObjectBuilder::new()
+                        .schema_type(SchemaType::String)
+                        .format(Some(SchemaFormat::KnownFormat(KnownFormat::Binary)))
+                        .description(description)
+                        .into();
+                }
+            }
+        }
+
+        schema
+    }
+
+    /// Marker used to resolve responses of a type which may or may not implement
+    /// [`IntoResponses`][crate::IntoResponses]. Method resolution picks
+    /// [`IntoResponsesOfType`] when the type implements the trait and falls back to
+    /// [`NoResponsesOfType`] otherwise.
+    pub struct ResponsesOf<T: ?Sized>(pub core::marker::PhantomData<T>);
+
+    pub trait IntoResponsesOfType {
+        fn responses(
+            &self,
+        ) -> std::collections::BTreeMap<String, crate::openapi::RefOr<crate::openapi::Response>>;
+    }
+
+    impl<T: crate::IntoResponses + ?Sized> IntoResponsesOfType for ResponsesOf<T> {
+        fn responses(
+            &self,
+        ) -> std::collections::BTreeMap<String, crate::openapi::RefOr<crate::openapi::Response>>
+        {
+            T::responses()
+        }
+    }
+
+    pub trait NoResponsesOfType {
+        fn responses(
+            &self,
+        ) -> std::collections::BTreeMap<String, crate::openapi::RefOr<crate::openapi::Response>>
+        {
+            std::collections::BTreeMap::new()
+        }
+    }
+
+    impl<T: ?Sized> NoResponsesOfType for &ResponsesOf<T> {}
+
+    /// Path of a handler registered by `#[utoipa::path(...)]` with **auto_collect** feature.
+    #[cfg(feature = "auto_collect")]
+    pub struct CollectedPath {
+        pub path: fn() -> &'static str,
+        pub path_item: fn(Option<&str>) -> crate::openapi::path::PathItem,
+    }
+
+    #[cfg(feature = "auto_collect")]
+    inventory::collect!(CollectedPath);
+
+    /// Add paths collected with **auto_collect** feature to `paths` prefixed with `context_path`.
+    /// Operations already defined for a path are kept thus the handlers of the document take
+    /// precedence over the collected ones.
+    #[cfg(feature = "auto_collect")]
+    pub fn add_collected_paths(
+        paths: crate::openapi::path::PathsBuilder,
+        context_path: &str,
+    ) -> crate::openapi::path::PathsBuilder {
+        use std::collections::btree_map::Entry;
+
+        let mut paths = paths.build();
+        for collected in inventory::iter::<CollectedPath> {
+            let path_item = (collected.path_item)(None);
+            match paths
+                .paths
+                .entry(format!("{context_path}{}", (collected.path)()))
+            {
+                Entry::Occupied(mut entry) => {
+                    for (path_item_type, operation) in path_item.operations {
+                        entry
+                            .get_mut()
+                            .operations
+                            .entry(path_item_type)
+                            .or_insert(operation);
+                    }
+                }
+                Entry::Vacant(entry) => {
+                    entry.insert(path_item);
+                }
+            }
+        }
+
+        paths.into()
+    }
+
+    #[cfg(feature = "auto_collect")]
+    pub use inventory;
+}
@@ -1,13 +1,20 @@
 //! Rust implementation of Openapi Spec V3.
 
+use std::collections::BTreeMap;
+
 use serde::{de::Visitor, Deserialize, Serialize, Serializer};
 
+use self::path::Operation;
+
 pub use self::{
     content::{Content, ContentBuilder},
+    diff::{diff, Changelog},
+    extensions::{Extensions, ExtensionsBuilder},
     external_docs::ExternalDocs,
     header::{Header, HeaderBuilder},
     info::{Contact, ContactBuilder, Info, InfoBuilder, License, LicenseBuilder},
     path::{PathItem, PathItemType, Paths, PathsBuilder},
+    reference::{bundle, resolve, BundleMode},
     response::{Response, ResponseBuilder, Responses, ResponsesBuilder},
     schema::{
         AllOf, AllOfBuilder, Array, ArrayBuilder, Components, ComponentsBuilder, Discriminator,
@@ -19,19 +26,25 @@ pub use self::{
     tag::Tag,
 };
 
+pub mod compat;
 pub mod content;
+pub mod diff;
 pub mod encoding;
 pub mod example;
+pub mod extensions;
 pub mod external_docs;
 pub mod header;
 pub mod info;
 pub mod path;
+pub mod reference;
 pub mod request_body;
 pub mod response;
 pub mod schema;
 pub mod security;
 pub mod server;
 pub mod tag;
+pub mod validation;
+mod version_3_1;
 pub mod xml;
 
 builder! {
@@ -58,14 +71,25 @@ builder! {
     /// construct a new [`OpenApi`] object.
     ///
     /// See more details at <https://spec.openapis.org/oas/latest.html#openapi-object>.
+    ///
+    /// Document is serialized according to its [`OpenApi::openapi`] version. With
+    /// [`OpenApiVersion::Version31`] nullable schemas are serialized with type arrays e.g.
+    /// _`"type": ["string", "null"]`_ and schema _`example`_ as _`examples`_ array.
     #[non_exhaustive]
     #[derive(Serialize, Deserialize, Default, Clone, PartialEq)]
     #[cfg_attr(feature = "debug", derive(Debug))]
-    #[serde(rename_all = "camelCase")]
+    #[serde(rename_all = "camelCase", remote = "Self")]
     pub struct OpenApi {
         /// OpenAPI document version.
         pub openapi: OpenApiVersion,
 
+        /// Default _`$schema`_ dialect of the schemas within the document. Only applicable to
+        /// OpenAPI 3.1 documents.
+        ///
+        /// See more details at <https://spec.openapis.org/oas/v3.1.0#fixed-fields>.
+        #[serde(skip_serializing_if = "Option::is_none", default)]
+        pub json_schema_dialect: Option<String>,
+
         /// Provides metadata about the API.
         ///
         /// See more details at <https://spec.openapis.org/oas/latest.html#info-object>.
@@ -85,6 +109,15 @@ builder! {
         #[serde(flatten)]
         pub paths: Paths,
 
+        /// Incoming requests the API may initiate to the consumer e.g. _`"newPet"`_ event. Key of the
+        /// map is unique name of the webhook.
+        ///
+        /// Webhooks are defined by OpenAPI 3.1 and tools supporting only 3.0 will ignore them.
+        ///
+        /// See more details at <https://spec.openapis.org/oas/v3.1.0#oasWebhooks>.
+        #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
+        pub webhooks: BTreeMap<String, PathItem>,
+
         /// Holds various reusable schemas for the OpenAPI document.
         ///
         /// Few of these elements are security schemas and object schemas.
@@ -112,6 +145,17 @@ builder! {
         /// See more details at <https://spec.openapis.org/oas/latest.html#external-documentation-object>.
         #[serde(skip_serializing_if = "Option::is_none")]
         pub external_docs: Option<ExternalDocs>,
+
+        /// Optional extensions _`x-something`_ written to the root of the OpenAPI document e.g.
+        /// _`x-google-backend`_ or _`x-amazon-apigateway-request-validators`_.
+        ///
+        /// See more details at <https://spec.openapis.org/oas/latest.html#specification-extensions>.
+        #[serde(
+            skip_serializing_if = "Option::is_none",
+            flatten,
+            deserialize_with = "extensions::deserialize_optional"
+        )]
+        pub extensions: Option<Extensions>,
     }
 }
 
@@ -146,6 +190,19 @@ impl OpenApi {
         serde_json::to_string_pretty(self)
     }
 
+    /// Converts this [`OpenApi`] to JSON String where keys of all objects are sorted
+    /// alphabetically.
+    ///
+    /// Unlike [`OpenApi::to_json`] the output does not depend on declaration order of e.g.
+    /// properties or response contents, which makes it suitable for specs committed to version
+    /// control.
+    pub fn to_sorted_json(&self) -> Result<String, serde_json::Error> {
+        let mut value = serde_json::to_value(self)?;
+        sort_keys(&mut value);
+
+        serde_json::to_string(&value)
+    }
+
     /// Converts this [`OpenApi`] to YAML String. This method essentially calls [`serde_yaml::to_string`] method.
     #[cfg(feature = "yaml")]
     #[cfg_attr(doc_cfg, doc(cfg(feature = "yaml")))]
@@ -155,18 +212,22 @@ impl OpenApi {
 
     /// Merge `other` [`OpenApi`] consuming it and resuing it's content.
     ///
-    /// Merge function will take all `self` nonexistent _`servers`, `paths`, `schemas`, `responses`,
-    /// `security_schemes`, `security_requirements` and `tags`_ from _`other`_ [`OpenApi`].
+    /// Merge function will take all `self` nonexistent _`servers`, `paths`, `webhooks`, `schemas`,
+    /// `responses`, `security_schemes`, `security_requirements`, `tags` and `extensions`_ from
+    /// _`other`_ [`OpenApi`].
     ///
-    /// This function performs a shallow comparison for `paths`, `schemas`, `responses` and
-    /// `security schemes` which means that only _`name`_ and _`path`_ is used for comparison. When
+    /// This function performs a shallow comparison for `paths`, `webhooks`, `schemas`, `responses`
+    /// and `security schemes` which means that only _`name`_ and _`path`_ is used for comparison. When
     /// match occurs the whole item will be ignored from merged results. Only items not
     /// found will be appended to `self`.
     ///
-    /// For _`servers`_, _`tags`_ and _`security_requirements`_ the whole item will be used for
-    /// comparison. Items not found from `self` will be appended to `self`.
+    /// For _`servers`_ and _`security_requirements`_ the whole item will be used for
+    /// comparison and _`tags`_ are compared by their _`name`_. Items not found from `self` will be
+    /// appended to `self` keeping their order.
     ///
-    /// **Note!** `info`, `openapi` and `external_docs` will not be merged.
+    /// **Note!** `info`, `openapi` and `external_docs` will not be merged. Use
+    /// [`OpenApi::try_merge`] to refuse merging _`other`_ defining schemas already defined
+    /// differently in `self`.
     pub fn merge(&mut self, mut other: OpenApi) {
         if let Some(other_servers) = &mut other.servers {
             let servers = self.servers.get_or_insert(Vec::new());
@@ -182,25 +243,28 @@ impl OpenApi {
             self.paths.paths.append(&mut other.paths.paths);
         };
 
+        other
+            .webhooks
+            .retain(|name, _| !self.webhooks.contains_key(name));
+        self.webhooks.append(&mut other.webhooks);
+
         if let Some(other_components) = &mut other.components {
             let components = self.components.get_or_insert(Components::default());
 
-            other_components
-                .schemas
-                .retain(|name, _| !components.schemas.contains_key(name));
-            components.schemas.append(&mut other_components.schemas);
-
-            other_components
-                .responses
-                .retain(|name, _| !components.responses.contains_key(name));
-            components.responses.append(&mut other_components.responses);
-
-            other_components
-                .security_schemes
-                .retain(|name, _| !components.security_schemes.contains_key(name));
-            components
-                .security_schemes
-                .append(&mut other_components.security_schemes);
+            append_absent(&mut components.schemas, &mut other_components.schemas);
+            append_absent(&mut components.responses, &mut other_components.responses);
+            append_absent(&mut components.parameters, &mut other_components.parameters);
+            append_absent(&mut components.examples, &mut other_components.examples);
+            append_absent(
+                &mut components.request_bodies,
+                &mut other_components.request_bodies,
+            );
+            append_absent(&mut components.headers, &mut other_components.headers);
+            append_absent(&mut components.path_items, &mut other_components.path_items);
+            append_absent(
+                &mut components.security_schemes,
+                &mut other_components.security_schemes,
+            );
         }
 
         if let Some(other_security) = &mut other.security {
@@ -211,13 +275,329 @@ impl OpenApi {
 
         if let Some(other_tags) = &mut other.tags {
             let tags = self.tags.get_or_insert(Vec::new());
-            other_tags.retain(|tag| !tags.contains(tag));
+            other_tags.retain(|tag| !tags.iter().any(|existing| existing.name == tag.name));
             tags.append(other_tags);
         }
+
+        if let Some(other_extensions) = other.extensions {
+            self.extensions
+                .get_or_insert_with(Extensions::default)
+                .merge(other_extensions);
+        }
+    }
+
+    /// Merge `other` [`OpenApi`] the same way as with [`OpenApi::merge`] unless it defines schemas
+    /// already defined differently in `self`.
+    ///
+    /// Merging such _`other`_ would silently keep only the schemas of `self` thus instead `self`
+    /// is left untouched and a [`SchemaConflict`] is returned for every conflicting schema.
+    ///
+    /// # Examples
+    ///
+    /// _**Refuse to merge document defining `User` schema differently.**_
+    /// ```rust
+    /// # use utoipa::openapi::{ComponentsBuilder, Info, ObjectBuilder, OpenApiBuilder, SchemaType};
+    /// let api = |title, schema_type| {
+    ///     OpenApiBuilder::new()
+    ///         .info(Info::new(title, "v1"))
+    ///         .components(Some(
+    ///             ComponentsBuilder::new()
+    ///                 .schema("User", ObjectBuilder::new().schema_type(schema_type))
+    ///                 .build(),
+    ///         ))
+    ///         .build()
+    /// };
+    ///
+    /// let mut users = api("users", SchemaType::Object);
+    /// let conflicts = users.try_merge(api("admin", SchemaType::String)).unwrap_err();
+    ///
+    /// assert_eq!(conflicts[0].name, "User");
+    /// assert_eq!(conflicts[0].defined_in, "users");
+    /// assert_eq!(conflicts[0].other_defined_in, "admin");
+    /// ```
+    pub fn try_merge(&mut self, other: OpenApi) -> Result<(), Vec<SchemaConflict>> {
+        let conflicts = self
+            .conflicting_schemas(&other)
+            .into_iter()
+            .map(|name| SchemaConflict {
+                name: name.to_string(),
+                defined_in: self.info.title.clone(),
+                other_defined_in: other.info.title.clone(),
+            })
+            .collect::<Vec<_>>();
+
+        if conflicts.is_empty() {
+            self.merge(other);
+            Ok(())
+        } else {
+            Err(conflicts)
+        }
+    }
+
+    /// Get names of schemas defined both in `self` and in `other` [`OpenApi`] with different
+    /// content. Such schemas of _`other`_ are ignored when merging or nesting it to `self`.
+    pub fn conflicting_schemas<'o>(&self, other: &'o OpenApi) -> Vec<&'o str> {
+        match (&self.components, &other.components) {
+            (Some(components), Some(other_components)) => other_components
+                .schemas
+                .iter()
+                .filter(|(name, schema)| {
+                    components
+                        .schemas
+                        .get(*name)
+                        .is_some_and(|existing| existing != *schema)
+                })
+                .map(|(name, _)| name.as_str())
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Validate the document returning found [`ValidationIssue`][issue]s. Empty result means that
+    /// no issues were found.
+    ///
+    /// Validation finds
+    /// * references to components which are not defined,
+    /// * operation ids used by more than one operation,
+    /// * responses with empty description,
+    /// * path parameters not present in the path template and path template variables not declared
+    ///   as path parameters,
+    /// * components which are never referenced.
+    ///
+    /// # Examples
+    ///
+    /// _**Assert that derived document has no issues in a test.**_
+    /// ```rust
+    /// # use utoipa::OpenApi;
+    /// #[derive(OpenApi)]
+    /// #[openapi()]
+    /// struct ApiDoc;
+    ///
+    /// let issues = ApiDoc::openapi().validate();
+    /// assert!(issues.is_empty(), "{}", issues.iter().map(ToString::to_string).collect::<Vec<_>>().join("\n"));
+    /// ```
+    ///
+    /// [issue]: validation/struct.ValidationIssue.html
+    pub fn validate(&self) -> Vec<validation::ValidationIssue> {
+        validation::validate(self)
+    }
+
+    /// Return _`Option`_ of mutable reference to [`Operation`][operation] by relative path _`P`_
+    /// and [`PathItemType`] or `None` if the operation is not found.
+    ///
+    /// # Examples
+    ///
+    /// _**Add security requirement to single operation in a modifier.**_
+    /// ```rust
+    /// # use utoipa::openapi::{OpenApi, PathItemType, SecurityRequirement};
+    /// fn secure_delete(openapi: &mut OpenApi) {
+    ///     if let Some(operation) = openapi.get_operation_mut("/api/v1/user", PathItemType::Delete) {
+    ///         operation
+    ///             .security
+    ///             .get_or_insert_with(Vec::new)
+    ///             .push(SecurityRequirement::new("api_key", ["edit:users"]));
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [operation]: path/struct.Operation.html
+    pub fn get_operation_mut<P: AsRef<str>>(
+        &mut self,
+        path: P,
+        item_type: PathItemType,
+    ) -> Option<&mut Operation> {
+        self.paths.get_path_operation_mut(path, item_type)
+    }
+
+    /// Call _`f`_ for each [`Operation`][operation] of [`OpenApi::paths`] with the path, the
+    /// [`PathItemType`] and mutable reference to the operation. Operations are visited in order
+    /// of paths and path item types. Operations of webhooks are not visited.
+    ///
+    /// # Examples
+    ///
+    /// _**Mark all delete operations deprecated.**_
+    /// ```rust
+    /// # use utoipa::openapi::{Deprecated, OpenApi, PathItemType};
+    /// fn deprecate_deletes(openapi: &mut OpenApi) {
+    ///     openapi.for_each_operation(|_, item_type, operation| {
+    ///         if *item_type == PathItemType::Delete {
+    ///             operation.deprecated = Some(Deprecated::True);
+    ///         }
+    ///     });
+    /// }
+    /// ```
+    ///
+    /// [operation]: path/struct.Operation.html
+    pub fn for_each_operation<F: FnMut(&str, &PathItemType, &mut Operation)>(&mut self, mut f: F) {
+        for (path, path_item) in self.paths.paths.iter_mut() {
+            for (item_type, operation) in path_item.operations.iter_mut() {
+                f(path, item_type, operation);
+            }
+        }
+    }
+
+    /// Nest `other` [`OpenApi`] under given `path` consuming it.
+    ///
+    /// Paths of _`other`_ are prefixed with _`path`_ after which it is merged to `self` the same
+    /// way as with [`OpenApi::merge`]. Webhooks are not prefixed since their names are not paths.
+    /// Trailing slash of the _`path`_ is ignored thus nesting
+    /// under _`"/api/v1/"`_ results _`"/api/v1/users"`_ for path _`"/users"`_.
+    pub fn nest<P: Into<String>>(&mut self, path: P, mut other: OpenApi) {
+        other.prefix_paths(&path.into());
+
+        self.merge(other);
+    }
+
+    /// Merge `other` [`OpenApi`] to `self` under given path `prefix` renaming its tags.
+    ///
+    /// Paths of _`other`_ are prefixed the same way as with [`OpenApi::nest`]. Then tags of the
+    /// operations and [`Tag`] definitions of _`other`_ are renamed with _`tags`_ map of
+    /// _`(old_name, new_name)`_ pairs before merging with [`OpenApi::merge`]. Tags not found from the
+    /// map are kept as is. Mapping a tag to a name already used in `self` assigns the merged
+    /// operations to that tag.
+    ///
+    /// # Examples
+    ///
+    /// _**Compose users service document under gateway path.**_
+    /// ```rust
+    /// # use utoipa::openapi::{Info, OpenApi, Paths};
+    /// let mut gateway = OpenApi::new(Info::new("Gateway", "v1"), Paths::new());
+    /// let users = OpenApi::new(Info::new("Users", "v1"), Paths::new());
+    ///
+    /// gateway.merge_with_prefix("/users-service", [("crate", "users")], users);
+    /// ```
+    pub fn merge_with_prefix<P, I, K, V>(&mut self, prefix: P, tags: I, mut other: OpenApi)
+    where
+        P: Into<String>,
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<String>,
+    {
+        other.prefix_paths(&prefix.into());
+
+        let tags = tags
+            .into_iter()
+            .map(|(old_name, new_name)| (old_name.into(), new_name.into()))
+            .collect::<BTreeMap<String, String>>();
+        if !tags.is_empty() {
+            other.rename_tags(&tags);
+        }
+
+        self.merge(other);
+    }
+
+    fn prefix_paths(&mut self, prefix: &str) {
+        let prefix = prefix.trim_end_matches('/');
+        self.paths.paths = std::mem::take(&mut self.paths.paths)
+            .into_iter()
+            .map(|(item_path, path_item)| (format!("{prefix}{item_path}"), path_item))
+            .collect();
+    }
+
+    fn rename_tags(&mut self, tags: &BTreeMap<String, String>) {
+        let rename = |name: &mut String| {
+            if let Some(new_name) = tags.get(name) {
+                *name = new_name.clone();
+            }
+        };
+
+        self.for_each_operation(|_, _, operation| {
+            if let Some(operation_tags) = &mut operation.tags {
+                operation_tags.iter_mut().for_each(rename);
+                let mut seen = Vec::with_capacity(operation_tags.len());
+                operation_tags.retain(|tag| {
+                    let is_new = !seen.contains(tag);
+                    if is_new {
+                        seen.push(tag.clone());
+                    }
+                    is_new
+                });
+            }
+        });
+
+        if let Some(other_tags) = &mut self.tags {
+            let mut renamed: Vec<Tag> = Vec::with_capacity(other_tags.len());
+            for mut tag in other_tags.drain(..) {
+                rename(&mut tag.name);
+                if !renamed.iter().any(|existing| existing.name == tag.name) {
+                    renamed.push(tag);
+                }
+            }
+            *other_tags = renamed;
+        }
+    }
+}
+
+/// Recursively sort keys of all objects within the `value`.
+fn sort_keys(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Object(map) => {
+            let mut entries = std::mem::take(map).into_iter().collect::<Vec<_>>();
+            entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+            for (key, mut value) in entries {
+                sort_keys(&mut value);
+                map.insert(key, value);
+            }
+        }
+        serde_json::Value::Array(values) => values.iter_mut().for_each(sort_keys),
+        _ => (),
+    }
+}
+
+/// Move entries of `other` to `map` which are not already defined in `map`.
+fn append_absent<T>(map: &mut BTreeMap<String, T>, other: &mut BTreeMap<String, T>) {
+    other.retain(|name, _| !map.contains_key(name));
+    map.append(other);
+}
+
+impl Serialize for OpenApi {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let version_3_1 = self.openapi == OpenApiVersion::Version31;
+        if !version_3_1
+            && self
+                .components
+                .as_ref()
+                .is_some_and(|components| !components.path_items.is_empty())
+        {
+            return Err(serde::ser::Error::custom(
+                "path items of components are only supported by OpenAPI 3.1",
+            ));
+        }
+
+        version_3_1::with_version_3_1(version_3_1, || OpenApi::serialize(self, serializer))
+    }
+}
+
+impl<'de> Deserialize<'de> for OpenApi {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        let version_3_1 = value
+            .get("openapi")
+            .and_then(serde_json::Value::as_str)
+            .is_some_and(|version| version.starts_with("3.1"));
+
+        version_3_1::with_version_3_1(version_3_1, || OpenApi::deserialize(value))
+            .map_err(serde::de::Error::custom)
     }
 }
 
 impl OpenApiBuilder {
+    /// Add or change OpenAPI version of the document. Defaults to [`OpenApiVersion::Version3`].
+    pub fn openapi(mut self, openapi: OpenApiVersion) -> Self {
+        set_value!(self openapi openapi)
+    }
+
+    /// Add or change default _`$schema`_ dialect of the schemas. Only applicable to OpenAPI 3.1.
+    pub fn json_schema_dialect<S: Into<String>>(mut self, json_schema_dialect: Option<S>) -> Self {
+        set_value!(self json_schema_dialect json_schema_dialect.map(|dialect| dialect.into()))
+    }
+
     /// Add [`Info`] metadata of the API.
     pub fn info<I: Into<Info>>(mut self, info: I) -> Self {
         set_value!(self info info.into())
@@ -233,6 +613,24 @@ impl OpenApiBuilder {
         set_value!(self paths paths.into())
     }
 
+    /// Add webhook [`PathItem`] with given unique name. If webhook with the name already exists
+    /// the operations of the [`PathItem`]s are merged.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the webhook is already referencing a different reusable path item.
+    pub fn webhook<N: Into<String>, P: Into<PathItem>>(mut self, name: N, item: P) -> Self {
+        let item = item.into();
+        let name = name.into();
+        if let Some(existing_item) = self.webhooks.get_mut(&name) {
+            existing_item.merge(item);
+        } else {
+            self.webhooks.insert(name, item);
+        }
+
+        self
+    }
+
     /// Add [`Components`] to configure reusable schemas.
     pub fn components(mut self, components: Option<Components>) -> Self {
         set_value!(self components components)
@@ -255,6 +653,29 @@ impl OpenApiBuilder {
     pub fn external_docs(mut self, external_docs: Option<ExternalDocs>) -> Self {
         set_value!(self external_docs external_docs)
     }
+
+    /// Add openapi [`Extensions`] (`x-something`) of the API.
+    pub fn extensions(mut self, extensions: Option<Extensions>) -> Self {
+        set_value!(self extensions extensions)
+    }
+}
+
+/// Schema defined differently by two [`OpenApi`] documents found by [`OpenApi::try_merge`].
+#[non_exhaustive]
+#[derive(Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct SchemaConflict {
+    /// Name of the schema.
+    pub name: String,
+
+    /// Title of the document the schema was merged to. Title of documents derived with
+    /// [`OpenApi`][derive] defaults to the name of the crate.
+    ///
+    /// [derive]: ../derive.OpenApi.html
+    pub defined_in: String,
+
+    /// Title of the merged document defining the schema differently.
+    pub other_defined_in: String,
 }
 
 /// Represents available [OpenAPI versions][version].
@@ -266,6 +687,15 @@ pub enum OpenApiVersion {
     /// Will serialize to `3.0.3` the latest from 3.0 serde.
     #[serde(rename = "3.0.3")]
     Version3,
+    /// Will serialize to `3.1.0` the latest from 3.1 serde.
+    #[serde(rename = "3.1.0")]
+    Version31,
+}
+
+impl OpenApiVersion {
+    /// Default _`jsonSchemaDialect`_ of OpenAPI 3.1 documents.
+    pub const JSON_SCHEMA_DIALECT_3_1: &'static str =
+        "https://spec.openapis.org/oas/3.1/dialect/base";
 }
 
 impl Default for OpenApiVersion {
@@ -534,6 +964,72 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn serialize_openapi_pretty_json_and_yaml_representations() {
+        let openapi = OpenApiBuilder::new()
+            .openapi(OpenApiVersion::Version31)
+            .info(
+                InfoBuilder::new()
+                    .title("My api")
+                    .description(Some("Pets"))
+                    .version("1.0.0")
+                    .build(),
+            )
+            .components(Some(
+                ComponentsBuilder::new()
+                    .schema(
+                        "Name",
+                        ObjectBuilder::new()
+                            .schema_type(SchemaType::String)
+                            .description(Some("Name of pet"))
+                            .nullable(true),
+                    )
+                    .build(),
+            ))
+            .build();
+
+        // Nested fields are serialized in the order of the model, not alphabetically.
+        assert_eq!(
+            openapi.to_pretty_json().unwrap(),
+            r#"{
+  "openapi": "3.1.0",
+  "info": {
+    "title": "My api",
+    "description": "Pets",
+    "version": "1.0.0"
+  },
+  "paths": {},
+  "components": {
+    "schemas": {
+      "Name": {
+        "type": [
+          "string",
+          "null"
+        ],
+        "description": "Name of pet"
+      }
+    }
+  }
+}"#
+        );
+
+        #[cfg(feature = "yaml")]
+        assert_eq!(
+            openapi.to_yaml().unwrap(),
+            "openapi: 3.1.0\ninfo:\n  title: My api\n  description: Pets\n  version: 1.0.0\npaths: {}\ncomponents:\n  schemas:\n    Name:\n      type:\n      - string\n      - 'null'\n      description: Name of pet\n"
+        );
+    }
+
+    #[test]
+    fn deserialize_openapi_round_trip() -> Result<(), serde_json::Error> {
+        let raw_json: serde_json::Value =
+            serde_json::from_str(include_str!("openapi/testdata/openapi_round_trip.json"))?;
+        let openapi: OpenApi = serde_json::from_value(raw_json.clone())?;
+
+        assert_eq!(serde_json::to_value(&openapi)?, raw_json);
+        Ok(())
+    }
+
     #[test]
     fn serialize_openapi_json_with_paths_success() -> Result<(), serde_json::Error> {
         let openapi = OpenApi::new(
@@ -690,4 +1186,432 @@ mod tests {
             )
         )
     }
+
+    #[test]
+    fn conflicting_schemas_of_2_openapi_documents() {
+        let api = |name_type| {
+            OpenApiBuilder::new()
+                .components(Some(
+                    ComponentsBuilder::new()
+                        .schema(
+                            "User",
+                            ObjectBuilder::new()
+                                .schema_type(SchemaType::Object)
+                                .property("name", ObjectBuilder::new().schema_type(name_type)),
+                        )
+                        .schema("Id", ObjectBuilder::new().schema_type(SchemaType::Integer))
+                        .build(),
+                ))
+                .build()
+        };
+
+        let api_1 = api(SchemaType::String);
+
+        assert!(api_1
+            .conflicting_schemas(&api(SchemaType::String))
+            .is_empty());
+        assert_eq!(
+            api_1.conflicting_schemas(&api(SchemaType::Integer)),
+            ["User"]
+        );
+    }
+
+    #[test]
+    fn merge_openapi_with_conflicting_schemas() {
+        let api = |title, name_type| {
+            OpenApiBuilder::new()
+                .info(Info::new(title, "v1"))
+                .components(Some(
+                    ComponentsBuilder::new()
+                        .schema(
+                            "User",
+                            ObjectBuilder::new()
+                                .schema_type(SchemaType::Object)
+                                .property("name", ObjectBuilder::new().schema_type(name_type)),
+                        )
+                        .build(),
+                ))
+                .build()
+        };
+
+        let mut api_1 = api("api_1", SchemaType::String);
+        assert!(api_1.try_merge(api("api_2", SchemaType::String)).is_ok());
+
+        let conflicts = api_1
+            .try_merge(api("api_3", SchemaType::Integer))
+            .unwrap_err();
+        assert!(
+            conflicts
+                == [SchemaConflict {
+                    name: "User".to_string(),
+                    defined_in: "api_1".to_string(),
+                    other_defined_in: "api_3".to_string(),
+                }]
+        );
+
+        api_1.merge(api("api_3", SchemaType::Integer));
+        assert!(api_1 == api("api_1", SchemaType::String));
+    }
+
+    #[test]
+    fn nest_openapi_document_with_path_prefix() {
+        let mut api = OpenApi::new(
+            Info::new("Api", "v1"),
+            PathsBuilder::new()
+                .path(
+                    "/health",
+                    PathItem::new(
+                        PathItemType::Get,
+                        OperationBuilder::new().response("200", Response::new("Healthy")),
+                    ),
+                )
+                .build(),
+        );
+        let users_api = OpenApi::new(
+            Info::new("Users", "v1"),
+            PathsBuilder::new()
+                .path(
+                    "/users",
+                    PathItem::new(
+                        PathItemType::Get,
+                        OperationBuilder::new().response("200", Response::new("Get users")),
+                    ),
+                )
+                .build(),
+        );
+
+        api.nest("/api/v1/", users_api);
+
+        assert_eq!(
+            api.paths.paths.keys().collect::<Vec<_>>(),
+            ["/api/v1/users", "/health"]
+        );
+    }
+
+    #[test]
+    fn merge_openapi_with_prefix_and_renamed_tags() {
+        let mut gateway = OpenApiBuilder::new()
+            .paths(PathsBuilder::new().path(
+                "/health",
+                PathItem::new(PathItemType::Get, OperationBuilder::new().tag("gateway")),
+            ))
+            .tags(Some([Tag::new("gateway"), Tag::new("users")]))
+            .build();
+        let users = OpenApiBuilder::new()
+            .paths(
+                PathsBuilder::new()
+                    .path(
+                        "/users",
+                        PathItem::new(
+                            PathItemType::Get,
+                            OperationBuilder::new().tag("crate").tag("user"),
+                        ),
+                    )
+                    .path(
+                        "/pets",
+                        PathItem::new(PathItemType::Get, OperationBuilder::new().tag("pet")),
+                    ),
+            )
+            .tags(Some([Tag::new("crate"), Tag::new("user"), Tag::new("pet")]))
+            .build();
+
+        gateway.merge_with_prefix(
+            "/users-service/",
+            [("crate", "users"), ("user", "users")],
+            users,
+        );
+
+        let tags_of = |path: &str| {
+            gateway
+                .paths
+                .get_path_operation(path, PathItemType::Get)
+                .and_then(|operation| operation.tags.clone())
+                .unwrap_or_default()
+        };
+        assert_eq!(tags_of("/users-service/users"), ["users"]);
+        assert_eq!(tags_of("/users-service/pets"), ["pet"]);
+        assert_eq!(tags_of("/health"), ["gateway"]);
+        assert_eq!(
+            gateway
+                .tags
+                .iter()
+                .flatten()
+                .map(|tag| tag.name.as_str())
+                .collect::<Vec<_>>(),
+            ["gateway", "users", "pet"]
+        );
+    }
+
+    #[test]
+    fn serialize_openapi_sorted_json() -> Result<(), serde_json::Error> {
+        let openapi = OpenApi::new(
+            Info::new("Api", "v1"),
+            PathsBuilder::new().path(
+                "/pets",
+                PathItem::new(
+                    PathItemType::Get,
+                    OperationBuilder::new().response(
+                        "200",
+                        ResponseBuilder::new()
+                            .description("Pets")
+                            .content("text/plain", Content::new(Ref::from_schema_name("Pet")))
+                            .content(
+                                "application/json",
+                                Content::new(Ref::from_schema_name("Pet")),
+                            ),
+                    ),
+                ),
+            ),
+        );
+
+        assert_eq!(
+            openapi.to_sorted_json()?,
+            concat!(
+                r##"{"info":{"title":"Api","version":"v1"},"openapi":"3.0.3","##,
+                r##""paths":{"/pets":{"get":{"responses":{"200":{"content":{"##,
+                r##""application/json":{"schema":{"$ref":"#/components/schemas/Pet"}},"##,
+                r##""text/plain":{"schema":{"$ref":"#/components/schemas/Pet"}}},"##,
+                r##""description":"Pets"}}}}}}"##
+            )
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn mutate_openapi_operations() {
+        let mut api = OpenApi::new(
+            Info::new("Api", "v1"),
+            PathsBuilder::new()
+                .path("/users", PathItem::new(PathItemType::Get, Operation::new()))
+                .path(
+                    "/users",
+                    PathItem::new(PathItemType::Delete, Operation::new()),
+                )
+                .path(
+                    "/health",
+                    PathItem::new(PathItemType::Get, Operation::new()),
+                )
+                .build(),
+        );
+
+        api.get_operation_mut("/users", PathItemType::Delete)
+            .expect("Delete users operation must exist")
+            .deprecated = Some(Deprecated::True);
+        assert!(api.get_operation_mut("/users", PathItemType::Put).is_none());
+
+        let mut visited = Vec::new();
+        api.for_each_operation(|path, item_type, operation| {
+            visited.push((path.to_string(), item_type.clone()));
+            operation.operation_id = Some(path.trim_start_matches('/').to_string());
+        });
+
+        assert!(
+            visited
+                == [
+                    ("/health".to_string(), PathItemType::Get),
+                    ("/users".to_string(), PathItemType::Get),
+                    ("/users".to_string(), PathItemType::Delete)
+                ]
+        );
+        assert_eq!(
+            serde_json::to_value(&api.paths.paths).unwrap(),
+            json!({
+                "/health": {
+                    "get": {
+                        "operationId": "health",
+                        "responses": {}
+                    }
+                },
+                "/users": {
+                    "get": {
+                        "operationId": "users",
+                        "responses": {}
+                    },
+                    "delete": {
+                        "operationId": "users",
+                        "responses": {},
+                        "deprecated": true
+                    }
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn merge_openapi_tags_by_name_keeping_order() {
+        let mut api = OpenApiBuilder::new()
+            .tags(Some([
+                tag::TagBuilder::new()
+                    .name("users")
+                    .description(Some("User operations"))
+                    .build(),
+                Tag::new("pets"),
+            ]))
+            .build();
+        let other = OpenApiBuilder::new()
+            .tags(Some([
+                Tag::new("stores"),
+                tag::TagBuilder::new()
+                    .name("users")
+                    .description(Some("Other user operations"))
+                    .build(),
+            ]))
+            .build();
+
+        api.merge(other);
+
+        let tags = api.tags.unwrap();
+        assert_eq!(
+            tags.iter().map(|tag| tag.name.as_str()).collect::<Vec<_>>(),
+            ["users", "pets", "stores"]
+        );
+        assert_eq!(tags[0].description.as_deref(), Some("User operations"));
+    }
+
+    #[test]
+    fn merge_openapi_reusable_components_keeping_existing() {
+        let mut api = OpenApiBuilder::new()
+            .components(Some(
+                ComponentsBuilder::new()
+                    .example("Pet", example::ExampleBuilder::new().summary("Milo"))
+                    .build(),
+            ))
+            .build();
+        let other = OpenApiBuilder::new()
+            .components(Some(
+                ComponentsBuilder::new()
+                    .example("Pet", example::ExampleBuilder::new().summary("Other"))
+                    .header("X-Rate-Limit", header::Header::default())
+                    .build(),
+            ))
+            .build();
+
+        api.merge(other);
+
+        assert_eq!(
+            serde_json::to_value(api.components).unwrap(),
+            json!({
+                "examples": {
+                    "Pet": {
+                        "summary": "Milo"
+                    }
+                },
+                "headers": {
+                    "X-Rate-Limit": {
+                        "schema": {
+                            "type": "string"
+                        }
+                    }
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn serialize_openapi_3_1_with_nullable_schema() {
+        let openapi = OpenApiBuilder::new()
+            .openapi(OpenApiVersion::Version31)
+            .json_schema_dialect(Some(OpenApiVersion::JSON_SCHEMA_DIALECT_3_1))
+            .info(Info::new("Pet api", "1.0.0"))
+            .components(Some(
+                ComponentsBuilder::new()
+                    .schema(
+                        "Name",
+                        ObjectBuilder::new()
+                            .schema_type(SchemaType::String)
+                            .nullable(true),
+                    )
+                    .build(),
+            ))
+            .build();
+
+        let value = serde_json::to_value(&openapi).unwrap();
+
+        assert_eq!(
+            value,
+            json!({
+                "openapi": "3.1.0",
+                "jsonSchemaDialect": "https://spec.openapis.org/oas/3.1/dialect/base",
+                "info": {
+                    "title": "Pet api",
+                    "version": "1.0.0"
+                },
+                "paths": {},
+                "components": {
+                    "schemas": {
+                        "Name": {
+                            "type": ["string", "null"]
+                        }
+                    }
+                }
+            })
+        );
+        assert!(
+            serde_json::from_value::<OpenApi>(value).unwrap().openapi == OpenApiVersion::Version31
+        );
+    }
+
+    #[test]
+    fn serialize_openapi_json_with_webhooks() {
+        let openapi = OpenApiBuilder::new()
+            .info(Info::new("Pet api", "1.0.0"))
+            .webhook(
+                "newPet",
+                PathItem::new(
+                    PathItemType::Post,
+                    OperationBuilder::new().response("200", Response::new("Pet received")),
+                ),
+            )
+            .build();
+
+        assert_eq!(
+            serde_json::to_value(&openapi).unwrap(),
+            json!({
+                "openapi": "3.0.3",
+                "info": {
+                    "title": "Pet api",
+                    "version": "1.0.0"
+                },
+                "paths": {},
+                "webhooks": {
+                    "newPet": {
+                        "post": {
+                            "responses": {
+                                "200": {
+                                    "description": "Pet received"
+                                }
+                            }
+                        }
+                    }
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn serialize_path_items_of_components_requires_openapi_3_1() {
+        let components = ComponentsBuilder::new()
+            .path_item(
+                "Crud",
+                PathItem::new(PathItemType::Get, OperationBuilder::new()),
+            )
+            .build();
+        let openapi = OpenApiBuilder::new()
+            .info(Info::new("Pet api", "1.0.0"))
+            .components(Some(components))
+            .build();
+
+        assert_eq!(
+            serde_json::to_value(&openapi).unwrap_err().to_string(),
+            "path items of components are only supported by OpenAPI 3.1"
+        );
+
+        let openapi = OpenApiBuilder::from(openapi)
+            .openapi(OpenApiVersion::Version31)
+            .build();
+        assert!(serde_json::to_value(&openapi)
+            .unwrap()
+            .pointer("/components/pathItems/Crud/get")
+            .is_some());
+    }
 }
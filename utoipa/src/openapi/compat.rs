@@ -0,0 +1,32 @@
+//! Conversions of [`OpenApi`] document to other API description formats.
+//!
+//! Conversions are lossy where the target format is not able to express features of the OpenAPI
+//! document. Such features are dropped and reported in the converted document.
+use super::OpenApi;
+
+pub mod swagger2;
+
+/// Convert [`OpenApi`] document to [Swagger 2.0][swagger2] [`swagger2::Document`].
+///
+/// Features of the OpenAPI document not supported by Swagger 2.0 are dropped and described in
+/// [`swagger2::Document::dropped`] e.g. cookie parameters, callbacks, _`oneOf`_ schemas and
+/// all but the first server.
+///
+/// # Examples
+///
+/// _**Convert OpenAPI document to Swagger 2.0 JSON.**_
+/// ```rust
+/// # use utoipa::openapi::{compat, Info, OpenApi, Paths};
+/// let openapi = OpenApi::new(Info::new("Pet api", "1.0.0"), Paths::new());
+///
+/// let swagger = compat::to_swagger2(&openapi);
+/// for dropped in &swagger.dropped {
+///     eprintln!("not supported by Swagger 2.0: {dropped}");
+/// }
+/// let json = swagger.to_json().unwrap();
+/// ```
+///
+/// [swagger2]: https://swagger.io/specification/v2/
+pub fn to_swagger2(openapi: &OpenApi) -> swagger2::Document {
+    swagger2::convert(openapi)
+}
@@ -0,0 +1,1044 @@
+//! Implements [Swagger 2.0][swagger2] document created with [`to_swagger2`][super::to_swagger2].
+//!
+//! Paths and reusable definitions of the document are represented as JSON [`Value`]s already
+//! converted to Swagger 2.0 format.
+//!
+//! [swagger2]: https://swagger.io/specification/v2/
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+
+use crate::openapi::{Extensions, ExternalDocs, Info, OpenApi, SecurityRequirement, Tag};
+
+/// Keywords of a schema allowed in Swagger 2.0 non body parameters, headers and their items.
+const PRIMITIVE_KEYWORDS: [&str; 16] = [
+    "type",
+    "format",
+    "items",
+    "default",
+    "enum",
+    "maximum",
+    "exclusiveMaximum",
+    "minimum",
+    "exclusiveMinimum",
+    "maxLength",
+    "minLength",
+    "pattern",
+    "maxItems",
+    "minItems",
+    "uniqueItems",
+    "multipleOf",
+];
+
+/// Operation fields having the same format in OpenAPI 3.0 and Swagger 2.0.
+const OPERATION_FIELDS: [&str; 7] = [
+    "tags",
+    "summary",
+    "description",
+    "operationId",
+    "externalDocs",
+    "deprecated",
+    "security",
+];
+
+const PATH_ITEM_OPERATIONS: [&str; 7] =
+    ["get", "put", "post", "delete", "options", "head", "patch"];
+
+/// OAuth2 flows of OpenAPI 3.0 with their Swagger 2.0 names.
+const OAUTH2_FLOWS: [(&str, &str); 4] = [
+    ("implicit", "implicit"),
+    ("password", "password"),
+    ("clientCredentials", "application"),
+    ("authorizationCode", "accessCode"),
+];
+
+/// [Swagger 2.0][swagger2] root document converted from [`OpenApi`].
+///
+/// [swagger2]: https://swagger.io/specification/v2/#swagger-object
+#[non_exhaustive]
+#[derive(Serialize, Deserialize, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[serde(rename_all = "camelCase")]
+pub struct Document {
+    /// Swagger specification version which is always _`"2.0"`_.
+    pub swagger: String,
+
+    /// Metadata of the API.
+    pub info: Info,
+
+    /// Host serving the API e.g. _`"petstore.swagger.io"`_.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub host: Option<String>,
+
+    /// Base path of the API relative to the [`Document::host`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub base_path: Option<String>,
+
+    /// Transfer protocols of the API e.g. _`"https"`_.
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub schemes: Vec<String>,
+
+    /// Map of [path item objects][path_item] by their relative path.
+    ///
+    /// [path_item]: https://swagger.io/specification/v2/#path-item-object
+    pub paths: BTreeMap<String, Value>,
+
+    /// Map of schema definitions by their name.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
+    pub definitions: BTreeMap<String, Value>,
+
+    /// Map of reusable parameters by their name.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
+    pub parameters: BTreeMap<String, Value>,
+
+    /// Map of reusable responses by their name.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
+    pub responses: BTreeMap<String, Value>,
+
+    /// Map of security schemes by their name.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
+    pub security_definitions: BTreeMap<String, Value>,
+
+    /// Security requirements global to all operations.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub security: Option<Vec<SecurityRequirement>>,
+
+    /// List of tags used by the operations.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<Tag>>,
+
+    /// Optional global additional documentation reference.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_docs: Option<ExternalDocs>,
+
+    /// Optional extensions _`x-something`_ written to the root of the document.
+    #[serde(skip_serializing_if = "Option::is_none", flatten)]
+    pub extensions: Option<Extensions>,
+
+    /// Features of the [`OpenApi`] document not supported by Swagger 2.0 which were dropped or
+    /// approximated in the conversion. Each entry starts with the location of the feature e.g.
+    /// _`"paths./pets.get.callbacks: callbacks are not supported"`_.
+    ///
+    /// This is not part of the serialized document.
+    #[serde(skip)]
+    pub dropped: Vec<String>,
+}
+
+impl Document {
+    /// Converts this [`Document`] to JSON String. This method essentially calls [`serde_json::to_string`] method.
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string(self)
+    }
+
+    /// Converts this [`Document`] to pretty JSON String. This method essentially calls [`serde_json::to_string_pretty`] method.
+    pub fn to_pretty_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+
+    /// Converts this [`Document`] to YAML String. This method essentially calls [`serde_yaml::to_string`] method.
+    #[cfg(feature = "yaml")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "yaml")))]
+    pub fn to_yaml(&self) -> Result<String, serde_yaml::Error> {
+        serde_yaml::to_string(self)
+    }
+}
+
+pub(super) fn convert(openapi: &OpenApi) -> Document {
+    // Use the OpenAPI 3.0 representation of the document regardless of the version.
+    let value = OpenApi::serialize(openapi, serde_json::value::Serializer).unwrap_or_default();
+    let mut root = into_object(value);
+
+    let mut converter = Converter {
+        components: into_object(root.remove("components").unwrap_or_default()),
+        ..Default::default()
+    };
+    let mut document = Document {
+        swagger: "2.0".to_string(),
+        info: openapi.info.clone(),
+        tags: openapi.tags.clone(),
+        external_docs: openapi.external_docs.clone(),
+        extensions: openapi.extensions.clone(),
+        ..Default::default()
+    };
+
+    if document.info.summary.take().is_some() {
+        converter.drop("info.summary", "summary is not supported");
+    }
+    if let Some(license) = document.info.license.as_mut() {
+        if license.identifier.take().is_some() {
+            converter.drop(
+                "info.license.identifier",
+                "license identifier is not supported",
+            );
+        }
+    }
+    if root.contains_key("jsonSchemaDialect") {
+        converter.drop("jsonSchemaDialect", "JSON schema dialect is not supported");
+    }
+    if root.contains_key("webhooks") {
+        converter.drop("webhooks", "webhooks are not supported");
+    }
+
+    converter.convert_servers(root.remove("servers"), &mut document);
+    converter.convert_components(&mut document);
+
+    if let Some(security) = root.remove("security") {
+        let security = converter.retain_security(security, "security");
+        document.security = serde_json::from_value(security).ok();
+    }
+
+    for (path, path_item) in into_object(root.remove("paths").unwrap_or_default()) {
+        let path_item = converter.convert_path_item(path_item, &format!("paths.{path}"));
+        document.paths.insert(path, path_item);
+    }
+
+    for value in document
+        .paths
+        .values_mut()
+        .chain(document.definitions.values_mut())
+        .chain(document.parameters.values_mut())
+        .chain(document.responses.values_mut())
+    {
+        rewrite_refs(value);
+    }
+
+    document.dropped = converter.dropped;
+    document
+}
+
+#[derive(Default)]
+struct Converter {
+    /// Components of the OpenAPI document used to inline references to components which do not
+    /// exist in Swagger 2.0.
+    components: Map<String, Value>,
+    /// Names of security schemes which could not be converted.
+    dropped_security_schemes: Vec<String>,
+    dropped: Vec<String>,
+}
+
+impl Converter {
+    fn drop<F: AsRef<str>>(&mut self, location: &str, feature: F) {
+        self.dropped
+            .push(format!("{location}: {}", feature.as_ref()));
+    }
+
+    /// Get component of `kind` by its `reference` e.g. _`"#/components/requestBodies/Pet"`_.
+    fn component(&self, kind: &str, reference: &str) -> Option<Value> {
+        reference
+            .strip_prefix(&format!("#/components/{kind}/"))
+            .and_then(|name| self.components.get(kind)?.get(name))
+            .cloned()
+    }
+
+    /// Replace `value` with the referenced component of `kind` if `value` is a reference.
+    fn resolve(&mut self, kind: &str, value: Value, location: &str) -> Value {
+        match value.get("$ref").and_then(Value::as_str) {
+            Some(reference) => match self.component(kind, reference) {
+                Some(component) => component,
+                None => {
+                    self.drop(location, format!("unresolved reference {reference}"));
+                    Value::Object(Map::new())
+                }
+            },
+            None => value,
+        }
+    }
+
+    fn convert_servers(&mut self, servers: Option<Value>, document: &mut Document) {
+        let servers = match servers {
+            Some(Value::Array(servers)) => servers,
+            _ => return,
+        };
+        for index in 1..servers.len() {
+            self.drop(
+                &format!("servers[{index}]"),
+                "only the first server is supported",
+            );
+        }
+
+        let server = match servers.into_iter().next() {
+            Some(server) => server,
+            None => return,
+        };
+        let mut url = server
+            .get("url")
+            .and_then(Value::as_str)
+            .unwrap_or_default()
+            .to_string();
+        if let Some(Value::Object(variables)) = server.get("variables") {
+            for (name, variable) in variables {
+                let default_value = variable
+                    .get("default")
+                    .and_then(Value::as_str)
+                    .unwrap_or_default();
+                url = url.replace(&format!("{{{name}}}"), default_value);
+            }
+        }
+
+        let base_path = match url.split_once("://") {
+            Some((scheme, rest)) => {
+                document.schemes.push(scheme.to_string());
+                let (host, base_path) = match rest.find('/') {
+                    Some(index) => rest.split_at(index),
+                    None => (rest, ""),
+                };
+                document.host = Some(host.to_string());
+                base_path.to_string()
+            }
+            None => url,
+        };
+        let base_path = base_path.trim_end_matches('/');
+        if !base_path.is_empty() {
+            document.base_path = Some(base_path.to_string());
+        }
+    }
+
+    fn convert_components(&mut self, document: &mut Document) {
+        for kind in ["examples", "links", "callbacks"] {
+            if self.components.contains_key(kind) {
+                self.drop(
+                    &format!("components.{kind}"),
+                    "reusable component kind is not supported",
+                );
+            }
+        }
+
+        for (name, scheme) in self.component_entries("securitySchemes") {
+            let location = format!("components.securitySchemes.{name}");
+            match self.convert_security_scheme(scheme, &location) {
+                Some(scheme) => {
+                    document.security_definitions.insert(name, scheme);
+                }
+                None => self.dropped_security_schemes.push(name),
+            }
+        }
+
+        for (name, schema) in self.component_entries("schemas") {
+            let schema = self.convert_schema(schema, &format!("components.schemas.{name}"));
+            document.definitions.insert(name, schema);
+        }
+
+        for (name, parameter) in self.component_entries("parameters") {
+            let location = format!("components.parameters.{name}");
+            if let Some(parameter) = self.convert_parameter(parameter, &location) {
+                document.parameters.insert(name, parameter);
+            }
+        }
+
+        for (name, response) in self.component_entries("responses") {
+            let location = format!("components.responses.{name}");
+            let response = self.convert_response(response, &location, &mut Vec::new());
+            document.responses.insert(name, response);
+        }
+    }
+
+    fn component_entries(&self, kind: &str) -> Vec<(String, Value)> {
+        match self.components.get(kind) {
+            Some(Value::Object(components)) => components
+                .iter()
+                .map(|(name, component)| (name.clone(), component.clone()))
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    fn convert_security_scheme(&mut self, scheme: Value, location: &str) -> Option<Value> {
+        let scheme = into_object(scheme);
+        let field = |name: &str| scheme.get(name).and_then(Value::as_str).unwrap_or_default();
+
+        let mut converted = match field("type") {
+            "apiKey" if field("in") == "cookie" => {
+                self.drop(location, "cookie api key is not supported");
+                return None;
+            }
+            "apiKey" => json!({ "type": "apiKey", "name": field("name"), "in": field("in") }),
+            "http" if field("scheme").eq_ignore_ascii_case("basic") => json!({ "type": "basic" }),
+            "http" if field("scheme").eq_ignore_ascii_case("bearer") => {
+                self.drop(
+                    location,
+                    "bearer authentication is approximated with Authorization header api key",
+                );
+                json!({ "type": "apiKey", "name": "Authorization", "in": "header" })
+            }
+            "oauth2" => {
+                let flows = into_object(scheme.get("flows").cloned().unwrap_or_default());
+                let mut converted = None;
+                for (flow_name, swagger_flow) in OAUTH2_FLOWS {
+                    let flow = match flows.get(flow_name) {
+                        Some(flow) => flow,
+                        None => continue,
+                    };
+                    if converted.is_some() {
+                        self.drop(
+                            location,
+                            format!(
+                                "only the first OAuth2 flow is supported, {flow_name} flow dropped"
+                            ),
+                        );
+                        continue;
+                    }
+
+                    let mut oauth2 = json!({ "type": "oauth2", "flow": swagger_flow });
+                    for url in ["authorizationUrl", "tokenUrl"] {
+                        if let Some(value) = flow.get(url) {
+                            oauth2[url] = value.clone();
+                        }
+                    }
+                    oauth2["scopes"] = flow.get("scopes").cloned().unwrap_or_else(|| json!({}));
+                    converted = Some(oauth2);
+                }
+
+                match converted {
+                    Some(converted) => converted,
+                    None => {
+                        self.drop(location, "OAuth2 without flows is not supported");
+                        return None;
+                    }
+                }
+            }
+            "http" => {
+                self.drop(
+                    location,
+                    format!(
+                        "http authentication scheme {} is not supported",
+                        field("scheme")
+                    ),
+                );
+                return None;
+            }
+            other => {
+                self.drop(
+                    location,
+                    format!("{other} security scheme is not supported"),
+                );
+                return None;
+            }
+        };
+
+        if let Some(description) = scheme.get("description") {
+            converted["description"] = description.clone();
+        }
+        Some(converted)
+    }
+
+    /// Remove security requirements referencing security schemes which were dropped.
+    fn retain_security(&mut self, security: Value, location: &str) -> Value {
+        let requirements = match security {
+            Value::Array(requirements) => requirements,
+            other => return other,
+        };
+
+        let mut retained = Vec::with_capacity(requirements.len());
+        for (index, requirement) in requirements.into_iter().enumerate() {
+            let dropped_scheme = into_object(requirement.clone())
+                .keys()
+                .find(|name| self.dropped_security_schemes.contains(name))
+                .cloned();
+            match dropped_scheme {
+                Some(name) => self.drop(
+                    &format!("{location}[{index}]"),
+                    format!("requirement of unsupported security scheme {name}"),
+                ),
+                None => retained.push(requirement),
+            }
+        }
+        Value::Array(retained)
+    }
+
+    fn convert_path_item(&mut self, path_item: Value, location: &str) -> Value {
+        let mut path_item = into_object(path_item);
+        if path_item.contains_key("$ref") {
+            let reference = path_item.remove("$ref").unwrap_or_default();
+            let referenced = self.resolve("pathItems", json!({ "$ref": reference }), location);
+            for (key, value) in into_object(referenced) {
+                path_item.entry(key).or_insert(value);
+            }
+        }
+
+        let mut converted = Map::new();
+        for (key, value) in path_item {
+            let field_location = format!("{location}.{key}");
+            if PATH_ITEM_OPERATIONS.contains(&key.as_str()) {
+                let operation = self.convert_operation(value, &field_location);
+                converted.insert(key, operation);
+            } else if key == "parameters" {
+                let parameters = self.convert_parameters(value, &field_location);
+                converted.insert(key, Value::Array(parameters));
+            } else if key.starts_with("x-") {
+                converted.insert(key, value);
+            } else {
+                self.drop(&field_location, "not supported by path item");
+            }
+        }
+        Value::Object(converted)
+    }
+
+    fn convert_operation(&mut self, operation: Value, location: &str) -> Value {
+        let mut converted = Map::new();
+        let mut parameters = Vec::new();
+        let mut consumes = Vec::new();
+        let mut produces = Vec::new();
+
+        for (key, value) in into_object(operation) {
+            let field_location = format!("{location}.{key}");
+            match key.as_str() {
+                "parameters" => parameters.extend(self.convert_parameters(value, &field_location)),
+                "requestBody" => parameters.extend(self.convert_request_body(
+                    value,
+                    &field_location,
+                    &mut consumes,
+                )),
+                "responses" => {
+                    let responses = into_object(value)
+                        .into_iter()
+                        .map(|(status, response)| {
+                            let response_location = format!("{field_location}.{status}");
+                            let response =
+                                self.convert_response(response, &response_location, &mut produces);
+                            (status, response)
+                        })
+                        .collect();
+                    converted.insert(key, Value::Object(responses));
+                }
+                "security" => {
+                    let security = self.retain_security(value, &field_location);
+                    converted.insert(key, security);
+                }
+                field if OPERATION_FIELDS.contains(&field) || field.starts_with("x-") => {
+                    converted.insert(key, value);
+                }
+                _ => self.drop(&field_location, "not supported by operation"),
+            }
+        }
+
+        if !parameters.is_empty() {
+            converted.insert("parameters".to_string(), Value::Array(parameters));
+        }
+        for (key, media_types) in [("consumes", consumes), ("produces", produces)] {
+            let mut unique = Vec::with_capacity(media_types.len());
+            for media_type in media_types {
+                if !unique.contains(&media_type) {
+                    unique.push(media_type);
+                }
+            }
+            if !unique.is_empty() {
+                converted.insert(key.to_string(), json!(unique));
+            }
+        }
+        Value::Object(converted)
+    }
+
+    fn convert_parameters(&mut self, parameters: Value, location: &str) -> Vec<Value> {
+        match parameters {
+            Value::Array(parameters) => parameters
+                .into_iter()
+                .enumerate()
+                .filter_map(|(index, parameter)| {
+                    self.convert_parameter(parameter, &format!("{location}[{index}]"))
+                })
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    fn convert_parameter(&mut self, parameter: Value, location: &str) -> Option<Value> {
+        let mut parameter = into_object(parameter);
+        if parameter.contains_key("$ref") {
+            return Some(Value::Object(parameter));
+        }
+        let parameter_in = parameter
+            .get("in")
+            .and_then(Value::as_str)
+            .unwrap_or_default()
+            .to_string();
+        if parameter_in == "cookie" {
+            self.drop(location, "cookie parameter is not supported");
+            return None;
+        }
+
+        let style = parameter.remove("style");
+        let explode = parameter.remove("explode");
+        for key in [
+            "deprecated",
+            "example",
+            "examples",
+            "allowReserved",
+            "content",
+        ] {
+            if parameter.remove(key).is_some() {
+                self.drop(&format!("{location}.{key}"), "not supported by parameter");
+            }
+        }
+
+        let schema = parameter.remove("schema").unwrap_or_default();
+        self.insert_primitive_schema(&mut parameter, schema, location);
+
+        if parameter.get("type").and_then(Value::as_str) == Some("array") {
+            let style = style.as_ref().and_then(Value::as_str);
+            let is_form = style.map_or(parameter_in == "query", |style| style == "form");
+            let collection_format = match style {
+                Some("spaceDelimited") => "ssv",
+                Some("pipeDelimited") => "pipes",
+                _ if is_form && explode.as_ref().and_then(Value::as_bool) != Some(false) => "multi",
+                _ => "csv",
+            };
+            if collection_format != "csv" {
+                parameter.insert("collectionFormat".to_string(), json!(collection_format));
+            }
+        }
+
+        Some(Value::Object(parameter))
+    }
+
+    /// Write primitive schema to `target` as required by non body parameters and headers. Schemas
+    /// which are not primitive are replaced with _`string`_ type.
+    fn insert_primitive_schema(
+        &mut self,
+        target: &mut Map<String, Value>,
+        schema: Value,
+        location: &str,
+    ) {
+        match self.primitive_schema(schema, location) {
+            Some(schema) => {
+                for (key, value) in schema {
+                    target.entry(key).or_insert(value);
+                }
+            }
+            None => {
+                self.drop(
+                    location,
+                    "schema which is not primitive type or array is described as string",
+                );
+                target.insert("type".to_string(), json!("string"));
+            }
+        }
+    }
+
+    fn primitive_schema(&mut self, schema: Value, location: &str) -> Option<Map<String, Value>> {
+        let schema = self.resolve("schemas", schema, location);
+        let schema = into_object(self.convert_schema(schema, location));
+        match schema.get("type").and_then(Value::as_str) {
+            Some("object") | None => None,
+            Some(_) => {
+                let mut primitive = Map::new();
+                for (key, value) in schema {
+                    if key == "items" {
+                        let mut items = Map::new();
+                        self.insert_primitive_schema(&mut items, value, location);
+                        primitive.insert(key, Value::Object(items));
+                    } else if key == "description" || PRIMITIVE_KEYWORDS.contains(&key.as_str()) {
+                        primitive.insert(key, value);
+                    }
+                }
+                Some(primitive)
+            }
+        }
+    }
+
+    fn convert_request_body(
+        &mut self,
+        request_body: Value,
+        location: &str,
+        consumes: &mut Vec<String>,
+    ) -> Vec<Value> {
+        let mut request_body = into_object(self.resolve("requestBodies", request_body, location));
+        let content = into_object(request_body.remove("content").unwrap_or_default());
+        let required = request_body.remove("required");
+        consumes.extend(content.keys().cloned());
+
+        let mut contents = content.into_iter();
+        let (media_type, media) = match contents.next() {
+            Some((media_type, media)) => (media_type, into_object(media)),
+            None => return Vec::new(),
+        };
+        if contents.any(|(_, other)| other.get("schema") != media.get("schema")) {
+            self.drop(
+                location,
+                "only the schema of the first request body media type is kept",
+            );
+        }
+        if media.contains_key("example") || media.contains_key("examples") {
+            self.drop(location, "request body examples are not supported");
+        }
+        let schema = media.get("schema").cloned().unwrap_or_default();
+
+        if media_type == "application/x-www-form-urlencoded" || media_type == "multipart/form-data"
+        {
+            return self.convert_form_parameters(schema, location);
+        }
+
+        let mut parameter = json!({
+            "name": "body",
+            "in": "body",
+            "schema": self.convert_schema(schema, location),
+        });
+        if let Some(description) = request_body.remove("description") {
+            parameter["description"] = description;
+        }
+        if let Some(required) = required {
+            parameter["required"] = required;
+        }
+        vec![parameter]
+    }
+
+    fn convert_form_parameters(&mut self, schema: Value, location: &str) -> Vec<Value> {
+        let schema = into_object(self.resolve("schemas", schema, location));
+        let properties = match schema.get("properties") {
+            Some(Value::Object(properties)) => properties,
+            _ => {
+                self.drop(
+                    location,
+                    "form request body without object schema is not supported",
+                );
+                return Vec::new();
+            }
+        };
+        let required = schema
+            .get("required")
+            .and_then(Value::as_array)
+            .cloned()
+            .unwrap_or_default();
+
+        properties
+            .iter()
+            .map(|(name, property)| {
+                let property_location = format!("{location}.{name}");
+                let mut parameter = Map::new();
+                parameter.insert("name".to_string(), json!(name));
+                parameter.insert("in".to_string(), json!("formData"));
+                parameter.insert(
+                    "required".to_string(),
+                    json!(required.contains(&json!(name))),
+                );
+
+                let property = self.resolve("schemas", property.clone(), &property_location);
+                if property.get("format").and_then(Value::as_str) == Some("binary") {
+                    parameter.insert("type".to_string(), json!("file"));
+                } else {
+                    self.insert_primitive_schema(&mut parameter, property, &property_location);
+                }
+                Value::Object(parameter)
+            })
+            .collect()
+    }
+
+    fn convert_response(
+        &mut self,
+        response: Value,
+        location: &str,
+        produces: &mut Vec<String>,
+    ) -> Value {
+        let response = into_object(response);
+        if response.contains_key("$ref") {
+            return Value::Object(response);
+        }
+
+        let mut converted = Map::new();
+        let mut examples = Map::new();
+        for (key, value) in response {
+            let field_location = format!("{location}.{key}");
+            match key.as_str() {
+                "description" => {
+                    converted.insert(key, value);
+                }
+                "content" => {
+                    let mut schema = None;
+                    for (media_type, media) in into_object(value) {
+                        let mut media = into_object(media);
+                        produces.push(media_type.clone());
+                        if let Some(example) = media.remove("example") {
+                            examples.insert(media_type, example);
+                        }
+                        if media.contains_key("examples") {
+                            self.drop(&field_location, "named response examples are not supported");
+                        }
+                        match (&schema, media.remove("schema")) {
+                            (None, media_schema) => schema = media_schema,
+                            (Some(schema), Some(media_schema)) if *schema != media_schema => self
+                                .drop(
+                                    &field_location,
+                                    "only the schema of the first response media type is kept",
+                                ),
+                            _ => (),
+                        }
+                    }
+                    if let Some(schema) = schema {
+                        let schema = self.convert_schema(schema, &field_location);
+                        converted.insert("schema".to_string(), schema);
+                    }
+                }
+                "headers" => {
+                    let headers = into_object(value)
+                        .into_iter()
+                        .map(|(name, header)| {
+                            let header_location = format!("{field_location}.{name}");
+                            let mut header =
+                                into_object(self.resolve("headers", header, &header_location));
+                            let schema = header.remove("schema").unwrap_or_default();
+                            header.retain(|key, _| key == "description" || key.starts_with("x-"));
+                            self.insert_primitive_schema(&mut header, schema, &header_location);
+                            (name, Value::Object(header))
+                        })
+                        .collect();
+                    converted.insert(key, Value::Object(headers));
+                }
+                field if field.starts_with("x-") => {
+                    converted.insert(key, value);
+                }
+                _ => self.drop(&field_location, "not supported by response"),
+            }
+        }
+
+        converted.entry("description").or_insert_with(|| json!(""));
+        if !examples.is_empty() {
+            converted.insert("examples".to_string(), Value::Object(examples));
+        }
+        Value::Object(converted)
+    }
+
+    fn convert_schema(&mut self, schema: Value, location: &str) -> Value {
+        let mut schema = match schema {
+            Value::Object(schema) => schema,
+            other => return other,
+        };
+
+        if schema.remove("nullable") == Some(Value::Bool(true)) {
+            schema.insert("x-nullable".to_string(), Value::Bool(true));
+        }
+        for (exclusive, limit) in [
+            ("exclusiveMinimum", "minimum"),
+            ("exclusiveMaximum", "maximum"),
+        ] {
+            if let Some(value) = schema
+                .get(exclusive)
+                .filter(|value| value.is_number())
+                .cloned()
+            {
+                schema.insert(limit.to_string(), value);
+                schema.insert(exclusive.to_string(), Value::Bool(true));
+            }
+        }
+        if let Some(Value::Object(discriminator)) = schema.remove("discriminator") {
+            if discriminator.contains_key("mapping") {
+                self.drop(location, "discriminator mapping is not supported");
+            }
+            if let Some(property_name) = discriminator.get("propertyName") {
+                schema.insert("discriminator".to_string(), property_name.clone());
+            }
+        }
+        for keyword in ["oneOf", "anyOf", "not", "writeOnly", "deprecated"] {
+            if schema.remove(keyword).is_some() {
+                self.drop(location, format!("{keyword} of schema is not supported"));
+            }
+        }
+
+        for (key, value) in schema.iter_mut() {
+            match (key.as_str(), value) {
+                ("items" | "additionalProperties", value) => {
+                    *value = self.convert_schema(value.take(), location);
+                }
+                ("properties", Value::Object(properties)) => {
+                    for (name, property) in properties.iter_mut() {
+                        let property_location = format!("{location}.{name}");
+                        *property = self.convert_schema(property.take(), &property_location);
+                    }
+                }
+                ("allOf", Value::Array(schemas)) => {
+                    for schema in schemas.iter_mut() {
+                        *schema = self.convert_schema(schema.take(), location);
+                    }
+                }
+                _ => (),
+            }
+        }
+
+        Value::Object(schema)
+    }
+}
+
+/// Rewrite references to OpenAPI 3.0 components with the corresponding Swagger 2.0 location.
+fn rewrite_refs(value: &mut Value) {
+    match value {
+        Value::Object(object) => {
+            for (key, value) in object.iter_mut() {
+                match value {
+                    Value::String(reference) if key == "$ref" => {
+                        for (component, definition) in [
+                            ("#/components/schemas/", "#/definitions/"),
+                            ("#/components/parameters/", "#/parameters/"),
+                            ("#/components/responses/", "#/responses/"),
+                        ] {
+                            if let Some(name) = reference.strip_prefix(component) {
+                                *reference = format!("{definition}{name}");
+                                break;
+                            }
+                        }
+                    }
+                    value => rewrite_refs(value),
+                }
+            }
+        }
+        Value::Array(values) => values.iter_mut().for_each(rewrite_refs),
+        _ => (),
+    }
+}
+
+fn into_object(value: Value) -> Map<String, Value> {
+    match value {
+        Value::Object(object) => object,
+        _ => Map::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_json_diff::assert_json_eq;
+    use serde_json::json;
+
+    use crate::openapi::{compat::to_swagger2, OpenApi};
+
+    #[test]
+    fn convert_openapi_to_swagger2() {
+        let openapi: OpenApi = serde_json::from_value(json!({
+            "openapi": "3.0.3",
+            "info": { "title": "Pet api", "version": "1.0.0" },
+            "servers": [
+                { "url": "https://{host}/api/v1/", "variables": { "host": { "default": "pets.io" } } },
+                { "url": "/local" }
+            ],
+            "paths": {
+                "/pets": {
+                    "get": {
+                        "operationId": "listPets",
+                        "parameters": [
+                            {
+                                "name": "kind",
+                                "in": "query",
+                                "schema": { "type": "array", "items": { "$ref": "#/components/schemas/Kind" } }
+                            },
+                            { "name": "session", "in": "cookie", "schema": { "type": "string" } }
+                        ],
+                        "responses": {
+                            "200": {
+                                "description": "Pets",
+                                "headers": { "X-Total": { "schema": { "type": "integer" } } },
+                                "content": {
+                                    "application/json": {
+                                        "schema": { "type": "array", "items": { "$ref": "#/components/schemas/Pet" } },
+                                        "example": []
+                                    }
+                                }
+                            }
+                        },
+                        "security": [{ "bearer": [] }, { "oidc": [] }]
+                    },
+                    "post": {
+                        "requestBody": {
+                            "content": { "application/json": { "schema": { "$ref": "#/components/schemas/Pet" } } },
+                            "required": true
+                        },
+                        "responses": { "201": { "description": "Created" } },
+                        "callbacks": {}
+                    }
+                }
+            },
+            "components": {
+                "schemas": {
+                    "Kind": { "type": "string", "enum": ["cat", "dog"] },
+                    "Pet": {
+                        "type": "object",
+                        "properties": {
+                            "name": { "type": "string", "nullable": true },
+                            "age": { "type": "integer", "exclusiveMinimum": 0.0 },
+                            "owner": { "oneOf": [{ "type": "string" }, { "type": "integer" }] }
+                        }
+                    }
+                },
+                "securitySchemes": {
+                    "bearer": { "type": "http", "scheme": "bearer" },
+                    "oidc": { "type": "openIdConnect", "openIdConnectUrl": "https://pets.io/oidc" }
+                }
+            }
+        }))
+        .expect("OpenApi must deserialize");
+
+        let swagger = to_swagger2(&openapi);
+
+        assert_json_eq!(
+            serde_json::to_value(&swagger).unwrap(),
+            json!({
+                "swagger": "2.0",
+                "info": { "title": "Pet api", "version": "1.0.0" },
+                "host": "pets.io",
+                "basePath": "/api/v1",
+                "schemes": ["https"],
+                "paths": {
+                    "/pets": {
+                        "get": {
+                            "operationId": "listPets",
+                            "parameters": [
+                                {
+                                    "name": "kind",
+                                    "in": "query",
+                                    "required": false,
+                                    "type": "array",
+                                    "items": { "type": "string", "enum": ["cat", "dog"] },
+                                    "collectionFormat": "multi"
+                                }
+                            ],
+                            "responses": {
+                                "200": {
+                                    "description": "Pets",
+                                    "headers": { "X-Total": { "type": "integer" } },
+                                    "schema": { "type": "array", "items": { "$ref": "#/definitions/Pet" } },
+                                    "examples": { "application/json": [] }
+                                }
+                            },
+                            "produces": ["application/json"],
+                            "security": [{ "bearer": [] }]
+                        },
+                        "post": {
+                            "parameters": [
+                                {
+                                    "name": "body",
+                                    "in": "body",
+                                    "required": true,
+                                    "schema": { "$ref": "#/definitions/Pet" }
+                                }
+                            ],
+                            "consumes": ["application/json"],
+                            "responses": { "201": { "description": "Created" } }
+                        }
+                    }
+                },
+                "definitions": {
+                    "Kind": { "type": "string", "enum": ["cat", "dog"] },
+                    "Pet": {
+                        "type": "object",
+                        "properties": {
+                            "name": { "type": "string", "x-nullable": true },
+                            "age": { "type": "integer", "minimum": 0.0, "exclusiveMinimum": true },
+                            "owner": {}
+                        }
+                    }
+                },
+                "securityDefinitions": {
+                    "bearer": { "type": "apiKey", "name": "Authorization", "in": "header" }
+                }
+            })
+        );
+        assert_eq!(
+            swagger.dropped,
+            [
+                "servers[1]: only the first server is supported",
+                "components.securitySchemes.bearer: bearer authentication is approximated with Authorization header api key",
+                "components.securitySchemes.oidc: openIdConnect security scheme is not supported",
+                "components.schemas.Pet.owner: oneOf of schema is not supported",
+                "paths./pets.get.parameters[1]: cookie parameter is not supported",
+                "paths./pets.get.security[1]: requirement of unsupported security scheme oidc",
+                "paths./pets.post.callbacks: not supported by operation",
+            ]
+        );
+    }
+}
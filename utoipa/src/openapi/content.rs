@@ -7,7 +7,12 @@ use serde_json::Value;
 
 use super::builder;
 use super::example::Example;
-use super::{encoding::Encoding, set_value, RefOr, Schema};
+use super::{
+    encoding::Encoding,
+    extensions::{self, Extensions},
+    schema::{Object, SchemaType},
+    set_value, RefOr, Schema,
+};
 
 builder! {
     ContentBuilder;
@@ -18,7 +23,10 @@ builder! {
     #[cfg_attr(feature = "debug", derive(Debug))]
     #[non_exhaustive]
     pub struct Content {
-        /// Schema used in response body or request body.
+        /// Schema used in response body or request body. Schema accepting any value is not
+        /// serialized which describes content of a media type without schema e.g. an empty
+        /// response body. See [`ContentBuilder::no_schema`].
+        #[serde(skip_serializing_if = "is_any_schema", default = "any_schema")]
         pub schema: RefOr<Schema>,
 
         /// Example for request body or response body.
@@ -29,7 +37,7 @@ builder! {
         /// media type and specified schema if present. [`Content::examples`] and
         /// [`Content::example`] are mutually exclusive. If both are defined `examples` will
         /// override value in `example`.
-        #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+        #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
         pub examples: BTreeMap<String, RefOr<Example>>,
 
         /// A map between a property name and its encoding information.
@@ -42,9 +50,25 @@ builder! {
         /// multipart or `application/x-www-form-urlencoded`.
         #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
         pub encoding: BTreeMap<String, Encoding>,
+
+        /// Optional extensions _`x-something`_ of the [`Content`].
+        #[serde(
+            skip_serializing_if = "Option::is_none",
+            flatten,
+            deserialize_with = "extensions::deserialize_optional"
+        )]
+        pub extensions: Option<Extensions>,
     }
 }
 
+fn any_schema() -> RefOr<Schema> {
+    RefOr::T(Schema::Object(Object::with_type(SchemaType::Value)))
+}
+
+fn is_any_schema(schema: &RefOr<Schema>) -> bool {
+    *schema == any_schema()
+}
+
 impl Content {
     pub fn new<I: Into<RefOr<Schema>>>(schema: I) -> Self {
         Self {
@@ -60,6 +84,13 @@ impl ContentBuilder {
         set_value!(self schema component.into())
     }
 
+    /// Describe content of a media type without schema e.g. an empty response body or plain
+    /// text response without known schema. This sets schema accepting any value which is not
+    /// serialized.
+    pub fn no_schema(mut self) -> Self {
+        set_value!(self schema any_schema())
+    }
+
     /// Add example of schema.
     pub fn example(mut self, example: Option<Value>) -> Self {
         set_value!(self example example)
@@ -105,4 +136,9 @@ impl ContentBuilder {
         self.encoding.insert(property_name.into(), encoding.into());
         self
     }
+
+    /// Add openapi extensions (`x-something`) of the [`Content`].
+    pub fn extensions(mut self, extensions: Option<Extensions>) -> Self {
+        set_value!(self extensions extensions)
+    }
 }
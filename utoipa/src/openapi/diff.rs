@@ -0,0 +1,832 @@
+//! Implements comparison of two [`OpenApi`] documents with [`diff`].
+//!
+//! Changes are classified as breaking or non breaking from the point of view of an existing
+//! client. E.g. a new enum value is breaking in a response the client must understand but not in
+//! a request the client sends.
+use std::fmt::{self, Display};
+
+use serde_json::{Map, Value};
+
+use super::{
+    reference::{bundle, BundleMode},
+    OpenApi,
+};
+
+const PATH_ITEM_OPERATIONS: [&str; 8] = [
+    "get", "put", "post", "delete", "options", "head", "patch", "trace",
+];
+
+/// Constraints of a schema where smaller value narrows the allowed values.
+const UPPER_LIMITS: [&str; 5] = [
+    "maximum",
+    "exclusiveMaximum",
+    "maxLength",
+    "maxItems",
+    "maxProperties",
+];
+
+/// Constraints of a schema where greater value narrows the allowed values.
+const LOWER_LIMITS: [&str; 5] = [
+    "minimum",
+    "exclusiveMinimum",
+    "minLength",
+    "minItems",
+    "minProperties",
+];
+
+/// List of [`Change`]s between two [`OpenApi`] documents created with [`diff`].
+#[non_exhaustive]
+#[derive(Default, Clone, PartialEq)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct Changelog {
+    /// Changes ordered by their location in the compared documents.
+    pub changes: Vec<Change>,
+}
+
+impl Changelog {
+    /// Check whether any of the [`Change`]s is breaking.
+    pub fn is_breaking(&self) -> bool {
+        self.changes.iter().any(|change| change.breaking)
+    }
+
+    /// Iterate breaking [`Change`]s.
+    pub fn breaking(&self) -> impl Iterator<Item = &Change> {
+        self.changes.iter().filter(|change| change.breaking)
+    }
+}
+
+impl Display for Changelog {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for change in &self.changes {
+            writeln!(f, "{change}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Single change between two [`OpenApi`] documents.
+#[non_exhaustive]
+#[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct Change {
+    /// Location of the change in the document e.g. _`"paths./pets.get.parameters.query.kind"`_.
+    pub location: String,
+
+    /// Kind of the change.
+    pub kind: ChangeKind,
+
+    /// Whether the change may break existing clients.
+    pub breaking: bool,
+}
+
+impl Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let classification = if self.breaking {
+            "breaking"
+        } else {
+            "non-breaking"
+        };
+        write!(f, "[{classification}] {}: {}", self.location, self.kind)
+    }
+}
+
+/// Kinds of [`Change`]s.
+#[non_exhaustive]
+#[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub enum ChangeKind {
+    /// Path was added.
+    PathAdded,
+    /// Path was removed.
+    PathRemoved,
+    /// Operation was added to existing path.
+    OperationAdded,
+    /// Operation was removed from existing path.
+    OperationRemoved,
+    /// Parameter was added to operation.
+    ParameterAdded {
+        /// Whether the new parameter is required.
+        required: bool,
+    },
+    /// Parameter was removed from operation.
+    ParameterRemoved,
+    /// Optional parameter became required.
+    ParameterBecameRequired,
+    /// Request body was added to operation.
+    RequestBodyAdded {
+        /// Whether the new request body is required.
+        required: bool,
+    },
+    /// Request body was removed from operation.
+    RequestBodyRemoved,
+    /// Optional request body became required.
+    RequestBodyBecameRequired,
+    /// Response with the status was added.
+    ResponseAdded,
+    /// Response with the status was removed.
+    ResponseRemoved,
+    /// Media type was added to request body or response content.
+    MediaTypeAdded,
+    /// Media type was removed from request body or response content.
+    MediaTypeRemoved,
+    /// Type of the schema changed. Types are described with the _`type`_ of the schema or the
+    /// reference of the schema.
+    SchemaTypeChanged {
+        /// Type before the change.
+        old: String,
+        /// Type after the change.
+        new: String,
+    },
+    /// Value was added to enum of the schema.
+    EnumValueAdded(Value),
+    /// Value was removed from enum of the schema.
+    EnumValueRemoved(Value),
+    /// Property was added to the schema.
+    PropertyAdded {
+        /// Whether the new property is required.
+        required: bool,
+    },
+    /// Property was removed from the schema.
+    PropertyRemoved,
+    /// Optional property became required.
+    PropertyBecameRequired,
+    /// Required property became optional.
+    PropertyBecameOptional,
+    /// Constraint with given keyword e.g. _`maxLength`_ allows less values than before.
+    ConstraintNarrowed(String),
+    /// Constraint with given keyword e.g. _`maxLength`_ allows more values than before.
+    ConstraintWidened(String),
+}
+
+impl Display for ChangeKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::PathAdded => write!(f, "path added"),
+            Self::PathRemoved => write!(f, "path removed"),
+            Self::OperationAdded => write!(f, "operation added"),
+            Self::OperationRemoved => write!(f, "operation removed"),
+            Self::ParameterAdded { required: true } => write!(f, "required parameter added"),
+            Self::ParameterAdded { required: false } => write!(f, "optional parameter added"),
+            Self::ParameterRemoved => write!(f, "parameter removed"),
+            Self::ParameterBecameRequired => write!(f, "parameter became required"),
+            Self::RequestBodyAdded { required: true } => write!(f, "required request body added"),
+            Self::RequestBodyAdded { required: false } => {
+                write!(f, "optional request body added")
+            }
+            Self::RequestBodyRemoved => write!(f, "request body removed"),
+            Self::RequestBodyBecameRequired => write!(f, "request body became required"),
+            Self::ResponseAdded => write!(f, "response added"),
+            Self::ResponseRemoved => write!(f, "response removed"),
+            Self::MediaTypeAdded => write!(f, "media type added"),
+            Self::MediaTypeRemoved => write!(f, "media type removed"),
+            Self::SchemaTypeChanged { old, new } => {
+                write!(f, "schema type changed from {old} to {new}")
+            }
+            Self::EnumValueAdded(value) => write!(f, "enum value {value} added"),
+            Self::EnumValueRemoved(value) => write!(f, "enum value {value} removed"),
+            Self::PropertyAdded { required: true } => write!(f, "required property added"),
+            Self::PropertyAdded { required: false } => write!(f, "optional property added"),
+            Self::PropertyRemoved => write!(f, "property removed"),
+            Self::PropertyBecameRequired => write!(f, "property became required"),
+            Self::PropertyBecameOptional => write!(f, "property became optional"),
+            Self::ConstraintNarrowed(keyword) => write!(f, "{keyword} narrowed"),
+            Self::ConstraintWidened(keyword) => write!(f, "{keyword} widened"),
+        }
+    }
+}
+
+/// Direction of the data described by a schema.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Direction {
+    /// Data sent by the client.
+    Request,
+    /// Data received by the client.
+    Response,
+}
+
+impl ChangeKind {
+    fn is_breaking(&self, direction: Direction) -> bool {
+        let is_request = direction == Direction::Request;
+        match self {
+            Self::PathAdded
+            | Self::OperationAdded
+            | Self::ParameterRemoved
+            | Self::RequestBodyRemoved
+            | Self::ResponseAdded
+            | Self::MediaTypeAdded => false,
+            Self::PathRemoved
+            | Self::OperationRemoved
+            | Self::ParameterBecameRequired
+            | Self::RequestBodyBecameRequired
+            | Self::ResponseRemoved
+            | Self::MediaTypeRemoved
+            | Self::SchemaTypeChanged { .. } => true,
+            Self::ParameterAdded { required } | Self::RequestBodyAdded { required } => *required,
+            Self::PropertyAdded { required } => is_request && *required,
+            Self::EnumValueRemoved(_)
+            | Self::PropertyBecameRequired
+            | Self::ConstraintNarrowed(_) => is_request,
+            Self::EnumValueAdded(_)
+            | Self::PropertyRemoved
+            | Self::PropertyBecameOptional
+            | Self::ConstraintWidened(_) => !is_request,
+        }
+    }
+}
+
+/// Compare `old` and `new` [`OpenApi`] documents and classify the changes as breaking or non
+/// breaking.
+///
+/// Paths, operations, parameters, request bodies, responses and their schemas are compared.
+/// References to components are inlined before the comparison thus a change in a component is
+/// reported in every location using the component.
+///
+/// Returns error if references of either of the documents cannot be inlined with [`bundle`].
+///
+/// # Examples
+///
+/// _**Fail CI on breaking changes.**_
+/// ```rust
+/// # use utoipa::openapi::{self, Info, OpenApi, Paths};
+/// let released = OpenApi::new(Info::new("Pet api", "1.0.0"), Paths::new());
+/// let current = OpenApi::new(Info::new("Pet api", "1.1.0"), Paths::new());
+///
+/// let changelog = openapi::diff(&released, &current)?;
+/// assert!(!changelog.is_breaking(), "breaking changes found:\n{changelog}");
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+pub fn diff(old: &OpenApi, new: &OpenApi) -> Result<Changelog, serde_json::Error> {
+    let mut differ = Differ::default();
+    let old = inlined_paths(old)?;
+    let new = inlined_paths(new)?;
+
+    for (path, old_item, new_item) in entries(&old, &new) {
+        let location = format!("paths.{path}");
+        match (old_item, new_item) {
+            (Some(_), None) => {
+                differ.change(&location, ChangeKind::PathRemoved, Direction::Request)
+            }
+            (None, Some(_)) => differ.change(&location, ChangeKind::PathAdded, Direction::Request),
+            (Some(old_item), Some(new_item)) => {
+                differ.diff_path_item(old_item, new_item, &location)
+            }
+            (None, None) => (),
+        }
+    }
+
+    Ok(Changelog {
+        changes: differ.changes,
+    })
+}
+
+/// Serialize paths of the `openapi` with references to components inlined.
+fn inlined_paths(openapi: &OpenApi) -> Result<Map<String, Value>, serde_json::Error> {
+    let mut openapi = openapi.clone();
+    bundle(&mut openapi, BundleMode::Inline)?;
+
+    match OpenApi::serialize(&openapi, serde_json::value::Serializer)? {
+        Value::Object(mut document) => match document.remove("paths") {
+            Some(Value::Object(paths)) => Ok(paths),
+            _ => Ok(Map::new()),
+        },
+        _ => Ok(Map::new()),
+    }
+}
+
+/// Pair entries of `old` and `new` by their key keeping the order of `old` followed by entries
+/// only in `new`.
+fn entries<'a>(
+    old: &'a Map<String, Value>,
+    new: &'a Map<String, Value>,
+) -> Vec<(&'a str, Option<&'a Value>, Option<&'a Value>)> {
+    old.iter()
+        .map(|(key, value)| (key.as_str(), Some(value), new.get(key)))
+        .chain(
+            new.iter()
+                .filter(|(key, _)| !old.contains_key(*key))
+                .map(|(key, value)| (key.as_str(), None, Some(value))),
+        )
+        .collect()
+}
+
+fn object(value: &Value, field: &str) -> Map<String, Value> {
+    value
+        .get(field)
+        .and_then(Value::as_object)
+        .cloned()
+        .unwrap_or_default()
+}
+
+fn is_required(value: &Value) -> bool {
+    value.get("required").and_then(Value::as_bool) == Some(true)
+}
+
+/// Describe type of the schema for [`ChangeKind::SchemaTypeChanged`].
+fn schema_type(schema: &Value) -> String {
+    match (schema.get("$ref"), schema.get("type")) {
+        (Some(Value::String(reference)), _) => reference.clone(),
+        (_, Some(Value::String(schema_type))) => schema_type.clone(),
+        _ => ["oneOf", "allOf", "anyOf"]
+            .into_iter()
+            .find(|keyword| schema.get(keyword).is_some())
+            .unwrap_or("any")
+            .to_string(),
+    }
+}
+
+#[derive(Default)]
+struct Differ {
+    changes: Vec<Change>,
+}
+
+impl Differ {
+    fn change(&mut self, location: &str, kind: ChangeKind, direction: Direction) {
+        let breaking = kind.is_breaking(direction);
+        self.changes.push(Change {
+            location: location.to_string(),
+            kind,
+            breaking,
+        });
+    }
+
+    fn diff_path_item(&mut self, old: &Value, new: &Value, location: &str) {
+        for method in PATH_ITEM_OPERATIONS {
+            let operation_location = format!("{location}.{method}");
+            match (old.get(method), new.get(method)) {
+                (Some(_), None) => self.change(
+                    &operation_location,
+                    ChangeKind::OperationRemoved,
+                    Direction::Request,
+                ),
+                (None, Some(_)) => self.change(
+                    &operation_location,
+                    ChangeKind::OperationAdded,
+                    Direction::Request,
+                ),
+                (Some(old_operation), Some(new_operation)) => self.diff_operation(
+                    (old, old_operation),
+                    (new, new_operation),
+                    &operation_location,
+                ),
+                (None, None) => (),
+            }
+        }
+    }
+
+    /// Compare operations together with their path items which hold the common parameters.
+    fn diff_operation(
+        &mut self,
+        (old_item, old): (&Value, &Value),
+        (new_item, new): (&Value, &Value),
+        location: &str,
+    ) {
+        let old_parameters = parameters(old_item, old);
+        let new_parameters = parameters(new_item, new);
+        for (key, old_parameter, new_parameter) in entries(&old_parameters, &new_parameters) {
+            let parameter_location = format!("{location}.parameters.{key}");
+            match (old_parameter, new_parameter) {
+                (Some(_), None) => self.change(
+                    &parameter_location,
+                    ChangeKind::ParameterRemoved,
+                    Direction::Request,
+                ),
+                (None, Some(parameter)) => self.change(
+                    &parameter_location,
+                    ChangeKind::ParameterAdded {
+                        required: is_required(parameter),
+                    },
+                    Direction::Request,
+                ),
+                (Some(old_parameter), Some(new_parameter)) => {
+                    if !is_required(old_parameter) && is_required(new_parameter) {
+                        self.change(
+                            &parameter_location,
+                            ChangeKind::ParameterBecameRequired,
+                            Direction::Request,
+                        );
+                    }
+                    self.diff_schema(
+                        old_parameter.get("schema"),
+                        new_parameter.get("schema"),
+                        &format!("{parameter_location}.schema"),
+                        Direction::Request,
+                    );
+                }
+                (None, None) => (),
+            }
+        }
+
+        let request_body_location = format!("{location}.requestBody");
+        match (old.get("requestBody"), new.get("requestBody")) {
+            (Some(_), None) => self.change(
+                &request_body_location,
+                ChangeKind::RequestBodyRemoved,
+                Direction::Request,
+            ),
+            (None, Some(request_body)) => self.change(
+                &request_body_location,
+                ChangeKind::RequestBodyAdded {
+                    required: is_required(request_body),
+                },
+                Direction::Request,
+            ),
+            (Some(old_body), Some(new_body)) => {
+                if !is_required(old_body) && is_required(new_body) {
+                    self.change(
+                        &request_body_location,
+                        ChangeKind::RequestBodyBecameRequired,
+                        Direction::Request,
+                    );
+                }
+                self.diff_content(
+                    old_body,
+                    new_body,
+                    &request_body_location,
+                    Direction::Request,
+                );
+            }
+            (None, None) => (),
+        }
+
+        let old_responses = object(old, "responses");
+        let new_responses = object(new, "responses");
+        for (status, old_response, new_response) in entries(&old_responses, &new_responses) {
+            let response_location = format!("{location}.responses.{status}");
+            match (old_response, new_response) {
+                (Some(_), None) => self.change(
+                    &response_location,
+                    ChangeKind::ResponseRemoved,
+                    Direction::Response,
+                ),
+                (None, Some(_)) => self.change(
+                    &response_location,
+                    ChangeKind::ResponseAdded,
+                    Direction::Response,
+                ),
+                (Some(old_response), Some(new_response)) => self.diff_content(
+                    old_response,
+                    new_response,
+                    &response_location,
+                    Direction::Response,
+                ),
+                (None, None) => (),
+            }
+        }
+    }
+
+    fn diff_content(&mut self, old: &Value, new: &Value, location: &str, direction: Direction) {
+        let old_content = object(old, "content");
+        let new_content = object(new, "content");
+        for (media_type, old_media, new_media) in entries(&old_content, &new_content) {
+            let media_location = format!("{location}.content.{media_type}");
+            match (old_media, new_media) {
+                (Some(_), None) => {
+                    self.change(&media_location, ChangeKind::MediaTypeRemoved, direction)
+                }
+                (None, Some(_)) => {
+                    self.change(&media_location, ChangeKind::MediaTypeAdded, direction)
+                }
+                (Some(old_media), Some(new_media)) => self.diff_schema(
+                    old_media.get("schema"),
+                    new_media.get("schema"),
+                    &format!("{media_location}.schema"),
+                    direction,
+                ),
+                (None, None) => (),
+            }
+        }
+    }
+
+    fn diff_schema(
+        &mut self,
+        old: Option<&Value>,
+        new: Option<&Value>,
+        location: &str,
+        direction: Direction,
+    ) {
+        let (old, new) = match (old, new) {
+            (Some(old), Some(new)) if old != new => (old, new),
+            _ => return,
+        };
+
+        let (old_type, new_type) = (schema_type(old), schema_type(new));
+        if old_type != new_type {
+            self.change(
+                location,
+                ChangeKind::SchemaTypeChanged {
+                    old: old_type,
+                    new: new_type,
+                },
+                direction,
+            );
+            return;
+        }
+        if old.get("$ref").is_some() {
+            // Same recursive reference on both sides.
+            return;
+        }
+
+        self.diff_enum(old, new, location, direction);
+        self.diff_constraints(old, new, location, direction);
+
+        let old_properties = object(old, "properties");
+        let new_properties = object(new, "properties");
+        let required = |schema: &Value, name: &str| {
+            schema
+                .get("required")
+                .and_then(Value::as_array)
+                .is_some_and(|required| required.iter().any(|value| value == name))
+        };
+        for (name, old_property, new_property) in entries(&old_properties, &new_properties) {
+            let property_location = format!("{location}.properties.{name}");
+            match (old_property, new_property) {
+                (Some(_), None) => {
+                    self.change(&property_location, ChangeKind::PropertyRemoved, direction)
+                }
+                (None, Some(_)) => self.change(
+                    &property_location,
+                    ChangeKind::PropertyAdded {
+                        required: required(new, name),
+                    },
+                    direction,
+                ),
+                (Some(old_property), Some(new_property)) => {
+                    match (required(old, name), required(new, name)) {
+                        (false, true) => self.change(
+                            &property_location,
+                            ChangeKind::PropertyBecameRequired,
+                            direction,
+                        ),
+                        (true, false) => self.change(
+                            &property_location,
+                            ChangeKind::PropertyBecameOptional,
+                            direction,
+                        ),
+                        _ => (),
+                    }
+                    self.diff_schema(
+                        Some(old_property),
+                        Some(new_property),
+                        &property_location,
+                        direction,
+                    );
+                }
+                (None, None) => (),
+            }
+        }
+
+        for keyword in ["items", "additionalProperties"] {
+            self.diff_schema(
+                old.get(keyword),
+                new.get(keyword),
+                &format!("{location}.{keyword}"),
+                direction,
+            );
+        }
+        for keyword in ["allOf", "oneOf", "anyOf"] {
+            if let (Some(Value::Array(old_schemas)), Some(Value::Array(new_schemas))) =
+                (old.get(keyword), new.get(keyword))
+            {
+                for (index, (old_schema, new_schema)) in
+                    old_schemas.iter().zip(new_schemas).enumerate()
+                {
+                    self.diff_schema(
+                        Some(old_schema),
+                        Some(new_schema),
+                        &format!("{location}.{keyword}[{index}]"),
+                        direction,
+                    );
+                }
+            }
+        }
+    }
+
+    fn diff_enum(&mut self, old: &Value, new: &Value, location: &str, direction: Direction) {
+        match (old.get("enum"), new.get("enum")) {
+            (Some(Value::Array(old_values)), Some(Value::Array(new_values))) => {
+                for value in old_values
+                    .iter()
+                    .filter(|value| !new_values.contains(value))
+                {
+                    self.change(
+                        location,
+                        ChangeKind::EnumValueRemoved(value.clone()),
+                        direction,
+                    );
+                }
+                for value in new_values
+                    .iter()
+                    .filter(|value| !old_values.contains(value))
+                {
+                    self.change(
+                        location,
+                        ChangeKind::EnumValueAdded(value.clone()),
+                        direction,
+                    );
+                }
+            }
+            (None, Some(_)) => self.change(
+                location,
+                ChangeKind::ConstraintNarrowed("enum".to_string()),
+                direction,
+            ),
+            (Some(_), None) => self.change(
+                location,
+                ChangeKind::ConstraintWidened("enum".to_string()),
+                direction,
+            ),
+            _ => (),
+        }
+    }
+
+    fn diff_constraints(&mut self, old: &Value, new: &Value, location: &str, direction: Direction) {
+        let limits = UPPER_LIMITS
+            .into_iter()
+            .map(|keyword| (keyword, true))
+            .chain(LOWER_LIMITS.into_iter().map(|keyword| (keyword, false)));
+        for (keyword, is_upper) in limits {
+            let limit = |schema: &Value| schema.get(keyword).and_then(Value::as_f64);
+            let narrowed = match (limit(old), limit(new)) {
+                (None, Some(_)) => true,
+                (Some(_), None) => false,
+                (Some(old_limit), Some(new_limit)) if old_limit != new_limit => {
+                    (new_limit < old_limit) == is_upper
+                }
+                _ => continue,
+            };
+            self.constraint_change(keyword, narrowed, location, direction);
+        }
+
+        if old.get("pattern") != new.get("pattern") {
+            // Patterns cannot be compared thus any change may reject previously valid values.
+            self.constraint_change("pattern", new.get("pattern").is_some(), location, direction);
+        }
+
+        let nullable =
+            |schema: &Value| schema.get("nullable").and_then(Value::as_bool) == Some(true);
+        if nullable(old) != nullable(new) {
+            self.constraint_change("nullable", nullable(old), location, direction);
+        }
+    }
+
+    fn constraint_change(
+        &mut self,
+        keyword: &str,
+        narrowed: bool,
+        location: &str,
+        direction: Direction,
+    ) {
+        let kind = if narrowed {
+            ChangeKind::ConstraintNarrowed(keyword.to_string())
+        } else {
+            ChangeKind::ConstraintWidened(keyword.to_string())
+        };
+        self.change(location, kind, direction);
+    }
+}
+
+/// Get parameters of the operation including the common parameters of the path item keyed by
+/// their location and name e.g. _`"query.kind"`_.
+fn parameters(path_item: &Value, operation: &Value) -> Map<String, Value> {
+    [path_item, operation]
+        .into_iter()
+        .filter_map(|value| value.get("parameters").and_then(Value::as_array))
+        .flatten()
+        .filter_map(|parameter| {
+            let parameter_in = parameter.get("in").and_then(Value::as_str)?;
+            let name = parameter.get("name").and_then(Value::as_str)?;
+            Some((format!("{parameter_in}.{name}"), parameter.clone()))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn diff_openapi_documents() {
+        let old: OpenApi = serde_json::from_value(json!({
+            "openapi": "3.0.3",
+            "info": { "title": "Pet api", "version": "1.0.0" },
+            "paths": {
+                "/pets": {
+                    "get": {
+                        "parameters": [
+                            { "name": "kind", "in": "query", "required": false, "schema": { "type": "string" } }
+                        ],
+                        "responses": {
+                            "200": {
+                                "description": "Pets",
+                                "content": {
+                                    "application/json": {
+                                        "schema": { "type": "array", "items": { "$ref": "#/components/schemas/Pet" } }
+                                    }
+                                }
+                            }
+                        }
+                    },
+                    "post": {
+                        "requestBody": {
+                            "content": {
+                                "application/json": { "schema": { "$ref": "#/components/schemas/Pet" } }
+                            }
+                        },
+                        "responses": { "201": { "description": "Created" } }
+                    },
+                    "delete": { "responses": { "204": { "description": "Deleted" } } }
+                },
+                "/owners": {
+                    "get": { "responses": { "200": { "description": "Owners" } } }
+                }
+            },
+            "components": {
+                "schemas": {
+                    "Pet": {
+                        "type": "object",
+                        "required": ["name"],
+                        "properties": {
+                            "name": { "type": "string", "maxLength": 20 },
+                            "kind": { "type": "string", "enum": ["cat", "dog", "bird"] }
+                        }
+                    }
+                }
+            }
+        }))
+        .expect("OpenApi must deserialize");
+        let new: OpenApi = serde_json::from_value(json!({
+            "openapi": "3.0.3",
+            "info": { "title": "Pet api", "version": "2.0.0" },
+            "paths": {
+                "/pets": {
+                    "get": {
+                        "parameters": [
+                            { "name": "kind", "in": "query", "required": true, "schema": { "type": "string" } },
+                            { "name": "limit", "in": "query", "required": false, "schema": { "type": "integer" } }
+                        ],
+                        "responses": {
+                            "200": {
+                                "description": "Pets",
+                                "content": {
+                                    "application/json": {
+                                        "schema": { "type": "array", "items": { "$ref": "#/components/schemas/Pet" } }
+                                    }
+                                }
+                            }
+                        }
+                    },
+                    "post": {
+                        "requestBody": {
+                            "content": {
+                                "application/json": { "schema": { "$ref": "#/components/schemas/Pet" } }
+                            }
+                        },
+                        "responses": { "201": { "description": "Created" } }
+                    }
+                },
+                "/pets/{id}": {
+                    "get": { "responses": { "200": { "description": "Pet" } } }
+                }
+            },
+            "components": {
+                "schemas": {
+                    "Pet": {
+                        "type": "object",
+                        "required": ["name", "age"],
+                        "properties": {
+                            "name": { "type": "string", "maxLength": 10 },
+                            "kind": { "type": "string", "enum": ["cat", "dog", "fish"] },
+                            "age": { "type": "integer" }
+                        }
+                    }
+                }
+            }
+        }))
+        .expect("OpenApi must deserialize");
+
+        let changelog = diff(&old, &new).unwrap();
+
+        assert!(changelog.is_breaking());
+        assert_eq!(
+            changelog.to_string(),
+            r#"[breaking] paths./owners: path removed
+[breaking] paths./pets.get.parameters.query.kind: parameter became required
+[non-breaking] paths./pets.get.parameters.query.limit: optional parameter added
+[non-breaking] paths./pets.get.responses.200.content.application/json.schema.items.properties.kind: enum value "bird" removed
+[breaking] paths./pets.get.responses.200.content.application/json.schema.items.properties.kind: enum value "fish" added
+[non-breaking] paths./pets.get.responses.200.content.application/json.schema.items.properties.name: maxLength narrowed
+[non-breaking] paths./pets.get.responses.200.content.application/json.schema.items.properties.age: required property added
+[breaking] paths./pets.post.requestBody.content.application/json.schema.properties.kind: enum value "bird" removed
+[non-breaking] paths./pets.post.requestBody.content.application/json.schema.properties.kind: enum value "fish" added
+[breaking] paths./pets.post.requestBody.content.application/json.schema.properties.name: maxLength narrowed
+[breaking] paths./pets.post.requestBody.content.application/json.schema.properties.age: required property added
+[breaking] paths./pets.delete: operation removed
+[non-breaking] paths./pets/{id}: path added
+"#
+        );
+        assert_eq!(changelog.breaking().count(), 7);
+        assert!(!diff(&old, &old).unwrap().is_breaking());
+    }
+}
@@ -4,7 +4,12 @@ use std::collections::BTreeMap;
 
 use serde::{Deserialize, Serialize};
 
-use super::{builder, path::ParameterStyle, set_value, Header};
+use super::{
+    builder,
+    extensions::{self, Extensions},
+    path::ParameterStyle,
+    set_value, Header,
+};
 
 builder! {
     EncodingBuilder;
@@ -27,7 +32,7 @@ builder! {
         /// A map allowing additional information to be provided as headers, for example
         /// Content-Disposition. Content-Type is described separately and SHALL be ignored in this
         /// section. This property SHALL be ignored if the request body media type is not a multipart.
-        #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+        #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
         pub headers: BTreeMap<String, Header>,
 
         /// Describes how a specific property value will be serialized depending on its type. See
@@ -51,6 +56,14 @@ builder! {
         /// `application/x-www-form-urlencoded`.
         #[serde(skip_serializing_if = "Option::is_none")]
         pub allow_reserved: Option<bool>,
+
+        /// Optional extensions _`x-something`_ of the [`Encoding`].
+        #[serde(
+            skip_serializing_if = "Option::is_none",
+            flatten,
+            deserialize_with = "extensions::deserialize_optional"
+        )]
+        pub extensions: Option<Extensions>,
     }
 }
 
@@ -81,4 +94,9 @@ impl EncodingBuilder {
     pub fn allow_reserved(mut self, allow_reserved: Option<bool>) -> Self {
         set_value!(self allow_reserved allow_reserved)
     }
+
+    /// Add openapi extensions (`x-something`) of the [`Encoding`].
+    pub fn extensions(mut self, extensions: Option<Extensions>) -> Self {
+        set_value!(self extensions extensions)
+    }
 }
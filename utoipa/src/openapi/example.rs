@@ -6,7 +6,11 @@
 //! [request_body]: request_body/struct.RequestBody.html
 use serde::{Deserialize, Serialize};
 
-use super::{builder, set_value, RefOr};
+use super::{
+    builder,
+    extensions::{self, Extensions},
+    set_value, Ref, RefOr,
+};
 
 builder! {
     /// # Examples
@@ -32,12 +36,12 @@ builder! {
     #[serde(rename_all = "camelCase")]
     pub struct Example {
         /// Short description for the [`Example`].
-        #[serde(skip_serializing_if = "String::is_empty")]
+        #[serde(skip_serializing_if = "String::is_empty", default)]
         pub summary: String,
 
         /// Long description for the [`Example`]. Value supports markdown syntax for rich text
         /// representation.
-        #[serde(skip_serializing_if = "String::is_empty")]
+        #[serde(skip_serializing_if = "String::is_empty", default)]
         pub description: String,
 
         /// Embedded literal example value. [`Example::value`] and [`Example::external_value`] are
@@ -48,8 +52,16 @@ builder! {
         /// An URI that points to a literal example value. [`Example::external_value`] provides the
         /// capability to references an example that cannot be easily included in JSON or YAML.
         /// [`Example::value`] and [`Example::external_value`] are mutually exclusive.
-        #[serde(skip_serializing_if = "String::is_empty")]
+        #[serde(skip_serializing_if = "String::is_empty", default)]
         pub external_value: String,
+
+        /// Optional extensions _`x-something`_ of the [`Example`].
+        #[serde(
+            skip_serializing_if = "Option::is_none",
+            flatten,
+            deserialize_with = "extensions::deserialize_optional"
+        )]
+        pub extensions: Option<Extensions>,
     }
 }
 
@@ -92,6 +104,11 @@ impl ExampleBuilder {
     pub fn external_value<E: Into<String>>(mut self, external_value: E) -> Self {
         set_value!(self external_value external_value.into())
     }
+
+    /// Add openapi extensions (`x-something`) of the [`Example`].
+    pub fn extensions(mut self, extensions: Option<Extensions>) -> Self {
+        set_value!(self extensions extensions)
+    }
 }
 
 impl From<ExampleBuilder> for RefOr<Example> {
@@ -99,3 +116,9 @@ impl From<ExampleBuilder> for RefOr<Example> {
         Self::T(example_builder.build())
     }
 }
+
+impl From<Ref> for RefOr<Example> {
+    fn from(r: Ref) -> Self {
+        Self::Ref(r)
+    }
+}
@@ -0,0 +1,231 @@
+//! Implements [OpenAPI Specification Extensions][extensions].
+//!
+//! [extensions]: https://spec.openapis.org/oas/latest.html#specification-extensions
+use std::{collections::BTreeMap, ops::Deref};
+
+use serde::{ser::SerializeMap, Deserialize, Serialize};
+use serde_json::Value;
+
+use super::builder;
+
+const EXTENSION_PREFIX: &str = "x-";
+
+builder! {
+    ExtensionsBuilder;
+
+    /// Additional [data for extending][extensions] the OpenAPI specification. Extensions are
+    /// serialized as part of the object they are defined for e.g. _`x-google-backend`_ at the
+    /// root of the OpenAPI document.
+    ///
+    /// Names of the extensions must start with _`x-`_ thus extensions cannot override fields of
+    /// the object they are defined for. Adding extension with other name panics and other fields
+    /// are ignored when extensions are deserialized.
+    ///
+    /// [extensions]: https://spec.openapis.org/oas/latest.html#specification-extensions
+    #[derive(Default, Clone, PartialEq, Eq)]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    pub struct Extensions {
+        extensions: BTreeMap<String, Value>,
+    }
+}
+
+impl Extensions {
+    /// Insert extension with given name and value returning the previous value of the extension
+    /// if any.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the name does not start with _`x-`_.
+    pub fn insert<K: Into<String>, V: Into<Value>>(&mut self, name: K, value: V) -> Option<Value> {
+        let name = name.into();
+        assert_extension_name(&name);
+
+        self.extensions.insert(name, value.into())
+    }
+
+    /// Merge other [`Extensions`] to this one. Extensions already defined in `self` are not
+    /// overridden.
+    pub fn merge(&mut self, other: Extensions) {
+        for (name, value) in other.extensions {
+            self.extensions.entry(name).or_insert(value);
+        }
+    }
+}
+
+impl Deref for Extensions {
+    type Target = BTreeMap<String, Value>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.extensions
+    }
+}
+
+impl Serialize for Extensions {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut map = serializer.serialize_map(None)?;
+        for (name, value) in &self.extensions {
+            map.serialize_entry(name, value)?;
+        }
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for Extensions {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let extensions: BTreeMap<String, Value> = BTreeMap::deserialize(deserializer)?;
+
+        Ok(Self {
+            extensions: extensions
+                .into_iter()
+                .filter(|(name, _)| name.starts_with(EXTENSION_PREFIX))
+                .collect(),
+        })
+    }
+}
+
+/// Deserialize flattened [`Extensions`] of an object as `None` when the object has no extensions.
+pub(crate) fn deserialize_optional<'de, D>(deserializer: D) -> Result<Option<Extensions>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let extensions = Extensions::deserialize(deserializer)?;
+
+    Ok(Some(extensions).filter(|extensions| !extensions.is_empty()))
+}
+
+impl ExtensionsBuilder {
+    /// Add extension with given name and value. Name must start with _`x-`_ e.g.
+    /// _`x-amazon-apigateway-integration`_.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the name does not start with _`x-`_.
+    pub fn add<K: Into<String>, V: Into<Value>>(mut self, name: K, value: V) -> Self {
+        let name = name.into();
+        assert_extension_name(&name);
+        self.extensions.insert(name, value.into());
+
+        self
+    }
+}
+
+fn assert_extension_name(name: &str) {
+    assert!(
+        name.starts_with(EXTENSION_PREFIX),
+        "extension name must start with `{EXTENSION_PREFIX}`, found: `{name}`"
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn deserialize_only_extension_fields() {
+        let extensions: Extensions = serde_json::from_value(json!({
+            "x-internal": true,
+            "title": "not an extension"
+        }))
+        .unwrap();
+
+        assert!(extensions == ExtensionsBuilder::new().add("x-internal", true).build());
+    }
+
+    #[test]
+    #[should_panic(expected = "extension name must start with `x-`, found: `operationId`")]
+    fn add_extension_without_prefix() {
+        ExtensionsBuilder::new().add("operationId", "overridden");
+    }
+
+    #[test]
+    #[should_panic(expected = "extension name must start with `x-`, found: `title`")]
+    fn insert_extension_without_prefix() {
+        Extensions::default().insert("title", "overridden");
+    }
+
+    #[test]
+    fn merge_extensions_keeping_existing() {
+        let mut extensions = ExtensionsBuilder::new().add("x-tier", "free").build();
+
+        extensions.merge(
+            ExtensionsBuilder::new()
+                .add("x-tier", "paid")
+                .add("x-internal", false)
+                .build(),
+        );
+
+        assert_eq!(
+            serde_json::to_value(&extensions).unwrap(),
+            json!({
+                "x-tier": "free",
+                "x-internal": false
+            })
+        );
+    }
+
+    #[test]
+    fn serialize_and_deserialize_extensions_of_model_objects() {
+        use crate::openapi::{
+            path::{Operation, OperationBuilder},
+            schema::{Object, ObjectBuilder, SchemaType},
+        };
+
+        let operation = OperationBuilder::new()
+            .operation_id(Some("getPet"))
+            .extensions(Some(
+                ExtensionsBuilder::new()
+                    .add("x-codegen-request-body-name", "pet")
+                    .build(),
+            ))
+            .build();
+        let schema = ObjectBuilder::new()
+            .schema_type(SchemaType::String)
+            .extensions(Some(
+                ExtensionsBuilder::new().add("x-nullable", true).build(),
+            ))
+            .build();
+
+        let operation_json = serde_json::to_value(&operation).unwrap();
+        let schema_json = serde_json::to_value(&schema).unwrap();
+        assert_eq!(
+            operation_json,
+            json!({
+                "operationId": "getPet",
+                "responses": {},
+                "x-codegen-request-body-name": "pet"
+            })
+        );
+        assert_eq!(
+            schema_json,
+            json!({
+                "type": "string",
+                "x-nullable": true
+            })
+        );
+
+        let operation: Operation = serde_json::from_value(operation_json).unwrap();
+        let schema: Object = serde_json::from_value(schema_json).unwrap();
+        assert!(
+            operation.extensions
+                == Some(
+                    ExtensionsBuilder::new()
+                        .add("x-codegen-request-body-name", "pet")
+                        .build()
+                )
+        );
+        assert!(
+            schema.extensions == Some(ExtensionsBuilder::new().add("x-nullable", true).build())
+        );
+
+        let operation: Operation = serde_json::from_value(json!({ "responses": {} })).unwrap();
+        assert!(operation.extensions.is_none());
+    }
+}
@@ -3,7 +3,11 @@
 //! [external_docs]: https://spec.openapis.org/oas/latest.html#xml-object
 use serde::{Deserialize, Serialize};
 
-use super::{builder, set_value};
+use super::{
+    builder,
+    extensions::{self, Extensions},
+    set_value,
+};
 
 builder! {
     ExternalDocsBuilder;
@@ -17,7 +21,16 @@ builder! {
         /// Target url for external documentation location.
         pub url: String,
         /// Additional description supporting markdown syntax of the external documentation.
+        #[serde(skip_serializing_if = "Option::is_none")]
         pub description: Option<String>,
+
+        /// Optional extensions _`x-something`_ of the [`ExternalDocs`].
+        #[serde(
+            skip_serializing_if = "Option::is_none",
+            flatten,
+            deserialize_with = "extensions::deserialize_optional"
+        )]
+        pub extensions: Option<Extensions>,
     }
 }
 
@@ -50,4 +63,9 @@ impl ExternalDocsBuilder {
     pub fn description<S: Into<String>>(mut self, description: Option<S>) -> Self {
         set_value!(self description description.map(|description| description.into()))
     }
+
+    /// Add openapi extensions (`x-something`) of the [`ExternalDocs`].
+    pub fn extensions(mut self, extensions: Option<Extensions>) -> Self {
+        set_value!(self extensions extensions)
+    }
 }
@@ -4,7 +4,11 @@
 
 use serde::{Deserialize, Serialize};
 
-use super::{builder, set_value, Object, RefOr, Schema, SchemaType};
+use super::{
+    builder,
+    extensions::{self, Extensions},
+    set_value, Deprecated, Object, Ref, RefOr, Required, Schema, SchemaType,
+};
 
 builder! {
     HeaderBuilder;
@@ -22,6 +26,22 @@ builder! {
         /// Additional descripiton of the header value.
         #[serde(skip_serializing_if = "Option::is_none")]
         pub description: Option<String>,
+
+        /// Declares whether the header is mandatory in the response.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub required: Option<Required>,
+
+        /// Declares the header deprecated.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub deprecated: Option<Deprecated>,
+
+        /// Optional extensions _`x-something`_ of the [`Header`].
+        #[serde(
+            skip_serializing_if = "Option::is_none",
+            flatten,
+            deserialize_with = "extensions::deserialize_optional"
+        )]
+        pub extensions: Option<Extensions>,
     }
 }
 
@@ -55,11 +75,20 @@ impl Default for Header {
     fn default() -> Self {
         Self {
             description: Default::default(),
+            required: Default::default(),
+            deprecated: Default::default(),
             schema: Object::with_type(SchemaType::String).into(),
+            extensions: Default::default(),
         }
     }
 }
 
+impl From<Ref> for RefOr<Header> {
+    fn from(r: Ref) -> Self {
+        Self::Ref(r)
+    }
+}
+
 impl HeaderBuilder {
     /// Add schema of header.
     pub fn schema<I: Into<RefOr<Schema>>>(mut self, component: I) -> Self {
@@ -70,4 +99,19 @@ impl HeaderBuilder {
     pub fn description<S: Into<String>>(mut self, description: Option<S>) -> Self {
         set_value!(self description description.map(|description| description.into()))
     }
+
+    /// Add or change required declaration of the header.
+    pub fn required(mut self, required: Option<Required>) -> Self {
+        set_value!(self required required)
+    }
+
+    /// Add or change deprecated status of the header.
+    pub fn deprecated(mut self, deprecated: Option<Deprecated>) -> Self {
+        set_value!(self deprecated deprecated)
+    }
+
+    /// Add openapi extensions (`x-something`) of the [`Header`].
+    pub fn extensions(mut self, extensions: Option<Extensions>) -> Self {
+        set_value!(self extensions extensions)
+    }
 }
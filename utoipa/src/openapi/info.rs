@@ -8,7 +8,11 @@
 //! [derive]: ../../derive.OpenApi.html
 use serde::{Deserialize, Serialize};
 
-use super::{builder, set_value};
+use super::{
+    builder,
+    extensions::{self, Extensions},
+    set_value,
+};
 
 builder! {
     /// # Examples
@@ -42,6 +46,10 @@ builder! {
         /// Title of the API.
         pub title: String,
 
+        /// Optional short summary of the API. Only applicable to OpenAPI 3.1.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub summary: Option<String>,
+
         /// Optional description of the API.
         ///
         /// Value supports markdown syntax.
@@ -66,6 +74,14 @@ builder! {
 
         /// Document version typically the API version.
         pub version: String,
+
+        /// Optional extensions _`x-something`_ of the [`Info`].
+        #[serde(
+            skip_serializing_if = "Option::is_none",
+            flatten,
+            deserialize_with = "extensions::deserialize_optional"
+        )]
+        pub extensions: Option<Extensions>,
     }
 }
 
@@ -101,6 +117,11 @@ impl InfoBuilder {
         set_value!(self version version.into())
     }
 
+    /// Add short summary of the API. Only applicable to OpenAPI 3.1.
+    pub fn summary<S: Into<String>>(mut self, summary: Option<S>) -> Self {
+        set_value!(self summary summary.map(|summary| summary.into()))
+    }
+
     /// Add description of the API.
     pub fn description<S: Into<String>>(mut self, description: Option<S>) -> Self {
         set_value!(self description description.map(|description| description.into()))
@@ -120,6 +141,11 @@ impl InfoBuilder {
     pub fn license(mut self, license: Option<License>) -> Self {
         set_value!(self license license)
     }
+
+    /// Add openapi extensions (`x-something`) of the API.
+    pub fn extensions(mut self, extensions: Option<Extensions>) -> Self {
+        set_value!(self extensions extensions)
+    }
 }
 
 builder! {
@@ -148,6 +174,14 @@ builder! {
         /// Email of the contact person or the organization of the API.
         #[serde(skip_serializing_if = "Option::is_none")]
         pub email: Option<String>,
+
+        /// Optional extensions _`x-something`_ of the [`Contact`].
+        #[serde(
+            skip_serializing_if = "Option::is_none",
+            flatten,
+            deserialize_with = "extensions::deserialize_optional"
+        )]
+        pub extensions: Option<Extensions>,
     }
 }
 
@@ -173,6 +207,11 @@ impl ContactBuilder {
     pub fn email<S: Into<String>>(mut self, email: Option<S>) -> Self {
         set_value!(self email email.map(|email| email.into()))
     }
+
+    /// Add openapi extensions (`x-something`) of the [`Contact`].
+    pub fn extensions(mut self, extensions: Option<Extensions>) -> Self {
+        set_value!(self extensions extensions)
+    }
 }
 
 builder! {
@@ -192,6 +231,21 @@ builder! {
         /// Optional url pointing to the license.
         #[serde(skip_serializing_if = "Option::is_none")]
         pub url: Option<String>,
+
+        /// Optional [SPDX][spdx] license expression of the API e.g. _`MIT OR Apache-2.0`_. Only
+        /// applicable to OpenAPI 3.1 and mutually exclusive with [`License::url`].
+        ///
+        /// [spdx]: https://spdx.org/licenses/
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub identifier: Option<String>,
+
+        /// Optional extensions _`x-something`_ of the [`License`].
+        #[serde(
+            skip_serializing_if = "Option::is_none",
+            flatten,
+            deserialize_with = "extensions::deserialize_optional"
+        )]
+        pub extensions: Option<Extensions>,
     }
 }
 
@@ -217,6 +271,17 @@ impl LicenseBuilder {
     pub fn url<S: Into<String>>(mut self, url: Option<S>) -> Self {
         set_value!(self url url.map(|url| url.into()))
     }
+
+    /// Add [SPDX](https://spdx.org/licenses/) license expression of the API. Only applicable to
+    /// OpenAPI 3.1.
+    pub fn identifier<S: Into<String>>(mut self, identifier: Option<S>) -> Self {
+        set_value!(self identifier identifier.map(|identifier| identifier.into()))
+    }
+
+    /// Add openapi extensions (`x-something`) of the [`License`].
+    pub fn extensions(mut self, extensions: Option<Extensions>) -> Self {
+        set_value!(self extensions extensions)
+    }
 }
 
 #[cfg(test)]
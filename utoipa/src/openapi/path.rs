@@ -8,11 +8,16 @@ use serde_json::Value;
 
 use super::{
     builder,
+    content::Content,
+    example::Example,
+    extensions::{self, Extensions},
     request_body::RequestBody,
     response::{Response, Responses},
-    set_value, Deprecated, ExternalDocs, RefOr, Required, Schema, SecurityRequirement, Server,
+    set_value, Deprecated, ExternalDocs, Ref, RefOr, Required, Schema, SecurityRequirement, Server,
 };
 
+pub mod template;
+
 builder! {
     PathsBuilder;
 
@@ -75,20 +80,63 @@ impl Paths {
             .get(path.as_ref())
             .and_then(|path| path.operations.get(&item_type))
     }
-}
 
-impl PathsBuilder {
-    /// Append [`PathItem`] with path to map of paths. If path already exists it will merge [`Operation`]s of
-    /// [`PathItem`] with already found path item operations.
-    pub fn path<I: Into<String>>(mut self, path: I, mut item: PathItem) -> Self {
+    /// Return _`Option`_ of mutable reference to [`Operation`] from map of paths or `None` if not
+    /// found. See [`Paths::get_path_operation`] for details of the lookup.
+    pub fn get_path_operation_mut<P: AsRef<str>>(
+        &mut self,
+        path: P,
+        item_type: PathItemType,
+    ) -> Option<&mut Operation> {
+        self.paths
+            .get_mut(path.as_ref())
+            .and_then(|path| path.operations.get_mut(&item_type))
+    }
+
+    /// Append [`PathItem`] with path to map of paths. If path already exists it will merge
+    /// [`Operation`]s of [`PathItem`] with already found path item operations.
+    ///
+    /// # Panics
+    ///
+    /// Panics if both [`PathItem`]s reference a different reusable path item with _`$ref`_.
+    pub fn add_path<I: Into<String>, P: Into<PathItem>>(&mut self, path: I, item: P) {
+        let item = item.into();
         let path_string = path.into();
         if let Some(existing_item) = self.paths.get_mut(&path_string) {
-            existing_item.operations.append(&mut item.operations);
+            existing_item.merge(item);
         } else {
             self.paths.insert(path_string, item);
         }
+    }
 
-        self
+    /// Merge `other` [`Paths`] to this one. [`PathItem`]s of paths found from both are merged
+    /// the same way as with [`Paths::add_path`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`PathItem`]s of the same path reference a different reusable path item.
+    pub fn merge(&mut self, other: Paths) {
+        for (path, item) in other.paths {
+            self.add_path(path, item);
+        }
+    }
+}
+
+impl PathsBuilder {
+    /// Append [`PathItem`] with path to map of paths. If path already exists it will merge [`Operation`]s of
+    /// [`PathItem`] with already found path item operations.
+    ///
+    /// Path item can also be a [`Ref`] to reusable path item e.g.
+    /// _`.path("/pets", Ref::from_path_item_name("Crud"))`_.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the path is already referencing a different reusable path item.
+    pub fn path<I: Into<String>, P: Into<PathItem>>(self, path: I, item: P) -> Self {
+        let mut paths = Paths::from(self);
+        paths.add_path(path, item);
+
+        paths.into()
     }
 }
 
@@ -104,6 +152,13 @@ builder! {
     #[cfg_attr(feature = "debug", derive(Debug))]
     #[serde(rename_all = "camelCase")]
     pub struct PathItem {
+        /// Reference to reusable [`PathItem`] e.g. one defined in [`Components::path_items`][path_items].
+        /// Other fields of the [`PathItem`] complement the referenced one.
+        ///
+        /// [path_items]: ../schema/struct.Components.html#structfield.path_items
+        #[serde(rename = "$ref", skip_serializing_if = "Option::is_none")]
+        pub ref_location: Option<String>,
+
         /// Optional summary intented to apply all operations in this [`PathItem`].
         #[serde(skip_serializing_if = "Option::is_none")]
         pub summary: Option<String>,
@@ -141,6 +196,47 @@ impl PathItem {
             ..Default::default()
         }
     }
+
+    /// Merge [`Operation`]s and common [`Parameter`]s of other [`PathItem`] to this one.
+    /// Parameters already defined by name and location are not duplicated.
+    ///
+    /// Panics if the [`PathItem`]s reference a different reusable path item since only one
+    /// _`$ref`_ can be kept.
+    pub(super) fn merge(&mut self, mut other: PathItem) {
+        match (&self.ref_location, other.ref_location) {
+            (Some(ref_location), Some(other_ref_location))
+                if *ref_location != other_ref_location =>
+            {
+                panic!(
+                    "conflicting $ref of path item: `{ref_location}` and `{other_ref_location}`, path item can reference only one reusable path item"
+                )
+            }
+            (None, other_ref_location) => self.ref_location = other_ref_location,
+            _ => (),
+        }
+        self.operations.append(&mut other.operations);
+
+        if let Some(other_parameters) = other.parameters {
+            let parameters = self.parameters.get_or_insert_with(Vec::new);
+            for parameter in other_parameters {
+                if !parameters.iter().any(|existing| {
+                    existing.name == parameter.name
+                        && existing.parameter_in == parameter.parameter_in
+                }) {
+                    parameters.push(parameter);
+                }
+            }
+        }
+    }
+}
+
+impl From<Ref> for PathItem {
+    fn from(r: Ref) -> Self {
+        Self {
+            ref_location: Some(r.ref_location),
+            ..Default::default()
+        }
+    }
 }
 
 impl PathItemBuilder {
@@ -173,10 +269,32 @@ impl PathItemBuilder {
         set_value!(self servers servers.map(|servers| servers.into_iter().collect()))
     }
 
-    /// Append list of [`Parameter`]s common to all [`Operation`]s to this [`PathItem`].
+    /// Add or change list of [`Parameter`]s common to all [`Operation`]s of this [`PathItem`].
     pub fn parameters<I: IntoIterator<Item = Parameter>>(mut self, parameters: Option<I>) -> Self {
         set_value!(self parameters parameters.map(|parameters| parameters.into_iter().collect()))
     }
+
+    /// Append [`Parameter`] common to all [`Operation`]s to this [`PathItem`].
+    pub fn parameter<P: Into<Parameter>>(mut self, parameter: P) -> Self {
+        self.parameters
+            .get_or_insert_with(Vec::new)
+            .push(parameter.into());
+
+        self
+    }
+
+    /// Append list of [`Parameter`]s common to all [`Operation`]s to existing parameters of this
+    /// [`PathItem`]. Use [`PathItemBuilder::parameters`] to replace the existing parameters.
+    pub fn extend_parameters<I: IntoIterator<Item = P>, P: Into<Parameter>>(
+        mut self,
+        parameters: I,
+    ) -> Self {
+        self.parameters
+            .get_or_insert_with(Vec::new)
+            .extend(parameters.into_iter().map(|parameter| parameter.into()));
+
+        self
+    }
 }
 
 /// Path item operation type.
@@ -269,9 +387,9 @@ builder! {
         /// List of possible responses returned by the [`Operation`].
         pub responses: Responses,
 
-        // TODO
+        /// Map of out-of band [`Callback`]s related to the [`Operation`] by callback name.
         #[serde(skip_serializing_if = "Option::is_none")]
-        pub callbacks: Option<String>,
+        pub callbacks: Option<BTreeMap<String, Callback>>,
 
         /// Define whether the operation is deprecated or not and thus should be avoided consuming.
         #[serde(skip_serializing_if = "Option::is_none")]
@@ -288,6 +406,14 @@ builder! {
         /// Alternative [`Server`]s for this [`Operation`].
         #[serde(skip_serializing_if = "Option::is_none")]
         pub servers: Option<Vec<Server>>,
+
+        /// Optional extensions _`x-something`_ of the [`Operation`].
+        #[serde(
+            skip_serializing_if = "Option::is_none",
+            flatten,
+            deserialize_with = "extensions::deserialize_optional"
+        )]
+        pub extensions: Option<Extensions>,
     }
 }
 
@@ -332,6 +458,11 @@ impl OperationBuilder {
         set_value!(self operation_id operation_id.map(|operation_id| operation_id.into()))
     }
 
+    /// Add or change additional [`ExternalDocs`] of the [`Operation`].
+    pub fn external_docs(mut self, external_docs: Option<ExternalDocs>) -> Self {
+        set_value!(self external_docs external_docs)
+    }
+
     /// Add or change parameters of the [`Operation`].
     pub fn parameters<I: IntoIterator<Item = P>, P: Into<Parameter>>(
         mut self,
@@ -429,6 +560,95 @@ impl OperationBuilder {
 
         self
     }
+
+    /// Add or change map of [`Callback`]s of the [`Operation`].
+    pub fn callbacks<I: IntoIterator<Item = (S, Callback)>, S: Into<String>>(
+        mut self,
+        callbacks: Option<I>,
+    ) -> Self {
+        let callbacks = callbacks.map(|callbacks| {
+            callbacks
+                .into_iter()
+                .map(|(name, callback)| (name.into(), callback))
+                .collect()
+        });
+        set_value!(self callbacks callbacks)
+    }
+
+    /// Append a new [`Callback`] with name to the [`Operation`] callbacks. If callback with
+    /// same name already exists the paths of the given [`Callback`] are merged to it.
+    pub fn callback<S: Into<String>>(mut self, name: S, callback: Callback) -> Self {
+        let name = name.into();
+        let callbacks = self.callbacks.get_or_insert_with(BTreeMap::new);
+        match callbacks.get_mut(&name) {
+            Some(existing_callback) => existing_callback.merge(callback),
+            None => {
+                callbacks.insert(name, callback);
+            }
+        }
+
+        self
+    }
+
+    /// Add openapi extensions (`x-something`) of the [`Operation`].
+    pub fn extensions(mut self, extensions: Option<Extensions>) -> Self {
+        set_value!(self extensions extensions)
+    }
+}
+
+builder! {
+    CallbackBuilder;
+
+    /// Implements [OpenAPI Callback Object][callback] describing out-of band requests the
+    /// API provider may initiate in relation to an [`Operation`].
+    ///
+    /// Callback is a map of runtime expressions, e.g. `{$request.body#/callbackUrl}`, to
+    /// [`PathItem`]s describing the requests sent to the resolved url.
+    ///
+    /// [callback]: https://spec.openapis.org/oas/latest.html#callback-object
+    #[non_exhaustive]
+    #[derive(Serialize, Deserialize, Default, Clone, PartialEq)]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    pub struct Callback {
+        /// Map of runtime expressions with [`PathItem`]s holding the callback [`Operation`]s.
+        #[serde(flatten)]
+        pub paths: BTreeMap<String, PathItem>,
+    }
+}
+
+impl Callback {
+    /// Construct a new [`Callback`] with [`PathItem`] for given runtime expression.
+    pub fn new<E: Into<String>>(expression: E, path_item: PathItem) -> Self {
+        Self {
+            paths: BTreeMap::from_iter(iter::once((expression.into(), path_item))),
+        }
+    }
+
+    /// Merge [`PathItem`]s of other [`Callback`] to this [`Callback`].
+    fn merge(&mut self, other: Callback) {
+        for (expression, path_item) in other.paths {
+            if let Some(existing_item) = self.paths.get_mut(&expression) {
+                existing_item.merge(path_item);
+            } else {
+                self.paths.insert(expression, path_item);
+            }
+        }
+    }
+}
+
+impl CallbackBuilder {
+    /// Append [`PathItem`] for runtime expression to the [`Callback`]. If expression already
+    /// exists the [`Operation`]s of the [`PathItem`] are merged with existing ones.
+    pub fn path<E: Into<String>>(mut self, expression: E, path_item: PathItem) -> Self {
+        let expression = expression.into();
+        if let Some(existing_item) = self.paths.get_mut(&expression) {
+            existing_item.merge(path_item);
+        } else {
+            self.paths.insert(expression, path_item);
+        }
+
+        self
+    }
 }
 
 builder! {
@@ -459,16 +679,31 @@ builder! {
         /// Declares whether the parameter is required or not for api.
         ///
         /// * For [`ParameterIn::Path`] this must and will be [`Required::True`].
+        #[serde(default)]
         pub required: Required,
 
         /// Delcares the parameter deprecated status.
         #[serde(skip_serializing_if = "Option::is_none")]
         pub deprecated: Option<Deprecated>,
-        // pub allow_empty_value: bool, this is going to be removed from further open api spec releases
+
+        /// Defines whether empty value e.g. _`?flag=`_ or _`?flag`_ is allowed for the parameter.
+        /// This is only applicable with [`ParameterIn::Query`]. Default value is _`false`_.
+        ///
+        /// Use of this property is not recommended by the OpenAPI specification and it is likely
+        /// to be removed in a later revision.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub allow_empty_value: Option<bool>,
+
         /// Schema of the parameter. Typically [`Schema::Object`] is used.
         #[serde(skip_serializing_if = "Option::is_none")]
         pub schema: Option<RefOr<Schema>>,
 
+        /// Map of [`Content`] describing the parameter when it is serialized with a media type
+        /// e.g. _`application/json`_. This is an alternative for [`Parameter::schema`] and
+        /// should contain only a single entry.
+        #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
+        pub content: BTreeMap<String, Content>,
+
         /// Describes how [`Parameter`] is being serialized depending on [`Parameter::schema`] (type of a content).
         /// Default value is based on [`ParameterIn`].
         #[serde(skip_serializing_if = "Option::is_none")]
@@ -499,6 +734,20 @@ builder! {
         /// within [`Parameter::schema`] if defined.
         #[serde(skip_serializing_if = "Option::is_none")]
         example: Option<Value>,
+
+        /// Named examples of [`Parameter`]'s potential value. [`Parameter::examples`] and
+        /// [`Parameter::example`] are mutually exclusive, thus `example` is cleared when
+        /// examples are added with [`ParameterBuilder::examples_from_iter`].
+        #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
+        pub examples: BTreeMap<String, RefOr<Example>>,
+
+        /// Optional extensions _`x-something`_ of the [`Parameter`].
+        #[serde(
+            skip_serializing_if = "Option::is_none",
+            flatten,
+            deserialize_with = "extensions::deserialize_optional"
+        )]
+        pub extensions: Option<Extensions>,
     }
 }
 
@@ -513,6 +762,12 @@ impl Parameter {
     }
 }
 
+impl From<Ref> for RefOr<Parameter> {
+    fn from(r: Ref) -> Self {
+        Self::Ref(r)
+    }
+}
+
 impl ParameterBuilder {
     /// Add name of the [`Parameter`].
     pub fn name<I: Into<String>>(mut self, name: I) -> Self {
@@ -551,6 +806,13 @@ impl ParameterBuilder {
         set_value!(self schema component.map(|component| component.into()))
     }
 
+    /// Add [`Content`] of the [`Parameter`] with content type e.g `application/json`.
+    pub fn content<S: Into<String>>(mut self, content_type: S, content: Content) -> Self {
+        self.content.insert(content_type.into(), content);
+
+        self
+    }
+
     /// Add or change serialization style of [`Parameter`].
     pub fn style(mut self, style: Option<ParameterStyle>) -> Self {
         set_value!(self style style)
@@ -561,6 +823,11 @@ impl ParameterBuilder {
         set_value!(self explode explode)
     }
 
+    /// Add or change whether [`Parameter`] should allow empty value.
+    pub fn allow_empty_value(mut self, allow_empty_value: Option<bool>) -> Self {
+        set_value!(self allow_empty_value allow_empty_value)
+    }
+
     /// Add or change whether [`Parameter`] should allow reserved characters.
     pub fn allow_reserved(mut self, allow_reserved: Option<bool>) -> Self {
         set_value!(self allow_reserved allow_reserved)
@@ -570,10 +837,40 @@ impl ParameterBuilder {
     pub fn example(mut self, example: Option<Value>) -> Self {
         set_value!(self example example)
     }
+
+    /// Add iterator of _`(N, V)`_ where `N` is name of example and `V` is [`Example`][example] to
+    /// [`Parameter`].
+    ///
+    /// [`Parameter::examples`] and [`Parameter::example`] are mutually exclusive thus any
+    /// previously defined `example` is cleared.
+    ///
+    /// [example]: ../example/struct.Example.html
+    pub fn examples_from_iter<
+        E: IntoIterator<Item = (N, V)>,
+        N: Into<String>,
+        V: Into<RefOr<Example>>,
+    >(
+        mut self,
+        examples: E,
+    ) -> Self {
+        self.examples.extend(
+            examples
+                .into_iter()
+                .map(|(name, example)| (name.into(), example.into())),
+        );
+        self.example = None;
+
+        self
+    }
+
+    /// Add openapi extensions (`x-something`) of the [`Parameter`].
+    pub fn extensions(mut self, extensions: Option<Extensions>) -> Self {
+        set_value!(self extensions extensions)
+    }
 }
 
 /// In definition of [`Parameter`].
-#[derive(Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[derive(Serialize, Deserialize, PartialEq, Eq, Clone, Copy)]
 #[serde(rename_all = "lowercase")]
 #[cfg_attr(feature = "debug", derive(Debug))]
 pub enum ParameterIn {
@@ -626,8 +923,11 @@ pub enum ParameterStyle {
 
 #[cfg(test)]
 mod tests {
-    use super::{Operation, OperationBuilder};
-    use crate::openapi::{security::SecurityRequirement, server::Server};
+    use super::{
+        Callback, Operation, OperationBuilder, ParameterBuilder, ParameterIn, PathItem,
+        PathItemBuilder, PathItemType, PathsBuilder,
+    };
+    use crate::openapi::{security::SecurityRequirement, server::Server, Ref};
 
     #[test]
     fn operation_new() {
@@ -660,6 +960,27 @@ mod tests {
         assert!(operation.security.is_some());
     }
 
+    #[test]
+    fn operation_builder_callback() {
+        let on_data = |path_item_type| {
+            Callback::new(
+                "{$request.body#/callbackUrl}",
+                PathItem::new(path_item_type, OperationBuilder::new().build()),
+            )
+        };
+        let operation = OperationBuilder::new()
+            .callback("onData", on_data(PathItemType::Post))
+            .callback("onData", on_data(PathItemType::Put))
+            .build();
+
+        let callbacks = operation.callbacks.expect("Operation must have callbacks");
+        let path_item = callbacks["onData"]
+            .paths
+            .get("{$request.body#/callbackUrl}")
+            .expect("Callback must have path item for expression");
+        assert_eq!(path_item.operations.len(), 2);
+    }
+
     #[test]
     fn operation_builder_server() {
         let server1 = Server::new("/api");
@@ -671,4 +992,80 @@ mod tests {
 
         assert!(operation.servers.is_some());
     }
+
+    #[test]
+    fn paths_builder_merge_path_item_parameters() {
+        let path_item = |path_item_type| {
+            PathItemBuilder::new()
+                .parameter(
+                    ParameterBuilder::new()
+                        .name("org_id")
+                        .parameter_in(ParameterIn::Path),
+                )
+                .operation(path_item_type, OperationBuilder::new().build())
+                .build()
+        };
+        let paths = PathsBuilder::new()
+            .path("/orgs/{org_id}", path_item(PathItemType::Get))
+            .path("/orgs/{org_id}", path_item(PathItemType::Delete))
+            .build();
+
+        let path_item = paths
+            .get_path_item("/orgs/{org_id}")
+            .expect("Paths must have path item");
+        assert_eq!(path_item.operations.len(), 2);
+        assert_eq!(path_item.parameters.as_ref().map(Vec::len), Some(1));
+    }
+
+    #[test]
+    fn paths_builder_merge_path_item_ref() {
+        let paths = PathsBuilder::new()
+            .path("/pets", Ref::from_path_item_name("Crud"))
+            .path("/pets", PathItem::new(PathItemType::Head, Operation::new()))
+            .build();
+
+        let path_item = paths
+            .get_path_item("/pets")
+            .expect("Paths must have path item");
+        assert_eq!(
+            path_item.ref_location.as_deref(),
+            Some("#/components/pathItems/Crud")
+        );
+        assert_eq!(path_item.operations.len(), 1);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "conflicting $ref of path item: `#/components/pathItems/Crud` and `#/components/pathItems/Admin`"
+    )]
+    fn paths_builder_conflicting_path_item_refs_panics() {
+        PathsBuilder::new()
+            .path("/pets", Ref::from_path_item_name("Crud"))
+            .path("/pets", Ref::from_path_item_name("Admin"));
+    }
+
+    #[test]
+    fn paths_merge_path_items_of_same_path() {
+        let mut paths = PathsBuilder::new()
+            .path("/pets", PathItem::new(PathItemType::Get, Operation::new()))
+            .build();
+
+        paths.merge(
+            PathsBuilder::new()
+                .path("/pets", PathItem::new(PathItemType::Post, Operation::new()))
+                .path(
+                    "/pets/{id}",
+                    PathItem::new(PathItemType::Get, Operation::new()),
+                )
+                .build(),
+        );
+
+        assert_eq!(paths.paths.len(), 2);
+        assert_eq!(
+            paths
+                .get_path_item("/pets")
+                .map(|path_item| path_item.operations.len()),
+            Some(2)
+        );
+    }
 }
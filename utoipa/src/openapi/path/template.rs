@@ -0,0 +1,187 @@
+//! Conversion of path templates between OpenAPI and web framework syntaxes.
+//!
+//! OpenAPI declares path parameters with braces e.g. _`/pets/{id}`_ while web frameworks use
+//! their own syntax e.g. _`/pets/:id`_ or _`/pets/<id>`_ possibly with regular expressions
+//! restricting the matched value.
+
+/// Syntax of path parameters in path templates.
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub enum PathSyntax {
+    /// Parameters in braces e.g. _`/pets/{id}`_ used by OpenAPI, actix-web and axum 0.8.
+    Braces,
+    /// Parameters prefixed with colon e.g. _`/pets/:id`_ used by axum prior to 0.8.
+    Colon,
+    /// Parameters in angle brackets e.g. _`/pets/<id>`_ used by rocket.
+    Angle,
+}
+
+/// Convert path template of any [`PathSyntax`] to OpenAPI path template.
+///
+/// * Regular expressions of parameters are removed e.g. _`{id:\d+}`_ becomes _`{id}`_.
+/// * Wildcard parameters e.g. _`*rest`_ of axum and _`<rest..>`_ of rocket become _`{rest}`_.
+/// * Unnamed parameters e.g. _`<_>`_ or _`{_:.*}`_ are named _`arg0`_, _`arg1`_ and so on.
+/// * Query part of the path e.g. _`?<limit>`_ of rocket is removed.
+///
+/// # Examples
+///
+/// ```rust
+/// # use utoipa::openapi::path::template;
+/// assert_eq!(template::to_openapi_path("/pets/:id"), "/pets/{id}");
+/// assert_eq!(template::to_openapi_path("/pets/<id>?<limit>"), "/pets/{id}");
+/// assert_eq!(template::to_openapi_path(r"/pets/{id:\d+}/{_:.*}"), "/pets/{id}/{arg0}");
+/// ```
+pub fn to_openapi_path(path: &str) -> String {
+    let mut openapi_path = String::with_capacity(path.len());
+    let mut unnamed_count = 0;
+    let mut push_parameter = |openapi_path: &mut String, name: &str| {
+        let name = name.trim();
+        if name.is_empty() || name == "_" {
+            openapi_path.push_str(&format!("{{arg{unnamed_count}}}"));
+            unnamed_count += 1;
+        } else {
+            openapi_path.push_str(&format!("{{{name}}}"));
+        }
+    };
+
+    let mut rest = path;
+    while let Some(char) = rest.chars().next() {
+        // parameters are consumed as whole thus `?` within braces e.g. `{id:\d+?}` is not taken
+        // as start of the query
+        if char == '?' {
+            break;
+        }
+
+        let segment_start = openapi_path.is_empty() || openapi_path.ends_with('/');
+        let parameter_len = match char {
+            '{' => closing_brace(rest).map(|end| {
+                let parameter = &rest[1..end];
+                let name = parameter
+                    .split_once(':')
+                    .map_or(parameter, |(name, _)| name);
+                push_parameter(&mut openapi_path, name);
+                end + 1
+            }),
+            '<' => rest.find('>').map(|end| {
+                push_parameter(&mut openapi_path, rest[1..end].trim_end_matches(".."));
+                end + 1
+            }),
+            ':' | '*' if segment_start => {
+                let end = rest.find(['/', '?']).unwrap_or(rest.len());
+                push_parameter(&mut openapi_path, &rest[1..end]);
+                Some(end)
+            }
+            _ => None,
+        };
+
+        match parameter_len {
+            Some(len) => rest = &rest[len..],
+            None => {
+                openapi_path.push(char);
+                rest = &rest[char.len_utf8()..];
+            }
+        }
+    }
+
+    openapi_path
+}
+
+/// Convert OpenAPI path template to path template of given [`PathSyntax`].
+///
+/// The `path` is first normalized with [`to_openapi_path`] thus path of any [`PathSyntax`] can be
+/// converted to another one.
+///
+/// # Examples
+///
+/// ```rust
+/// # use utoipa::openapi::path::template::{self, PathSyntax};
+/// assert_eq!(template::from_openapi_path("/pets/{id}", PathSyntax::Colon), "/pets/:id");
+/// assert_eq!(template::from_openapi_path("/pets/:id", PathSyntax::Angle), "/pets/<id>");
+/// ```
+pub fn from_openapi_path(path: &str, syntax: PathSyntax) -> String {
+    let openapi_path = to_openapi_path(path);
+    if syntax == PathSyntax::Braces {
+        return openapi_path;
+    }
+
+    let mut path = String::with_capacity(openapi_path.len());
+    let mut rest = openapi_path.as_str();
+    while let Some((prefix, parameter)) = rest.split_once('{') {
+        let (name, suffix) = parameter.split_once('}').unwrap_or((parameter, ""));
+        path.push_str(prefix);
+        match syntax {
+            PathSyntax::Colon => path.push_str(&format!(":{name}")),
+            PathSyntax::Angle => path.push_str(&format!("<{name}>")),
+            PathSyntax::Braces => path.push_str(&format!("{{{name}}}")),
+        }
+        rest = suffix;
+    }
+    path.push_str(rest);
+
+    path
+}
+
+/// Find index of the brace closing the brace at the start of the `path`. Braces within the
+/// parameter e.g. in regular expression _`{id:\d{2}}`_ are taken into account.
+fn closing_brace(path: &str) -> Option<usize> {
+    let mut depth = 0;
+    for (index, char) in path.char_indices() {
+        match char {
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(index);
+                }
+            }
+            _ => (),
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn convert_framework_paths_to_openapi_path() {
+        for (path, expected) in [
+            ("/pets/{id}", "/pets/{id}"),
+            (r"/pets/{id:\d{2}}/{name:[^/]+}", "/pets/{id}/{name}"),
+            ("/files/{_:.*}/{_:.*}", "/files/{arg0}/{arg1}"),
+            ("/pets/:id/owners/:owner_id", "/pets/{id}/owners/{owner_id}"),
+            ("/files/*path", "/files/{path}"),
+            ("/pets/<id>/<_>?<limit>&<offset>", "/pets/{id}/{arg0}"),
+            ("/files/<path..>", "/files/{path}"),
+            ("/time/12:30", "/time/12:30"),
+            ("/pets/:id?limit=10", "/pets/{id}"),
+            (
+                r"/pets/{id:\d+?}/{name:[a-z]?}?limit=10",
+                "/pets/{id}/{name}",
+            ),
+        ] {
+            assert_eq!(to_openapi_path(path), expected, "path: {path}");
+        }
+    }
+
+    #[test]
+    fn convert_openapi_path_to_framework_paths() {
+        let path = "/pets/{id}/owners/{owner_id}";
+
+        assert_eq!(from_openapi_path(path, PathSyntax::Braces), path);
+        assert_eq!(
+            from_openapi_path(path, PathSyntax::Colon),
+            "/pets/:id/owners/:owner_id"
+        );
+        assert_eq!(
+            from_openapi_path(path, PathSyntax::Angle),
+            "/pets/<id>/owners/<owner_id>"
+        );
+        assert_eq!(
+            from_openapi_path(r"/pets/{id:\d+}", PathSyntax::Colon),
+            "/pets/:id"
+        );
+    }
+}
@@ -0,0 +1,426 @@
+//! Implements resolving and bundling of [`Ref`]s to [`Components`] of [`OpenApi`] document.
+//!
+//! * [`resolve`] finds the component a [`Ref`] points to.
+//! * [`bundle`] rewrites references of the whole document either by inlining or renaming the
+//!   referenced components.
+use std::collections::BTreeMap;
+
+use serde_json::{Map, Value};
+
+use super::{
+    example::Example, header::Header, path::Parameter, request_body::RequestBody, Components,
+    OpenApi, Ref, RefOr, Response, Schema,
+};
+
+/// Kinds of components which can be referenced and thus inlined by [`BundleMode::Inline`].
+const REFERENCEABLE_KINDS: [&str; 7] = [
+    "schemas",
+    "responses",
+    "parameters",
+    "examples",
+    "requestBodies",
+    "headers",
+    "pathItems",
+];
+
+/// Component type which can be resolved from [`Components`] with [`resolve`].
+pub trait ResolveRef: Sized {
+    /// Kind of the component in the reference location e.g. _`"schemas"`_ of
+    /// _`"#/components/schemas/Pet"`_.
+    const KIND: &'static str;
+
+    /// Get map of components of this type.
+    fn components(components: &Components) -> &BTreeMap<String, RefOr<Self>>;
+}
+
+macro_rules! impl_resolve_ref {
+    ( $( $ty:ty => $kind:literal $field:ident ),* ) => {
+        $(
+            impl ResolveRef for $ty {
+                const KIND: &'static str = $kind;
+
+                fn components(components: &Components) -> &BTreeMap<String, RefOr<Self>> {
+                    &components.$field
+                }
+            }
+        )*
+    };
+}
+
+impl_resolve_ref!(
+    Schema => "schemas" schemas,
+    Response => "responses" responses,
+    Parameter => "parameters" parameters,
+    Example => "examples" examples,
+    RequestBody => "requestBodies" request_bodies,
+    Header => "headers" headers
+);
+
+/// Resolve component of type _`T`_ the [`Ref`] points to from [`Components`] of the `openapi`.
+///
+/// Components which are references themselves are followed until the actual component is found.
+/// Returns `None` if the reference does not point to [`Components`] of type _`T`_, the component
+/// does not exist or the references are cyclic.
+///
+/// # Examples
+///
+/// _**Resolve referenced schema in a modifier.**_
+/// ```rust
+/// # use utoipa::openapi::{self, OpenApi, Ref, Schema};
+/// fn pet_schema(openapi: &OpenApi) -> Option<&Schema> {
+///     openapi::resolve::<Schema>(openapi, &Ref::from_schema_name("Pet"))
+/// }
+/// ```
+pub fn resolve<'o, T: ResolveRef>(openapi: &'o OpenApi, reference: &Ref) -> Option<&'o T> {
+    let components = T::components(openapi.components.as_ref()?);
+    let prefix = format!("#/components/{}/", T::KIND);
+
+    let mut reference = reference;
+    for _ in 0..=components.len() {
+        let name = reference.ref_location.strip_prefix(&prefix)?;
+        match components.get(name)? {
+            RefOr::T(component) => return Some(component),
+            RefOr::Ref(next) => reference = next,
+        }
+    }
+    None
+}
+
+/// Mode of [`bundle`].
+#[non_exhaustive]
+pub enum BundleMode<'f> {
+    /// Replace references with copies of the referenced components and remove components which
+    /// are not referenced anymore. Recursive references are kept as is. Security schemes are
+    /// always kept since they are not referenced with [`Ref`]s.
+    Inline,
+    /// Rename components with given function which receives the current name of the component
+    /// and returns the new name. References and security requirements are updated accordingly.
+    Rename(&'f dyn Fn(&str) -> String),
+}
+
+/// Bundle references of the `openapi` document according to [`BundleMode`].
+///
+/// The document is bundled in its serialized form. Returns error if the document cannot be
+/// serialized or the bundled document cannot be deserialized back in which case the `openapi`
+/// is left unchanged.
+///
+/// # Examples
+///
+/// _**Prefix components of a sub service before merging it to the gateway document.**_
+/// ```rust
+/// # use utoipa::openapi::{self, BundleMode, Info, OpenApi, Paths};
+/// let mut users = OpenApi::new(Info::new("Users", "v1"), Paths::new());
+/// openapi::bundle(&mut users, BundleMode::Rename(&|name| format!("Users{name}")))?;
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+///
+/// _**Inline all referenced components.**_
+/// ```rust
+/// # use utoipa::openapi::{self, BundleMode, Info, OpenApi, Paths};
+/// let mut openapi = OpenApi::new(Info::new("Pets", "v1"), Paths::new());
+/// openapi::bundle(&mut openapi, BundleMode::Inline)?;
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+pub fn bundle(openapi: &mut OpenApi, mode: BundleMode) -> Result<(), serde_json::Error> {
+    // Work with the OpenAPI 3.0 representation used by the model regardless of the version.
+    let mut document = into_object(OpenApi::serialize(openapi, serde_json::value::Serializer)?);
+    let mut components = into_object(document.remove("components").unwrap_or_default());
+
+    match mode {
+        BundleMode::Inline => {
+            let mut stack = Vec::new();
+            for value in document.values_mut() {
+                inline_refs(value, &components, &mut stack);
+            }
+
+            // Recursive references of components still need the components to exist.
+            let mut references = Vec::new();
+            for value in document.values() {
+                collect_refs(value, &mut references);
+            }
+            loop {
+                let mut component_references = Vec::new();
+                for reference in &references {
+                    if let Some(component) = component(&components, reference) {
+                        collect_refs(component, &mut component_references);
+                    }
+                }
+                component_references.retain(|reference| !references.contains(reference));
+                if component_references.is_empty() {
+                    break;
+                }
+                references.append(&mut component_references);
+            }
+
+            for kind in REFERENCEABLE_KINDS {
+                if let Some(Value::Object(entries)) = components.get_mut(kind) {
+                    entries.retain(|name, _| {
+                        references.contains(&format!("#/components/{kind}/{name}"))
+                    });
+                }
+            }
+            components.retain(|_, entries| !entries.as_object().is_some_and(Map::is_empty));
+        }
+        BundleMode::Rename(rename) => {
+            let mut renamed = BTreeMap::new();
+            for (kind, entries) in components.iter_mut() {
+                if let Value::Object(entries) = entries {
+                    *entries = std::mem::take(entries)
+                        .into_iter()
+                        .map(|(name, component)| {
+                            let new_name = rename(&name);
+                            renamed.insert(
+                                format!("#/components/{kind}/{name}"),
+                                format!("#/components/{kind}/{new_name}"),
+                            );
+                            (new_name, component)
+                        })
+                        .collect();
+                }
+            }
+
+            for value in document.values_mut().chain(components.values_mut()) {
+                rename_refs(value, &renamed, rename);
+            }
+        }
+    }
+
+    if !components.is_empty() {
+        document.insert("components".to_string(), Value::Object(components));
+    }
+    *openapi = OpenApi::deserialize(Value::Object(document))?;
+
+    Ok(())
+}
+
+/// Get component by its `reference` from serialized `components`.
+fn component<'c>(components: &'c Map<String, Value>, reference: &str) -> Option<&'c Value> {
+    let (kind, name) = reference.strip_prefix("#/components/")?.split_once('/')?;
+    components.get(kind)?.get(name)
+}
+
+fn inline_refs(value: &mut Value, components: &Map<String, Value>, stack: &mut Vec<String>) {
+    match value {
+        Value::Object(object) => {
+            let reference = object
+                .get("$ref")
+                .and_then(Value::as_str)
+                .map(ToString::to_string);
+            match reference {
+                Some(reference) if !stack.contains(&reference) => {
+                    if let Some(Value::Object(referenced)) = component(components, &reference) {
+                        object.remove("$ref");
+                        for (key, value) in referenced {
+                            object.entry(key.clone()).or_insert_with(|| value.clone());
+                        }
+
+                        stack.push(reference);
+                        object
+                            .values_mut()
+                            .for_each(|value| inline_refs(value, components, stack));
+                        stack.pop();
+                    }
+                }
+                Some(_) => (),
+                None => object
+                    .values_mut()
+                    .for_each(|value| inline_refs(value, components, stack)),
+            }
+        }
+        Value::Array(values) => values
+            .iter_mut()
+            .for_each(|value| inline_refs(value, components, stack)),
+        _ => (),
+    }
+}
+
+fn collect_refs(value: &Value, references: &mut Vec<String>) {
+    match value {
+        Value::Object(object) => {
+            if let Some(reference) = object.get("$ref").and_then(Value::as_str) {
+                if !references.iter().any(|existing| existing == reference) {
+                    references.push(reference.to_string());
+                }
+            }
+            object
+                .values()
+                .for_each(|value| collect_refs(value, references));
+        }
+        Value::Array(values) => values
+            .iter()
+            .for_each(|value| collect_refs(value, references)),
+        _ => (),
+    }
+}
+
+fn rename_refs(
+    value: &mut Value,
+    renamed: &BTreeMap<String, String>,
+    rename: &dyn Fn(&str) -> String,
+) {
+    match value {
+        Value::Object(object) => {
+            for (key, value) in object.iter_mut() {
+                match (key.as_str(), value) {
+                    ("$ref", Value::String(reference)) => {
+                        if let Some(new_reference) = renamed.get(reference) {
+                            *reference = new_reference.clone();
+                        }
+                    }
+                    ("security", Value::Array(requirements)) => {
+                        for requirement in requirements {
+                            if let Value::Object(requirement) = requirement {
+                                *requirement = std::mem::take(requirement)
+                                    .into_iter()
+                                    .map(|(name, scopes)| (rename(&name), scopes))
+                                    .collect();
+                            }
+                        }
+                    }
+                    (_, value) => rename_refs(value, renamed, rename),
+                }
+            }
+        }
+        Value::Array(values) => values
+            .iter_mut()
+            .for_each(|value| rename_refs(value, renamed, rename)),
+        _ => (),
+    }
+}
+
+fn into_object(value: Value) -> Map<String, Value> {
+    match value {
+        Value::Object(object) => object,
+        _ => Map::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_json_diff::assert_json_eq;
+    use serde_json::json;
+
+    use super::*;
+
+    fn pet_api() -> OpenApi {
+        serde_json::from_value(json!({
+            "openapi": "3.0.3",
+            "info": { "title": "Pet api", "version": "1.0.0" },
+            "paths": {
+                "/pets": {
+                    "get": {
+                        "responses": {
+                            "200": {
+                                "description": "Pet",
+                                "content": {
+                                    "application/json": {
+                                        "schema": { "$ref": "#/components/schemas/Pet" }
+                                    }
+                                }
+                            }
+                        },
+                        "security": [{ "api_key": [] }]
+                    }
+                }
+            },
+            "components": {
+                "schemas": {
+                    "Pet": {
+                        "type": "object",
+                        "properties": {
+                            "owner": { "$ref": "#/components/schemas/Owner" },
+                            "parent": { "$ref": "#/components/schemas/Pet" }
+                        }
+                    },
+                    "Owner": { "type": "string" },
+                    "OwnerAlias": { "$ref": "#/components/schemas/Owner" },
+                    "Unused": { "type": "integer" }
+                },
+                "securitySchemes": {
+                    "api_key": { "type": "apiKey", "in": "header", "name": "x-api-key" }
+                }
+            }
+        }))
+        .expect("OpenApi must deserialize")
+    }
+
+    #[test]
+    fn resolve_component_references() {
+        let openapi = pet_api();
+
+        assert!(matches!(
+            resolve::<Schema>(&openapi, &Ref::from_schema_name("OwnerAlias")),
+            Some(Schema::Object(_))
+        ));
+        assert!(resolve::<Schema>(&openapi, &Ref::from_schema_name("Missing")).is_none());
+        assert!(resolve::<Response>(&openapi, &Ref::from_schema_name("Pet")).is_none());
+    }
+
+    #[test]
+    fn bundle_inline_references() {
+        let mut openapi = pet_api();
+
+        bundle(&mut openapi, BundleMode::Inline).unwrap();
+
+        let value = serde_json::to_value(&openapi).unwrap();
+        assert_json_eq!(
+            value.pointer("/paths/~1pets/get/responses/200/content/application~1json/schema"),
+            json!({
+                "type": "object",
+                "properties": {
+                    "owner": { "type": "string" },
+                    "parent": { "$ref": "#/components/schemas/Pet" }
+                }
+            })
+        );
+        assert_json_eq!(
+            value
+                .pointer("/components/schemas")
+                .and_then(Value::as_object)
+                .map(|schemas| schemas.keys().collect::<Vec<_>>()),
+            json!(["Owner", "Pet"])
+        );
+        assert!(value
+            .pointer("/components/securitySchemes/api_key")
+            .is_some());
+    }
+
+    #[test]
+    fn bundle_rename_references() {
+        let mut openapi = pet_api();
+
+        bundle(
+            &mut openapi,
+            BundleMode::Rename(&|name| format!("Pets{name}")),
+        )
+        .unwrap();
+
+        let value = serde_json::to_value(&openapi).unwrap();
+        assert_json_eq!(
+            value.pointer("/paths/~1pets/get"),
+            json!({
+                "responses": {
+                    "200": {
+                        "description": "Pet",
+                        "content": {
+                            "application/json": {
+                                "schema": { "$ref": "#/components/schemas/PetsPet" }
+                            }
+                        }
+                    }
+                },
+                "security": [{ "Petsapi_key": [] }]
+            })
+        );
+        assert_json_eq!(
+            value.pointer("/components/schemas/PetsPet/properties"),
+            json!({
+                "owner": { "$ref": "#/components/schemas/PetsOwner" },
+                "parent": { "$ref": "#/components/schemas/PetsPet" }
+            })
+        );
+        assert!(value
+            .pointer("/components/securitySchemes/Petsapi_key")
+            .is_some());
+    }
+}
@@ -5,7 +5,11 @@ use std::collections::BTreeMap;
 
 use serde::{Deserialize, Serialize};
 
-use super::{builder, set_value, Content, Required};
+use super::{
+    builder,
+    extensions::{self, Extensions},
+    set_value, Content, Ref, RefOr, Required,
+};
 
 builder! {
     RequestBodyBuilder;
@@ -28,6 +32,14 @@ builder! {
         /// Determines whether request body is reuqired in the request or not.
         #[serde(skip_serializing_if = "Option::is_none")]
         pub required: Option<Required>,
+
+        /// Optional extensions _`x-something`_ of the [`RequestBody`].
+        #[serde(
+            skip_serializing_if = "Option::is_none",
+            flatten,
+            deserialize_with = "extensions::deserialize_optional"
+        )]
+        pub extensions: Option<Extensions>,
     }
 }
 
@@ -55,6 +67,17 @@ impl RequestBodyBuilder {
 
         self
     }
+
+    /// Add openapi extensions (`x-something`) of the [`RequestBody`].
+    pub fn extensions(mut self, extensions: Option<Extensions>) -> Self {
+        set_value!(self extensions extensions)
+    }
+}
+
+impl From<Ref> for RefOr<RequestBody> {
+    fn from(r: Ref) -> Self {
+        Self::Ref(r)
+    }
 }
 
 /// Trait with convenience functions for documenting request bodies.
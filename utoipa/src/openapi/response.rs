@@ -9,7 +9,12 @@ use serde::{Deserialize, Serialize};
 use crate::openapi::{Ref, RefOr};
 use crate::IntoResponses;
 
-use super::{builder, header::Header, set_value, Content};
+use super::{
+    builder,
+    extensions::{self, Extensions},
+    header::{Header, HeaderBuilder},
+    set_value, Content, KnownFormat, ObjectBuilder, SchemaFormat, SchemaType,
+};
 
 builder! {
     ResponsesBuilder;
@@ -118,6 +123,14 @@ builder! {
         /// will create and show default example according to the first entry in `content` map.
         #[serde(skip_serializing_if = "IndexMap::is_empty", default)]
         pub content: IndexMap<String, Content>,
+
+        /// Optional extensions _`x-something`_ of the [`Response`].
+        #[serde(
+            skip_serializing_if = "Option::is_none",
+            flatten,
+            deserialize_with = "extensions::deserialize_optional"
+        )]
+        pub extensions: Option<Extensions>,
     }
 }
 
@@ -152,6 +165,11 @@ impl ResponseBuilder {
 
         self
     }
+
+    /// Add openapi extensions (`x-something`) of the [`Response`].
+    pub fn extensions(mut self, extensions: Option<Extensions>) -> Self {
+        set_value!(self extensions extensions)
+    }
 }
 
 impl From<ResponseBuilder> for RefOr<Response> {
@@ -166,6 +184,115 @@ impl From<Ref> for RefOr<Response> {
     }
 }
 
+/// Helper for documenting file download responses.
+///
+/// Creates a [`Response`] with binary string schema for the content type, which defaults to
+/// `application/octet-stream`, and a `content-disposition` header describing how the file
+/// is served to the client.
+///
+/// # Examples
+///
+/// Document a PDF download.
+/// ```rust
+/// # use utoipa::openapi::response::{FileResponse, Response};
+/// let response: Response = FileResponse::new("Monthly report")
+///     .content_type("application/pdf")
+///     .build();
+/// ```
+///
+/// With [`ToResponse`][to_response] the helper can be used in `#[utoipa::path(...)]` responses.
+/// ```rust
+/// # use utoipa::openapi::response::FileResponse;
+/// # use utoipa::openapi::{RefOr, Response};
+/// struct ReportFile;
+///
+/// impl utoipa::ToResponse for ReportFile {
+///     fn response() -> (String, RefOr<Response>) {
+///         (
+///             "ReportFile".to_string(),
+///             FileResponse::new("Monthly report").content_type("application/pdf").into(),
+///         )
+///     }
+/// }
+///
+/// #[utoipa::path(
+///     get,
+///     path = "/report",
+///     responses(
+///         (status = 200, response = inline(ReportFile))
+///     )
+/// )]
+/// fn get_report() {}
+/// ```
+///
+/// [to_response]: crate::ToResponse
+#[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct FileResponse {
+    description: String,
+    content_type: String,
+    content_disposition: Option<String>,
+}
+
+impl FileResponse {
+    /// Construct a new [`FileResponse`] with given description.
+    pub fn new<S: Into<String>>(description: S) -> Self {
+        Self {
+            description: description.into(),
+            content_type: "application/octet-stream".to_string(),
+            content_disposition: None,
+        }
+    }
+
+    /// Change content type of the served file. Defaults to `application/octet-stream`.
+    pub fn content_type<S: Into<String>>(mut self, content_type: S) -> Self {
+        self.content_type = content_type.into();
+
+        self
+    }
+
+    /// Override description of the `content-disposition` header
+    /// e.g. `attachment; filename="report.pdf"`.
+    pub fn content_disposition<S: Into<String>>(mut self, content_disposition: S) -> Self {
+        self.content_disposition = Some(content_disposition.into());
+
+        self
+    }
+
+    /// Consume the [`FileResponse`] and build the [`Response`].
+    pub fn build(self) -> Response {
+        let binary = ObjectBuilder::new()
+            .schema_type(SchemaType::String)
+            .format(Some(SchemaFormat::KnownFormat(KnownFormat::Binary)));
+        let content_disposition = self.content_disposition.unwrap_or_else(|| {
+            "Attachment with the file name e.g. `attachment; filename=\"file.txt\"`".to_string()
+        });
+
+        ResponseBuilder::new()
+            .description(self.description)
+            .content(self.content_type, Content::new(binary))
+            .header(
+                "content-disposition",
+                HeaderBuilder::new()
+                    .description(Some(content_disposition))
+                    .build(),
+            )
+            .build()
+    }
+}
+
+impl From<FileResponse> for Response {
+    fn from(file: FileResponse) -> Self {
+        file.build()
+    }
+}
+
+impl From<FileResponse> for RefOr<Response> {
+    fn from(file: FileResponse) -> Self {
+        Self::T(file.build())
+    }
+}
+
 /// Trait with convenience functions for documenting response bodies.
 ///
 /// This trait requires a feature-flag to enable:
@@ -254,7 +381,8 @@ impl ResponseExt for ResponseBuilder {
 
 #[cfg(test)]
 mod tests {
-    use super::{Content, ResponseBuilder, Responses};
+    use super::{Content, FileResponse, Response, ResponseBuilder, Responses};
+    use crate::openapi::ContentBuilder;
     use assert_json_diff::assert_json_eq;
     use serde_json::json;
 
@@ -291,6 +419,58 @@ mod tests {
         );
         Ok(())
     }
+
+    #[test]
+    fn response_with_content_without_schema() -> Result<(), serde_json::Error> {
+        let response = ResponseBuilder::new()
+            .description("No content")
+            .content("text/plain", ContentBuilder::new().no_schema().build())
+            .build();
+
+        let value = serde_json::to_value(&response)?;
+        assert_json_eq!(
+            value,
+            json!({
+              "description": "No content",
+              "content": {
+                "text/plain": {}
+              }
+            })
+        );
+        assert!(serde_json::from_value::<Response>(value)? == response);
+        Ok(())
+    }
+
+    #[test]
+    fn file_response() {
+        let response = FileResponse::new("Monthly report")
+            .content_type("application/pdf")
+            .content_disposition("attachment; filename=\"report.pdf\"")
+            .build();
+
+        assert_json_eq!(
+            response,
+            json!({
+              "description": "Monthly report",
+              "headers": {
+                "content-disposition": {
+                  "schema": {
+                    "type": "string"
+                  },
+                  "description": "attachment; filename=\"report.pdf\""
+                }
+              },
+              "content": {
+                "application/pdf": {
+                  "schema": {
+                    "type": "string",
+                    "format": "binary"
+                  }
+                }
+              }
+            })
+        );
+    }
 }
 
 #[cfg(all(test, feature = "openapi_extensions"))]
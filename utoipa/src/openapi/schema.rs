@@ -8,8 +8,19 @@ use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
 use super::RefOr;
-use super::{builder, security::SecurityScheme, set_value, xml::Xml, Deprecated, Response};
-use crate::ToResponse;
+use super::{
+    builder,
+    example::Example,
+    extensions::{self, Extensions},
+    header::Header,
+    path::{Parameter, PathItem},
+    request_body::RequestBody,
+    security::SecurityScheme,
+    set_value, version_3_1,
+    xml::Xml,
+    Deprecated, Response,
+};
+use crate::{IntoParams, ToResponse};
 
 macro_rules! component_from_builder {
     ( $name:ident ) => {
@@ -59,11 +70,54 @@ builder! {
         #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
         pub responses: BTreeMap<String, RefOr<Response>>,
 
+        /// Map of reusable [OpenAPI Parameter Object][parameter]s identified by their name.
+        ///
+        /// [parameter]: https://spec.openapis.org/oas/latest.html#parameter-object
+        #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
+        pub parameters: BTreeMap<String, RefOr<Parameter>>,
+
+        /// Map of reusable [OpenAPI Example Object][example]s.
+        ///
+        /// [example]: https://spec.openapis.org/oas/latest.html#example-object
+        #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
+        pub examples: BTreeMap<String, RefOr<Example>>,
+
+        /// Map of reusable [OpenAPI Request Body Object][request_body]s.
+        ///
+        /// [request_body]: https://spec.openapis.org/oas/latest.html#request-body-object
+        #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
+        pub request_bodies: BTreeMap<String, RefOr<RequestBody>>,
+
+        /// Map of reusable [OpenAPI Header Object][header]s.
+        ///
+        /// [header]: https://spec.openapis.org/oas/latest.html#header-object
+        #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
+        pub headers: BTreeMap<String, RefOr<Header>>,
+
+        /// Map of reusable [OpenAPI Path Item Object][path_item]s which can be referenced from
+        /// paths and webhooks with [`Ref::from_path_item_name`]. Path items of components are
+        /// defined by OpenAPI 3.1 and serializing [`OpenApi`][openapi] of another version having
+        /// them fails.
+        ///
+        /// [openapi]: ../struct.OpenApi.html
+        ///
+        /// [path_item]: https://spec.openapis.org/oas/v3.1.0#path-item-object
+        #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
+        pub path_items: BTreeMap<String, PathItem>,
+
         /// Map of reusable [OpenAPI Security Scheme Object][security_scheme]s.
         ///
         /// [security_scheme]: https://spec.openapis.org/oas/latest.html#security-scheme-object
         #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
         pub security_schemes: BTreeMap<String, SecurityScheme>,
+
+        /// Optional extensions _`x-something`_ of the [`Components`].
+        #[serde(
+            skip_serializing_if = "Option::is_none",
+            flatten,
+            deserialize_with = "extensions::deserialize_optional"
+        )]
+        pub extensions: Option<Extensions>,
     }
 }
 
@@ -187,6 +241,70 @@ impl ComponentsBuilder {
         self
     }
 
+    /// Add [`Parameter`] to [`Components`]. Parameter can be later referenced with
+    /// [`Ref::from_parameter_name`].
+    pub fn parameter<S: Into<String>, P: Into<RefOr<Parameter>>>(
+        mut self,
+        name: S,
+        parameter: P,
+    ) -> Self {
+        self.parameters.insert(name.into(), parameter.into());
+
+        self
+    }
+
+    /// Add all [`Parameter`]s of [`IntoParams`] type to [`Components`] by the name of each
+    /// parameter. Parameters use the _`parameter_in`_ defined for the type.
+    pub fn parameters_from_into_params<I: IntoParams>(mut self) -> Self {
+        self.parameters.extend(
+            I::into_params(|| None)
+                .into_iter()
+                .map(|parameter| (parameter.name.clone(), parameter.into())),
+        );
+
+        self
+    }
+
+    /// Add [`Example`] to [`Components`]. Example can be later referenced with
+    /// [`Ref::from_example_name`].
+    pub fn example<S: Into<String>, E: Into<RefOr<Example>>>(
+        mut self,
+        name: S,
+        example: E,
+    ) -> Self {
+        self.examples.insert(name.into(), example.into());
+
+        self
+    }
+
+    /// Add [`RequestBody`] to [`Components`]. Request body can be later referenced with
+    /// [`Ref::from_request_body_name`].
+    pub fn request_body<S: Into<String>, R: Into<RefOr<RequestBody>>>(
+        mut self,
+        name: S,
+        request_body: R,
+    ) -> Self {
+        self.request_bodies.insert(name.into(), request_body.into());
+
+        self
+    }
+
+    /// Add [`Header`] to [`Components`]. Header can be later referenced with
+    /// [`Ref::from_header_name`].
+    pub fn header<S: Into<String>, H: Into<RefOr<Header>>>(mut self, name: S, header: H) -> Self {
+        self.headers.insert(name.into(), header.into());
+
+        self
+    }
+
+    /// Add reusable [`PathItem`] to [`Components`]. Path item can be later referenced with
+    /// [`Ref::from_path_item_name`]. Reusable path items are only supported by OpenAPI 3.1.
+    pub fn path_item<S: Into<String>>(mut self, name: S, path_item: PathItem) -> Self {
+        self.path_items.insert(name.into(), path_item);
+
+        self
+    }
+
     /// Add [`SecurityScheme`] to [`Components`].
     ///
     /// Accepts two arguments where first is the name of the [`SecurityScheme`]. This is later when
@@ -203,6 +321,11 @@ impl ComponentsBuilder {
 
         self
     }
+
+    /// Add openapi extensions (`x-something`) of the [`Components`].
+    pub fn extensions(mut self, extensions: Option<Extensions>) -> Self {
+        set_value!(self extensions extensions)
+    }
 }
 
 /// Is super type for [OpenAPI Schema Object][schemas]. Schema is reusable resource what can be
@@ -210,9 +333,8 @@ impl ComponentsBuilder {
 ///
 /// [schemas]: https://spec.openapis.org/oas/latest.html#schema-object
 #[non_exhaustive]
-#[derive(Serialize, Deserialize, Clone, PartialEq)]
+#[derive(Clone, PartialEq)]
 #[cfg_attr(feature = "debug", derive(Debug))]
-#[serde(untagged, rename_all = "camelCase")]
 pub enum Schema {
     /// Defines array schema from another schema. Typically used with
     /// [`Schema::Object`]. Slice and Vec types are translated to [`Schema::Array`] types.
@@ -239,6 +361,88 @@ impl Default for Schema {
     }
 }
 
+impl Serialize for Schema {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        if version_3_1::is_version_3_1() {
+            return version_3_1::serialize_schema(self, serializer);
+        }
+
+        match self {
+            Self::Array(array) => array.serialize(serializer),
+            Self::Object(object) => object.serialize(serializer),
+            Self::OneOf(one_of) => one_of.serialize(serializer),
+            Self::AllOf(all_of) => all_of.serialize(serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Schema {
+    /// Resolve the variant by the composite keyword or the _`type`_ of the schema. Schemas
+    /// without _`type`_ are deserialized as [`Schema::Object`] with [`SchemaType::Value`].
+    ///
+    /// Composite schemas are rejected if they have keywords which [`OneOf`] and [`AllOf`] cannot
+    /// hold e.g. _`type`_ or _`properties`_ since the keywords would be lost.
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let mut value = Value::deserialize(deserializer)?;
+        if version_3_1::is_version_3_1() {
+            version_3_1::revert_schema(&mut value);
+        }
+        let composite = match (value.get("oneOf"), value.get("allOf")) {
+            (Some(_), Some(_)) => {
+                return Err(serde::de::Error::custom(
+                    "schema cannot have both oneOf and allOf",
+                ))
+            }
+            (Some(_), None) => Some("oneOf"),
+            (None, Some(_)) => Some("allOf"),
+            (None, None) => None,
+        };
+        if let (Some(composite), Some(object)) = (composite, value.as_object()) {
+            let unsupported = object
+                .keys()
+                .filter(|key| {
+                    *key != composite
+                        && !COMPOSITE_KEYWORDS.contains(&key.as_str())
+                        && !key.starts_with("x-")
+                })
+                .map(String::as_str)
+                .collect::<Vec<_>>();
+            if !unsupported.is_empty() {
+                return Err(serde::de::Error::custom(format!(
+                    "unsupported keywords with {composite}: {}",
+                    unsupported.join(", ")
+                )));
+            }
+        }
+
+        let schema = match composite {
+            Some("oneOf") => OneOf::deserialize(value).map(Schema::OneOf),
+            Some(_) => AllOf::deserialize(value).map(Schema::AllOf),
+            None if value.get("type").and_then(Value::as_str) == Some("array") => {
+                Array::deserialize(value).map(Schema::Array)
+            }
+            None => Object::deserialize(value).map(Schema::Object),
+        };
+
+        schema.map_err(serde::de::Error::custom)
+    }
+}
+
+/// Keywords of [`OneOf`] and [`AllOf`] besides the composite keyword itself and extensions.
+const COMPOSITE_KEYWORDS: [&str; 5] = [
+    "description",
+    "default",
+    "example",
+    "discriminator",
+    "nullable",
+];
+
 /// OpenAPI [Discriminator][discriminator] object which can be optionally used together with
 /// [`OneOf`] composite object.
 ///
@@ -306,6 +510,14 @@ builder! {
         /// Set `true` to allow `"null"` to be used as value for given type.
         #[serde(default, skip_serializing_if = "is_false")]
         pub nullable: bool,
+
+        /// Optional extensions _`x-something`_ of the [`OneOf`].
+        #[serde(
+            skip_serializing_if = "Option::is_none",
+            flatten,
+            deserialize_with = "extensions::deserialize_optional"
+        )]
+        pub extensions: Option<Extensions>,
     }
 }
 
@@ -373,6 +585,11 @@ impl OneOfBuilder {
     }
 
     to_array_builder!();
+
+    /// Add openapi extensions (`x-something`) of the [`OneOf`].
+    pub fn extensions(mut self, extensions: Option<Extensions>) -> Self {
+        set_value!(self extensions extensions)
+    }
 }
 
 impl From<OneOf> for Schema {
@@ -425,6 +642,14 @@ builder! {
         /// Set `true` to allow `"null"` to be used as value for given type.
         #[serde(default, skip_serializing_if = "is_false")]
         pub nullable: bool,
+
+        /// Optional extensions _`x-something`_ of the [`AllOf`].
+        #[serde(
+            skip_serializing_if = "Option::is_none",
+            flatten,
+            deserialize_with = "extensions::deserialize_optional"
+        )]
+        pub extensions: Option<Extensions>,
     }
 }
 
@@ -492,6 +717,11 @@ impl AllOfBuilder {
     }
 
     to_array_builder!();
+
+    /// Add openapi extensions (`x-something`) of the [`AllOf`].
+    pub fn extensions(mut self, extensions: Option<Extensions>) -> Self {
+        set_value!(self extensions extensions)
+    }
 }
 
 impl From<AllOf> for Schema {
@@ -528,8 +758,12 @@ builder! {
     #[serde(rename_all = "camelCase")]
     pub struct Object {
         /// Type of [`Object`] e.g. [`SchemaType::Object`] for `object` and [`SchemaType::String`] for
-        /// `string` types.
-        #[serde(rename = "type")]
+        /// `string` types. [`SchemaType::Value`] is not serialized.
+        #[serde(
+            rename = "type",
+            default = "SchemaType::value",
+            skip_serializing_if = "SchemaType::is_value"
+        )]
         pub schema_type: SchemaType,
 
         /// Changes the [`Object`] title.
@@ -561,7 +795,15 @@ builder! {
         pub properties: ObjectPropertiesMap<String, RefOr<Schema>>,
 
         /// Additional [`Schema`] for non specified fields (Useful for typed maps).
-        #[serde(skip_serializing_if = "Option::is_none")]
+        ///
+        /// Deserializing _`true`_ results schema of [`SchemaType::Value`] which allows any value
+        /// same way as _`true`_. Deserializing _`false`_ is an error since it cannot be
+        /// represented.
+        #[serde(
+            skip_serializing_if = "Option::is_none",
+            deserialize_with = "deserialize_additional_properties",
+            default
+        )]
         pub additional_properties: Option<Box<RefOr<Schema>>>,
 
         /// Changes the [`Object`] deprecated status.
@@ -637,6 +879,14 @@ builder! {
         /// `0` will have same effect as omitting the attribute.
         #[serde(skip_serializing_if = "Option::is_none")]
         pub min_properties: Option<usize>,
+
+        /// Optional extensions _`x-something`_ of the [`Object`].
+        #[serde(
+            skip_serializing_if = "Option::is_none",
+            flatten,
+            deserialize_with = "extensions::deserialize_optional"
+        )]
+        pub extensions: Option<Extensions>,
     }
 }
 
@@ -644,6 +894,30 @@ fn is_false(value: &bool) -> bool {
     !*value
 }
 
+fn deserialize_additional_properties<'de, D>(
+    deserializer: D,
+) -> Result<Option<Box<RefOr<Schema>>>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum AdditionalProperties {
+        Bool(bool),
+        Schema(Box<RefOr<Schema>>),
+    }
+
+    match AdditionalProperties::deserialize(deserializer)? {
+        AdditionalProperties::Bool(true) => {
+            Ok(Some(Box::new(Object::with_type(SchemaType::Value).into())))
+        }
+        AdditionalProperties::Bool(false) => Err(serde::de::Error::custom(
+            "additionalProperties false is not supported",
+        )),
+        AdditionalProperties::Schema(schema) => Ok(Some(schema)),
+    }
+}
+
 impl Object {
     /// Initialize a new [`Object`] with default [`SchemaType`]. This effectifly same as calling
     /// `Object::with_type(SchemaType::Object)`.
@@ -820,6 +1094,11 @@ impl ObjectBuilder {
     }
 
     to_array_builder!();
+
+    /// Add openapi extensions (`x-something`) of the [`Object`].
+    pub fn extensions(mut self, extensions: Option<Extensions>) -> Self {
+        set_value!(self extensions extensions)
+    }
 }
 
 component_from_builder!(ObjectBuilder);
@@ -864,6 +1143,39 @@ impl Ref {
         Self::new(format!("#/components/responses/{}", response_name.into()))
     }
 
+    /// Construct a new [`Ref`] from provided parameter name. This will create a [`Ref`] that
+    /// references the reusable parameter.
+    pub fn from_parameter_name<I: Into<String>>(parameter_name: I) -> Self {
+        Self::new(format!("#/components/parameters/{}", parameter_name.into()))
+    }
+
+    /// Construct a new [`Ref`] from provided example name. This will create a [`Ref`] that
+    /// references the reusable example.
+    pub fn from_example_name<I: Into<String>>(example_name: I) -> Self {
+        Self::new(format!("#/components/examples/{}", example_name.into()))
+    }
+
+    /// Construct a new [`Ref`] from provided request body name. This will create a [`Ref`] that
+    /// references the reusable request body.
+    pub fn from_request_body_name<I: Into<String>>(request_body_name: I) -> Self {
+        Self::new(format!(
+            "#/components/requestBodies/{}",
+            request_body_name.into()
+        ))
+    }
+
+    /// Construct a new [`Ref`] from provided path item name. This will create a [`Ref`] that
+    /// references the reusable path item.
+    pub fn from_path_item_name<I: Into<String>>(path_item_name: I) -> Self {
+        Self::new(format!("#/components/pathItems/{}", path_item_name.into()))
+    }
+
+    /// Construct a new [`Ref`] from provided header name. This will create a [`Ref`] that
+    /// references the reusable header.
+    pub fn from_header_name<I: Into<String>>(header_name: I) -> Self {
+        Self::new(format!("#/components/headers/{}", header_name.into()))
+    }
+
     to_array_builder!();
 }
 
@@ -937,6 +1249,14 @@ builder! {
         /// Xml format of the array.
         #[serde(skip_serializing_if = "Option::is_none")]
         pub xml: Option<Xml>,
+
+        /// Optional extensions _`x-something`_ of the [`Array`].
+        #[serde(
+            skip_serializing_if = "Option::is_none",
+            flatten,
+            deserialize_with = "extensions::deserialize_optional"
+        )]
+        pub extensions: Option<Extensions>,
     }
 }
 
@@ -950,6 +1270,7 @@ impl Default for Array {
             max_items: Default::default(),
             min_items: Default::default(),
             xml: Default::default(),
+            extensions: Default::default(),
         }
     }
 }
@@ -1004,6 +1325,11 @@ impl ArrayBuilder {
     }
 
     to_array_builder!();
+
+    /// Add openapi extensions (`x-something`) of the [`Array`].
+    pub fn extensions(mut self, extensions: Option<Extensions>) -> Self {
+        set_value!(self extensions extensions)
+    }
 }
 
 component_from_builder!(ArrayBuilder);
@@ -1054,6 +1380,9 @@ pub enum SchemaType {
     Boolean,
     /// Used with [`Array`] and [`ArrayBuilder`]. Indicates array type of content.
     Array,
+    /// Free form type of [`Object`] which allows any value. [`Object`] of this type is serialized
+    /// without _`type`_ e.g. _`{}`_ and it is the type of deserialized schemas without _`type`_.
+    Value,
 }
 
 impl Default for SchemaType {
@@ -1062,6 +1391,16 @@ impl Default for SchemaType {
     }
 }
 
+impl SchemaType {
+    fn value() -> Self {
+        Self::Value
+    }
+
+    fn is_value(&self) -> bool {
+        *self == Self::Value
+    }
+}
+
 /// Additional format for [`SchemaType`] to fine tune the data type used. If the **format** is not
 /// supported by the UI it may default back to [`SchemaType`] alone.
 /// Format is an open value, so you can use any formats, even not those defined by the
@@ -1595,4 +1934,74 @@ mod tests {
 
         assert_eq!(json_str, json_de_str);
     }
+
+    #[test]
+    fn deserialize_schema_variants() {
+        let json = json!({
+            "any": {},
+            "map": {
+                "type": "object",
+                "additionalProperties": true
+            },
+            "pet": {
+                "oneOf": [{ "type": "string" }],
+                "description": "Pet"
+            },
+            "pets": {
+                "type": "array",
+                "items": { "$ref": "#/components/schemas/Pet" }
+            }
+        });
+        let schemas: BTreeMap<String, Schema> =
+            serde_json::from_value(json.clone()).expect("schemas must deserialize");
+
+        assert!(matches!(
+            &schemas["any"],
+            Schema::Object(object) if object.schema_type == SchemaType::Value
+        ));
+        assert!(matches!(&schemas["pet"], Schema::OneOf(_)));
+        assert!(matches!(&schemas["pets"], Schema::Array(_)));
+        assert_json_eq!(
+            serde_json::to_value(&schemas).unwrap(),
+            json!({
+                "any": {},
+                "map": {
+                    "type": "object",
+                    "additionalProperties": {}
+                },
+                "pet": {
+                    "oneOf": [{ "type": "string" }],
+                    "description": "Pet"
+                },
+                "pets": {
+                    "type": "array",
+                    "items": { "$ref": "#/components/schemas/Pet" }
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn deserialize_lossy_schema_fails() {
+        for (schema, error) in [
+            (
+                json!({ "type": "object", "oneOf": [{ "type": "string" }] }),
+                "unsupported keywords with oneOf: type",
+            ),
+            (
+                json!({ "allOf": [], "oneOf": [] }),
+                "schema cannot have both oneOf and allOf",
+            ),
+            (
+                json!({ "type": "object", "additionalProperties": false }),
+                "additionalProperties false is not supported",
+            ),
+        ] {
+            let result = serde_json::from_value::<Schema>(schema);
+            assert_eq!(
+                result.err().map(|error| error.to_string()),
+                Some(error.to_string())
+            );
+        }
+    }
 }
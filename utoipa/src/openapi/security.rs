@@ -7,7 +7,10 @@ use std::{collections::BTreeMap, iter};
 
 use serde::{Deserialize, Serialize};
 
-use super::builder;
+use super::{
+    builder,
+    extensions::{self, Extensions},
+};
 
 /// OpenAPI [security requirment][security] object.
 ///
@@ -20,6 +23,9 @@ use super::builder;
 /// make the security requirements available for that operation. Only one of the requirements must be
 /// satisfied.
 ///
+/// Single [`SecurityRequirement`] can also hold multiple [`SecurityScheme`]s which all must be satisfied
+/// for the requirement to be met. See [`SecurityRequirement::add`].
+///
 /// [security]: https://spec.openapis.org/oas/latest.html#security-requirement-object
 /// [path]: ../../attr.path.html
 /// [openapi]: ../../derive.OpenApi.html
@@ -66,6 +72,31 @@ impl SecurityRequirement {
             })),
         }
     }
+
+    /// Add another [`SecurityScheme`] name with scopes to this [`SecurityRequirement`].
+    ///
+    /// All schemes of a single [`SecurityRequirement`] are required at the same time while the
+    /// [`SecurityRequirement`]s of an operation are alternatives to each other.
+    ///
+    /// # Examples
+    ///
+    /// Require both oauth2 with read scope and an api key.
+    /// ```rust
+    /// # use utoipa::openapi::security::SecurityRequirement;
+    /// SecurityRequirement::new("oauth", ["read"]).add("api_key", [] as [&str; 0]);
+    /// ```
+    pub fn add<N: Into<String>, S: IntoIterator<Item = I>, I: Into<String>>(
+        mut self,
+        name: N,
+        scopes: S,
+    ) -> Self {
+        self.value.insert(
+            name.into(),
+            scopes.into_iter().map(Into::<String>::into).collect(),
+        );
+
+        self
+    }
 }
 
 /// OpenAPI [security scheme][security] for path operations.
@@ -144,6 +175,14 @@ pub struct ApiKeyValue {
     /// Description of the the [`ApiKey`] [`SecurityScheme`]. Supports markdown syntax.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+
+    /// Optional extensions _`x-something`_ of the [`ApiKeyValue`].
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        flatten,
+        deserialize_with = "extensions::deserialize_optional"
+    )]
+    pub extensions: Option<Extensions>,
 }
 
 impl ApiKeyValue {
@@ -160,6 +199,7 @@ impl ApiKeyValue {
         Self {
             name: name.into(),
             description: None,
+            extensions: None,
         }
     }
 
@@ -176,6 +216,7 @@ impl ApiKeyValue {
         Self {
             name: name.into(),
             description: Some(description.into()),
+            extensions: None,
         }
     }
 }
@@ -201,6 +242,14 @@ builder! {
         /// Optional description of [`Http`] [`SecurityScheme`] supporting markdown syntax.
         #[serde(skip_serializing_if = "Option::is_none")]
         pub description: Option<String>,
+
+        /// Optional extensions _`x-something`_ of the [`Http`].
+        #[serde(
+            skip_serializing_if = "Option::is_none",
+            flatten,
+            deserialize_with = "extensions::deserialize_optional"
+        )]
+        pub extensions: Option<Extensions>,
     }
 }
 
@@ -221,6 +270,7 @@ impl Http {
             scheme,
             bearer_format: None,
             description: None,
+            extensions: None,
         }
     }
 }
@@ -265,6 +315,13 @@ impl HttpBuilder {
 
         self
     }
+
+    /// Add openapi extensions (`x-something`) of the [`Http`].
+    pub fn extensions(mut self, extensions: Option<Extensions>) -> Self {
+        self.extensions = extensions;
+
+        self
+    }
 }
 
 /// Implements types according [RFC7235](https://datatracker.ietf.org/doc/html/rfc7235#section-5.1).
@@ -306,6 +363,14 @@ pub struct OpenIdConnect {
     /// Description of [`OpenIdConnect`] [`SecurityScheme`] supporting markdown syntax.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+
+    /// Optional extensions _`x-something`_ of the [`OpenIdConnect`].
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        flatten,
+        deserialize_with = "extensions::deserialize_optional"
+    )]
+    pub extensions: Option<Extensions>,
 }
 
 impl OpenIdConnect {
@@ -321,6 +386,7 @@ impl OpenIdConnect {
         Self {
             open_id_connect_url: open_id_connect_url.into(),
             description: None,
+            extensions: None,
         }
     }
 
@@ -337,6 +403,7 @@ impl OpenIdConnect {
         Self {
             open_id_connect_url: open_id_connect_url.into(),
             description: Some(description.into()),
+            extensions: None,
         }
     }
 }
@@ -352,6 +419,14 @@ pub struct OAuth2 {
     /// Optional description for the [`OAuth2`] [`Flow`] [`SecurityScheme`].
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+
+    /// Optional extensions _`x-something`_ of the [`OAuth2`].
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        flatten,
+        deserialize_with = "extensions::deserialize_optional"
+    )]
+    pub extensions: Option<Extensions>,
 }
 
 impl OAuth2 {
@@ -392,6 +467,7 @@ impl OAuth2 {
                     .map(|auth_flow| (String::from(auth_flow.get_type_as_str()), auth_flow)),
             ),
             description: None,
+            extensions: None,
         }
     }
 
@@ -434,6 +510,7 @@ impl OAuth2 {
                     .map(|auth_flow| (String::from(auth_flow.get_type_as_str()), auth_flow)),
             ),
             description: Some(description.into()),
+            extensions: None,
         }
     }
 }
@@ -1189,6 +1266,15 @@ mod tests {
 }"###
     }
 
+    test_fn! {
+        security_requirement_with_multiple_schemes:
+        SecurityRequirement::new("oauth", ["read"]).add("api_key", [] as [&str; 0]);
+        r###"{
+  "oauth": ["read"],
+  "api_key": []
+}"###
+    }
+
     test_fn! {
         security_schema_correct_mutual_tls:
         SecurityScheme::MutualTls {
@@ -3,7 +3,12 @@
 //! [tag]: https://spec.openapis.org/oas/latest.html#tag-object
 use serde::{Deserialize, Serialize};
 
-use super::{builder, external_docs::ExternalDocs, set_value};
+use super::{
+    builder,
+    extensions::{self, Extensions},
+    external_docs::ExternalDocs,
+    set_value,
+};
 
 builder! {
     TagBuilder;
@@ -28,6 +33,14 @@ builder! {
         /// Additional external documentation for the tag.
         #[serde(skip_serializing_if = "Option::is_none")]
         pub external_docs: Option<ExternalDocs>,
+
+        /// Optional extensions _`x-something`_ of the [`Tag`].
+        #[serde(
+            skip_serializing_if = "Option::is_none",
+            flatten,
+            deserialize_with = "extensions::deserialize_optional"
+        )]
+        pub extensions: Option<Extensions>,
     }
 }
 
@@ -56,4 +69,9 @@ impl TagBuilder {
     pub fn external_docs(mut self, external_docs: Option<ExternalDocs>) -> Self {
         set_value!(self external_docs external_docs)
     }
+
+    /// Add openapi extensions (`x-something`) of the [`Tag`].
+    pub fn extensions(mut self, extensions: Option<Extensions>) -> Self {
+        set_value!(self extensions extensions)
+    }
 }
@@ -0,0 +1,469 @@
+//! Implements validation of [`OpenApi`] document with [`OpenApi::validate`].
+//!
+//! Validation finds issues which are not prevented by the types of the document e.g. references
+//! to components which do not exist.
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::{self, Display};
+
+use serde_json::Value;
+
+use super::OpenApi;
+
+const PATH_ITEM_OPERATIONS: [&str; 8] = [
+    "get", "put", "post", "delete", "options", "head", "patch", "trace",
+];
+
+/// Issue found by [`OpenApi::validate`].
+#[non_exhaustive]
+#[derive(Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct ValidationIssue {
+    /// Location of the issue in the document e.g. _`"paths./pets.get.responses.200"`_.
+    pub location: String,
+
+    /// Kind of the issue.
+    pub kind: ValidationIssueKind,
+}
+
+/// Kinds of [`ValidationIssue`]s.
+#[non_exhaustive]
+#[derive(Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub enum ValidationIssueKind {
+    /// Reference points to a component which is not defined in the document.
+    DanglingRef(String),
+    /// Operation id is used by more than one operation.
+    DuplicateOperationId(String),
+    /// Parameter with same name and location is defined more than once for the same path item
+    /// or operation e.g. when multiple `IntoParams` types define the same field.
+    DuplicateParameter {
+        /// Name of the parameter.
+        name: String,
+        /// Location of the parameter e.g. _`"query"`_.
+        parameter_in: String,
+    },
+    /// Response has empty description.
+    EmptyResponseDescription,
+    /// Path parameter with given name is not present in the path template.
+    PathParameterNotInTemplate(String),
+    /// Path template contains a variable with given name which is not declared as path parameter.
+    UndeclaredPathParameter(String),
+    /// Component is never referenced in the document. Security schemes are considered used when
+    /// referenced by a security requirement.
+    UnusedComponent {
+        /// Kind of the component e.g. _`"schemas"`_.
+        kind: String,
+        /// Name of the component.
+        name: String,
+    },
+}
+
+impl Display for ValidationIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: ", self.location)?;
+        match &self.kind {
+            ValidationIssueKind::DanglingRef(reference) => {
+                write!(f, "reference {reference} does not exist")
+            }
+            ValidationIssueKind::DuplicateOperationId(operation_id) => {
+                write!(f, "operation id {operation_id} is not unique")
+            }
+            ValidationIssueKind::DuplicateParameter { name, parameter_in } => {
+                write!(
+                    f,
+                    "parameter {name} in {parameter_in} is defined more than once"
+                )
+            }
+            ValidationIssueKind::EmptyResponseDescription => {
+                write!(f, "response description is empty")
+            }
+            ValidationIssueKind::PathParameterNotInTemplate(name) => {
+                write!(f, "path parameter {name} is not present in the path")
+            }
+            ValidationIssueKind::UndeclaredPathParameter(name) => {
+                write!(f, "path variable {name} is not declared as path parameter")
+            }
+            ValidationIssueKind::UnusedComponent { kind, name } => {
+                write!(f, "component {name} of {kind} is not used")
+            }
+        }
+    }
+}
+
+pub(super) fn validate(openapi: &OpenApi) -> Vec<ValidationIssue> {
+    let document = OpenApi::serialize(openapi, serde_json::value::Serializer).unwrap_or_default();
+    let mut validator = Validator::default();
+
+    let components = document
+        .get("components")
+        .and_then(Value::as_object)
+        .cloned()
+        .unwrap_or_default();
+    for (kind, entries) in &components {
+        for name in entries
+            .as_object()
+            .into_iter()
+            .flat_map(|entries| entries.keys())
+        {
+            validator
+                .components
+                .insert(format!("#/components/{kind}/{name}"));
+        }
+    }
+
+    validator.visit_refs(&document, "");
+    validator.visit_operations(&document, &components);
+    validator.check_unused_components(&components);
+
+    validator.issues
+}
+
+#[derive(Default)]
+struct Validator {
+    /// References of all components defined in the document.
+    components: BTreeSet<String>,
+    /// References used in the document.
+    references: BTreeSet<String>,
+    /// Names of security schemes used by security requirements.
+    security_schemes: BTreeSet<String>,
+    issues: Vec<ValidationIssue>,
+}
+
+impl Validator {
+    fn issue(&mut self, location: &str, kind: ValidationIssueKind) {
+        self.issues.push(ValidationIssue {
+            location: location.to_string(),
+            kind,
+        })
+    }
+
+    fn visit_refs(&mut self, value: &Value, location: &str) {
+        match value {
+            Value::Object(object) => {
+                for (key, value) in object {
+                    let field_location = join(location, key);
+                    match (key.as_str(), value) {
+                        ("$ref", Value::String(reference)) => {
+                            if reference.starts_with('#') && !self.components.contains(reference) {
+                                self.issue(
+                                    location,
+                                    ValidationIssueKind::DanglingRef(reference.clone()),
+                                );
+                            }
+                            self.references.insert(reference.clone());
+                        }
+                        ("security", Value::Array(requirements)) => {
+                            for requirement in requirements.iter().filter_map(Value::as_object) {
+                                self.security_schemes.extend(requirement.keys().cloned());
+                            }
+                        }
+                        _ => self.visit_refs(value, &field_location),
+                    }
+                }
+            }
+            Value::Array(values) => {
+                for (index, value) in values.iter().enumerate() {
+                    self.visit_refs(value, &format!("{location}[{index}]"));
+                }
+            }
+            _ => (),
+        }
+    }
+
+    fn visit_operations(&mut self, document: &Value, components: &serde_json::Map<String, Value>) {
+        let mut operation_ids = BTreeMap::<&str, usize>::new();
+
+        let path_items = ["paths", "webhooks"].into_iter().flat_map(|field| {
+            document
+                .get(field)
+                .and_then(Value::as_object)
+                .into_iter()
+                .flatten()
+                .map(move |(path, path_item)| (field, path, path_item))
+        });
+        for (field, path, path_item) in path_items {
+            let location = join(field, path);
+            let template_variables = if field == "paths" {
+                Some(path_template_variables(path))
+            } else {
+                None
+            };
+            let path_parameters = path_parameters(path_item, components);
+            self.check_duplicate_parameters(path_item, components, &location);
+
+            for method in PATH_ITEM_OPERATIONS {
+                let operation = match path_item.get(method) {
+                    Some(operation) => operation,
+                    None => continue,
+                };
+                let operation_location = join(&location, method);
+                self.check_duplicate_parameters(operation, components, &operation_location);
+
+                if let Some(operation_id) = operation.get("operationId").and_then(Value::as_str) {
+                    let count = operation_ids.entry(operation_id).or_default();
+                    *count += 1;
+                    if *count == 2 {
+                        self.issue(
+                            &operation_location,
+                            ValidationIssueKind::DuplicateOperationId(operation_id.to_string()),
+                        );
+                    }
+                }
+
+                if let Some(responses) = operation.get("responses").and_then(Value::as_object) {
+                    for (status, response) in responses {
+                        self.check_response(
+                            response,
+                            &join(&join(&operation_location, "responses"), status),
+                        );
+                    }
+                }
+
+                if let Some(template_variables) = &template_variables {
+                    let mut parameters = path_parameters.clone();
+                    parameters.extend(self::path_parameters(operation, components));
+
+                    for name in &parameters {
+                        if !template_variables.contains(name) {
+                            self.issue(
+                                &operation_location,
+                                ValidationIssueKind::PathParameterNotInTemplate(name.clone()),
+                            );
+                        }
+                    }
+                    for name in template_variables {
+                        if !parameters.contains(name) {
+                            self.issue(
+                                &operation_location,
+                                ValidationIssueKind::UndeclaredPathParameter(name.clone()),
+                            );
+                        }
+                    }
+                }
+            }
+        }
+
+        if let Some(responses) = components.get("responses").and_then(Value::as_object) {
+            for (name, response) in responses {
+                self.check_response(response, &join("components.responses", name));
+            }
+        }
+    }
+
+    fn check_duplicate_parameters(
+        &mut self,
+        value: &Value,
+        components: &serde_json::Map<String, Value>,
+        location: &str,
+    ) {
+        let mut seen = BTreeSet::<(&str, &str)>::new();
+        for parameter in parameters(value, components) {
+            let name = parameter.get("name").and_then(Value::as_str);
+            let parameter_in = parameter.get("in").and_then(Value::as_str);
+            if let (Some(name), Some(parameter_in)) = (name, parameter_in) {
+                if !seen.insert((name, parameter_in)) {
+                    self.issue(
+                        location,
+                        ValidationIssueKind::DuplicateParameter {
+                            name: name.to_string(),
+                            parameter_in: parameter_in.to_string(),
+                        },
+                    );
+                }
+            }
+        }
+    }
+
+    fn check_response(&mut self, response: &Value, location: &str) {
+        let has_description = response
+            .get("description")
+            .and_then(Value::as_str)
+            .is_some_and(|description| !description.trim().is_empty());
+        if response.get("$ref").is_none() && !has_description {
+            self.issue(location, ValidationIssueKind::EmptyResponseDescription);
+        }
+    }
+
+    fn check_unused_components(&mut self, components: &serde_json::Map<String, Value>) {
+        for (kind, entries) in components {
+            for name in entries
+                .as_object()
+                .into_iter()
+                .flat_map(|entries| entries.keys())
+            {
+                let is_used = if kind == "securitySchemes" {
+                    self.security_schemes.contains(name)
+                } else {
+                    self.references
+                        .contains(&format!("#/components/{kind}/{name}"))
+                };
+                if !is_used {
+                    self.issue(
+                        &join(&join("components", kind), name),
+                        ValidationIssueKind::UnusedComponent {
+                            kind: kind.clone(),
+                            name: name.clone(),
+                        },
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Get parameters of path item or operation. Parameters referencing components are resolved from
+/// the `components`.
+fn parameters<'a>(
+    value: &'a Value,
+    components: &'a serde_json::Map<String, Value>,
+) -> impl Iterator<Item = &'a Value> {
+    value
+        .get("parameters")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(
+            |parameter| match parameter.get("$ref").and_then(Value::as_str) {
+                Some(reference) => reference
+                    .strip_prefix("#/components/parameters/")
+                    .and_then(|name| components.get("parameters")?.get(name)),
+                None => Some(parameter),
+            },
+        )
+}
+
+/// Get names of path parameters of path item or operation.
+fn path_parameters(value: &Value, components: &serde_json::Map<String, Value>) -> Vec<String> {
+    parameters(value, components)
+        .filter(|parameter| parameter.get("in").and_then(Value::as_str) == Some("path"))
+        .filter_map(|parameter| parameter.get("name").and_then(Value::as_str))
+        .map(ToString::to_string)
+        .collect()
+}
+
+/// Get names of variables in path template e.g. _`id`_ of _`"/pets/{id}"`_.
+fn path_template_variables(path: &str) -> Vec<String> {
+    path.split('{')
+        .skip(1)
+        .filter_map(|part| part.split_once('}'))
+        .map(|(name, _)| name.to_string())
+        .collect()
+}
+
+fn join(location: &str, field: &str) -> String {
+    if location.is_empty() {
+        field.to_string()
+    } else {
+        format!("{location}.{field}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn validate_openapi_document() {
+        let openapi: OpenApi = serde_json::from_value(json!({
+            "openapi": "3.0.3",
+            "info": { "title": "Pet api", "version": "1.0.0" },
+            "paths": {
+                "/pets/{id}": {
+                    "parameters": [
+                        { "name": "id", "in": "path", "required": true, "schema": { "type": "integer" } }
+                    ],
+                    "get": {
+                        "operationId": "getPet",
+                        "responses": {
+                            "200": {
+                                "description": "Pet",
+                                "content": {
+                                    "application/json": {
+                                        "schema": { "$ref": "#/components/schemas/Pet" }
+                                    }
+                                }
+                            }
+                        },
+                        "security": [{ "api_key": [] }]
+                    },
+                    "delete": {
+                        "operationId": "getPet",
+                        "parameters": [
+                            { "name": "name", "in": "path", "required": true, "schema": { "type": "string" } }
+                        ],
+                        "responses": { "204": { "description": " " } }
+                    }
+                },
+                "/owners/{owner_id}": {
+                    "get": {
+                        "responses": { "200": { "$ref": "#/components/responses/Owner" } }
+                    }
+                }
+            },
+            "components": {
+                "schemas": {
+                    "Pet": { "type": "object" },
+                    "Unused": { "type": "string" }
+                },
+                "securitySchemes": {
+                    "api_key": { "type": "apiKey", "in": "header", "name": "x-api-key" },
+                    "basic": { "type": "http", "scheme": "basic" }
+                }
+            }
+        }))
+        .expect("OpenApi must deserialize");
+
+        let issues = openapi
+            .validate()
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            issues,
+            [
+                "paths./owners/{owner_id}.get.responses.200: reference #/components/responses/Owner does not exist",
+                "paths./owners/{owner_id}.get: path variable owner_id is not declared as path parameter",
+                "paths./pets/{id}.delete: operation id getPet is not unique",
+                "paths./pets/{id}.delete.responses.204: response description is empty",
+                "paths./pets/{id}.delete: path parameter name is not present in the path",
+                "components.schemas.Unused: component Unused of schemas is not used",
+                "components.securitySchemes.basic: component basic of securitySchemes is not used",
+            ]
+        );
+    }
+
+    #[test]
+    fn validate_duplicate_parameters() {
+        let openapi: OpenApi = serde_json::from_value(json!({
+            "openapi": "3.0.3",
+            "info": { "title": "Pet api", "version": "1.0.0" },
+            "paths": {
+                "/pets": {
+                    "get": {
+                        "parameters": [
+                            { "name": "page", "in": "query", "schema": { "type": "integer" } },
+                            { "name": "page", "in": "header", "schema": { "type": "integer" } },
+                            { "name": "page", "in": "query", "schema": { "type": "string" } }
+                        ],
+                        "responses": { "200": { "description": "Pets" } }
+                    }
+                }
+            }
+        }))
+        .expect("OpenApi must deserialize");
+
+        let issues = openapi
+            .validate()
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            issues,
+            ["paths./pets.get: parameter page in query is defined more than once"]
+        );
+    }
+}
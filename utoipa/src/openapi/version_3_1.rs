@@ -0,0 +1,386 @@
+//! Conversion of [`Schema`]s between the OpenAPI 3.0 format of the model and
+//! [OpenAPI 3.1][openapi_3_1] format.
+//!
+//! Schemas of 3.1 are JSON Schema compatible thus _`nullable`_ is expressed with _`"null"`_ type
+//! and single schema _`example`_ with _`examples`_ array. Schemas are converted while the typed
+//! model is serialized or deserialized within [`with_version_3_1`] thus only actual schemas are
+//! converted and the order of the fields is kept.
+//!
+//! [openapi_3_1]: https://spec.openapis.org/oas/v3.1.0
+use std::cell::Cell;
+
+use serde::{
+    ser::{Error, Impossible, SerializeMap},
+    Serialize, Serializer,
+};
+use serde_json::{json, Value};
+
+use super::schema::{Schema, SchemaType};
+
+thread_local! {
+    static VERSION_3_1: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Restores the previous version of the thread when dropped.
+struct VersionGuard(bool);
+
+impl Drop for VersionGuard {
+    fn drop(&mut self) {
+        VERSION_3_1.with(|version| version.set(self.0));
+    }
+}
+
+/// Run `f` with [`Schema`]s serialized and deserialized in 3.1 format if `enabled`.
+pub(super) fn with_version_3_1<T>(enabled: bool, f: impl FnOnce() -> T) -> T {
+    let _guard = VersionGuard(VERSION_3_1.with(|version| version.replace(enabled)));
+
+    f()
+}
+
+/// Check whether [`Schema`]s are serialized and deserialized in 3.1 format.
+pub(super) fn is_version_3_1() -> bool {
+    VERSION_3_1.with(Cell::get)
+}
+
+/// Serialize `schema` in 3.1 format. Nullable schemas having a type get _`"null"`_ added to the
+/// type and to the enum values if any. Other nullable schemas are wrapped with _`anyOf`_ allowing
+/// _`null`_.
+pub(super) fn serialize_schema<S: Serializer>(
+    schema: &Schema,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let (nullable, typed) = match schema {
+        Schema::Object(object) => (object.nullable, object.schema_type != SchemaType::Value),
+        Schema::Array(_) => (false, true),
+        Schema::OneOf(one_of) => (one_of.nullable, false),
+        Schema::AllOf(all_of) => (all_of.nullable, false),
+    };
+
+    if nullable && !typed {
+        let mut any_of = serializer.serialize_map(Some(1))?;
+        any_of.serialize_entry(
+            "anyOf",
+            &(
+                SchemaSerialize {
+                    schema,
+                    nullable: false,
+                },
+                json!({"type": "null"}),
+            ),
+        )?;
+        any_of.end()
+    } else {
+        SchemaSerialize { schema, nullable }.serialize(serializer)
+    }
+}
+
+/// Serialize the [`Schema`] with [`SchemaSerializer`].
+struct SchemaSerialize<'s> {
+    schema: &'s Schema,
+    nullable: bool,
+}
+
+impl Serialize for SchemaSerialize<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let serializer = SchemaSerializer {
+            serializer,
+            nullable: self.nullable,
+        };
+
+        match self.schema {
+            Schema::Object(object) => object.serialize(serializer),
+            Schema::Array(array) => array.serialize(serializer),
+            Schema::OneOf(one_of) => one_of.serialize(serializer),
+            Schema::AllOf(all_of) => all_of.serialize(serializer),
+        }
+    }
+}
+
+/// Serializer of a single schema map renaming the fields to 3.1 format.
+struct SchemaSerializer<S> {
+    serializer: S,
+    nullable: bool,
+}
+
+/// Fields of the schema map renamed to 3.1 format by [`SchemaSerializer`].
+struct SchemaFields<M> {
+    map: M,
+    nullable: bool,
+    key: Option<String>,
+}
+
+impl<M: SerializeMap> SerializeMap for SchemaFields<M> {
+    type Ok = M::Ok;
+    type Error = M::Error;
+
+    fn serialize_key<T: ?Sized + Serialize>(&mut self, key: &T) -> Result<(), Self::Error> {
+        match serde_json::to_value(key).map_err(M::Error::custom)? {
+            Value::String(key) => self.key = Some(key),
+            key => return Err(M::Error::custom(format!("expected string key, got: {key}"))),
+        }
+
+        Ok(())
+    }
+
+    fn serialize_value<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Self::Error> {
+        let key = self
+            .key
+            .take()
+            .ok_or_else(|| M::Error::custom("schema value serialized without key"))?;
+
+        match (key.as_str(), self.nullable) {
+            ("nullable", _) => Ok(()),
+            ("example", _) => self.map.serialize_entry("examples", &(value,)),
+            ("type", true) => self.map.serialize_entry("type", &(value, "null")),
+            ("enum", true) => {
+                let mut values = serde_json::to_value(value).map_err(M::Error::custom)?;
+                if let Value::Array(values) = &mut values {
+                    values.push(Value::Null);
+                }
+                self.map.serialize_entry("enum", &values)
+            }
+            (key, _) => self.map.serialize_entry(key, value),
+        }
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.map.end()
+    }
+}
+
+macro_rules! unsupported {
+    ( $( $name:ident ( $( $arg:ty ),* ) -> $ok:ty ),* ) => {
+        $(
+            fn $name(self, $( _: $arg ),*) -> Result<$ok, Self::Error> {
+                Err(S::Error::custom("schema must be serialized as map"))
+            }
+        )*
+    };
+}
+
+impl<S: Serializer> Serializer for SchemaSerializer<S> {
+    type Ok = S::Ok;
+    type Error = S::Error;
+    type SerializeSeq = Impossible<S::Ok, S::Error>;
+    type SerializeTuple = Impossible<S::Ok, S::Error>;
+    type SerializeTupleStruct = Impossible<S::Ok, S::Error>;
+    type SerializeTupleVariant = Impossible<S::Ok, S::Error>;
+    type SerializeMap = SchemaFields<S::SerializeMap>;
+    type SerializeStruct = Impossible<S::Ok, S::Error>;
+    type SerializeStructVariant = Impossible<S::Ok, S::Error>;
+
+    fn serialize_map(self, len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        Ok(SchemaFields {
+            map: self.serializer.serialize_map(len)?,
+            nullable: self.nullable,
+            key: None,
+        })
+    }
+
+    unsupported!(
+        serialize_bool(bool) -> S::Ok,
+        serialize_i8(i8) -> S::Ok,
+        serialize_i16(i16) -> S::Ok,
+        serialize_i32(i32) -> S::Ok,
+        serialize_i64(i64) -> S::Ok,
+        serialize_u8(u8) -> S::Ok,
+        serialize_u16(u16) -> S::Ok,
+        serialize_u32(u32) -> S::Ok,
+        serialize_u64(u64) -> S::Ok,
+        serialize_f32(f32) -> S::Ok,
+        serialize_f64(f64) -> S::Ok,
+        serialize_char(char) -> S::Ok,
+        serialize_str(&str) -> S::Ok,
+        serialize_bytes(&[u8]) -> S::Ok,
+        serialize_none() -> S::Ok,
+        serialize_unit() -> S::Ok,
+        serialize_unit_struct(&'static str) -> S::Ok,
+        serialize_unit_variant(&'static str, u32, &'static str) -> S::Ok,
+        serialize_seq(Option<usize>) -> Self::SerializeSeq,
+        serialize_tuple(usize) -> Self::SerializeTuple,
+        serialize_tuple_struct(&'static str, usize) -> Self::SerializeTupleStruct,
+        serialize_tuple_variant(&'static str, u32, &'static str, usize) -> Self::SerializeTupleVariant,
+        serialize_struct(&'static str, usize) -> Self::SerializeStruct,
+        serialize_struct_variant(&'static str, u32, &'static str, usize) -> Self::SerializeStructVariant
+    );
+
+    fn serialize_some<T: ?Sized + Serialize>(self, _: &T) -> Result<Self::Ok, Self::Error> {
+        Err(S::Error::custom("schema must be serialized as map"))
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        _: &'static str,
+        _: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        Err(S::Error::custom("schema must be serialized as map"))
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        Err(S::Error::custom("schema must be serialized as map"))
+    }
+}
+
+/// Revert fields of serialized 3.1 schema to 3.0 format used by the model. Sub schemas are
+/// reverted when they are deserialized.
+pub(super) fn revert_schema(value: &mut Value) {
+    let null = json!({"type": "null"});
+    let schema = match value {
+        Value::Object(schema) => schema,
+        _ => return,
+    };
+
+    if let Some(Value::Array(any_of)) = schema.get("anyOf") {
+        if let (1, [other, null_schema]) = (schema.len(), any_of.as_slice()) {
+            if *null_schema == null {
+                *value = other.clone();
+                revert_schema(value);
+                if let Value::Object(schema) = value {
+                    schema.insert("nullable".to_string(), Value::Bool(true));
+                }
+                return;
+            }
+        }
+    }
+
+    if let Some(Value::Array(examples)) = schema.remove("examples") {
+        if let Some(example) = examples.into_iter().next() {
+            schema.insert("example".to_string(), example);
+        }
+    }
+
+    if let Some(Value::Array(types)) = schema.get_mut("type") {
+        let nullable = types.iter().any(|schema_type| schema_type == "null");
+        types.retain(|schema_type| schema_type != "null");
+        if let [schema_type] = types.as_slice() {
+            let schema_type = schema_type.clone();
+            schema.insert("type".to_string(), schema_type);
+        }
+        if nullable {
+            if let Some(Value::Array(values)) = schema.get_mut("enum") {
+                values.retain(|value| !value.is_null());
+            }
+            schema.insert("nullable".to_string(), Value::Bool(true));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::openapi::{
+        schema::{AllOfBuilder, ArrayBuilder, ObjectBuilder, OneOfBuilder},
+        Ref, RefOr,
+    };
+
+    use super::*;
+
+    fn to_value_3_1(schema: &Schema) -> Value {
+        with_version_3_1(true, || serde_json::to_value(schema)).unwrap()
+    }
+
+    fn from_value_3_1(value: Value) -> Schema {
+        with_version_3_1(true, || serde_json::from_value(value)).unwrap()
+    }
+
+    #[test]
+    fn convert_nullable_and_example_of_nested_schemas() {
+        let schema: Schema = ObjectBuilder::new()
+            .property(
+                "name",
+                ObjectBuilder::new()
+                    .schema_type(SchemaType::String)
+                    .nullable(true)
+                    .enum_values(Some(["Milo", "Nala"]))
+                    .example(Some(json!("Milo"))),
+            )
+            .property(
+                "owner",
+                AllOfBuilder::new()
+                    .item(Ref::from_schema_name("Owner"))
+                    .nullable(true),
+            )
+            .property(
+                "kind",
+                OneOfBuilder::new()
+                    .item(ObjectBuilder::new().schema_type(SchemaType::String))
+                    .nullable(true),
+            )
+            .property(
+                "tags",
+                ArrayBuilder::new()
+                    .items(ObjectBuilder::new().schema_type(SchemaType::String))
+                    .example(Some(json!(["a"]))),
+            )
+            .property(
+                "any",
+                ObjectBuilder::new()
+                    .schema_type(SchemaType::Value)
+                    .nullable(true),
+            )
+            .property(
+                "example",
+                ObjectBuilder::new()
+                    .schema_type(SchemaType::Object)
+                    .example(Some(json!({ "schema": { "nullable": true } }))),
+            )
+            .into();
+
+        let value = to_value_3_1(&schema);
+
+        assert_eq!(
+            value,
+            json!({
+                "type": "object",
+                "properties": {
+                    "name": {
+                        "type": ["string", "null"],
+                        "enum": ["Milo", "Nala", null],
+                        "examples": ["Milo"]
+                    },
+                    "owner": {
+                        "anyOf": [
+                            {"allOf": [{"$ref": "#/components/schemas/Owner"}]},
+                            {"type": "null"}
+                        ]
+                    },
+                    "kind": {
+                        "anyOf": [
+                            {"oneOf": [{"type": "string"}]},
+                            {"type": "null"}
+                        ]
+                    },
+                    "tags": {
+                        "type": "array",
+                        "items": {"type": "string"},
+                        "examples": [["a"]]
+                    },
+                    "any": {
+                        "anyOf": [{}, {"type": "null"}]
+                    },
+                    "example": {
+                        "type": "object",
+                        "examples": [{ "schema": { "nullable": true } }]
+                    }
+                }
+            })
+        );
+        assert!(from_value_3_1(value) == schema);
+        assert_eq!(
+            serde_json::to_value(RefOr::T(schema)).unwrap()["properties"]["name"],
+            json!({
+                "type": "string",
+                "nullable": true,
+                "enum": ["Milo", "Nala"],
+                "example": "Milo"
+            })
+        );
+    }
+}
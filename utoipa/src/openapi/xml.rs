@@ -5,7 +5,11 @@ use std::borrow::Cow;
 
 use serde::{Deserialize, Serialize};
 
-use super::{builder, set_value};
+use super::{
+    builder,
+    extensions::{self, Extensions},
+    set_value,
+};
 
 builder! {
     /// # Examples
@@ -53,6 +57,14 @@ builder! {
         /// `<pets><pet></pet></pets>` instead of unwrapped `<pet></pet>`.
         #[serde(skip_serializing_if = "Option::is_none")]
         pub wrapped: Option<bool>,
+
+        /// Optional extensions _`x-something`_ of the [`Xml`].
+        #[serde(
+            skip_serializing_if = "Option::is_none",
+            flatten,
+            deserialize_with = "extensions::deserialize_optional"
+        )]
+        pub extensions: Option<Extensions>,
     }
 }
 
@@ -100,6 +112,11 @@ impl XmlBuilder {
     pub fn wrapped(mut self, wrapped: Option<bool>) -> Self {
         set_value!(self wrapped wrapped)
     }
+
+    /// Add openapi extensions (`x-something`) of the [`Xml`].
+    pub fn extensions(mut self, extensions: Option<Extensions>) -> Self {
+        set_value!(self extensions extensions)
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,250 @@
+//! Implements framework independent [`ServeOpenApi`] for serving OpenAPI docs and [`MountDocs`]
+//! for mounting them to web frameworks.
+//!
+//! Documentation UIs such as _`utoipa-rapidoc`_ and _`utoipa-redoc`_ implement [`ServeOpenApi`]
+//! once without depending on any web framework. [`MountDocs`] is implemented for each supported
+//! framework version behind its own feature flag thus new framework versions can be supported
+//! by adding a feature without forking the integrations of the documentation UIs or breaking
+//! users of the older versions.
+//!
+//! **Currently implemented for:**
+//!
+//! * **axum_06** `axum::Router` of axum `version 0.6`
+//! * **axum_07** `axum::Router` of axum `version 0.7`
+//! * **actix_web_3** `web::ServiceConfig` and `Scope` of actix-web `version 3`
+//! * **actix_web_4** `web::ServiceConfig` and `Scope` of actix-web `version 4`
+//!
+//! # Examples
+//!
+//! _**Implement [`ServeOpenApi`] serving the api doc as JSON and mount it to axum 0.7 router with
+//! `axum_07` feature.**_
+//! ```rust
+//! # use std::borrow::Cow;
+//! # use utoipa::openapi::{Info, OpenApi, Paths};
+//! use utoipa::serve::{Document, MountDocs, ServeOpenApi};
+//! # #[cfg(feature = "axum_07")]
+//! # use axum_07 as axum;
+//!
+//! struct JsonDoc(OpenApi);
+//!
+//! impl ServeOpenApi for JsonDoc {
+//!     fn paths(&self) -> Vec<Cow<'static, str>> {
+//!         vec![Cow::Borrowed("/api-docs/openapi.json")]
+//!     }
+//!
+//!     fn serve(&self, _: &str) -> Option<Document> {
+//!         Some(Document::json(&self.0))
+//!     }
+//! }
+//!
+//! # #[cfg(feature = "axum_07")]
+//! let app = axum::Router::<()>::new().mount_docs(JsonDoc(OpenApi::new(
+//!     Info::new("Pet api", "1.0.0"),
+//!     Paths::new(),
+//! )));
+//! ```
+use std::borrow::Cow;
+
+use crate::openapi::OpenApi;
+
+use self::auth::Guard;
+
+mod actix_web_3;
+mod actix_web_4;
+pub mod auth;
+mod axum_06;
+mod axum_07;
+pub mod csp;
+
+/// OpenAPI docs served with _`GET`_ requests from fixed paths e.g. documentation UI and the api
+/// doc it loads.
+pub trait ServeOpenApi: Send + Sync + 'static {
+    /// Paths the docs are served from e.g. _`"/rapidoc"`_ and _`"/api-docs/openapi.json"`_.
+    fn paths(&self) -> Vec<Cow<'static, str>>;
+
+    /// Create [`Document`] served from `path` which is one of the [`ServeOpenApi::paths`].
+    /// Returning `None` responds with _`404 Not Found`_.
+    fn serve(&self, path: &str) -> Option<Document>;
+
+    /// [`Guard`] checked for each request to any of the [`ServeOpenApi::paths`]. Requests not
+    /// passing it are responded with _`401 Unauthorized`_. By default the docs are not guarded.
+    fn guard(&self) -> Option<&Guard> {
+        None
+    }
+}
+
+/// Document served by [`ServeOpenApi`] with its content type.
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Document {
+    /// Value of the _`Content-Type`_ header of the response e.g. _`"text/html"`_.
+    pub content_type: &'static str,
+
+    /// Body of the response.
+    pub body: Cow<'static, [u8]>,
+
+    /// Additional headers of the response as _`(name, value)`_ pairs e.g.
+    /// _`("content-security-policy", "script-src 'nonce-abc'")`_.
+    pub headers: Vec<(&'static str, String)>,
+}
+
+impl Document {
+    /// Construct a new [`Document`] with `content_type` and `body`.
+    pub fn new<B: Into<Cow<'static, [u8]>>>(content_type: &'static str, body: B) -> Self {
+        Self {
+            content_type,
+            body: body.into(),
+            headers: Vec::new(),
+        }
+    }
+
+    /// Add header with `name` and `value` to the response of this [`Document`]. Header name must be
+    /// in lower case e.g. _`"cache-control"`_.
+    pub fn header<V: Into<String>>(mut self, name: &'static str, value: V) -> Self {
+        self.headers.push((name, value.into()));
+
+        self
+    }
+
+    /// Add _`Content-Security-Policy`_ header allowing only the scripts and styles with `nonce` to
+    /// the response of this [`Document`]. See [`csp::content_security_policy`].
+    pub fn content_security_policy(self, nonce: &str) -> Self {
+        self.header(
+            "content-security-policy",
+            csp::content_security_policy(nonce),
+        )
+    }
+
+    /// Construct a new _`text/html`_ [`Document`].
+    pub fn html<H: Into<String>>(html: H) -> Self {
+        Self::new("text/html; charset=utf-8", html.into().into_bytes())
+    }
+
+    /// Construct a new _`application/json`_ [`Document`] from [`OpenApi`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the [`OpenApi`] cannot be serialized to JSON.
+    pub fn json(openapi: &OpenApi) -> Self {
+        let json = openapi.to_json().expect("OpenApi should serialize to JSON");
+
+        Self::new("application/json", json.into_bytes())
+    }
+}
+
+/// Mount [`ServeOpenApi`] docs to a router or service configuration of a web framework.
+///
+/// Each of the [`ServeOpenApi::paths`] is routed for _`GET`_ requests as is. Requests not passing
+/// the [`ServeOpenApi::guard`] are responded with _`401 Unauthorized`_.
+///
+/// Implemented for each framework version supported by the _`axum_06`_, _`axum_07`_,
+/// _`actix_web_3`_ and _`actix_web_4`_ features thus documentation UIs implementing
+/// [`ServeOpenApi`] such as _`utoipa-swagger-ui`_, _`utoipa-rapidoc`_ and _`utoipa-redoc`_ can be
+/// mounted to any of them without depending on the framework version themselves.
+pub trait MountDocs: Sized {
+    /// Mount `docs` and return the mounted router or service configuration.
+    fn mount_docs<D: ServeOpenApi>(self, docs: D) -> Self;
+}
+
+/// Outcome of a request to one of the [`ServeOpenApi::paths`] converted to a response by each
+/// [`MountDocs`] implementation.
+#[cfg(any(
+    feature = "axum_06",
+    feature = "axum_07",
+    feature = "actix_web_3",
+    feature = "actix_web_4"
+))]
+enum Outcome {
+    Document(Document),
+    NotFound,
+    /// Request did not pass the [`ServeOpenApi::guard`], holds headers of the response.
+    Unauthorized(Vec<(&'static str, String)>),
+}
+
+/// Serve `path` of the `docs` to request with `request_path` and `headers` checking the
+/// [`ServeOpenApi::guard`] first.
+#[cfg(any(
+    feature = "axum_06",
+    feature = "axum_07",
+    feature = "actix_web_3",
+    feature = "actix_web_4"
+))]
+async fn serve_request<'a, D, H>(docs: &D, path: &str, request_path: &str, headers: H) -> Outcome
+where
+    D: ServeOpenApi,
+    H: IntoIterator<Item = (&'a str, &'a [u8])>,
+{
+    if let Some(guard) = docs.guard() {
+        if !guard
+            .authorize(auth::Request::new(request_path, headers))
+            .await
+        {
+            return Outcome::Unauthorized(guard.unauthorized_headers());
+        }
+    }
+
+    docs.serve(path)
+        .map_or(Outcome::NotFound, Outcome::Document)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::openapi::{Info, Paths};
+
+    use super::*;
+
+    /// Docs serving HTML and JSON documents and responding `None` for _`"/missing"`_, guarded with
+    /// the [`Guard`] if present.
+    #[cfg(any(
+        feature = "axum_06",
+        feature = "axum_07",
+        feature = "actix_web_3",
+        feature = "actix_web_4"
+    ))]
+    pub(super) struct TestDocs(pub(super) Option<Guard>);
+
+    #[cfg(any(
+        feature = "axum_06",
+        feature = "axum_07",
+        feature = "actix_web_3",
+        feature = "actix_web_4"
+    ))]
+    impl ServeOpenApi for TestDocs {
+        fn paths(&self) -> Vec<Cow<'static, str>> {
+            ["/docs", "/api-docs/openapi.json", "/missing"]
+                .into_iter()
+                .map(Cow::Borrowed)
+                .collect()
+        }
+
+        fn serve(&self, path: &str) -> Option<Document> {
+            match path {
+                "/docs" => {
+                    Some(Document::html("<html></html>").header("cache-control", "no-cache"))
+                }
+                "/api-docs/openapi.json" => Some(Document::json(&OpenApi::new(
+                    Info::new("title", "1.0.0"),
+                    Paths::new(),
+                ))),
+                _ => None,
+            }
+        }
+
+        fn guard(&self) -> Option<&Guard> {
+            self.0.as_ref()
+        }
+    }
+
+    #[test]
+    fn document_with_content_type() {
+        let html = Document::html("<html></html>");
+        assert_eq!(html.content_type, "text/html; charset=utf-8");
+        assert_eq!(html.body.as_ref(), b"<html></html>");
+
+        assert!(html.headers.is_empty());
+
+        let json = Document::json(&OpenApi::new(Info::new("title", "1.0.0"), Paths::new()));
+        assert_eq!(json.content_type, "application/json");
+        assert!(String::from_utf8_lossy(&json.body).contains(r#""title":"title""#));
+    }
+}
@@ -0,0 +1,179 @@
+#![cfg(feature = "actix_web_3")]
+
+use std::sync::Arc;
+
+use actix_web_3::{
+    web::{self, ServiceConfig},
+    HttpRequest, HttpResponse, Resource, Scope,
+};
+
+use super::{MountDocs, Outcome, ServeOpenApi};
+
+#[cfg_attr(doc_cfg, doc(cfg(feature = "actix_web_3")))]
+impl MountDocs for &mut ServiceConfig {
+    fn mount_docs<D: ServeOpenApi>(self, docs: D) -> Self {
+        for resource in resources(docs) {
+            self.service(resource);
+        }
+
+        self
+    }
+}
+
+#[cfg_attr(doc_cfg, doc(cfg(feature = "actix_web_3")))]
+impl MountDocs for Scope {
+    fn mount_docs<D: ServeOpenApi>(self, docs: D) -> Self {
+        resources(docs).fold(self, Scope::service)
+    }
+}
+
+/// Create _`GET`_ [`Resource`] for each of the paths of the `docs`.
+fn resources<D: ServeOpenApi>(docs: D) -> impl Iterator<Item = Resource> {
+    let docs = Arc::new(docs);
+
+    docs.paths().into_iter().map(move |path| {
+        // HttpResponse of actix-web 3 is a future itself but it is responded as is.
+        #[allow(clippy::async_yields_async)]
+        let handler = {
+            let docs = Arc::clone(&docs);
+            let path = path.clone();
+            move |request: HttpRequest| {
+                let docs = Arc::clone(&docs);
+                let path = path.clone();
+                async move {
+                    let headers = request
+                        .headers()
+                        .iter()
+                        .map(|(name, value)| (name.as_str(), value.as_bytes()));
+
+                    respond(
+                        super::serve_request(docs.as_ref(), &path, request.path(), headers).await,
+                    )
+                }
+            }
+        };
+
+        web::resource(path.as_ref()).route(web::get().to(handler))
+    })
+}
+
+fn respond(outcome: Outcome) -> HttpResponse {
+    match outcome {
+        Outcome::Document(document) => {
+            let mut response = HttpResponse::Ok();
+            response.content_type(document.content_type);
+            for (name, value) in document.headers {
+                response.header(name, value);
+            }
+
+            response.body(document.body.into_owned())
+        }
+        Outcome::NotFound => HttpResponse::NotFound().finish(),
+        Outcome::Unauthorized(headers) => {
+            let mut response = HttpResponse::Unauthorized();
+            for (name, value) in headers {
+                response.header(name, value);
+            }
+
+            response.finish()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web_3::{
+        http::{header, StatusCode},
+        rt::System,
+        test::{call_service, init_service, read_body, TestRequest},
+        App,
+    };
+
+    use super::*;
+    use crate::serve::{auth::Guard, tests::TestDocs};
+
+    #[test]
+    fn mount_docs_to_service_config_and_scope() {
+        System::new("test").block_on(async {
+            let mut app = init_service(
+                App::new()
+                    .configure(|config| {
+                        config.mount_docs(TestDocs(None));
+                    })
+                    .service(web::scope("/api").mount_docs(TestDocs(None))),
+            )
+            .await;
+
+            for prefix in ["", "/api"] {
+                let response = call_service(
+                    &mut app,
+                    TestRequest::get()
+                        .uri(&format!("{prefix}/docs"))
+                        .to_request(),
+                )
+                .await;
+                assert_eq!(response.status(), StatusCode::OK);
+                assert_eq!(
+                    response.headers().get(header::CONTENT_TYPE).unwrap(),
+                    "text/html; charset=utf-8"
+                );
+                assert_eq!(
+                    response.headers().get(header::CACHE_CONTROL).unwrap(),
+                    "no-cache"
+                );
+                assert_eq!(read_body(response).await, "<html></html>".as_bytes());
+
+                let response = call_service(
+                    &mut app,
+                    TestRequest::get()
+                        .uri(&format!("{prefix}/api-docs/openapi.json"))
+                        .to_request(),
+                )
+                .await;
+                assert_eq!(response.status(), StatusCode::OK);
+                assert_eq!(
+                    response.headers().get(header::CONTENT_TYPE).unwrap(),
+                    "application/json"
+                );
+
+                let response = call_service(
+                    &mut app,
+                    TestRequest::get()
+                        .uri(&format!("{prefix}/missing"))
+                        .to_request(),
+                )
+                .await;
+                assert_eq!(response.status(), StatusCode::NOT_FOUND);
+            }
+        });
+    }
+
+    #[test]
+    fn mount_guarded_docs() {
+        System::new("test").block_on(async {
+            let mut app = init_service(
+                App::new()
+                    .service(web::scope("/api").mount_docs(TestDocs(Some(Guard::bearer("token"))))),
+            )
+            .await;
+
+            let response =
+                call_service(&mut app, TestRequest::get().uri("/api/docs").to_request()).await;
+            assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+            assert_eq!(
+                response.headers().get(header::WWW_AUTHENTICATE).unwrap(),
+                "Bearer"
+            );
+
+            let response = call_service(
+                &mut app,
+                TestRequest::get()
+                    .uri("/api/docs")
+                    .header(header::AUTHORIZATION, "Bearer token")
+                    .to_request(),
+            )
+            .await;
+            assert_eq!(response.status(), StatusCode::OK);
+        });
+    }
+}
@@ -0,0 +1,216 @@
+//! Implements [`Guard`] for protecting served OpenAPI docs e.g. in production.
+//!
+//! The guard of [`ServeOpenApi::guard`][super::ServeOpenApi::guard] is checked by
+//! [`MountDocs`][super::MountDocs] for each request before serving the docs. Requests not passing
+//! the guard are responded with _`401 Unauthorized`_. Framework integrations check the guard with
+//! [`Guard::authorize`].
+
+use std::{
+    borrow::Cow,
+    future::{ready, Future},
+    pin::Pin,
+    sync::Arc,
+};
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+
+type Predicate = dyn Fn(Request) -> Pin<Box<dyn Future<Output = bool> + Send>> + Send + Sync;
+
+/// Guard deciding whether a request is allowed to access documentation UI or api docs.
+///
+/// # Examples
+///
+/// _**Protect the docs with basic auth.**_
+/// ```rust
+/// # use utoipa::serve::auth::Guard;
+/// let guard = Guard::basic("admin", "secret");
+/// ```
+///
+/// _**Allow only requests with api key.**_
+/// ```rust
+/// # use utoipa::serve::auth::Guard;
+/// let guard = Guard::new(|request| async move { request.header("x-api-key") == Some("key") });
+/// ```
+#[derive(Clone)]
+pub struct Guard {
+    predicate: Arc<Predicate>,
+    challenge: Option<Cow<'static, str>>,
+}
+
+impl Guard {
+    /// Construct a new [`Guard`] allowing requests for which the async `predicate` resolves to
+    /// `true`.
+    pub fn new<F, Fut>(predicate: F) -> Self
+    where
+        F: Fn(Request) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = bool> + Send + 'static,
+    {
+        Self {
+            predicate: Arc::new(move |request| Box::pin(predicate(request))),
+            challenge: None,
+        }
+    }
+
+    /// Construct a new [`Guard`] allowing requests with basic auth _`Authorization`_ header
+    /// matching the `username` and `password`.
+    ///
+    /// Unauthorized responses have basic auth challenge thus browsers prompt for the credentials.
+    pub fn basic<U: AsRef<str>, P: AsRef<str>>(username: U, password: P) -> Self {
+        let credentials = STANDARD.encode(format!("{}:{}", username.as_ref(), password.as_ref()));
+
+        Self::new(move |request| {
+            ready(
+                request.credentials("Basic").is_some_and(|value| {
+                    constant_time_eq(value.as_bytes(), credentials.as_bytes())
+                }),
+            )
+        })
+        .challenge(r#"Basic realm="API docs", charset="UTF-8""#)
+    }
+
+    /// Construct a new [`Guard`] allowing requests with bearer _`Authorization`_ header matching
+    /// the `token`.
+    pub fn bearer<T: Into<String>>(token: T) -> Self {
+        let token = token.into();
+
+        Self::new(move |request| {
+            ready(
+                request
+                    .credentials("Bearer")
+                    .is_some_and(|value| constant_time_eq(value.as_bytes(), token.as_bytes())),
+            )
+        })
+        .challenge("Bearer")
+    }
+
+    /// Set _`WWW-Authenticate`_ header value of unauthorized responses e.g. _`"Bearer"`_.
+    pub fn challenge<C: Into<Cow<'static, str>>>(mut self, challenge: C) -> Self {
+        self.challenge = Some(challenge.into());
+
+        self
+    }
+
+    /// Check whether the `request` is allowed by this [`Guard`].
+    pub async fn authorize(&self, request: Request) -> bool {
+        (self.predicate)(request).await
+    }
+
+    /// Headers of the _`401 Unauthorized`_ response of requests not allowed by this [`Guard`].
+    pub fn unauthorized_headers(&self) -> Vec<(&'static str, String)> {
+        self.challenge
+            .iter()
+            .map(|challenge| ("www-authenticate", challenge.to_string()))
+            .collect()
+    }
+}
+
+/// Request checked by [`Guard`].
+#[non_exhaustive]
+#[derive(Clone, Debug)]
+pub struct Request {
+    path: String,
+    headers: Vec<(String, String)>,
+}
+
+impl Request {
+    /// Construct a new [`Request`] with `path` and `headers` as _`(name, value)`_ pairs. Headers
+    /// with non UTF-8 value are ignored.
+    pub fn new<'a, H>(path: &str, headers: H) -> Self
+    where
+        H: IntoIterator<Item = (&'a str, &'a [u8])>,
+    {
+        Self {
+            path: path.to_string(),
+            headers: headers
+                .into_iter()
+                .filter_map(|(name, value)| {
+                    std::str::from_utf8(value)
+                        .ok()
+                        .map(|value| (name.to_ascii_lowercase(), value.to_string()))
+                })
+                .collect(),
+        }
+    }
+
+    /// Get path of the request.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Get value of the header by case insensitive `name`. Only the first value is returned if
+    /// there are multiple headers with the same name.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(header, _)| header.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Get credentials of the _`Authorization`_ header with given auth `scheme` e.g. _`"Bearer"`_.
+    pub fn credentials(&self, scheme: &str) -> Option<&str> {
+        self.header("authorization")
+            .and_then(|authorization| authorization.split_once(' '))
+            .filter(|(value_scheme, _)| value_scheme.eq_ignore_ascii_case(scheme))
+            .map(|(_, credentials)| credentials.trim())
+    }
+}
+
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |diff, (a, b)| diff | (a ^ b)) == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(authorization: &str) -> Request {
+        Request::new(
+            "/docs/",
+            [
+                ("Authorization", authorization.as_bytes()),
+                ("X-Api-Key", b"key".as_slice()),
+            ],
+        )
+    }
+
+    #[tokio::test]
+    async fn authorize_with_basic_auth() {
+        let guard = Guard::basic("admin", "secret");
+
+        assert!(guard.authorize(request("Basic YWRtaW46c2VjcmV0")).await);
+        assert!(guard.authorize(request("basic  YWRtaW46c2VjcmV0")).await);
+        assert!(!guard.authorize(request("Basic YWRtaW46c2VjcmV1")).await);
+        assert!(!guard.authorize(request("Bearer YWRtaW46c2VjcmV0")).await);
+        assert_eq!(
+            guard.unauthorized_headers(),
+            [(
+                "www-authenticate",
+                r#"Basic realm="API docs", charset="UTF-8""#.to_string()
+            )]
+        );
+    }
+
+    #[tokio::test]
+    async fn authorize_with_bearer_token() {
+        let guard = Guard::bearer("token");
+
+        assert!(guard.authorize(request("Bearer token")).await);
+        assert!(!guard.authorize(request("Bearer token2")).await);
+        assert!(!guard.authorize(request("token")).await);
+    }
+
+    #[tokio::test]
+    async fn authorize_with_predicate() {
+        let guard = Guard::new(|request| async move {
+            request.path().starts_with("/docs") && request.header("x-api-key") == Some("key")
+        });
+
+        assert!(guard.authorize(request("")).await);
+        assert!(
+            !guard
+                .authorize(Request::new("/docs/", std::iter::empty()))
+                .await
+        );
+        assert!(guard.unauthorized_headers().is_empty());
+    }
+}
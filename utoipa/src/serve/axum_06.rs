@@ -0,0 +1,120 @@
+#![cfg(feature = "axum_06")]
+
+use std::sync::Arc;
+
+use axum_06::{
+    body::HttpBody,
+    http::{header, HeaderMap, StatusCode, Uri},
+    response::{AppendHeaders, IntoResponse, Response},
+    routing, Router,
+};
+
+use super::{MountDocs, Outcome, ServeOpenApi};
+
+#[cfg_attr(doc_cfg, doc(cfg(feature = "axum_06")))]
+impl<S, B> MountDocs for Router<S, B>
+where
+    S: Clone + Send + Sync + 'static,
+    B: HttpBody + Send + 'static,
+{
+    fn mount_docs<D: ServeOpenApi>(self, docs: D) -> Self {
+        let docs = Arc::new(docs);
+
+        docs.paths().into_iter().fold(self, |router, path| {
+            let handler = {
+                let docs = Arc::clone(&docs);
+                let path = path.clone();
+                move |uri: Uri, headers: HeaderMap| async move {
+                    let headers = headers
+                        .iter()
+                        .map(|(name, value)| (name.as_str(), value.as_bytes()));
+
+                    respond(super::serve_request(docs.as_ref(), &path, uri.path(), headers).await)
+                }
+            };
+
+            router.route(&path, routing::get(handler))
+        })
+    }
+}
+
+fn respond(outcome: Outcome) -> Response {
+    match outcome {
+        Outcome::Document(document) => (
+            [(header::CONTENT_TYPE, document.content_type)],
+            AppendHeaders(document.headers),
+            document.body,
+        )
+            .into_response(),
+        Outcome::NotFound => StatusCode::NOT_FOUND.into_response(),
+        Outcome::Unauthorized(headers) => {
+            (StatusCode::UNAUTHORIZED, AppendHeaders(headers)).into_response()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum_06::{
+        body::Body,
+        http::{HeaderMap, Request},
+    };
+    use tower::ServiceExt;
+
+    use super::*;
+    use crate::serve::{auth::Guard, tests::TestDocs};
+
+    async fn get(router: Router, uri: &str) -> (StatusCode, HeaderMap, String) {
+        let response = router
+            .oneshot(Request::get(uri).body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        let status = response.status();
+        let headers = response.headers().clone();
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+
+        (status, headers, String::from_utf8(body.to_vec()).unwrap())
+    }
+
+    #[tokio::test]
+    async fn mount_docs_to_router() {
+        let router = Router::new().mount_docs(TestDocs(None));
+
+        let (status, headers, html) = get(router.clone(), "/docs").await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(headers[header::CONTENT_TYPE], "text/html; charset=utf-8");
+        assert_eq!(headers[header::CACHE_CONTROL], "no-cache");
+        assert_eq!(html, "<html></html>");
+
+        let (status, headers, json) = get(router.clone(), "/api-docs/openapi.json").await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(headers[header::CONTENT_TYPE], "application/json");
+        assert!(json.contains(r#""title":"title""#));
+
+        assert_eq!(
+            get(router.clone(), "/missing").await.0,
+            StatusCode::NOT_FOUND
+        );
+        assert_eq!(get(router, "/other").await.0, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn mount_guarded_docs_to_router() {
+        let router = Router::new().mount_docs(TestDocs(Some(Guard::bearer("token"))));
+
+        let (status, headers, _) = get(router.clone(), "/docs").await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+        assert_eq!(headers[header::WWW_AUTHENTICATE], "Bearer");
+
+        let response = router
+            .oneshot(
+                Request::get("/docs")
+                    .header(header::AUTHORIZATION, "Bearer token")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+}
@@ -0,0 +1,126 @@
+//! Implements [`Nonce`] for serving documentation UIs under strict _`Content-Security-Policy`_.
+//!
+//! The nonce is attached to the _`<script>`_, _`<style>`_ and stylesheet _`<link>`_ tags of the
+//! HTML page with [`attach_nonce`] and allowed by the _`Content-Security-Policy`_ header created
+//! with [`content_security_policy`] thus only the scripts and styles of the page are run.
+//!
+//! # Examples
+//!
+//! _**Attach new nonce to the page for each request.**_
+//! ```rust
+//! # use utoipa::serve::csp::{self, Nonce};
+//! # fn random_nonce() -> String { String::from("rAnd0m") }
+//! let nonce = Nonce::new(random_nonce);
+//!
+//! let value = nonce.create();
+//! let html = csp::attach_nonce("<script>init()</script>", &value);
+//!
+//! assert_eq!(html, r#"<script nonce="rAnd0m">init()</script>"#);
+//! assert_eq!(
+//!     csp::content_security_policy(&value),
+//!     "script-src 'nonce-rAnd0m'; style-src 'nonce-rAnd0m'"
+//! );
+//! ```
+use std::{borrow::Cow, fmt::Debug, sync::Arc};
+
+/// Nonce of the scripts and styles of a documentation UI page either fixed or created by a
+/// function for each request.
+#[derive(Clone)]
+pub struct Nonce(Arc<dyn Fn() -> String + Send + Sync>);
+
+impl Nonce {
+    /// Construct a new [`Nonce`] creating the value with `nonce_fn` for each request e.g. from
+    /// cryptographically secure random bytes.
+    pub fn new<F: Fn() -> String + Send + Sync + 'static>(nonce_fn: F) -> Self {
+        Self(Arc::new(nonce_fn))
+    }
+
+    /// Create value of the nonce for a request.
+    pub fn create(&self) -> String {
+        (self.0)()
+    }
+}
+
+impl Debug for Nonce {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Nonce").finish()
+    }
+}
+
+impl From<Cow<'static, str>> for Nonce {
+    fn from(nonce: Cow<'static, str>) -> Self {
+        Self::new(move || nonce.to_string())
+    }
+}
+
+impl From<&'static str> for Nonce {
+    fn from(nonce: &'static str) -> Self {
+        Self::from(Cow::Borrowed(nonce))
+    }
+}
+
+impl From<String> for Nonce {
+    fn from(nonce: String) -> Self {
+        Self::from(Cow::<'static, str>::Owned(nonce))
+    }
+}
+
+/// Attach `nonce` to the _`<script>`_, _`<style>`_ and stylesheet _`<link>`_ tags of the `html`.
+/// The `nonce` is escaped with [`escape_html`].
+pub fn attach_nonce(html: &str, nonce: &str) -> String {
+    let nonce = escape_html(nonce);
+
+    html.replace("<script", &format!(r#"<script nonce="{nonce}""#))
+        .replace("<style", &format!(r#"<style nonce="{nonce}""#))
+        .replace(
+            r#"<link rel="stylesheet""#,
+            &format!(r#"<link nonce="{nonce}" rel="stylesheet""#),
+        )
+}
+
+/// Create _`Content-Security-Policy`_ header value allowing only the scripts and styles with the
+/// `nonce`.
+pub fn content_security_policy(nonce: &str) -> String {
+    format!("script-src 'nonce-{nonce}'; style-src 'nonce-{nonce}'")
+}
+
+/// Escape `value` to be placed in HTML text or quoted attribute value.
+pub fn escape_html(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn attach_escaped_nonce_to_scripts_and_styles() {
+        let html = attach_nonce(
+            r#"<link rel="stylesheet" href="./index.css" /><style></style><script src="./index.js"></script>"#,
+            r#"n"0nce"#,
+        );
+
+        assert_eq!(
+            html,
+            r#"<link nonce="n&quot;0nce" rel="stylesheet" href="./index.css" /><style nonce="n&quot;0nce"></style><script nonce="n&quot;0nce" src="./index.js"></script>"#
+        );
+    }
+
+    #[test]
+    fn create_nonce_for_each_request() {
+        let counter = std::sync::atomic::AtomicUsize::new(0);
+        let nonce = Nonce::new(move || {
+            counter
+                .fetch_add(1, std::sync::atomic::Ordering::Relaxed)
+                .to_string()
+        });
+
+        assert_eq!(nonce.create(), "0");
+        assert_eq!(nonce.create(), "1");
+        assert_eq!(Nonce::from("n0nce").create(), "n0nce");
+    }
+}